@@ -21,12 +21,11 @@ use milvus::v2::error::Result;
 use milvus::v2::prelude::*;
 use rand::Rng;
 use serde_json::json;
-use std::collections::BTreeMap;
 use utils::*;
 
 fn sparse_vector(dimension: u32) -> SparseVector {
     let mut rng = rand::thread_rng();
-    let mut vector = BTreeMap::new();
+    let mut vector = SparseVector::new();
     while vector.len() < 8 {
         vector.insert(rng.gen_range(0..dimension), rng.gen_range(0.0..1.0));
     }
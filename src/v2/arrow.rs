@@ -0,0 +1,831 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Apache Arrow interoperability, enabled by the `arrow` feature.
+//!
+//! Insert columns are read from a [`RecordBatch`] by matching column names against a
+//! [`CollectionSchema`], so ambiguous Arrow types such as `FixedSizeBinary` resolve to the vector
+//! type the collection declares:
+//!
+//! | Milvus field | Arrow column |
+//! | --- | --- |
+//! | `Bool`, `Int8`..`Int64`, `Float`, `Double` | matching primitive array |
+//! | `VarChar`, `Json`, `Geometry`, `Timestamptz` | `Utf8`, `LargeUtf8`, or `Utf8View` |
+//! | `Array` | `List` or `LargeList` of the element type |
+//! | `FloatVector`, `Int8Vector` | `FixedSizeList<Float32>`, `FixedSizeList<Int8>` of `dim` |
+//! | `BinaryVector` | `FixedSizeBinary(dim / 8)` |
+//! | `Float16Vector`, `BFloat16Vector` | `FixedSizeBinary(dim * 2)`, little-endian |
+//! | `SparseFloatVector` | `Map<UInt32, Float32>` |
+//!
+//! Arrow validity bitmaps become [`FieldData::with_validity`] columns for nullable fields.
+//! Query and search results convert back with `to_record_batch`, which uses the same mapping and
+//! stores JSON and struct values as JSON text.
+//!
+//! ```no_run
+//! # use milvus::v2::prelude::*;
+//! use milvus::v2::arrow::RecordBatch;
+//!
+//! # fn example(batch: &RecordBatch, schema: &CollectionSchema) -> Result<()> {
+//! let request = InsertRequestBuilder::try_from((batch, schema))?
+//!     .collection_name("books")
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::v2::columnar::{expand, half_word_bytes, half_words, json_texts, nullable_parts, split};
+use crate::v2::error::{Error, Result};
+use crate::v2::request::dml::{InsertRequest, InsertRequestBuilder};
+use crate::v2::types::{
+    CollectionSchema, DataType, FieldData, FieldSchema, Ids, QueryResults, SearchResults,
+    SparseVector,
+};
+use arrow_array::builder::{
+    BooleanBuilder, Float32Builder, ListBuilder, MapBuilder, StringBuilder, UInt32Builder,
+};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt32Type,
+};
+use arrow_array::{
+    Array, ArrayRef, ArrowPrimitiveType, BooleanArray, FixedSizeBinaryArray, FixedSizeListArray,
+    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, ListArray,
+    PrimitiveArray, RecordBatchOptions, StringArray, UInt32Array,
+};
+use arrow_schema::{Field, Schema};
+use std::sync::Arc;
+
+pub use arrow_array::RecordBatch;
+
+///////////////////////////////////////////////////////////////////////////////
+// RecordBatch -> FieldData
+///////////////////////////////////////////////////////////////////////////////
+
+/// Converts every column of `batch` into insert columns typed by the matching schema fields.
+pub fn record_batch_to_columns(
+    batch: &RecordBatch,
+    schema: &CollectionSchema,
+) -> Result<Vec<FieldData>> {
+    batch
+        .schema()
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(column, array)| {
+            let field = schema
+                .fields
+                .iter()
+                .find(|field| field.name == *column.name())
+                .ok_or_else(|| {
+                    Error::validation(
+                        column.name().clone(),
+                        "column is not present in collection schema".into(),
+                    )
+                })?;
+            column_from_arrow(field, array.as_ref())
+        })
+        .collect()
+}
+
+impl TryFrom<(&RecordBatch, &CollectionSchema)> for InsertRequestBuilder {
+    type Error = Error;
+
+    /// Starts an insert request whose columns are read from an Arrow record batch.
+    fn try_from((batch, schema): (&RecordBatch, &CollectionSchema)) -> Result<Self> {
+        Ok(InsertRequest::builder().columns(record_batch_to_columns(batch, schema)?))
+    }
+}
+
+fn column_from_arrow(field: &FieldSchema, array: &dyn Array) -> Result<FieldData> {
+    let name = field.name.clone();
+    let (column, validity) = match field.data_type {
+        DataType::Bool => {
+            let (values, validity) = split(booleans(&name, array)?);
+            (FieldData::boolean(name, values), validity)
+        }
+        DataType::Int8 => {
+            let (values, validity) = split(primitives::<Int8Type>(&name, array)?);
+            (FieldData::int8(name, values), validity)
+        }
+        DataType::Int16 => {
+            let (values, validity) = split(primitives::<Int16Type>(&name, array)?);
+            (FieldData::int16(name, values), validity)
+        }
+        DataType::Int32 => {
+            let (values, validity) = split(primitives::<Int32Type>(&name, array)?);
+            (FieldData::int32(name, values), validity)
+        }
+        DataType::Int64 => {
+            let (values, validity) = split(primitives::<Int64Type>(&name, array)?);
+            (FieldData::int64(name, values), validity)
+        }
+        DataType::Float => {
+            let (values, validity) = split(primitives::<Float32Type>(&name, array)?);
+            (FieldData::float(name, values), validity)
+        }
+        DataType::Double => {
+            let (values, validity) = split(primitives::<Float64Type>(&name, array)?);
+            (FieldData::double(name, values), validity)
+        }
+        DataType::VarChar => {
+            let (values, validity) = split(strings(&name, array)?);
+            (FieldData::varchar(name, values), validity)
+        }
+        DataType::Geometry => {
+            let (values, validity) = split(strings(&name, array)?);
+            (FieldData::geometry(name, values), validity)
+        }
+        DataType::Timestamptz => {
+            let (values, validity) = split(strings(&name, array)?);
+            (FieldData::timestamptz(name, values), validity)
+        }
+        DataType::Json => {
+            let (values, validity) = split(strings(&name, array)?);
+            let values = values
+                .iter()
+                .map(|value| serde_json::from_str(value))
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|error| {
+                    Error::validation(name.clone(), format!("invalid JSON: {error}"))
+                })?;
+            (FieldData::json(name, values), validity)
+        }
+        DataType::Array => array_column(field, array)?,
+        DataType::FloatVector => {
+            let dim = field.get_dimension() as usize;
+            let rows = fixed_size_list::<Float32Type>(&name, array, dim)?;
+            let (values, validity) = split(rows);
+            (FieldData::float_vector(name, values), validity)
+        }
+        DataType::Int8Vector => {
+            let dim = field.get_dimension() as usize;
+            let rows = fixed_size_list::<Int8Type>(&name, array, dim)?;
+            let (values, validity) = split(rows);
+            (FieldData::int8_vector(name, values), validity)
+        }
+        DataType::BinaryVector => {
+            let bytes = (field.get_dimension() as usize).div_ceil(8);
+            let (values, validity) = split(fixed_size_binary(&name, array, bytes)?);
+            (FieldData::binary_vector(name, values), validity)
+        }
+        DataType::Float16Vector | DataType::BFloat16Vector => {
+            let bytes = field.get_dimension() as usize * 2;
+            let rows = fixed_size_binary(&name, array, bytes)?
+                .into_iter()
+                .map(|row| row.map(|row| half_words(&name, &row)).transpose())
+                .collect::<Result<_>>()?;
+            let (values, validity) = split(rows);
+            if field.data_type == DataType::Float16Vector {
+                (FieldData::float16_vector(name, values), validity)
+            } else {
+                (FieldData::bfloat16_vector(name, values), validity)
+            }
+        }
+        DataType::SparseFloatVector => {
+            let (values, validity) = split(sparse_rows(&name, array)?);
+            (FieldData::sparse_float_vector(name, values), validity)
+        }
+        DataType::Struct | DataType::Unknown => {
+            return Err(Error::validation(
+                field.name.clone(),
+                format!(
+                    "{:?} fields cannot be read from Arrow columns",
+                    field.data_type
+                ),
+            ))
+        }
+    };
+    match validity {
+        None => Ok(column),
+        Some(_) if !field.nullable => Err(Error::validation(
+            field.name.clone(),
+            "column contains nulls but the field is not nullable".into(),
+        )),
+        Some(validity) => column.with_validity(validity),
+    }
+}
+
+fn array_column(field: &FieldSchema, array: &dyn Array) -> Result<(FieldData, Option<Vec<bool>>)> {
+    let name = field.name.clone();
+    let element_type = field.element_type.unwrap_or_default();
+    macro_rules! elements {
+        ($read:expr, $constructor:path) => {{
+            let rows = list_rows(&name, array, $read)?;
+            let (values, validity) = split(rows);
+            ($constructor(name, values), validity)
+        }};
+    }
+    Ok(match element_type {
+        DataType::Bool => elements!(booleans, FieldData::array_bool),
+        DataType::Int8 => elements!(primitives::<Int8Type>, FieldData::array_int8),
+        DataType::Int16 => elements!(primitives::<Int16Type>, FieldData::array_int16),
+        DataType::Int32 => elements!(primitives::<Int32Type>, FieldData::array_int32),
+        DataType::Int64 => elements!(primitives::<Int64Type>, FieldData::array_int64),
+        DataType::Float => elements!(primitives::<Float32Type>, FieldData::array_float),
+        DataType::Double => elements!(primitives::<Float64Type>, FieldData::array_double),
+        DataType::VarChar => elements!(strings, FieldData::array_varchar),
+        other => {
+            return Err(Error::validation(
+                name,
+                format!("{other:?} array elements cannot be read from Arrow columns"),
+            ))
+        }
+    })
+}
+
+fn type_mismatch(name: &str, expected: &str, array: &dyn Array) -> Error {
+    Error::validation(
+        name.to_owned(),
+        format!(
+            "expected an Arrow {expected} column, got {}",
+            array.data_type()
+        ),
+    )
+}
+
+fn booleans(name: &str, array: &dyn Array) -> Result<Vec<Option<bool>>> {
+    array
+        .as_boolean_opt()
+        .map(|array| array.iter().collect())
+        .ok_or_else(|| type_mismatch(name, "Boolean", array))
+}
+
+fn primitives<T: ArrowPrimitiveType>(
+    name: &str,
+    array: &dyn Array,
+) -> Result<Vec<Option<T::Native>>> {
+    array
+        .as_primitive_opt::<T>()
+        .map(|array| array.iter().collect())
+        .ok_or_else(|| type_mismatch(name, &T::DATA_TYPE.to_string(), array))
+}
+
+fn strings(name: &str, array: &dyn Array) -> Result<Vec<Option<String>>> {
+    fn owned<'a>(values: impl Iterator<Item = Option<&'a str>>) -> Vec<Option<String>> {
+        values.map(|value| value.map(str::to_owned)).collect()
+    }
+    if let Some(array) = array.as_string_opt::<i32>() {
+        Ok(owned(array.iter()))
+    } else if let Some(array) = array.as_string_opt::<i64>() {
+        Ok(owned(array.iter()))
+    } else if let Some(array) = array.as_string_view_opt() {
+        Ok(owned(array.iter()))
+    } else {
+        Err(type_mismatch(name, "Utf8", array))
+    }
+}
+
+fn list_rows<T>(
+    name: &str,
+    array: &dyn Array,
+    read: impl Fn(&str, &dyn Array) -> Result<Vec<Option<T>>>,
+) -> Result<Vec<Option<Vec<T>>>> {
+    let element = |values: ArrayRef| -> Result<Vec<T>> {
+        read(name, values.as_ref())?
+            .into_iter()
+            .map(|value| {
+                value.ok_or_else(|| {
+                    Error::validation(name.to_owned(), "array elements cannot be null".into())
+                })
+            })
+            .collect()
+    };
+    if let Some(list) = array.as_list_opt::<i32>() {
+        list.iter()
+            .map(|row| row.map(element).transpose())
+            .collect()
+    } else if let Some(list) = array.as_list_opt::<i64>() {
+        list.iter()
+            .map(|row| row.map(element).transpose())
+            .collect()
+    } else {
+        Err(type_mismatch(name, "List", array))
+    }
+}
+
+fn fixed_size_list<T: ArrowPrimitiveType>(
+    name: &str,
+    array: &dyn Array,
+    dim: usize,
+) -> Result<Vec<Option<Vec<T::Native>>>> {
+    let list = array
+        .as_fixed_size_list_opt()
+        .ok_or_else(|| type_mismatch(name, "FixedSizeList", array))?;
+    if list.value_length() as usize != dim {
+        return Err(Error::validation(
+            name.to_owned(),
+            format!(
+                "vector dimension {} does not match the schema dimension {dim}",
+                list.value_length()
+            ),
+        ));
+    }
+    (0..list.len())
+        .map(|row| {
+            if list.is_null(row) {
+                return Ok(None);
+            }
+            let values = list.value(row);
+            let values = values
+                .as_primitive_opt::<T>()
+                .ok_or_else(|| type_mismatch(name, &T::DATA_TYPE.to_string(), values.as_ref()))?;
+            if values.null_count() > 0 {
+                return Err(Error::validation(
+                    name.to_owned(),
+                    "vector elements cannot be null".into(),
+                ));
+            }
+            Ok(Some(values.values().to_vec()))
+        })
+        .collect()
+}
+
+fn fixed_size_binary(name: &str, array: &dyn Array, bytes: usize) -> Result<Vec<Option<Vec<u8>>>> {
+    let binary = array
+        .as_fixed_size_binary_opt()
+        .ok_or_else(|| type_mismatch(name, "FixedSizeBinary", array))?;
+    if binary.value_length() as usize != bytes {
+        return Err(Error::validation(
+            name.to_owned(),
+            format!(
+                "expected {bytes} bytes per vector for the schema dimension, got {}",
+                binary.value_length()
+            ),
+        ));
+    }
+    Ok(binary.iter().map(|row| row.map(<[u8]>::to_vec)).collect())
+}
+
+fn sparse_rows(name: &str, array: &dyn Array) -> Result<Vec<Option<SparseVector>>> {
+    let map = array
+        .as_map_opt()
+        .ok_or_else(|| type_mismatch(name, "Map", array))?;
+    let keys = map
+        .keys()
+        .as_primitive_opt::<UInt32Type>()
+        .ok_or_else(|| type_mismatch(name, "UInt32", map.keys().as_ref()))?;
+    let values = map
+        .values()
+        .as_primitive_opt::<Float32Type>()
+        .ok_or_else(|| type_mismatch(name, "Float32", map.values().as_ref()))?;
+    if values.null_count() > 0 {
+        return Err(Error::validation(
+            name.to_owned(),
+            "sparse vector values cannot be null".into(),
+        ));
+    }
+    let offsets = map.value_offsets();
+    Ok((0..map.len())
+        .map(|row| {
+            (!map.is_null(row)).then(|| {
+                let range = offsets[row] as usize..offsets[row + 1] as usize;
+                range
+                    .map(|entry| (keys.value(entry), values.value(entry)))
+                    .collect()
+            })
+        })
+        .collect())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// FieldData -> RecordBatch
+///////////////////////////////////////////////////////////////////////////////
+
+impl QueryResults {
+    /// Converts the query output fields into an Arrow record batch with one column per field.
+    pub fn to_record_batch(&self) -> Result<RecordBatch> {
+        let columns = self
+            .output_fields
+            .iter()
+            .map(column_to_arrow)
+            .collect::<Result<Vec<_>>>()?;
+        record_batch(columns, self.get_row_count() as usize)
+    }
+}
+
+impl SearchResults {
+    /// Converts the hits of every query into one Arrow record batch.
+    ///
+    /// The batch starts with a `query_index` column identifying the query vector of each hit,
+    /// followed by the primary-key and score columns and the requested output fields.
+    pub fn to_record_batch(&self) -> Result<RecordBatch> {
+        let hits = self.flatten()?;
+        let rows = hits.scores.len();
+        let id_column: ArrayRef = match hits.ids {
+            Ids::Int64(values) => Arc::new(Int64Array::from(values)),
+            Ids::VarChar(values) => Arc::new(StringArray::from(values)),
+        };
+        let mut columns = vec![
+            (
+                Field::new("query_index", arrow_schema::DataType::UInt32, false),
+                Arc::new(UInt32Array::from(hits.query_index)) as ArrayRef,
+            ),
+            (
+                Field::new(&hits.id_name, id_column.data_type().clone(), false),
+                id_column,
+            ),
+            (
+                Field::new(&hits.score_name, arrow_schema::DataType::Float32, false),
+                Arc::new(Float32Array::from(hits.scores)) as ArrayRef,
+            ),
+        ];
+        for field in &hits.output_fields {
+            columns.push(column_to_arrow(field)?);
+        }
+        record_batch(columns, rows)
+    }
+}
+
+fn record_batch(columns: Vec<(Field, ArrayRef)>, rows: usize) -> Result<RecordBatch> {
+    let (fields, arrays): (Vec<_>, Vec<_>) = columns.into_iter().unzip();
+    RecordBatch::try_new_with_options(
+        Arc::new(Schema::new(fields)),
+        arrays,
+        &RecordBatchOptions::new().with_row_count(Some(rows)),
+    )
+    .map_err(|error| Error::conversion(format!("cannot build Arrow record batch: {error}")))
+}
+
+fn column_to_arrow(column: &FieldData) -> Result<(Field, ArrayRef)> {
+    let (data, validity) = nullable_parts(column);
+    let array: ArrayRef = match data {
+        FieldData::Bool { values, .. } => Arc::new(BooleanArray::from_iter(
+            expand(values, validity)
+                .into_iter()
+                .map(|value| value.copied()),
+        )),
+        FieldData::Int8 { values, .. } => Arc::new(primitive_array::<Int8Type>(values, validity)),
+        FieldData::Int16 { values, .. } => Arc::new(primitive_array::<Int16Type>(values, validity)),
+        FieldData::Int32 { values, .. } => Arc::new(primitive_array::<Int32Type>(values, validity)),
+        FieldData::Int64 { values, .. } => Arc::new(primitive_array::<Int64Type>(values, validity)),
+        FieldData::Float { values, .. } => {
+            Arc::new(primitive_array::<Float32Type>(values, validity))
+        }
+        FieldData::Double { values, .. } => {
+            Arc::new(primitive_array::<Float64Type>(values, validity))
+        }
+        FieldData::VarChar { values, .. }
+        | FieldData::Geometry { values, .. }
+        | FieldData::Timestamptz { values, .. } => Arc::new(StringArray::from_iter(
+            expand(values, validity)
+                .into_iter()
+                .map(|value| value.map(String::as_str)),
+        )),
+        FieldData::Json { values, .. } => Arc::new(json_array(values, validity)?),
+        FieldData::Struct { values, .. } => Arc::new(json_array(values, validity)?),
+        FieldData::ArrayBool { values, .. } => {
+            let mut builder = ListBuilder::new(BooleanBuilder::new());
+            for row in expand(values, validity) {
+                builder.append_option(row.map(|row| row.iter().copied().map(Some)));
+            }
+            Arc::new(builder.finish())
+        }
+        FieldData::ArrayInt8 { values, .. } => Arc::new(list_array::<Int8Type>(values, validity)),
+        FieldData::ArrayInt16 { values, .. } => Arc::new(list_array::<Int16Type>(values, validity)),
+        FieldData::ArrayInt32 { values, .. } => Arc::new(list_array::<Int32Type>(values, validity)),
+        FieldData::ArrayInt64 { values, .. } => Arc::new(list_array::<Int64Type>(values, validity)),
+        FieldData::ArrayFloat { values, .. } => {
+            Arc::new(list_array::<Float32Type>(values, validity))
+        }
+        FieldData::ArrayDouble { values, .. } => {
+            Arc::new(list_array::<Float64Type>(values, validity))
+        }
+        FieldData::ArrayVarChar { values, .. } => {
+            let mut builder = ListBuilder::new(StringBuilder::new());
+            for row in expand(values, validity) {
+                builder.append_option(row.map(|row| row.iter().map(Some)));
+            }
+            Arc::new(builder.finish())
+        }
+        FieldData::FloatVector { values, .. } => Arc::new(fixed_size_list_array::<Float32Type>(
+            column.name(),
+            values,
+            validity,
+        )?),
+        FieldData::DenseFloatVector { values, .. } => {
+            let rows = values.rows().collect::<Vec<_>>();
+            Arc::new(
+                FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(
+                    expand(&rows, validity)
+                        .into_iter()
+                        .map(|row| row.map(|row| row.iter().copied().map(Some))),
+                    values.dim() as i32,
+                ),
+            )
+        }
+        FieldData::Int8Vector { values, .. } => Arc::new(fixed_size_list_array::<Int8Type>(
+            column.name(),
+            values,
+            validity,
+        )?),
+        FieldData::BinaryVector { values, .. } => Arc::new(fixed_size_binary_array(
+            column.name(),
+            expand(values, validity)
+                .into_iter()
+                .map(|row| row.cloned())
+                .collect(),
+        )?),
+        FieldData::Float16Vector { values, .. } | FieldData::BFloat16Vector { values, .. } => {
+            Arc::new(fixed_size_binary_array(
+                column.name(),
+                expand(values, validity)
+                    .into_iter()
+                    .map(|row| row.map(|row| half_word_bytes(row)))
+                    .collect(),
+            )?)
+        }
+        FieldData::SparseFloatVector { values, .. } => {
+            let mut builder = MapBuilder::new(None, UInt32Builder::new(), Float32Builder::new());
+            for row in expand(values, validity) {
+                if let Some(row) = row {
+                    builder.keys().append_slice(row.indices());
+                    builder.values().append_slice(row.values());
+                }
+                builder.append(row.is_some()).map_err(|error| {
+                    Error::conversion(format!("cannot build Arrow sparse column: {error}"))
+                })?;
+            }
+            Arc::new(builder.finish())
+        }
+        FieldData::Nullable { .. } => {
+            return Err(Error::conversion(format!(
+                "field {} has nested validity",
+                column.name()
+            )))
+        }
+    };
+    let field = Field::new(column.name(), array.data_type().clone(), validity.is_some());
+    Ok((field, array))
+}
+
+fn primitive_array<T: ArrowPrimitiveType>(
+    values: &[T::Native],
+    validity: Option<&[bool]>,
+) -> PrimitiveArray<T> {
+    PrimitiveArray::from_iter(
+        expand(values, validity)
+            .into_iter()
+            .map(|value| value.copied()),
+    )
+}
+
+fn list_array<T: ArrowPrimitiveType>(
+    values: &[Vec<T::Native>],
+    validity: Option<&[bool]>,
+) -> ListArray {
+    ListArray::from_iter_primitive::<T, _, _>(
+        expand(values, validity)
+            .into_iter()
+            .map(|row| row.map(|row| row.iter().copied().map(Some))),
+    )
+}
+
+fn json_array<T: serde::Serialize>(values: &[T], validity: Option<&[bool]>) -> Result<StringArray> {
+    Ok(StringArray::from_iter(json_texts(values, validity)?))
+}
+
+fn uniform_width<T>(name: &str, rows: &[Option<&Vec<T>>]) -> Result<usize> {
+    let mut widths = rows.iter().flatten().map(|row| row.len());
+    let width = widths.next().unwrap_or_default();
+    if widths.any(|other| other != width) {
+        return Err(Error::conversion(format!(
+            "vector field {name} has rows of different dimensions"
+        )));
+    }
+    Ok(width)
+}
+
+fn fixed_size_list_array<T: ArrowPrimitiveType>(
+    name: &str,
+    values: &[Vec<T::Native>],
+    validity: Option<&[bool]>,
+) -> Result<FixedSizeListArray> {
+    let rows = expand(values, validity);
+    let dim = uniform_width(name, &rows)?;
+    Ok(FixedSizeListArray::from_iter_primitive::<T, _, _>(
+        rows.into_iter()
+            .map(|row| row.map(|row| row.iter().copied().map(Some))),
+        dim as i32,
+    ))
+}
+
+fn fixed_size_binary_array(name: &str, rows: Vec<Option<Vec<u8>>>) -> Result<FixedSizeBinaryArray> {
+    let width = uniform_width(name, &rows.iter().map(Option::as_ref).collect::<Vec<_>>())?;
+    FixedSizeBinaryArray::try_from_sparse_iter_with_size(rows.into_iter(), width as i32)
+        .map_err(|error| Error::conversion(format!("cannot build Arrow vector column: {error}")))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::types::SingleResult;
+
+    fn schema() -> CollectionSchema {
+        CollectionSchema::new()
+            .add_field(
+                FieldSchema::new()
+                    .name("id")
+                    .data_type(DataType::Int64)
+                    .primary_key(true),
+            )
+            .add_field(
+                FieldSchema::new()
+                    .name("title")
+                    .data_type(DataType::VarChar)
+                    .max_length(64)
+                    .nullable(true),
+            )
+            .add_field(
+                FieldSchema::new()
+                    .name("vector")
+                    .data_type(DataType::FloatVector)
+                    .dimension(2),
+            )
+            .add_field(
+                FieldSchema::new()
+                    .name("half")
+                    .data_type(DataType::Float16Vector)
+                    .dimension(2),
+            )
+    }
+
+    fn batch() -> RecordBatch {
+        let vectors = FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(
+            vec![
+                Some(vec![Some(0.1), Some(0.2)]),
+                Some(vec![Some(0.3), Some(0.4)]),
+            ],
+            2,
+        );
+        let half = FixedSizeBinaryArray::try_from_iter(
+            vec![vec![0x00, 0x3c, 0x00, 0x40], vec![0x00, 0x42, 0x00, 0x44]].into_iter(),
+        )
+        .unwrap();
+        RecordBatch::try_from_iter(vec![
+            ("id", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+            (
+                "title",
+                Arc::new(StringArray::from(vec![Some("first"), None])) as ArrayRef,
+            ),
+            ("vector", Arc::new(vectors) as ArrayRef),
+            ("half", Arc::new(half) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn record_batch_columns_follow_the_collection_schema() {
+        let request = InsertRequestBuilder::try_from((&batch(), &schema()))
+            .unwrap()
+            .collection_name("books")
+            .build()
+            .unwrap();
+        let columns = request.columns();
+
+        assert_eq!(columns[0], FieldData::int64("id", vec![1, 2]));
+        assert_eq!(
+            columns[1],
+            FieldData::varchar("title", vec!["first".into()])
+                .with_validity(vec![true, false])
+                .unwrap()
+        );
+        assert_eq!(
+            columns[2],
+            FieldData::float_vector("vector", vec![vec![0.1, 0.2], vec![0.3, 0.4]])
+        );
+        assert_eq!(
+            columns[3],
+            FieldData::float16_vector("half", vec![vec![0x3c00, 0x4000], vec![0x4200, 0x4400]])
+        );
+    }
+
+    #[test]
+    fn mismatched_dimensions_and_unknown_columns_are_rejected() {
+        let mut wrong_dim = schema();
+        wrong_dim.fields[2] = FieldSchema::new()
+            .name("vector")
+            .data_type(DataType::FloatVector)
+            .dimension(4);
+        assert!(record_batch_to_columns(&batch(), &wrong_dim).is_err());
+
+        let mut not_nullable = schema();
+        not_nullable.fields[1].nullable = false;
+        assert!(record_batch_to_columns(&batch(), &not_nullable).is_err());
+
+        let mut missing = schema();
+        missing.fields.retain(|field| field.name != "half");
+        assert!(record_batch_to_columns(&batch(), &missing).is_err());
+    }
+
+    #[test]
+    fn query_results_round_trip_through_record_batches() {
+        let results = QueryResults::new()
+            .output_fields(record_batch_to_columns(&batch(), &schema()).unwrap());
+        let converted = results.to_record_batch().unwrap();
+        assert_eq!(converted.num_rows(), 2);
+        assert_eq!(
+            record_batch_to_columns(&converted, &schema()).unwrap(),
+            results.output_fields
+        );
+        assert!(converted.column(1).is_null(1));
+    }
+
+    #[test]
+    fn search_results_keep_query_index_ids_and_scores() {
+        let results = SearchResults::new().results(vec![
+            SingleResult::new()
+                .ids(Ids::Int64(vec![7, 8]))
+                .scores(vec![0.9, 0.8])
+                .primary_field_name("id")
+                .output_fields(vec![FieldData::varchar(
+                    "title",
+                    vec!["a".into(), "b".into()],
+                )]),
+            SingleResult::new()
+                .ids(Ids::Int64(vec![9]))
+                .scores(vec![0.7])
+                .primary_field_name("id")
+                .output_fields(vec![FieldData::varchar("title", vec!["c".into()])]),
+        ]);
+        let batch = results.to_record_batch().unwrap();
+        let schema = batch.schema();
+        let names = schema
+            .fields()
+            .iter()
+            .map(|field| field.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["query_index", "id", "score", "title"]);
+        assert_eq!(
+            batch.column(0).as_primitive::<UInt32Type>().values(),
+            &[0, 0, 1]
+        );
+        assert_eq!(
+            batch.column(1).as_primitive::<Int64Type>().values(),
+            &[7, 8, 9]
+        );
+        assert_eq!(batch.column(3).as_string::<i32>().value(2), "c");
+    }
+
+    #[test]
+    fn search_results_match_output_fields_by_name() {
+        let hit = |id: i64, fields: Vec<FieldData>| {
+            SingleResult::new()
+                .ids(Ids::Int64(vec![id]))
+                .scores(vec![0.5])
+                .primary_field_name("id")
+                .output_fields(fields)
+        };
+        let title = |value: &str| FieldData::varchar("title", vec![value.into()]);
+        let year = |value: i64| FieldData::Int64 {
+            name: "year".into(),
+            values: vec![value],
+        };
+        let batch = SearchResults::new()
+            .results(vec![
+                hit(1, vec![title("a"), year(2001)]),
+                hit(2, vec![year(2002), title("b")]),
+            ])
+            .to_record_batch()
+            .unwrap();
+        assert_eq!(batch.column(3).as_string::<i32>().value(1), "b");
+        assert_eq!(
+            batch.column(4).as_primitive::<Int64Type>().values(),
+            &[2001, 2002]
+        );
+
+        for second in [vec![title("b")], vec![title("b"), year(2002), year(2003)]] {
+            let error = SearchResults::new()
+                .results(vec![hit(1, vec![title("a"), year(2001)]), hit(2, second)])
+                .to_record_batch()
+                .unwrap_err();
+            assert!(
+                error.to_string().contains("different output fields"),
+                "{error}"
+            );
+        }
+        let renamed = SearchResults::new()
+            .results(vec![
+                hit(1, vec![title("a")]),
+                hit(2, vec![FieldData::varchar("name", vec!["b".into()])]),
+            ])
+            .to_record_batch();
+        assert!(renamed.is_err());
+    }
+}
@@ -0,0 +1,563 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side BM25 sparse encoding for collections without a server BM25 function.
+//!
+//! A [`Bm25Encoder`] is fitted on a corpus to learn a vocabulary, document frequencies, and the
+//! average document length. Document vectors hold each known term's saturated term frequency and
+//! query vectors hold each known term's inverse document frequency, so the inner product of a
+//! query and a document vector is the document's BM25 score. Store document vectors in a
+//! `SparseFloatVector` field indexed with the `IP` metric, for example through
+//! [`FieldData::sparse_float_vector`], and search it with [`SearchVectors::SparseFloat`]. Terms
+//! outside the fitted vocabulary are ignored.
+//!
+//! Texts are split by a [`Tokenizer`], or by a server analyzer through
+//! [`ClientV2::run_analyzer`] so that tokens match a collection field's analyzer. Fitted
+//! parameters can be saved and loaded as JSON; the tokenizer is not saved and must be configured
+//! again after loading.
+//!
+//! ```no_run
+//! # async fn example(client: milvus::v2::ClientV2) -> milvus::v2::error::Result<()> {
+//! use milvus::v2::bm25::{Bm25Encoder, ServerAnalyzer};
+//! use milvus::v2::prelude::*;
+//!
+//! let corpus = vec!["the quick brown fox".to_owned(), "a lazy dog".to_owned()];
+//! let analyzer = ServerAnalyzer::field("docs", "text");
+//! let mut encoder = Bm25Encoder::new().analyzer(client.clone(), analyzer);
+//! encoder.fit(&corpus).await?;
+//! encoder.save("bm25.json")?;
+//!
+//! let vectors = encoder.encode_documents(&corpus).await?;
+//! let column = FieldData::sparse_float_vector("sparse", vectors);
+//! let queries = encoder.encode_queries(&["quick fox".to_owned()]).await?;
+//! let request = SearchRequest::builder()
+//!     .collection_name("docs")
+//!     .vector_field("sparse")
+//!     .vectors(SearchVectors::SparseFloat(queries))
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`FieldData::sparse_float_vector`]: crate::v2::types::FieldData::sparse_float_vector
+//! [`SearchVectors::SparseFloat`]: crate::v2::types::SearchVectors::SparseFloat
+
+use crate::v2::client::ClientV2;
+use crate::v2::error::{Error, Result};
+use crate::v2::request::utility::RunAnalyzerRequest;
+use crate::v2::types::SparseVector;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+const ANALYZER_BATCH_SIZE: usize = 256;
+
+///////////////////////////////////////////////////////////////////////////////
+// Tokenizer
+///////////////////////////////////////////////////////////////////////////////
+/// Splits a text into the terms a [`Bm25Encoder`] counts.
+///
+/// Closures taking `&str` and returning `Vec<String>` implement this trait.
+pub trait Tokenizer: Send + Sync {
+    /// Returns the terms of `text`, in order and with repeats.
+    fn tokenize(&self, text: &str) -> Vec<String>;
+}
+
+impl<F> Tokenizer for F
+where
+    F: Fn(&str) -> Vec<String> + Send + Sync,
+{
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        self(text)
+    }
+}
+
+/// Lowercases text and splits it on every character that is not alphanumeric.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SimpleTokenizer;
+
+impl Tokenizer for SimpleTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        text.split(|character: char| !character.is_alphanumeric())
+            .filter(|term| !term.is_empty())
+            .map(str::to_lowercase)
+            .collect()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ServerAnalyzer
+///////////////////////////////////////////////////////////////////////////////
+/// Server analyzer used to tokenize texts through [`ClientV2::run_analyzer`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ServerAnalyzer {
+    pub(crate) database_name: Option<String>,
+    pub(crate) collection_name: String,
+    pub(crate) field_name: String,
+    pub(crate) analyzer_params: String,
+}
+
+impl ServerAnalyzer {
+    /// Uses the analyzer configured on a collection field.
+    pub fn field(collection_name: impl Into<String>, field_name: impl Into<String>) -> Self {
+        Self {
+            database_name: None,
+            collection_name: collection_name.into(),
+            field_name: field_name.into(),
+            analyzer_params: String::new(),
+        }
+    }
+
+    /// Uses an analyzer described by JSON analyzer params, such as `{"tokenizer": "standard"}`.
+    pub fn params(analyzer_params: impl Into<String>) -> Self {
+        Self {
+            database_name: None,
+            collection_name: String::new(),
+            field_name: String::new(),
+            analyzer_params: analyzer_params.into(),
+        }
+    }
+
+    /// Sets the database of the collection and returns the updated value.
+    pub fn database_name(mut self, value: impl Into<String>) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    fn request(&self, texts: &[String]) -> Result<RunAnalyzerRequest> {
+        let mut builder = RunAnalyzerRequest::builder()
+            .texts(texts.iter().cloned())
+            .analyzer_params(self.analyzer_params.clone())
+            .collection_name(self.collection_name.clone())
+            .field_name(self.field_name.clone());
+        if let Some(database_name) = &self.database_name {
+            builder = builder.database_name(database_name.clone());
+        }
+        builder.build()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Bm25Encoder
+///////////////////////////////////////////////////////////////////////////////
+#[derive(Clone)]
+enum TokenSource {
+    Local(Arc<dyn Tokenizer>),
+    Server(ClientV2, ServerAnalyzer),
+}
+
+/// Fitted BM25 parameters as saved by [`Bm25Encoder::to_json`].
+#[derive(Debug, Serialize, Deserialize)]
+struct Bm25Parameters {
+    k1: f32,
+    b: f32,
+    document_count: u64,
+    average_document_length: f32,
+    /// Terms in index order with their document frequencies.
+    terms: Vec<(String, u64)>,
+}
+
+/// Encodes texts as BM25 sparse vectors with a vocabulary fitted on a corpus.
+///
+/// Term weights use the Milvus defaults `k1 = 1.2` and `b = 0.75`, and the inverse document
+/// frequency `ln(1 + (N - df + 0.5) / (df + 0.5))`, which is always positive.
+#[derive(Clone)]
+pub struct Bm25Encoder {
+    k1: f32,
+    b: f32,
+    tokens: TokenSource,
+    vocabulary: HashMap<String, u32>,
+    terms: Vec<(String, u64)>,
+    document_count: u64,
+    average_document_length: f32,
+}
+
+impl std::fmt::Debug for Bm25Encoder {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let analyzer = match &self.tokens {
+            TokenSource::Local(_) => None,
+            TokenSource::Server(_, analyzer) => Some(analyzer),
+        };
+        formatter
+            .debug_struct("Bm25Encoder")
+            .field("k1", &self.k1)
+            .field("b", &self.b)
+            .field("server_analyzer", &analyzer)
+            .field("vocabulary_size", &self.terms.len())
+            .field("document_count", &self.document_count)
+            .field("average_document_length", &self.average_document_length)
+            .finish()
+    }
+}
+
+impl Bm25Encoder {
+    /// Creates an unfitted encoder that uses [`SimpleTokenizer`].
+    pub fn new() -> Self {
+        Self {
+            k1: 1.2,
+            b: 0.75,
+            tokens: TokenSource::Local(Arc::new(SimpleTokenizer)),
+            vocabulary: HashMap::new(),
+            terms: Vec::new(),
+            document_count: 0,
+            average_document_length: 0.0,
+        }
+    }
+
+    /// Sets the term-frequency saturation and returns the updated value.
+    pub fn k1(mut self, value: f32) -> Self {
+        self.k1 = value;
+        self
+    }
+
+    /// Returns the term-frequency saturation.
+    pub fn get_k1(&self) -> f32 {
+        self.k1
+    }
+
+    /// Sets the document-length normalization and returns the updated value.
+    pub fn b(mut self, value: f32) -> Self {
+        self.b = value;
+        self
+    }
+
+    /// Returns the document-length normalization.
+    pub fn get_b(&self) -> f32 {
+        self.b
+    }
+
+    /// Tokenizes texts locally with `tokenizer` and returns the updated value.
+    pub fn tokenizer(mut self, tokenizer: impl Tokenizer + 'static) -> Self {
+        self.tokens = TokenSource::Local(Arc::new(tokenizer));
+        self
+    }
+
+    /// Tokenizes texts with a server analyzer and returns the updated value.
+    pub fn analyzer(mut self, client: ClientV2, analyzer: ServerAnalyzer) -> Self {
+        self.tokens = TokenSource::Server(client, analyzer);
+        self
+    }
+
+    /// Returns whether the encoder has been fitted or loaded.
+    pub fn is_fitted(&self) -> bool {
+        self.document_count > 0
+    }
+
+    /// Returns the number of terms in the fitted vocabulary.
+    pub fn vocabulary_size(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the number of documents the encoder was fitted on.
+    pub fn document_count(&self) -> u64 {
+        self.document_count
+    }
+
+    /// Splits each text into terms with the configured tokenizer or server analyzer.
+    pub async fn tokenize(&self, texts: &[String]) -> Result<Vec<Vec<String>>> {
+        let (client, analyzer) = match &self.tokens {
+            TokenSource::Local(tokenizer) => {
+                return Ok(texts.iter().map(|text| tokenizer.tokenize(text)).collect())
+            }
+            TokenSource::Server(client, analyzer) => (client, analyzer),
+        };
+        let mut tokens = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(ANALYZER_BATCH_SIZE) {
+            let response = client.run_analyzer(analyzer.request(batch)?).await?;
+            if response.results().len() != batch.len() {
+                return Err(Error::MalformedResponse(format!(
+                    "run analyzer returned {} results for {} texts",
+                    response.results().len(),
+                    batch.len()
+                )));
+            }
+            tokens.extend(response.results().iter().map(|result| {
+                result
+                    .get_tokens()
+                    .iter()
+                    .map(|token| token.get_text().to_owned())
+                    .collect()
+            }));
+        }
+        Ok(tokens)
+    }
+
+    /// Fits the vocabulary and corpus statistics on `texts`, replacing any earlier fit.
+    pub async fn fit(&mut self, texts: &[String]) -> Result<()> {
+        let documents = self.tokenize(texts).await?;
+        self.fit_tokens(&documents)
+    }
+
+    /// Fits the vocabulary and corpus statistics on tokenized documents, replacing any earlier
+    /// fit.
+    pub fn fit_tokens(&mut self, documents: &[Vec<String>]) -> Result<()> {
+        validate_parameters(self.k1, self.b)?;
+        if documents.is_empty() {
+            return Err(Error::validation(
+                "documents".into(),
+                "at least one document is required to fit".into(),
+            ));
+        }
+        let mut vocabulary = HashMap::new();
+        let mut terms: Vec<(String, u64)> = Vec::new();
+        let mut total_length = 0usize;
+        for document in documents {
+            total_length += document.len();
+            let mut seen = HashSet::new();
+            for term in document {
+                if !seen.insert(term.as_str()) {
+                    continue;
+                }
+                let index = *vocabulary.entry(term.clone()).or_insert_with(|| {
+                    terms.push((term.clone(), 0));
+                    (terms.len() - 1) as u32
+                });
+                terms[index as usize].1 += 1;
+            }
+        }
+        self.vocabulary = vocabulary;
+        self.terms = terms;
+        self.document_count = documents.len() as u64;
+        self.average_document_length = total_length as f32 / documents.len() as f32;
+        Ok(())
+    }
+
+    /// Encodes texts as document vectors for insertion.
+    pub async fn encode_documents(&self, texts: &[String]) -> Result<Vec<SparseVector>> {
+        self.tokenize(texts)
+            .await?
+            .iter()
+            .map(|tokens| self.encode_document_tokens(tokens))
+            .collect()
+    }
+
+    /// Encodes texts as query vectors for search.
+    pub async fn encode_queries(&self, texts: &[String]) -> Result<Vec<SparseVector>> {
+        self.tokenize(texts)
+            .await?
+            .iter()
+            .map(|tokens| self.encode_query_tokens(tokens))
+            .collect()
+    }
+
+    /// Encodes one tokenized document; each known term weighs its saturated term frequency.
+    pub fn encode_document_tokens(&self, tokens: &[String]) -> Result<SparseVector> {
+        self.ensure_fitted()?;
+        let length_ratio = if self.average_document_length > 0.0 {
+            tokens.len() as f32 / self.average_document_length
+        } else {
+            1.0
+        };
+        let norm = self.k1 * (1.0 - self.b + self.b * length_ratio);
+        Ok(self
+            .term_counts(tokens)
+            .into_iter()
+            .map(|(index, count)| (index, count * (self.k1 + 1.0) / (count + norm)))
+            .collect())
+    }
+
+    /// Encodes one tokenized query; each known term weighs its inverse document frequency.
+    pub fn encode_query_tokens(&self, tokens: &[String]) -> Result<SparseVector> {
+        self.ensure_fitted()?;
+        Ok(self
+            .term_counts(tokens)
+            .into_iter()
+            .map(|(index, count)| (index, count * self.idf(index)))
+            .collect())
+    }
+
+    /// Serializes the fitted parameters as JSON.
+    pub fn to_json(&self) -> Result<String> {
+        self.ensure_fitted()?;
+        serde_json::to_string(&Bm25Parameters {
+            k1: self.k1,
+            b: self.b,
+            document_count: self.document_count,
+            average_document_length: self.average_document_length,
+            terms: self.terms.clone(),
+        })
+        .map_err(|error| Error::conversion(format!("cannot serialize BM25 parameters: {error}")))
+    }
+
+    /// Restores an encoder from parameters written by [`Self::to_json`].
+    ///
+    /// The encoder uses [`SimpleTokenizer`] until another tokenizer or analyzer is set.
+    pub fn from_json(value: &str) -> Result<Self> {
+        let parameters: Bm25Parameters = serde_json::from_str(value).map_err(|error| {
+            Error::conversion(format!("cannot deserialize BM25 parameters: {error}"))
+        })?;
+        validate_parameters(parameters.k1, parameters.b)?;
+        let mut vocabulary = HashMap::with_capacity(parameters.terms.len());
+        for (index, (term, _)) in parameters.terms.iter().enumerate() {
+            if vocabulary.insert(term.clone(), index as u32).is_some() {
+                return Err(Error::conversion(format!(
+                    "BM25 parameters repeat the term {term:?}"
+                )));
+            }
+        }
+        Ok(Self {
+            k1: parameters.k1,
+            b: parameters.b,
+            vocabulary,
+            terms: parameters.terms,
+            document_count: parameters.document_count,
+            average_document_length: parameters.average_document_length,
+            ..Self::new()
+        })
+    }
+
+    /// Writes the fitted parameters to a JSON file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_json()?).map_err(|error| Error::io(path, error))
+    }
+
+    /// Restores an encoder from a JSON file written by [`Self::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let value = std::fs::read_to_string(path).map_err(|error| Error::io(path, error))?;
+        Self::from_json(&value)
+    }
+
+    fn ensure_fitted(&self) -> Result<()> {
+        if self.is_fitted() {
+            return Ok(());
+        }
+        Err(Error::validation(
+            "bm25_encoder".into(),
+            "must be fitted or loaded before encoding".into(),
+        ))
+    }
+
+    fn term_counts(&self, tokens: &[String]) -> HashMap<u32, f32> {
+        let mut counts = HashMap::new();
+        for token in tokens {
+            if let Some(&index) = self.vocabulary.get(token) {
+                *counts.entry(index).or_insert(0.0) += 1.0;
+            }
+        }
+        counts
+    }
+
+    fn idf(&self, index: u32) -> f32 {
+        let document_count = self.document_count as f32;
+        let frequency = self.terms[index as usize].1 as f32;
+        (1.0 + (document_count - frequency + 0.5) / (frequency + 0.5)).ln()
+    }
+}
+
+impl Default for Bm25Encoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn validate_parameters(k1: f32, b: f32) -> Result<()> {
+    if !(k1.is_finite() && k1 >= 0.0) {
+        return Err(Error::validation(
+            "k1".into(),
+            "must be finite and non-negative".into(),
+        ));
+    }
+    if !(0.0..=1.0).contains(&b) {
+        return Err(Error::validation(
+            "b".into(),
+            "must be between 0 and 1".into(),
+        ));
+    }
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::error::ConversionError;
+
+    fn corpus() -> Vec<String> {
+        vec![
+            "The quick brown fox".to_owned(),
+            "the lazy dog".to_owned(),
+            "quick quick dog".to_owned(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn query_and_document_vectors_score_bm25() {
+        let mut encoder = Bm25Encoder::new();
+        assert!(encoder.encode_queries(&corpus()).await.is_err());
+        encoder.fit(&corpus()).await.unwrap();
+        assert_eq!(encoder.vocabulary_size(), 6);
+        assert_eq!(encoder.document_count(), 3);
+
+        let documents = encoder.encode_documents(&corpus()).await.unwrap();
+        let query = &encoder
+            .encode_queries(&["Quick unknown".to_owned()])
+            .await
+            .unwrap()[0];
+        assert_eq!(query.len(), 1);
+        let quick = encoder.vocabulary["quick"];
+        let idf = (1.0f32 + (3.0 - 2.0 + 0.5) / (2.0 + 0.5)).ln();
+        assert!((query.get(quick).unwrap() - idf).abs() < 1e-6);
+
+        let scores = documents
+            .iter()
+            .map(|document| document.get(quick).unwrap_or(0.0) * idf)
+            .collect::<Vec<_>>();
+        assert!(scores[2] > scores[0]);
+        assert_eq!(scores[1], 0.0);
+    }
+
+    #[tokio::test]
+    async fn custom_tokenizers_and_saved_parameters_round_trip() {
+        let mut encoder =
+            Bm25Encoder::new().tokenizer(|text: &str| text.split(',').map(str::to_owned).collect());
+        encoder
+            .fit(&["a b,c".to_owned(), "c".to_owned()])
+            .await
+            .unwrap();
+        assert_eq!(encoder.vocabulary_size(), 2);
+
+        let restored = Bm25Encoder::from_json(&encoder.to_json().unwrap()).unwrap();
+        let tokens = vec!["c".to_owned(), "a b".to_owned()];
+        assert_eq!(
+            restored.encode_document_tokens(&tokens).unwrap(),
+            encoder.encode_document_tokens(&tokens).unwrap()
+        );
+        assert_eq!(
+            restored.encode_query_tokens(&tokens).unwrap(),
+            encoder.encode_query_tokens(&tokens).unwrap()
+        );
+        assert!(Bm25Encoder::from_json(
+            r#"{"k1":1.2,"b":2.0,"document_count":1,"average_document_length":1.0,"terms":[]}"#
+        )
+        .is_err());
+
+        let missing = std::env::temp_dir().join("milvus-bm25-missing/parameters.json");
+        let error = Bm25Encoder::load(&missing).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Conversion(ConversionError::Io { .. })
+        ));
+        assert!(error.to_string().contains("milvus-bm25-missing"), "{error}");
+    }
+}
@@ -45,6 +45,7 @@
 use crate::proto::milvus::milvus_service_client::MilvusServiceClient;
 use crate::proto::{common, milvus};
 use crate::v2::error::{Error, Result};
+use crate::v2::retry::{RetryContext, RetryDecision, RetryPolicy, RetrySemantics};
 use crate::v2::types::{ConnectConfig, RetryConfig};
 use parking_lot::RwLock;
 use std::future::Future;
@@ -57,6 +58,11 @@ use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity
 use tonic::{Code, Request, Response, Status};
 
 use cache::SchemaLoadScope;
+use circuit::CircuitBreaker;
+use hedging::Hedging;
+use limits::ClientLimits;
+use result_cache::ResultCache;
+use scope::CallScope;
 
 macro_rules! trace_debug {
     ($($field:tt)*) => {
@@ -70,8 +76,9 @@ macro_rules! rpc_with_retry {
         let request = $request;
         $client
             .retry_rpc(
+                stringify!($method),
                 || Ok(request.clone()),
-                $crate::v2::client::RetrySemantics::$semantics,
+                $crate::v2::retry::RetrySemantics::$semantics,
                 |mut service, request| async move { service.$method(request).await },
                 |response| response.status.clone(),
             )
@@ -87,8 +94,9 @@ macro_rules! status_rpc_with_retry {
         let request = $request;
         $client
             .retry_rpc(
+                stringify!($method),
                 || Ok(request.clone()),
-                $crate::v2::client::RetrySemantics::Idempotent,
+                $crate::v2::retry::RetrySemantics::Idempotent,
                 |mut service, request| async move { service.$method(request).await },
                 |status| Some(status.clone()),
             )
@@ -98,8 +106,9 @@ macro_rules! status_rpc_with_retry {
         let request = $request;
         $client
             .retry_rpc(
+                stringify!($method),
                 || Ok(request.clone()),
-                $crate::v2::client::RetrySemantics::NonIdempotent,
+                $crate::v2::retry::RetrySemantics::NonIdempotent,
                 |mut service, request| async move { service.$method(request).await },
                 |status| Some(status.clone()),
             )
@@ -108,22 +117,34 @@ macro_rules! status_rpc_with_retry {
 }
 
 mod alias;
+mod batcher;
 mod cache;
 mod cdc;
+mod circuit;
 mod collection;
 mod database;
 mod dml;
 mod dql;
+mod embedding;
+mod eval;
+mod federated;
+mod hedging;
 mod index;
 mod internal;
 mod iterator;
+mod limits;
 mod partition;
 mod rbac;
 mod resource_group;
+mod result_cache;
+mod scope;
 mod snapshot;
 mod utility;
 
-pub use iterator::{QueryIterator, SearchIterator, SearchIteratorV1, SearchIteratorV2};
+pub use batcher::SearchBatcher;
+pub use iterator::{
+    QueryIterator, SearchIterator, SearchIteratorV1, SearchIteratorV2, SearchPage, SearchPageToken,
+};
 pub use utility::OptimizeTask;
 
 type Service = MilvusServiceClient<InterceptedService<Channel, V2Interceptor>>;
@@ -145,15 +166,6 @@ fn normalize_database(database: String) -> Result<String> {
     Ok(database)
 }
 
-///////////////////////////////////////////////////////////////////////////////
-// RetrySemantics
-///////////////////////////////////////////////////////////////////////////////
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum RetrySemantics {
-    Idempotent,
-    NonIdempotent,
-}
-
 ///////////////////////////////////////////////////////////////////////////////
 // V2Interceptor
 ///////////////////////////////////////////////////////////////////////////////
@@ -209,16 +221,24 @@ impl Interceptor for V2Interceptor {
 /// - DDL operations that change a database, collection identity, schema, or alias should be
 ///   serialized with DML and DQL calls targeting the affected objects. Results are not guaranteed
 ///   when those operations overlap.
-/// - [`ClientV2::use_database`], [`ClientV2::set_rpc_deadline`], and
-///   [`ClientV2::set_retry_param`] are internally synchronized but update state shared by every
-///   clone. Serialize configuration changes with RPC creation when deterministic request settings
-///   are required.
+/// - [`ClientV2::use_database`], [`ClientV2::set_rpc_deadline`], [`ClientV2::set_retry_param`],
+///   and [`ClientV2::set_retry_policy`] are internally synchronized but update state shared by
+///   every clone. Serialize configuration changes with RPC creation when deterministic request
+///   settings are required.
+/// - Client-side limits configured with [`ConnectConfig::rate_limit`] are shared by every clone,
+///   so concurrent calls of one operation class queue behind the same budget.
+/// - The result cache configured with [`ConnectConfig::result_cache`] is shared by every clone;
+///   DML through any client for the same endpoint invalidates the affected collection's entries.
 #[derive(Clone)]
 pub struct ClientV2 {
     service: Service,
     database: Arc<RwLock<String>>,
     rpc_timeout: Arc<RwLock<Duration>>,
-    retry: Arc<RwLock<RetryConfig>>,
+    retry_policy: Arc<RwLock<Arc<dyn RetryPolicy>>>,
+    limits: Arc<ClientLimits>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    hedging: Option<Arc<Hedging>>,
+    result_cache: Option<Arc<ResultCache>>,
     cache_endpoint: Arc<String>,
     schema_load_scope: Arc<SchemaLoadScope>,
 }
@@ -251,7 +271,15 @@ impl ClientV2 {
             service,
             database,
             rpc_timeout: Arc::new(RwLock::new(param.rpc_timeout)),
-            retry: Arc::new(RwLock::new(param.retry)),
+            retry_policy: Arc::new(RwLock::new(
+                param.retry_policy.unwrap_or_else(|| Arc::new(param.retry)),
+            )),
+            limits: Arc::new(ClientLimits::new(&param.rate_limits)),
+            circuit_breaker: param
+                .circuit_breaker
+                .map(|config| Arc::new(CircuitBreaker::new(config))),
+            hedging: param.hedging.map(|config| Arc::new(Hedging::new(config))),
+            result_cache: param.result_cache.and_then(ResultCache::new).map(Arc::new),
             cache_endpoint,
             schema_load_scope: Arc::new(SchemaLoadScope::new()),
         })
@@ -262,29 +290,48 @@ impl ClientV2 {
         *self.rpc_timeout.write() = timeout;
     }
 
-    /// Replaces the retry policy used by subsequent RPC calls.
+    /// Replaces the retry policy used by subsequent RPC calls with an exponential-backoff policy.
     pub fn set_retry_param(&self, retry: RetryConfig) {
-        *self.retry.write() = retry;
+        self.set_retry_policy(retry);
+    }
+
+    /// Replaces the policy that decides whether subsequent failed RPC attempts are retried.
+    ///
+    /// See [`crate::v2::retry`] for per-operation overrides and retry budgets.
+    pub fn set_retry_policy(&self, policy: impl RetryPolicy + 'static) {
+        *self.retry_policy.write() = Arc::new(policy);
     }
 
     async fn retry_rpc<Req, Resp, MakeRequest, Call, CallFuture, GetStatus>(
         &self,
+        method: &'static str,
         mut make_request: MakeRequest,
         semantics: RetrySemantics,
         mut call: Call,
         get_status: GetStatus,
     ) -> Result<Resp>
     where
+        Req: prost::Message,
         MakeRequest: FnMut() -> Result<Req>,
         Call: FnMut(Service, Request<Req>) -> CallFuture,
         CallFuture: Future<Output = std::result::Result<Response<Resp>, Status>>,
         GetStatus: Fn(&Resp) -> Option<crate::proto::common::Status>,
     {
         self.retry_call(
+            method,
             || {
                 let service = self.service.clone();
-                let request = self.rpc_request(make_request()?);
-                Ok(call(service, request))
+                let request = make_request()?;
+                let limited = self.limits.limiter(method);
+                let bytes = request.encoded_len();
+                let call = call(service, self.rpc_request(request));
+                Ok(async move {
+                    let _permit = match limited {
+                        Some(limiter) => Some(limiter.acquire(method, bytes).await),
+                        None => None,
+                    };
+                    call.await
+                })
             },
             Some(get_status),
             semantics,
@@ -294,24 +341,35 @@ impl ClientV2 {
 
     async fn retry_transport<Req, Resp, Call, CallFuture>(
         &self,
+        method: &'static str,
         request: Req,
         apply_rpc_timeout: bool,
         mut call: Call,
     ) -> Result<Resp>
     where
-        Req: Clone,
+        Req: Clone + prost::Message,
         Call: FnMut(Service, Request<Req>) -> CallFuture,
         CallFuture: Future<Output = std::result::Result<Response<Resp>, Status>>,
     {
         self.retry_call(
+            method,
             || {
                 let service = self.service.clone();
+                let limited = self.limits.limiter(method);
+                let bytes = request.encoded_len();
                 let request = if apply_rpc_timeout {
                     self.rpc_request(request.clone())
                 } else {
                     Request::new(request.clone())
                 };
-                Ok(call(service, request))
+                let call = call(service, request);
+                Ok(async move {
+                    let _permit = match limited {
+                        Some(limiter) => Some(limiter.acquire(method, bytes).await),
+                        None => None,
+                    };
+                    call.await
+                })
             },
             None::<fn(&Resp) -> Option<crate::proto::common::Status>>,
             RetrySemantics::Idempotent,
@@ -319,8 +377,26 @@ impl ClientV2 {
         .await
     }
 
+    /// Runs one RPC attempt, hedging it when it is an eligible idempotent read.
+    async fn attempt<Resp, Call, CallFuture>(
+        &self,
+        method: &'static str,
+        semantics: RetrySemantics,
+        call: &mut Call,
+    ) -> Result<std::result::Result<Response<Resp>, Status>>
+    where
+        Call: FnMut() -> Result<CallFuture>,
+        CallFuture: Future<Output = std::result::Result<Response<Resp>, Status>>,
+    {
+        match &self.hedging {
+            Some(hedging) if hedging.applies(method, semantics) => hedging.run(method, call).await,
+            _ => Ok(call()?.await),
+        }
+    }
+
     async fn retry_call<Resp, Call, CallFuture, GetStatus>(
         &self,
+        method: &'static str,
         mut call: Call,
         get_status: Option<GetStatus>,
         semantics: RetrySemantics,
@@ -330,122 +406,117 @@ impl ClientV2 {
         CallFuture: Future<Output = std::result::Result<Response<Resp>, Status>>,
         GetStatus: Fn(&Resp) -> Option<crate::proto::common::Status>,
     {
-        let retry = self.retry.read().clone();
-        let max_attempts = retry.max_attempts.max(1);
+        let policy = CallScope::current()
+            .retry_policy()
+            .unwrap_or_else(|| Arc::clone(&*self.retry_policy.read()));
+        let max_retry_timeout = policy.max_retry_timeout(method);
         let started = Instant::now();
-        let mut backoff = retry.initial_backoff.min(retry.max_backoff);
-        let multiplier = if retry.backoff_multiplier.is_finite() && retry.backoff_multiplier > 0.0 {
-            retry.backoff_multiplier
-        } else {
-            1.0
-        };
 
-        for attempt in 1..=max_attempts {
+        let mut attempt = 0u32;
+        loop {
+            attempt = attempt.saturating_add(1);
             trace_debug!(
                 target: "milvus_sdk::retry",
+                method,
                 attempt,
-                max_attempts,
                 semantics = ?semantics,
                 "starting Milvus RPC attempt"
             );
-            let call = call()?;
-            let outcome = if retry.max_retry_timeout.is_zero() {
-                call.await
+            self.admit_circuit(method).await?;
+            let call = self.attempt(method, semantics, &mut call);
+            let outcome = if max_retry_timeout.is_zero() {
+                call.await?
             } else {
-                let remaining = retry.max_retry_timeout.saturating_sub(started.elapsed());
+                let remaining = max_retry_timeout.saturating_sub(started.elapsed());
                 if remaining.is_zero() {
-                    trace_debug!(target: "milvus_sdk::retry", attempt, max_attempts, timeout_ms = retry.max_retry_timeout.as_millis(), "Milvus RPC retry deadline reached before attempt completed");
-                    return Err(retry_attempt_timed_out(retry.max_retry_timeout, attempt));
+                    trace_debug!(target: "milvus_sdk::retry", method, attempt, timeout_ms = max_retry_timeout.as_millis(), "Milvus RPC retry deadline reached before attempt completed");
+                    return Err(retry_attempt_timed_out(max_retry_timeout, attempt));
                 }
                 match tokio::time::timeout(remaining, call).await {
-                    Ok(outcome) => outcome,
+                    Ok(outcome) => outcome?,
                     Err(_) => {
-                        trace_debug!(target: "milvus_sdk::retry", attempt, max_attempts, timeout_ms = retry.max_retry_timeout.as_millis(), "Milvus RPC attempt exceeded total retry deadline");
-                        return Err(retry_attempt_timed_out(retry.max_retry_timeout, attempt));
+                        trace_debug!(target: "milvus_sdk::retry", method, attempt, timeout_ms = max_retry_timeout.as_millis(), "Milvus RPC attempt exceeded total retry deadline");
+                        return Err(retry_attempt_timed_out(max_retry_timeout, attempt));
                     }
                 }
             };
-            let failure = match outcome {
+            self.record_circuit(outcome.as_ref().err().map(Status::code));
+            let (failure, rate_limited, transient) = match outcome {
                 Ok(response) => {
                     let response = response.into_inner();
-                    if let Some(get_status) = &get_status {
-                        let status = get_status(&response).ok_or_else(|| {
-                            Error::MalformedResponse(
-                                "RPC response does not contain a status".into(),
-                            )
-                        })?;
-                        if let Err(error) =
-                            crate::v2::error::status_to_result(&Some(status.clone()))
-                        {
-                            let rate_limited = is_rate_limit(&status);
-                            if (rate_limited && retry.retry_on_rate_limit)
-                                || (!rate_limited
-                                    && status.retriable
-                                    && semantics == RetrySemantics::Idempotent)
-                            {
-                                trace_debug!(
-                                    target: "milvus_sdk::retry",
-                                    attempt,
-                                    max_attempts,
-                                    rate_limited,
-                                    retriable = status.retriable,
-                                    semantics = ?semantics,
-                                    "Milvus server status is eligible for retry"
-                                );
-                                error
-                            } else {
-                                trace_debug!(target: "milvus_sdk::retry", attempt, max_attempts, rate_limited, retriable = status.retriable, semantics = ?semantics, error = %error, "Milvus server status is not eligible for retry");
-                                return Err(error);
-                            }
-                        } else {
-                            trace_debug!(target: "milvus_sdk::retry", attempt, max_attempts, elapsed_ms = started.elapsed().as_millis(), "Milvus RPC completed successfully");
+                    let Some(get_status) = &get_status else {
+                        trace_debug!(target: "milvus_sdk::retry", method, attempt, elapsed_ms = started.elapsed().as_millis(), "Milvus RPC completed successfully");
+                        self.limits.on_success(method);
+                        return Ok(response);
+                    };
+                    let status = get_status(&response).ok_or_else(|| {
+                        Error::MalformedResponse("RPC response does not contain a status".into())
+                    })?;
+                    match crate::v2::error::status_to_result(&Some(status.clone())) {
+                        Ok(()) => {
+                            trace_debug!(target: "milvus_sdk::retry", method, attempt, elapsed_ms = started.elapsed().as_millis(), "Milvus RPC completed successfully");
+                            self.limits.on_success(method);
                             return Ok(response);
                         }
-                    } else {
-                        trace_debug!(target: "milvus_sdk::retry", attempt, max_attempts, elapsed_ms = started.elapsed().as_millis(), "Milvus RPC completed successfully");
-                        return Ok(response);
+                        Err(error) => {
+                            let rate_limited = is_rate_limit(&status);
+                            let transient = !rate_limited
+                                && status.retriable
+                                && semantics == RetrySemantics::Idempotent;
+                            (error, rate_limited, transient)
+                        }
                     }
                 }
-                Err(status)
-                    if semantics == RetrySemantics::Idempotent
-                        && is_retryable_grpc(status.code()) =>
-                {
-                    trace_debug!(
-                        target: "milvus_sdk::retry",
-                        attempt,
-                        max_attempts,
-                        grpc_code = ?status.code(),
-                        "gRPC transport status is eligible for retry"
-                    );
-                    Error::Grpc(status)
-                }
                 Err(status) => {
-                    trace_debug!(target: "milvus_sdk::retry", attempt, max_attempts, grpc_code = ?status.code(), semantics = ?semantics, "gRPC transport status is not eligible for retry");
-                    return Err(status.into());
+                    let transient =
+                        semantics == RetrySemantics::Idempotent && is_retryable_grpc(status.code());
+                    (Error::Grpc(status), false, transient)
                 }
             };
-
-            if attempt >= max_attempts {
-                trace_debug!(target: "milvus_sdk::retry", attempt, max_attempts, error = %failure, elapsed_ms = started.elapsed().as_millis(), "Milvus RPC retry attempts exhausted");
-                return Err(retry_exhausted(max_attempts, failure));
+            if rate_limited {
+                self.limits.on_rate_limited(method);
             }
-            if retry_timeout_reached(started, backoff, retry.max_retry_timeout) {
-                trace_debug!(target: "milvus_sdk::retry", attempt, max_attempts, error = %failure, timeout_ms = retry.max_retry_timeout.as_millis(), elapsed_ms = started.elapsed().as_millis(), "Milvus RPC retry timeout reached");
-                return Err(retry_timed_out(retry.max_retry_timeout, attempt, &failure));
+
+            let context = RetryContext {
+                method,
+                semantics,
+                attempt,
+                elapsed: started.elapsed(),
+                error: &failure,
+                rate_limited,
+                transient,
+            };
+            let decision = if semantics == RetrySemantics::NonIdempotent && !rate_limited {
+                RetryDecision::Fail
+            } else {
+                policy.decide(&context)
+            };
+            let backoff = match decision {
+                RetryDecision::Retry(backoff) => backoff,
+                RetryDecision::Fail => {
+                    trace_debug!(target: "milvus_sdk::retry", method, attempt, rate_limited, transient, semantics = ?semantics, error = %failure, "Milvus RPC failure is not eligible for retry");
+                    return Err(failure);
+                }
+                RetryDecision::Exhausted => {
+                    trace_debug!(target: "milvus_sdk::retry", method, attempt, error = %failure, elapsed_ms = started.elapsed().as_millis(), "Milvus RPC retry attempts exhausted");
+                    return Err(retry_exhausted(attempt, failure));
+                }
+            };
+            if retry_timeout_reached(started, backoff, max_retry_timeout) {
+                trace_debug!(target: "milvus_sdk::retry", method, attempt, error = %failure, timeout_ms = max_retry_timeout.as_millis(), elapsed_ms = started.elapsed().as_millis(), "Milvus RPC retry timeout reached");
+                return Err(retry_timed_out(max_retry_timeout, attempt, &failure));
             }
 
             trace_debug!(
                 target: "milvus_sdk::retry",
+                method,
                 attempt,
-                max_attempts,
+                rate_limited,
                 backoff_ms = backoff.as_millis(),
                 "waiting before Milvus RPC retry"
             );
             tokio::time::sleep(backoff).await;
-            backoff = next_backoff(backoff, multiplier, retry.max_backoff);
         }
-
-        unreachable!("retry loop always returns")
     }
 }
 
@@ -595,12 +666,6 @@ fn is_rate_limit(status: &crate::proto::common::Status) -> bool {
     status.error_code == crate::proto::common::ErrorCode::RateLimit as i32 || status.code == 8
 }
 
-fn next_backoff(current: Duration, multiplier: f64, maximum: Duration) -> Duration {
-    Duration::try_from_secs_f64(current.as_secs_f64() * multiplier)
-        .unwrap_or(maximum)
-        .min(maximum)
-}
-
 fn retry_timeout_reached(started: Instant, backoff: Duration, limit: Duration) -> bool {
     if limit.is_zero() {
         return false;
@@ -654,7 +719,11 @@ mod retry_tests {
             service: MilvusServiceClient::with_interceptor(channel, interceptor),
             database,
             rpc_timeout: Arc::new(RwLock::new(Duration::from_secs(1))),
-            retry: Arc::new(RwLock::new(retry)),
+            retry_policy: Arc::new(RwLock::new(Arc::new(retry))),
+            limits: Arc::new(ClientLimits::default()),
+            circuit_breaker: None,
+            hedging: None,
+            result_cache: None,
             cache_endpoint: Arc::new("http://127.0.0.1:19530".to_owned()),
             schema_load_scope: Arc::new(SchemaLoadScope::new()),
         }
@@ -825,6 +894,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     let attempt = observed.fetch_add(1, Ordering::SeqCst) + 1;
                     Ok(async move {
@@ -851,6 +921,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     observed.fetch_add(1, Ordering::SeqCst);
                     Ok(async { Err::<Response<()>, _>(Status::invalid_argument("bad request")) })
@@ -874,6 +945,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     let attempt = observed.fetch_add(1, Ordering::SeqCst) + 1;
                     Ok(async move {
@@ -905,6 +977,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     observed.fetch_add(1, Ordering::SeqCst);
                     Ok(async { Ok(Response::new(retriable_server_status())) })
@@ -926,6 +999,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     let attempt = observed.fetch_add(1, Ordering::SeqCst) + 1;
                     Ok(async move {
@@ -957,6 +1031,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     observed.fetch_add(1, Ordering::SeqCst);
                     Ok(async {
@@ -988,6 +1063,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     observed.fetch_add(1, Ordering::SeqCst);
                     Ok(async { Ok(Response::new(retriable_server_status())) })
@@ -1018,6 +1094,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     observed.fetch_add(1, Ordering::SeqCst);
                     Ok(async { Ok(Response::new(retriable_server_status())) })
@@ -1042,6 +1119,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     observed.fetch_add(1, Ordering::SeqCst);
                     Ok(std::future::pending::<
@@ -1071,11 +1149,12 @@ mod retry_tests {
 
         let result = client
             .retry_rpc(
-                move || Ok(observed_builds.fetch_add(1, Ordering::SeqCst) + 1),
+                "test",
+                move || Ok((observed_builds.fetch_add(1, Ordering::SeqCst) + 1) as u32),
                 RetrySemantics::NonIdempotent,
                 move |_service, request| {
                     let attempt = observed_calls.fetch_add(1, Ordering::SeqCst) + 1;
-                    assert_eq!(request.into_inner(), attempt);
+                    assert_eq!(request.into_inner() as usize, attempt);
                     async move {
                         let status = if attempt < 3 {
                             common::Status {
@@ -1111,6 +1190,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     observed.fetch_add(1, Ordering::SeqCst);
                     Ok(async { Err::<Response<()>, _>(Status::unavailable("offline")) })
@@ -1135,6 +1215,7 @@ mod retry_tests {
         let observed = Arc::clone(&attempts);
         let result = client
             .retry_call(
+                "test",
                 move || {
                     observed.fetch_add(1, Ordering::SeqCst);
                     Ok(async {
@@ -1169,6 +1250,7 @@ mod retry_tests {
             let observed = Arc::clone(&attempts);
             let result = client
                 .retry_call(
+                    "test",
                     move || {
                         observed.fetch_add(1, Ordering::SeqCst);
                         Ok(async move { Err::<Response<()>, _>(Status::new(code, "ack lost")) })
@@ -1182,4 +1264,116 @@ mod retry_tests {
             assert_eq!(attempts.load(Ordering::SeqCst), 1);
         }
     }
+
+    #[derive(Debug)]
+    struct AlwaysRetry;
+
+    impl RetryPolicy for AlwaysRetry {
+        fn decide(&self, _context: &RetryContext<'_>) -> RetryDecision {
+            RetryDecision::Retry(Duration::ZERO)
+        }
+    }
+
+    #[tokio::test]
+    async fn custom_policies_cannot_replay_ambiguous_non_idempotent_failures() {
+        let client = client(RetryConfig::new());
+        client.set_retry_policy(AlwaysRetry);
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let observed = Arc::clone(&attempts);
+        let result = client
+            .retry_call(
+                "insert",
+                move || {
+                    observed.fetch_add(1, Ordering::SeqCst);
+                    Ok(async { Err::<Response<()>, _>(Status::unavailable("ack lost")) })
+                },
+                None::<fn(&()) -> Option<common::Status>>,
+                RetrySemantics::NonIdempotent,
+            )
+            .await;
+
+        assert!(matches!(result, Err(Error::Grpc(status)) if status.code() == Code::Unavailable));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn operation_overrides_select_the_policy_by_method_name() {
+        let client = client(RetryConfig::new());
+        client.set_retry_policy(
+            crate::v2::retry::OperationRetryPolicy::new(crate::v2::retry::NoRetry)
+                .operation("query", fast_retry(3)),
+        );
+        for (method, expected) in [("query", 3), ("search", 1)] {
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let observed = Arc::clone(&attempts);
+            let result = client
+                .retry_call(
+                    method,
+                    move || {
+                        observed.fetch_add(1, Ordering::SeqCst);
+                        Ok(async { Err::<Response<()>, _>(Status::unavailable("offline")) })
+                    },
+                    None::<fn(&()) -> Option<common::Status>>,
+                    RetrySemantics::Idempotent,
+                )
+                .await;
+
+            assert!(result.is_err());
+            assert_eq!(attempts.load(Ordering::SeqCst), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn request_retry_policy_overrides_the_client_policy() {
+        let client = client(fast_retry(3));
+        let mut options = crate::v2::request::options::RequestOptions::new();
+        options.retry_policy = Some(Arc::new(crate::v2::retry::NoRetry));
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let observed = Arc::clone(&attempts);
+        let result = client
+            .scoped(
+                &options,
+                client.retry_call(
+                    "query",
+                    move || {
+                        observed.fetch_add(1, Ordering::SeqCst);
+                        Ok(async { Err::<Response<()>, _>(Status::unavailable("offline")) })
+                    },
+                    None::<fn(&()) -> Option<common::Status>>,
+                    RetrySemantics::Idempotent,
+                ),
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn open_circuit_stops_retrying_unavailable_endpoint() {
+        let mut client = client(fast_retry(10));
+        client.circuit_breaker = Some(Arc::new(CircuitBreaker::new(
+            crate::v2::types::CircuitBreakerConfig::new().failure_threshold(2),
+        )));
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let observed = Arc::clone(&attempts);
+        let result = client
+            .retry_call(
+                "query",
+                move || {
+                    observed.fetch_add(1, Ordering::SeqCst);
+                    Ok(async { Err::<Response<()>, _>(Status::unavailable("offline")) })
+                },
+                None::<fn(&()) -> Option<common::Status>>,
+                RetrySemantics::Idempotent,
+            )
+            .await;
+
+        assert!(matches!(result, Err(Error::CircuitOpen(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            client.circuit_state(),
+            Some(crate::v2::types::CircuitState::Open)
+        );
+    }
 }
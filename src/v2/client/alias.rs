@@ -26,42 +26,54 @@ impl ClientV2 {
     ///
     /// The SDK also aligns schema and session-timestamp cache state with the aliased collection.
     pub async fn create_alias(&self, request: request::alias::CreateAliasRequest) -> Result<()> {
-        let database = self.current_database();
-        let raw = request.into_proto(&database);
-        let database = raw.db_name.clone();
-        let collection = raw.collection_name.clone();
-        let alias = raw.alias.clone();
-        let status = status_rpc_with_retry!(Idempotent, self, create_alias, raw)?;
-        self.status(status)?;
-        self.remove_collection_description(&database, &alias);
-        self.copy_dml_timestamp(&database, &collection, &alias);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let raw = request.into_proto(&database);
+            let database = raw.db_name.clone();
+            let collection = raw.collection_name.clone();
+            let alias = raw.alias.clone();
+            let status = status_rpc_with_retry!(Idempotent, self, create_alias, raw)?;
+            self.status(status)?;
+            self.remove_collection_description(&database, &alias);
+            self.copy_dml_timestamp(&database, &collection, &alias);
+            Ok(())
+        })
+        .await
     }
 
     /// Drops an alias without dropping the underlying collection.
     pub async fn drop_alias(&self, request: request::alias::DropAliasRequest) -> Result<()> {
-        let database = self.current_database();
-        let raw = request.into_proto(&database);
-        let database = raw.db_name.clone();
-        let alias = raw.alias.clone();
-        let status = status_rpc_with_retry!(Idempotent, self, drop_alias, raw)?;
-        self.status(status)?;
-        self.remove_collection_cache(&database, &alias);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let raw = request.into_proto(&database);
+            let database = raw.db_name.clone();
+            let alias = raw.alias.clone();
+            let status = status_rpc_with_retry!(Idempotent, self, drop_alias, raw)?;
+            self.status(status)?;
+            self.remove_collection_cache(&database, &alias);
+            Ok(())
+        })
+        .await
     }
 
     /// Repoints an alias from its current collection to another collection.
     pub async fn alter_alias(&self, request: request::alias::AlterAliasRequest) -> Result<()> {
-        let database = self.current_database();
-        let raw = request.into_proto(&database);
-        let database = raw.db_name.clone();
-        let collection = raw.collection_name.clone();
-        let alias = raw.alias.clone();
-        let status = status_rpc_with_retry!(Idempotent, self, alter_alias, raw)?;
-        self.status(status)?;
-        self.remove_collection_description(&database, &alias);
-        self.copy_dml_timestamp(&database, &collection, &alias);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let raw = request.into_proto(&database);
+            let database = raw.db_name.clone();
+            let collection = raw.collection_name.clone();
+            let alias = raw.alias.clone();
+            let status = status_rpc_with_retry!(Idempotent, self, alter_alias, raw)?;
+            self.status(status)?;
+            self.remove_collection_description(&database, &alias);
+            self.copy_dml_timestamp(&database, &collection, &alias);
+            Ok(())
+        })
+        .await
     }
 
     /// Resolves an alias to its database and canonical collection name.
@@ -69,10 +81,14 @@ impl ClientV2 {
         &self,
         request: request::alias::DescribeAliasRequest,
     ) -> Result<response::alias::DescribeAliasResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, describe_alias, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        Ok(response::alias::DescribeAliasResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(self, describe_alias, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            Ok(response::alias::DescribeAliasResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Lists aliases associated with a collection in the selected database.
@@ -80,9 +96,13 @@ impl ClientV2 {
         &self,
         request: request::alias::ListAliasesRequest,
     ) -> Result<response::alias::ListAliasesResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, list_aliases, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        Ok(response::alias::ListAliasesResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(self, list_aliases, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            Ok(response::alias::ListAliasesResponse::from_proto(response))
+        })
+        .await
     }
 }
@@ -0,0 +1,372 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Micro-batching of concurrent searches.
+//!
+//! A batch collects compatible searches until it is full or its wait expires, then a spawned task
+//! sends one `search` RPC carrying every caller's query vectors and hands each caller the
+//! `SingleResult`s of its own vectors. Searches are compatible when every request setting other
+//! than the query vectors is equal and the vectors share a kind and width, so one caller's
+//! malformed vectors cannot fail its companions' searches.
+
+use super::ClientV2;
+use crate::v2::error::{Error, Result};
+use crate::v2::request::dql::SearchRequest;
+use crate::v2::response::dql::SearchResponse;
+use crate::v2::types::{SearchBatchConfig, SearchResults, SearchVectors};
+use parking_lot::Mutex;
+use std::mem::{self, Discriminant};
+use std::sync::Arc;
+use tokio::sync::{oneshot, Notify};
+
+type Waiter = (usize, oneshot::Sender<Result<SearchResponse>>);
+
+impl ClientV2 {
+    /// Creates a batcher that coalesces concurrent compatible searches into shared RPCs.
+    ///
+    /// Batching is opt-in: only searches sent through the returned batcher, or its clones, are
+    /// coalesced.
+    pub fn search_batcher(&self, config: SearchBatchConfig) -> SearchBatcher {
+        SearchBatcher {
+            client: self.clone(),
+            config,
+            pending: Arc::new(Mutex::new(PendingBatches::default())),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SearchBatcher
+///////////////////////////////////////////////////////////////////////////////
+/// Coalesces concurrent compatible searches into one `search` RPC.
+///
+/// Two searches are batched together when they target the same database, collection, partitions,
+/// and vector field and agree on the filter, limit, offset, output fields, search parameters,
+/// consistency level, and timeout. A batch is sent when it holds
+/// [`SearchBatchConfig::max_batch_size`] query vectors or [`SearchBatchConfig::max_wait`] after its
+/// first search, and each caller receives one result per query vector it sent, along with the
+/// statistics of the shared response.
+///
+/// Searches by primary key, searches with a cancellation token, and searches carrying a full
+/// batch of vectors on their own bypass batching and are sent directly. A failed batch reports its
+/// error to every caller in it.
+#[derive(Clone)]
+pub struct SearchBatcher {
+    client: ClientV2,
+    config: SearchBatchConfig,
+    pending: Arc<Mutex<PendingBatches>>,
+}
+
+#[derive(Default)]
+struct PendingBatches {
+    next_id: u64,
+    batches: Vec<PendingBatch>,
+}
+
+struct PendingBatch {
+    id: u64,
+    template: SearchRequest,
+    shape: Shape,
+    vectors: SearchVectors,
+    query_count: usize,
+    waiters: Vec<Waiter>,
+    full: Arc<Notify>,
+}
+
+impl SearchBatcher {
+    /// Returns the batching limits.
+    pub fn config(&self) -> &SearchBatchConfig {
+        &self.config
+    }
+
+    /// Executes a search, sharing the RPC with concurrent compatible searches.
+    pub async fn search(&self, mut request: SearchRequest) -> Result<SearchResponse> {
+        let max_batch_size = self.config.max_batch_size;
+        let count = query_count(&request.vectors);
+        let batchable = request.ids.is_empty()
+            && request.options.cancellation_token.is_none()
+            && count > 0
+            && count < max_batch_size;
+        let Some(shape) = shape(&request.vectors).filter(|_| batchable) else {
+            return self.client.search(request).await;
+        };
+        let vectors = mem::take(&mut request.vectors);
+        let (sender, receiver) = oneshot::channel();
+        {
+            let mut pending = self.pending.lock();
+            let open = pending.batches.iter_mut().find(|batch| {
+                batch.shape == shape
+                    && batch.query_count + count <= max_batch_size
+                    && batch.template == request
+            });
+            if let Some(batch) = open {
+                append_vectors(&mut batch.vectors, vectors);
+                batch.query_count += count;
+                batch.waiters.push((count, sender));
+                if batch.query_count >= max_batch_size {
+                    batch.full.notify_one();
+                }
+            } else {
+                let id = pending.next_id;
+                pending.next_id += 1;
+                let full = Arc::new(Notify::new());
+                pending.batches.push(PendingBatch {
+                    id,
+                    template: request,
+                    shape,
+                    vectors,
+                    query_count: count,
+                    waiters: vec![(count, sender)],
+                    full: full.clone(),
+                });
+                tokio::spawn(self.clone().flush(id, full));
+            }
+        }
+        receiver.await.unwrap_or_else(|_| {
+            Err(Error::Unexpected(
+                "search batch ended without a response".into(),
+            ))
+        })
+    }
+
+    async fn flush(self, id: u64, full: Arc<Notify>) {
+        tokio::select! {
+            _ = tokio::time::sleep(self.config.max_wait) => {}
+            _ = full.notified() => {}
+        }
+        let batch = {
+            let mut pending = self.pending.lock();
+            match pending.batches.iter().position(|batch| batch.id == id) {
+                Some(position) => pending.batches.swap_remove(position),
+                None => return,
+            }
+        };
+        let PendingBatch {
+            mut template,
+            vectors,
+            waiters,
+            ..
+        } = batch;
+        template.vectors = vectors;
+        let counts = waiters.iter().map(|(count, _)| *count).collect::<Vec<_>>();
+        let outcome = self
+            .client
+            .search(template)
+            .await
+            .and_then(|response| split_response(response, &counts));
+        match outcome {
+            Ok(responses) => {
+                for ((_, sender), response) in waiters.into_iter().zip(responses) {
+                    let _ = sender.send(Ok(response));
+                }
+            }
+            Err(error) => {
+                for (_, sender) in waiters {
+                    let _ = sender.send(Err(error.clone()));
+                }
+            }
+        }
+    }
+}
+
+/// Kind and per-row width of a set of query vectors; sparse and text inputs have width zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Shape {
+    kind: Discriminant<SearchVectors>,
+    width: usize,
+}
+
+/// Returns the shape of `vectors`, or `None` when their rows have different widths.
+fn shape(vectors: &SearchVectors) -> Option<Shape> {
+    fn uniform<T>(rows: &[Vec<T>]) -> Option<usize> {
+        let width = rows.first().map_or(0, Vec::len);
+        rows.iter().all(|row| row.len() == width).then_some(width)
+    }
+    let width = match vectors {
+        SearchVectors::Float(rows) => uniform(rows)?,
+        SearchVectors::DenseFloat(rows) => rows.dim(),
+        SearchVectors::Binary(rows) => uniform(rows)?,
+        SearchVectors::Float16(rows) | SearchVectors::BFloat16(rows) => uniform(rows)?,
+        SearchVectors::Int8(rows) => uniform(rows)?,
+        SearchVectors::SparseFloat(_) | SearchVectors::EmbeddedText(_) => 0,
+        SearchVectors::EmbeddingLists(lists) => {
+            let mut rows = lists.iter().flat_map(|list| &list.vectors);
+            let width = rows.clone().next().map_or(0, Vec::len);
+            rows.all(|row| row.len() == width).then_some(width)?
+        }
+    };
+    Some(Shape {
+        kind: mem::discriminant(vectors),
+        width,
+    })
+}
+
+fn query_count(vectors: &SearchVectors) -> usize {
+    match vectors {
+        SearchVectors::Float(rows) => rows.len(),
+        SearchVectors::DenseFloat(rows) => rows.len(),
+        SearchVectors::Binary(rows) => rows.len(),
+        SearchVectors::Float16(rows) | SearchVectors::BFloat16(rows) => rows.len(),
+        SearchVectors::SparseFloat(rows) => rows.len(),
+        SearchVectors::Int8(rows) => rows.len(),
+        SearchVectors::EmbeddedText(rows) => rows.len(),
+        SearchVectors::EmbeddingLists(rows) => rows.len(),
+    }
+}
+
+/// Appends `other` to `target`; callers only batch vectors of the same shape.
+fn append_vectors(target: &mut SearchVectors, other: SearchVectors) {
+    match (target, other) {
+        (SearchVectors::Float(target), SearchVectors::Float(other)) => target.extend(other),
+        (SearchVectors::DenseFloat(target), SearchVectors::DenseFloat(other)) => {
+            target.data.extend(other.data)
+        }
+        (SearchVectors::Binary(target), SearchVectors::Binary(other)) => target.extend(other),
+        (SearchVectors::Float16(target), SearchVectors::Float16(other)) => target.extend(other),
+        (SearchVectors::BFloat16(target), SearchVectors::BFloat16(other)) => target.extend(other),
+        (SearchVectors::SparseFloat(target), SearchVectors::SparseFloat(other)) => {
+            target.extend(other)
+        }
+        (SearchVectors::Int8(target), SearchVectors::Int8(other)) => target.extend(other),
+        (SearchVectors::EmbeddedText(target), SearchVectors::EmbeddedText(other)) => {
+            target.extend(other)
+        }
+        (SearchVectors::EmbeddingLists(target), SearchVectors::EmbeddingLists(other)) => {
+            target.extend(other)
+        }
+        _ => unreachable!("batched search vectors share a shape"),
+    }
+}
+
+/// Splits a batched response into one response per caller, in the order of `counts`.
+///
+/// Recalls are split with the results when the server reports one per query vector and are
+/// otherwise shared by every caller.
+fn split_response(response: SearchResponse, counts: &[usize]) -> Result<Vec<SearchResponse>> {
+    let total = counts.iter().sum::<usize>();
+    let SearchResponse {
+        results: SearchResults { results, recalls },
+        session_timestamp,
+        cost,
+        scanned_remote_bytes,
+        scanned_total_bytes,
+        cache_hit_ratio,
+    } = response;
+    if results.len() != total {
+        return Err(Error::MalformedResponse(format!(
+            "batched search returned {} results for {total} query vectors",
+            results.len()
+        )));
+    }
+    let per_query_recalls = recalls.len() == total;
+    let mut results = results.into_iter();
+    let mut offset = 0;
+    Ok(counts
+        .iter()
+        .map(|&count| {
+            let recalls = if per_query_recalls {
+                recalls[offset..offset + count].to_vec()
+            } else {
+                recalls.clone()
+            };
+            offset += count;
+            SearchResponse {
+                results: SearchResults {
+                    results: results.by_ref().take(count).collect(),
+                    recalls,
+                },
+                session_timestamp,
+                cost,
+                scanned_remote_bytes,
+                scanned_total_bytes,
+                cache_hit_ratio,
+            }
+        })
+        .collect())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::types::{DenseVectors, Ids, SingleResult};
+
+    #[test]
+    fn shapes_require_matching_kind_and_width() {
+        let float = shape(&SearchVectors::Float(vec![vec![0.0; 4]])).unwrap();
+        let dense = shape(&SearchVectors::DenseFloat(
+            DenseVectors::new(4, vec![0.0; 8]).unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(float.width, 4);
+        assert_eq!(dense.width, 4);
+        assert_ne!(float, dense);
+        assert_ne!(
+            float,
+            shape(&SearchVectors::Float(vec![vec![0.0; 8]])).unwrap()
+        );
+        assert_eq!(
+            shape(&SearchVectors::Float(vec![vec![0.0; 4], vec![0.0; 2]])),
+            None
+        );
+        assert_eq!(
+            shape(&SearchVectors::EmbeddedText(vec!["a".into()]))
+                .unwrap()
+                .width,
+            0
+        );
+    }
+
+    #[test]
+    fn vectors_are_concatenated_in_arrival_order() {
+        let mut vectors = SearchVectors::DenseFloat(DenseVectors::new(2, vec![1.0, 2.0]).unwrap());
+        append_vectors(
+            &mut vectors,
+            SearchVectors::DenseFloat(DenseVectors::new(2, vec![3.0, 4.0, 5.0, 6.0]).unwrap()),
+        );
+        assert_eq!(query_count(&vectors), 3);
+        assert_eq!(
+            vectors,
+            SearchVectors::DenseFloat(
+                DenseVectors::new(2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn responses_are_split_by_caller() {
+        let results = (0..3)
+            .map(|id| SingleResult::new().ids(Ids::Int64(vec![id])))
+            .collect::<Vec<_>>();
+        let response = SearchResponse::builder()
+            .results(SearchResults::new().results(results.clone()))
+            .session_timestamp(7)
+            .build();
+        let split = split_response(response.clone(), &[1, 2]).unwrap();
+        assert_eq!(split.len(), 2);
+        assert_eq!(split[0].results().get_results(), &results[..1]);
+        assert_eq!(split[1].results().get_results(), &results[1..]);
+        assert_eq!(split[1].session_timestamp(), 7);
+
+        assert!(matches!(
+            split_response(response, &[1, 1]),
+            Err(Error::MalformedResponse(_))
+        ));
+    }
+}
@@ -27,13 +27,17 @@ impl ClientV2 {
         &self,
         request: request::cdc::UpdateReplicateConfigurationRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            update_replicate_configuration,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                update_replicate_configuration,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Retrieves the current CDC replication configuration.
@@ -41,9 +45,14 @@ impl ClientV2 {
         &self,
         request: request::cdc::GetReplicateConfigurationRequest,
     ) -> Result<response::cdc::GetReplicateConfigurationResponse> {
-        let response = rpc_with_retry!(self, get_replicate_configuration, request.into_proto())?;
-        status_to_result(&response.status)?;
-        response::cdc::GetReplicateConfigurationResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response =
+                rpc_with_retry!(self, get_replicate_configuration, request.into_proto())?;
+            status_to_result(&response.status)?;
+            response::cdc::GetReplicateConfigurationResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Retrieves replication progress and metadata for a CDC channel.
@@ -51,14 +60,19 @@ impl ClientV2 {
         &self,
         request: request::cdc::GetReplicateInfoRequest,
     ) -> Result<response::cdc::GetReplicateInfoResponse> {
-        let response = self
-            .retry_transport(
-                request.into_proto(),
-                true,
-                |mut service, request| async move { service.get_replicate_info(request).await },
-            )
-            .await?;
-        response::cdc::GetReplicateInfoResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = self
+                .retry_transport(
+                    "get_replicate_info",
+                    request.into_proto(),
+                    true,
+                    |mut service, request| async move { service.get_replicate_info(request).await },
+                )
+                .await?;
+            response::cdc::GetReplicateInfoResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Consume dumped WAL messages with an SDK-domain callback.
@@ -76,27 +90,31 @@ impl ClientV2 {
     where
         F: FnMut(&response::cdc::DumpedMessage) -> Result<()>,
     {
-        use crate::proto::milvus::dump_messages_response::Response;
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            use crate::proto::milvus::dump_messages_response::Response;
 
-        let mut service = self.service.clone();
-        let mut stream = service
-            .dump_messages(tonic::Request::new(request.into_proto()))
-            .await?
-            .into_inner();
-        while let Some(value) = stream.message().await? {
-            match value.response {
-                Some(Response::Message(message)) => {
-                    let message = response::cdc::DumpedMessage::from_proto(message)?;
-                    on_message(&message)?;
-                }
-                Some(Response::Status(status)) => status_to_result(&Some(status))?,
-                None => {
-                    return Err(crate::v2::error::Error::MalformedResponse(
-                        "dump messages returned an empty stream item".into(),
-                    ));
+            let mut service = self.service.clone();
+            let mut stream = service
+                .dump_messages(tonic::Request::new(request.into_proto()))
+                .await?
+                .into_inner();
+            while let Some(value) = stream.message().await? {
+                match value.response {
+                    Some(Response::Message(message)) => {
+                        let message = response::cdc::DumpedMessage::from_proto(message)?;
+                        on_message(&message)?;
+                    }
+                    Some(Response::Status(status)) => status_to_result(&Some(status))?,
+                    None => {
+                        return Err(crate::v2::error::Error::MalformedResponse(
+                            "dump messages returned an empty stream item".into(),
+                        ));
+                    }
                 }
             }
-        }
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 }
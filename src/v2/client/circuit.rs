@@ -0,0 +1,278 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ClientV2 circuit breaker around an unreachable endpoint.
+
+use super::ClientV2;
+use crate::proto::milvus;
+use crate::v2::error::{Error, Result};
+use crate::v2::types::{CircuitBreakerConfig, CircuitState};
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+use tonic::Code;
+
+impl ClientV2 {
+    /// Returns the state of the circuit breaker, or `None` when no breaker is configured.
+    ///
+    /// Readiness probes and load balancers can use this value to stop routing work to a client
+    /// whose endpoint is currently considered unreachable.
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.circuit_breaker
+            .as_ref()
+            .map(|breaker| breaker.state(Instant::now()))
+    }
+
+    /// Admits one RPC attempt, probing the endpoint with `check_health` when the breaker is due to
+    /// leave the open state.
+    pub(super) async fn admit_circuit(&self, method: &'static str) -> Result<()> {
+        #[cfg(not(feature = "tracing"))]
+        let _ = method;
+        let Some(breaker) = &self.circuit_breaker else {
+            return Ok(());
+        };
+        match breaker.admit(Instant::now()) {
+            Admission::Allow => Ok(()),
+            Admission::Reject(retry_after) => {
+                trace_debug!(target: "milvus_sdk::circuit", method, retry_after_ms = retry_after.as_millis(), "Milvus circuit breaker rejected RPC");
+                Err(circuit_open(retry_after))
+            }
+            Admission::Probe => {
+                let probe = ProbeGuard(Some(breaker));
+                let healthy = self.probe_health(breaker.config.probe_timeout).await;
+                trace_debug!(
+                    target: "milvus_sdk::circuit",
+                    method,
+                    healthy,
+                    "Milvus circuit breaker half-open probe finished"
+                );
+                probe.finish(healthy);
+                if healthy {
+                    Ok(())
+                } else {
+                    Err(circuit_open(breaker.config.open_timeout))
+                }
+            }
+        }
+    }
+
+    /// Records the transport outcome of one admitted RPC attempt.
+    pub(super) fn record_circuit(&self, code: Option<Code>) {
+        if let Some(breaker) = &self.circuit_breaker {
+            match code {
+                Some(Code::Unavailable | Code::DeadlineExceeded) => {
+                    if breaker.record_failure(Instant::now()) {
+                        trace_debug!(
+                            target: "milvus_sdk::circuit",
+                            threshold = breaker.config.failure_threshold,
+                            "Milvus circuit breaker opened"
+                        );
+                    }
+                }
+                _ => breaker.record_success(),
+            }
+        }
+    }
+
+    async fn probe_health(&self, timeout: Duration) -> bool {
+        let mut service = self.service.clone();
+        let request = self.rpc_request(milvus::CheckHealthRequest::default());
+        let probe = async move { service.check_health(request).await };
+        let outcome = if timeout.is_zero() {
+            probe.await
+        } else {
+            match tokio::time::timeout(timeout, probe).await {
+                Ok(outcome) => outcome,
+                Err(_) => return false,
+            }
+        };
+        outcome.is_ok_and(|response| {
+            let response = response.into_inner();
+            crate::v2::error::status_to_result(&response.status).is_ok() && response.is_healthy
+        })
+    }
+}
+
+fn circuit_open(retry_after: Duration) -> Error {
+    Error::CircuitOpen(format!(
+        "endpoint is unreachable; retry after {}ms",
+        retry_after.as_millis()
+    ))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// CircuitBreaker
+///////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+pub(super) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Admission {
+    Allow,
+    Probe,
+    Reject(Duration),
+}
+
+impl CircuitBreaker {
+    pub(super) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    fn state(&self, now: Instant) -> CircuitState {
+        let state = self.state.lock();
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(_) if state.probing => CircuitState::HalfOpen,
+            Some(opened_at)
+                if now.saturating_duration_since(opened_at) >= self.config.open_timeout =>
+            {
+                CircuitState::HalfOpen
+            }
+            Some(_) => CircuitState::Open,
+        }
+    }
+
+    fn admit(&self, now: Instant) -> Admission {
+        let mut state = self.state.lock();
+        let Some(opened_at) = state.opened_at else {
+            return Admission::Allow;
+        };
+        let open_for = now.saturating_duration_since(opened_at);
+        if state.probing || open_for < self.config.open_timeout {
+            return Admission::Reject(self.config.open_timeout.saturating_sub(open_for));
+        }
+        state.probing = true;
+        Admission::Probe
+    }
+
+    fn finish_probe(&self, healthy: bool, now: Instant) {
+        let mut state = self.state.lock();
+        state.probing = false;
+        if healthy {
+            state.consecutive_failures = 0;
+            state.opened_at = None;
+        } else {
+            state.opened_at = Some(now);
+        }
+    }
+
+    /// Returns `true` when this failure opened the breaker.
+    fn record_failure(&self, now: Instant) -> bool {
+        let mut state = self.state.lock();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.opened_at.is_none()
+            && state.consecutive_failures >= self.config.failure_threshold.max(1)
+        {
+            state.opened_at = Some(now);
+            return true;
+        }
+        false
+    }
+
+    fn record_success(&self) {
+        let mut state = self.state.lock();
+        if state.opened_at.is_none() {
+            state.consecutive_failures = 0;
+        }
+    }
+}
+
+/// Reopens the breaker if a half-open probe is dropped before it completes.
+struct ProbeGuard<'a>(Option<&'a CircuitBreaker>);
+
+impl ProbeGuard<'_> {
+    fn finish(mut self, healthy: bool) {
+        if let Some(breaker) = self.0.take() {
+            breaker.finish_probe(healthy, Instant::now());
+        }
+    }
+}
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(breaker) = self.0.take() {
+            breaker.finish_probe(false, Instant::now());
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(threshold: u32) -> CircuitBreaker {
+        CircuitBreaker::new(
+            CircuitBreakerConfig::new()
+                .failure_threshold(threshold)
+                .open_timeout(Duration::from_secs(10)),
+        )
+    }
+
+    #[test]
+    fn consecutive_failures_open_the_breaker() {
+        let breaker = breaker(3);
+        let now = Instant::now();
+        assert!(!breaker.record_failure(now));
+        breaker.record_success();
+        assert!(!breaker.record_failure(now));
+        assert!(!breaker.record_failure(now));
+        assert_eq!(breaker.state(now), CircuitState::Closed);
+
+        assert!(breaker.record_failure(now));
+        assert_eq!(breaker.state(now), CircuitState::Open);
+        assert_eq!(
+            breaker.admit(now + Duration::from_secs(4)),
+            Admission::Reject(Duration::from_secs(6))
+        );
+    }
+
+    #[test]
+    fn half_open_probe_closes_or_reopens_the_breaker() {
+        let breaker = breaker(1);
+        let opened = Instant::now();
+        breaker.record_failure(opened);
+
+        let due = opened + Duration::from_secs(10);
+        assert_eq!(breaker.state(due), CircuitState::HalfOpen);
+        assert_eq!(breaker.admit(due), Admission::Probe);
+        assert!(matches!(breaker.admit(due), Admission::Reject(_)));
+
+        breaker.finish_probe(false, due);
+        assert_eq!(breaker.state(due), CircuitState::Open);
+
+        let next = due + Duration::from_secs(10);
+        assert_eq!(breaker.admit(next), Admission::Probe);
+        breaker.finish_probe(true, next);
+        assert_eq!(breaker.state(next), CircuitState::Closed);
+        assert_eq!(breaker.admit(next), Admission::Allow);
+    }
+}
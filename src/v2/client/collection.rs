@@ -38,41 +38,45 @@ impl ClientV2 {
         &self,
         request: impl Into<request::collection::CreateCollectionRequest>,
     ) -> Result<()> {
-        let mut request = request.into();
-        let database = self.current_database();
-        let effective_database = self.effective_database(request.database_name.as_deref());
-        let collection_name = request.collection_name.clone();
-        let index_params = std::mem::take(&mut request.index_params);
-        let follow_up = if index_params.is_empty() {
-            None
-        } else {
-            let index_request = request::index::CreateIndexRequest::builder()
-                .database_name(effective_database.clone())
-                .collection_name(collection_name.clone())
-                .index_params(index_params)
-                .sync(false)
-                .build()?;
-            let load_request = request::collection::LoadCollectionRequest::builder()
-                .database_name(effective_database.clone())
-                .collection_name(collection_name.clone())
-                .sync(false)
-                .build()?;
-            Some((index_request, load_request))
-        };
-        let raw = request.into_proto(&database)?;
-        self.status(status_rpc_with_retry!(
-            Idempotent,
-            self,
-            create_collection,
-            raw
-        )?)?;
-        self.remove_collection_description(&effective_database, &collection_name);
+        let mut request: request::collection::CreateCollectionRequest = request.into();
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let effective_database = self.effective_database(request.database_name.as_deref());
+            let collection_name = request.collection_name.clone();
+            let index_params = std::mem::take(&mut request.index_params);
+            let follow_up = if index_params.is_empty() {
+                None
+            } else {
+                let index_request = request::index::CreateIndexRequest::builder()
+                    .database_name(effective_database.clone())
+                    .collection_name(collection_name.clone())
+                    .index_params(index_params)
+                    .sync(false)
+                    .build()?;
+                let load_request = request::collection::LoadCollectionRequest::builder()
+                    .database_name(effective_database.clone())
+                    .collection_name(collection_name.clone())
+                    .sync(false)
+                    .build()?;
+                Some((index_request, load_request))
+            };
+            let raw = request.into_proto(&database)?;
+            self.status(status_rpc_with_retry!(
+                Idempotent,
+                self,
+                create_collection,
+                raw
+            )?)?;
+            self.remove_collection_description(&effective_database, &collection_name);
 
-        if let Some((index_request, load_request)) = follow_up {
-            self.create_index(index_request).await?;
-            self.load_collection(load_request).await?;
-        }
-        Ok(())
+            if let Some((index_request, load_request)) = follow_up {
+                self.create_index(index_request).await?;
+                self.load_collection(load_request).await?;
+            }
+            Ok(())
+        })
+        .await
     }
 
     /// Checks whether a collection exists in the request's database.
@@ -80,10 +84,14 @@ impl ClientV2 {
         &self,
         request: request::collection::HasCollectionRequest,
     ) -> Result<response::collection::HasCollectionResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, has_collection, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        Ok(response::collection::HasCollectionResponse(response.value))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(self, has_collection, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            Ok(response::collection::HasCollectionResponse(response.value))
+        })
+        .await
     }
 
     /// Drops a collection together with its partitions, indexes, and stored segments.
@@ -94,18 +102,22 @@ impl ClientV2 {
         &self,
         request: request::collection::DropCollectionRequest,
     ) -> Result<()> {
-        let default_db = self.current_database();
-        let raw = request.into_proto(&default_db);
-        let db = raw.db_name.clone();
-        let name = raw.collection_name.clone();
-        self.status(status_rpc_with_retry!(
-            Idempotent,
-            self,
-            drop_collection,
-            raw
-        )?)?;
-        self.remove_collection_cache(&db, &name);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let default_db = self.current_database();
+            let raw = request.into_proto(&default_db);
+            let db = raw.db_name.clone();
+            let name = raw.collection_name.clone();
+            self.status(status_rpc_with_retry!(
+                Idempotent,
+                self,
+                drop_collection,
+                raw
+            )?)?;
+            self.remove_collection_cache(&db, &name);
+            Ok(())
+        })
+        .await
     }
 
     /// Loads collection data into query-node memory.
@@ -117,31 +129,35 @@ impl ClientV2 {
         &self,
         mut request: request::collection::LoadCollectionRequest,
     ) -> Result<()> {
-        let sync = request.sync;
-        let timeout_ms = request.timeout_ms;
-        let refresh = request.refresh;
-        let database = self.effective_database(request.database_name.as_deref());
-        request.database_name = Some(database.clone());
-        let collection = request.collection_name.clone();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            load_collection,
-            request.into_proto(&database)
-        )?;
-        self.status(status)?;
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let sync = request.sync;
+            let timeout_ms = request.timeout_ms;
+            let refresh = request.refresh;
+            let database = self.effective_database(request.database_name.as_deref());
+            request.database_name = Some(database.clone());
+            let collection = request.collection_name.clone();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                load_collection,
+                request.into_proto(&database)
+            )?;
+            self.status(status)?;
 
-        if !sync {
-            return Ok(());
-        }
-        self.wait_for_collection_loading(
-            &database,
-            &collection,
-            &[],
-            refresh,
-            timeout_ms,
-            "load collection timed out",
-        )
+            if !sync {
+                return Ok(());
+            }
+            self.wait_for_collection_loading(
+                &database,
+                &collection,
+                &[],
+                refresh,
+                timeout_ms,
+                "load collection timed out",
+            )
+            .await
+        })
         .await
     }
 
@@ -153,31 +169,35 @@ impl ClientV2 {
         &self,
         mut request: request::collection::RefreshLoadRequest,
     ) -> Result<()> {
-        let sync = request.sync;
-        let timeout_ms = request.timeout_ms;
-        let database = self.effective_database(request.database_name.as_deref());
-        request.database_name = Some(database.clone());
-        let collection = request.collection_name.clone();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            load_collection,
-            request.into_proto(&database)
-        )?;
-        self.status(status)?;
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let sync = request.sync;
+            let timeout_ms = request.timeout_ms;
+            let database = self.effective_database(request.database_name.as_deref());
+            request.database_name = Some(database.clone());
+            let collection = request.collection_name.clone();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                load_collection,
+                request.into_proto(&database)
+            )?;
+            self.status(status)?;
 
-        if !sync {
-            return Ok(());
-        }
+            if !sync {
+                return Ok(());
+            }
 
-        self.wait_for_collection_loading(
-            &database,
-            &collection,
-            &[],
-            true,
-            timeout_ms,
-            "refresh load timed out",
-        )
+            self.wait_for_collection_loading(
+                &database,
+                &collection,
+                &[],
+                true,
+                timeout_ms,
+                "refresh load timed out",
+            )
+            .await
+        })
         .await
     }
 
@@ -261,14 +281,18 @@ impl ClientV2 {
         &self,
         request: request::collection::ReleaseCollectionRequest,
     ) -> Result<()> {
-        let database = self.current_database();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            release_collection,
-            request.into_proto(&database)
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                release_collection,
+                request.into_proto(&database)
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Retrieves a collection's schema, functions, and collection properties.
@@ -279,10 +303,14 @@ impl ClientV2 {
         &self,
         request: request::collection::DescribeCollectionRequest,
     ) -> Result<response::collection::DescribeCollectionResponse> {
-        let raw = request.into_proto(&self.current_database());
-        let response = rpc_with_retry!(self, describe_collection, raw)?;
-        status_to_result(&response.status)?;
-        response::collection::DescribeCollectionResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let raw = request.into_proto(&self.current_database());
+            let response = rpc_with_retry!(self, describe_collection, raw)?;
+            status_to_result(&response.status)?;
+            response::collection::DescribeCollectionResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Lists collections visible in the selected database.
@@ -290,9 +318,13 @@ impl ClientV2 {
         &self,
         request: request::collection::ListCollectionsRequest,
     ) -> Result<response::collection::ListCollectionsResponse> {
-        let response = rpc_with_retry!(self, show_collections, request.into_proto())?;
-        status_to_result(&response.status)?;
-        response::collection::ListCollectionsResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, show_collections, request.into_proto())?;
+            status_to_result(&response.status)?;
+            response::collection::ListCollectionsResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Returns collection statistics, currently including the server-reported row count.
@@ -300,14 +332,18 @@ impl ClientV2 {
         &self,
         request: request::collection::GetCollectionStatsRequest,
     ) -> Result<response::collection::GetCollectionStatsResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(
-            self,
-            get_collection_statistics,
-            request.into_proto(&database)
-        )?;
-        status_to_result(&response.status)?;
-        Ok(response::collection::GetCollectionStatsResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(
+                self,
+                get_collection_statistics,
+                request.into_proto(&database)
+            )?;
+            status_to_result(&response.status)?;
+            Ok(response::collection::GetCollectionStatsResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Retrieves descriptions for multiple collections in one request.
@@ -315,9 +351,13 @@ impl ClientV2 {
         &self,
         request: request::collection::BatchDescribeCollectionsRequest,
     ) -> Result<response::collection::BatchDescribeCollectionsResponse> {
-        let response = rpc_with_retry!(self, batch_describe_collection, request.into_proto())?;
-        status_to_result(&response.status)?;
-        response::collection::BatchDescribeCollectionsResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, batch_describe_collection, request.into_proto())?;
+            status_to_result(&response.status)?;
+            response::collection::BatchDescribeCollectionsResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Describes the query replicas serving a collection and their resource assignments.
@@ -325,11 +365,15 @@ impl ClientV2 {
         &self,
         request: request::collection::DescribeReplicasRequest,
     ) -> Result<response::collection::DescribeReplicasResponse> {
-        let response = rpc_with_retry!(self, get_replicas, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::collection::DescribeReplicasResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, get_replicas, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::collection::DescribeReplicasResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Returns the current load state for a collection or selected partitions.
@@ -337,35 +381,39 @@ impl ClientV2 {
         &self,
         request: request::collection::GetLoadStateRequest,
     ) -> Result<response::collection::GetLoadStateResponse> {
-        let database = self.current_database();
-        let request = request.into_proto(&database);
-        let progress_request = milvus::GetLoadingProgressRequest {
-            base: None,
-            collection_name: request.collection_name.clone(),
-            partition_names: request.partition_names.clone(),
-            db_name: request.db_name.clone(),
-        };
-        let response = rpc_with_retry!(self, get_load_state, request)?;
-        status_to_result(&response.status)?;
-        let state = LoadState::from_proto(response.state);
-        let progress = match state {
-            LoadState::Loading => {
-                let progress = rpc_with_retry!(self, get_loading_progress, progress_request)?;
-                status_to_result(&progress.status)?;
-                progress.progress
-            }
-            LoadState::Loaded => 100,
-            LoadState::NotExist | LoadState::NotLoad => 0,
-            LoadState::Unknown => {
-                return Err(crate::v2::error::Error::MalformedResponse(format!(
-                    "get load state returned unknown state {}",
-                    response.state
-                )));
-            }
-        };
-        Ok(response::collection::GetLoadStateResponse::from_proto(
-            response, progress,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let request = request.into_proto(&database);
+            let progress_request = milvus::GetLoadingProgressRequest {
+                base: None,
+                collection_name: request.collection_name.clone(),
+                partition_names: request.partition_names.clone(),
+                db_name: request.db_name.clone(),
+            };
+            let response = rpc_with_retry!(self, get_load_state, request)?;
+            status_to_result(&response.status)?;
+            let state = LoadState::from_proto(response.state);
+            let progress = match state {
+                LoadState::Loading => {
+                    let progress = rpc_with_retry!(self, get_loading_progress, progress_request)?;
+                    status_to_result(&progress.status)?;
+                    progress.progress
+                }
+                LoadState::Loaded => 100,
+                LoadState::NotExist | LoadState::NotLoad => 0,
+                LoadState::Unknown => {
+                    return Err(crate::v2::error::Error::MalformedResponse(format!(
+                        "get load state returned unknown state {}",
+                        response.state
+                    )));
+                }
+            };
+            Ok(response::collection::GetLoadStateResponse::from_proto(
+                response, progress,
+            ))
+        })
+        .await
     }
 
     /// Removes all entities while retaining the collection schema, indexes, and properties.
@@ -376,14 +424,18 @@ impl ClientV2 {
         &self,
         request: request::collection::TruncateCollectionRequest,
     ) -> Result<()> {
-        let database = self.current_database();
-        let request = request.into_proto(&database);
-        let database = request.db_name.clone();
-        let collection = request.collection_name.clone();
-        let response = rpc_with_retry!(NonIdempotent, self, truncate_collection, request)?;
-        status_to_result(&response.status)?;
-        self.remove_dml_timestamp(&database, &collection);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let request = request.into_proto(&database);
+            let database = request.db_name.clone();
+            let collection = request.collection_name.clone();
+            let response = rpc_with_retry!(NonIdempotent, self, truncate_collection, request)?;
+            status_to_result(&response.status)?;
+            self.remove_dml_timestamp(&database, &collection);
+            Ok(())
+        })
+        .await
     }
 
     /// Renames a collection within the selected database.
@@ -394,16 +446,20 @@ impl ClientV2 {
         &self,
         request: request::collection::RenameCollectionRequest,
     ) -> Result<()> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let raw = request.into_proto(&database);
-        let old_database = raw.db_name.clone();
-        let new_database = raw.new_db_name.clone();
-        let old_name = raw.old_name.clone();
-        let new_name = raw.new_name.clone();
-        let status = status_rpc_with_retry!(Idempotent, self, rename_collection, raw)?;
-        self.status(status)?;
-        self.rename_collection_cache(&old_database, &old_name, &new_database, &new_name);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let raw = request.into_proto(&database);
+            let old_database = raw.db_name.clone();
+            let new_database = raw.new_db_name.clone();
+            let old_name = raw.old_name.clone();
+            let new_name = raw.new_name.clone();
+            let status = status_rpc_with_retry!(Idempotent, self, rename_collection, raw)?;
+            self.status(status)?;
+            self.rename_collection_cache(&old_database, &old_name, &new_database, &new_name);
+            Ok(())
+        })
+        .await
     }
 
     /// Alter a collection's properties.
@@ -411,20 +467,24 @@ impl ClientV2 {
         &self,
         request: request::collection::AlterCollectionPropertiesRequest,
     ) -> Result<()> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let invalidates_schema = request.properties.contains_key(ALLOW_INSERT_AUTO_ID);
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            alter_collection,
-            request.into_proto(&database)
-        )?;
-        self.status(status)?;
-        if invalidates_schema {
-            self.remove_collection_description(&database, &collection);
-        }
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let invalidates_schema = request.properties.contains_key(ALLOW_INSERT_AUTO_ID);
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                alter_collection,
+                request.into_proto(&database)
+            )?;
+            self.status(status)?;
+            if invalidates_schema {
+                self.remove_collection_description(&database, &collection);
+            }
+            Ok(())
+        })
+        .await
     }
 
     /// Drop a collection's properties.
@@ -432,20 +492,24 @@ impl ClientV2 {
         &self,
         request: request::collection::DropCollectionPropertiesRequest,
     ) -> Result<()> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let invalidates_schema = request.property_keys.contains(ALLOW_INSERT_AUTO_ID);
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            alter_collection,
-            request.into_proto(&database)
-        )?;
-        self.status(status)?;
-        if invalidates_schema {
-            self.remove_collection_description(&database, &collection);
-        }
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let invalidates_schema = request.property_keys.contains(ALLOW_INSERT_AUTO_ID);
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                alter_collection,
+                request.into_proto(&database)
+            )?;
+            self.status(status)?;
+            if invalidates_schema {
+                self.remove_collection_description(&database, &collection);
+            }
+            Ok(())
+        })
+        .await
     }
 
     /// Alter a field's properties.
@@ -453,17 +517,21 @@ impl ClientV2 {
         &self,
         request: request::collection::AlterCollectionFieldPropertiesRequest,
     ) -> Result<()> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            alter_collection_field,
-            request.into_proto(&database)
-        )?;
-        self.status(status)?;
-        self.remove_collection_description(&database, &collection);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                alter_collection_field,
+                request.into_proto(&database)
+            )?;
+            self.status(status)?;
+            self.remove_collection_description(&database, &collection);
+            Ok(())
+        })
+        .await
     }
 
     /// Drop a field's properties.
@@ -471,17 +539,21 @@ impl ClientV2 {
         &self,
         request: request::collection::DropCollectionFieldPropertiesRequest,
     ) -> Result<()> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            alter_collection_field,
-            request.into_proto(&database)
-        )?;
-        self.status(status)?;
-        self.remove_collection_description(&database, &collection);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                alter_collection_field,
+                request.into_proto(&database)
+            )?;
+            self.status(status)?;
+            self.remove_collection_description(&database, &collection);
+            Ok(())
+        })
+        .await
     }
 
     /// Add a field to an existing collection.
@@ -489,17 +561,21 @@ impl ClientV2 {
         &self,
         request: request::collection::AddCollectionFieldRequest,
     ) -> Result<()> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            add_collection_field,
-            request.into_proto()?
-        )?;
-        self.status(status)?;
-        self.remove_collection_description(&database, &collection);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                add_collection_field,
+                request.into_proto()?
+            )?;
+            self.status(status)?;
+            self.remove_collection_description(&database, &collection);
+            Ok(())
+        })
+        .await
     }
 
     /// Add a function to an existing collection.
@@ -507,17 +583,21 @@ impl ClientV2 {
         &self,
         request: request::collection::AddCollectionFunctionRequest,
     ) -> Result<()> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            add_collection_function,
-            request.into_proto()
-        )?;
-        self.status(status)?;
-        self.remove_collection_description(&database, &collection);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                add_collection_function,
+                request.into_proto()
+            )?;
+            self.status(status)?;
+            self.remove_collection_description(&database, &collection);
+            Ok(())
+        })
+        .await
     }
 
     /// Alter a function of an existing collection.
@@ -525,17 +605,21 @@ impl ClientV2 {
         &self,
         request: request::collection::AlterCollectionFunctionRequest,
     ) -> Result<()> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            alter_collection_function,
-            request.into_proto()
-        )?;
-        self.status(status)?;
-        self.remove_collection_description(&database, &collection);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                alter_collection_function,
+                request.into_proto()
+            )?;
+            self.status(status)?;
+            self.remove_collection_description(&database, &collection);
+            Ok(())
+        })
+        .await
     }
 
     /// Drop a function of an existing collection.
@@ -543,16 +627,20 @@ impl ClientV2 {
         &self,
         request: request::collection::DropCollectionFunctionRequest,
     ) -> Result<()> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            drop_collection_function,
-            request.into_proto()
-        )?;
-        self.status(status)?;
-        self.remove_collection_description(&database, &collection);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                drop_collection_function,
+                request.into_proto()
+            )?;
+            self.status(status)?;
+            self.remove_collection_description(&database, &collection);
+            Ok(())
+        })
+        .await
     }
 }
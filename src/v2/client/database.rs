@@ -48,9 +48,13 @@ impl ClientV2 {
         &self,
         request: request::database::CreateDatabaseRequest,
     ) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(Idempotent, self, create_database, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status =
+                status_rpc_with_retry!(Idempotent, self, create_database, request.into_proto())?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Drops a database and the resources owned by it according to server policy.
@@ -58,12 +62,16 @@ impl ClientV2 {
         &self,
         request: request::database::DropDatabaseRequest,
     ) -> Result<()> {
-        let raw = request.into_proto();
-        let name = raw.db_name.clone();
-        let status = status_rpc_with_retry!(Idempotent, self, drop_database, raw)?;
-        self.status(status)?;
-        self.clear_database_cache(&name);
-        Ok(())
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let raw = request.into_proto();
+            let name = raw.db_name.clone();
+            let status = status_rpc_with_retry!(Idempotent, self, drop_database, raw)?;
+            self.status(status)?;
+            self.clear_database_cache(&name);
+            Ok(())
+        })
+        .await
     }
 
     /// Lists databases visible to the authenticated user.
@@ -71,11 +79,15 @@ impl ClientV2 {
         &self,
         request: request::database::ListDatabasesRequest,
     ) -> Result<response::database::ListDatabasesResponse> {
-        let response = rpc_with_retry!(self, list_databases, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::database::ListDatabasesResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, list_databases, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::database::ListDatabasesResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Updates mutable properties of a database.
@@ -83,9 +95,13 @@ impl ClientV2 {
         &self,
         request: request::database::AlterDatabasePropertiesRequest,
     ) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(Idempotent, self, alter_database, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status =
+                status_rpc_with_retry!(Idempotent, self, alter_database, request.into_proto())?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Removes the requested mutable database properties.
@@ -93,9 +109,13 @@ impl ClientV2 {
         &self,
         request: request::database::DropDatabasePropertiesRequest,
     ) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(Idempotent, self, alter_database, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status =
+                status_rpc_with_retry!(Idempotent, self, alter_database, request.into_proto())?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Retrieves a database's metadata and properties.
@@ -103,11 +123,15 @@ impl ClientV2 {
         &self,
         request: request::database::DescribeDatabaseRequest,
     ) -> Result<response::database::DescribeDatabaseResponse> {
-        let response = rpc_with_retry!(self, describe_database, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::database::DescribeDatabaseResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, describe_database, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::database::DescribeDatabaseResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 }
 
@@ -16,71 +16,80 @@
 
 //! ClientV2 data-manipulation operations.
 
-use super::{ClientV2, RetrySemantics};
+use super::ClientV2;
 use crate::proto::{common, milvus};
 use crate::v2::error::status_to_result;
 use crate::v2::error::Result;
+use crate::v2::retry::RetrySemantics;
 use crate::v2::{request, response};
+use futures::stream::{self, StreamExt};
 
 impl ClientV2 {
     /// Inserts entities into a collection using column-based or row-based input.
     ///
-    /// The request is validated against the collection schema before it is sent. The client
-    /// resolves the effective database, fills the schema timestamp, and updates the session
-    /// timestamp cache after a successful insert. Because an insert is non-idempotent, ambiguous
-    /// transport failures are not replayed automatically.
+    /// Vector fields filled by the request's embeddings are computed first. The request is
+    /// validated against the collection schema before it is sent. The client resolves the
+    /// effective database, fills the schema timestamp, and updates the session timestamp cache
+    /// after a successful insert. Because an insert is non-idempotent, ambiguous transport
+    /// failures are not replayed automatically.
     pub async fn insert(
         &self,
-        request: request::dml::InsertRequest,
+        mut request: request::dml::InsertRequest,
     ) -> Result<response::dml::InsertResponse> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection_name = request.collection_name.clone();
-        for attempt in 0..2 {
-            let resolved = self
-                .resolve_data(
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            self.embed_insert(&database, &mut request).await?;
+            let collection_name = request.collection_name.clone();
+            for attempt in 0..2 {
+                let resolved = self
+                    .resolve_data(
+                        &database,
+                        &collection_name,
+                        &request.columns,
+                        &request.rows,
+                        false,
+                        false,
+                        &[],
+                    )
+                    .await?;
+                let response = self
+                    .retry_rpc(
+                        "insert",
+                        || {
+                            let fields = resolved.to_proto_fields(
+                                &request.columns,
+                                &request.rows,
+                                false,
+                                false,
+                            )?;
+                            request.to_proto_with_fields(
+                                fields,
+                                resolved.row_count,
+                                resolved.schema_timestamp,
+                                &database,
+                            )
+                        },
+                        RetrySemantics::NonIdempotent,
+                        |mut service, request| async move { service.insert(request).await },
+                        dml_retry_status,
+                    )
+                    .await?;
+                if attempt == 0 && is_schema_mismatch(&response.status) {
+                    self.remove_collection_description(&database, &collection_name);
+                    continue;
+                }
+                status_to_result(&response.status)?;
+                self.update_dml_timestamp(
                     &database,
-                    &collection_name,
-                    &request.columns,
-                    &request.rows,
-                    false,
-                    false,
-                    &[],
-                )
-                .await?;
-            let response = self
-                .retry_rpc(
-                    || {
-                        let fields = resolved.to_proto_fields(
-                            &request.columns,
-                            &request.rows,
-                            false,
-                            false,
-                        )?;
-                        request.to_proto_with_fields(
-                            fields,
-                            resolved.row_count,
-                            resolved.schema_timestamp,
-                            &database,
-                        )
-                    },
-                    RetrySemantics::NonIdempotent,
-                    |mut service, request| async move { service.insert(request).await },
-                    dml_retry_status,
-                )
-                .await?;
-            if attempt == 0 && is_schema_mismatch(&response.status) {
-                self.remove_collection_description(&database, &collection_name);
-                continue;
+                    &resolved.canonical_collection_name,
+                    response.timestamp,
+                );
+                return Ok(response::dml::DmlResponse::from_proto(response));
             }
-            status_to_result(&response.status)?;
-            self.update_dml_timestamp(
-                &database,
-                &resolved.canonical_collection_name,
-                response.timestamp,
-            );
-            return Ok(response::dml::DmlResponse::from_proto(response));
-        }
-        unreachable!("insert schema-mismatch retry loop always returns")
+            unreachable!("insert schema-mismatch retry loop always returns")
+        })
+        .await
     }
 
     /// Inserts new entities or updates existing entities in a collection.
@@ -93,74 +102,80 @@ impl ClientV2 {
         &self,
         request: request::dml::UpsertRequest,
     ) -> Result<response::dml::UpsertResponse> {
-        let partial_update = request.is_partial_update();
-        let field_ops = request.field_ops;
-        let request = request.insert;
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection_name = request.collection_name.clone();
-        for attempt in 0..2 {
-            let resolved = self
-                .resolve_data(
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let partial_update = request.is_partial_update();
+            let field_ops = request.field_ops;
+            let mut request = request.insert;
+            let database = self.effective_database(request.database_name.as_deref());
+            self.embed_insert(&database, &mut request).await?;
+            let collection_name = request.collection_name.clone();
+            for attempt in 0..2 {
+                let resolved = self
+                    .resolve_data(
+                        &database,
+                        &collection_name,
+                        &request.columns,
+                        &request.rows,
+                        true,
+                        partial_update,
+                        &field_ops,
+                    )
+                    .await?;
+                let response = self
+                    .retry_rpc(
+                        "upsert",
+                        || {
+                            let fields = resolved.to_proto_fields(
+                                &request.columns,
+                                &request.rows,
+                                true,
+                                partial_update,
+                            )?;
+                            let insert = request.to_proto_with_fields(
+                                fields,
+                                resolved.row_count,
+                                resolved.schema_timestamp,
+                                &database,
+                            )?;
+                            Ok(milvus::UpsertRequest {
+                                base: insert.base,
+                                db_name: insert.db_name,
+                                collection_name: insert.collection_name,
+                                partition_name: insert.partition_name,
+                                fields_data: insert.fields_data,
+                                hash_keys: insert.hash_keys,
+                                num_rows: insert.num_rows,
+                                schema_timestamp: insert.schema_timestamp,
+                                partial_update,
+                                namespace: None,
+                                field_ops: field_ops
+                                    .iter()
+                                    .cloned()
+                                    .map(crate::v2::types::FieldPartialUpdateOp::into_proto)
+                                    .collect(),
+                            })
+                        },
+                        RetrySemantics::NonIdempotent,
+                        |mut service, request| async move { service.upsert(request).await },
+                        dml_retry_status,
+                    )
+                    .await?;
+                if attempt == 0 && is_schema_mismatch(&response.status) {
+                    self.remove_collection_description(&database, &collection_name);
+                    continue;
+                }
+                status_to_result(&response.status)?;
+                self.update_dml_timestamp(
                     &database,
-                    &collection_name,
-                    &request.columns,
-                    &request.rows,
-                    true,
-                    partial_update,
-                    &field_ops,
-                )
-                .await?;
-            let response = self
-                .retry_rpc(
-                    || {
-                        let fields = resolved.to_proto_fields(
-                            &request.columns,
-                            &request.rows,
-                            true,
-                            partial_update,
-                        )?;
-                        let insert = request.to_proto_with_fields(
-                            fields,
-                            resolved.row_count,
-                            resolved.schema_timestamp,
-                            &database,
-                        )?;
-                        Ok(milvus::UpsertRequest {
-                            base: insert.base,
-                            db_name: insert.db_name,
-                            collection_name: insert.collection_name,
-                            partition_name: insert.partition_name,
-                            fields_data: insert.fields_data,
-                            hash_keys: insert.hash_keys,
-                            num_rows: insert.num_rows,
-                            schema_timestamp: insert.schema_timestamp,
-                            partial_update,
-                            namespace: None,
-                            field_ops: field_ops
-                                .iter()
-                                .cloned()
-                                .map(crate::v2::types::FieldPartialUpdateOp::into_proto)
-                                .collect(),
-                        })
-                    },
-                    RetrySemantics::NonIdempotent,
-                    |mut service, request| async move { service.upsert(request).await },
-                    dml_retry_status,
-                )
-                .await?;
-            if attempt == 0 && is_schema_mismatch(&response.status) {
-                self.remove_collection_description(&database, &collection_name);
-                continue;
+                    &resolved.canonical_collection_name,
+                    response.timestamp,
+                );
+                return Ok(response::dml::DmlResponse::from_proto(response));
             }
-            status_to_result(&response.status)?;
-            self.update_dml_timestamp(
-                &database,
-                &resolved.canonical_collection_name,
-                response.timestamp,
-            );
-            return Ok(response::dml::DmlResponse::from_proto(response));
-        }
-        unreachable!("upsert schema-mismatch retry loop always returns")
+            unreachable!("upsert schema-mismatch retry loop always returns")
+        })
+        .await
     }
 
     /// Deletes entities selected by a filter expression or primary-key IDs.
@@ -168,39 +183,83 @@ impl ClientV2 {
     /// The request must provide exactly one selection form. A successful delete updates the
     /// collection's session timestamp so subsequent Session-consistency reads observe the change.
     /// Delete is non-idempotent; the client does not replay an ambiguous mutation automatically.
+    ///
+    /// Large ID lists are split into chunks of the request's chunk size and deleted with bounded
+    /// concurrency; the response sums the chunk counts. If a chunk fails, chunks that already
+    /// succeeded stay deleted and the first error is returned.
     pub async fn delete(
         &self,
-        request: request::dml::DeleteRequest,
+        mut request: request::dml::DeleteRequest,
+    ) -> Result<response::dml::DeleteResponse> {
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection_name = request.collection_name.clone();
+            let description = self
+                .get_collection_description(&database, &collection_name)
+                .await?;
+            let canonical_collection_name = if description.collection_name.is_empty() {
+                collection_name.clone()
+            } else {
+                description.collection_name.clone()
+            };
+            let primary_field_name = if request.has_ids() {
+                Some(
+                    description
+                        .schema
+                        .as_ref()
+                        .and_then(|schema| schema.fields.iter().find(|field| field.is_primary_key))
+                        .map(|field| field.name.clone())
+                        .ok_or_else(|| {
+                            crate::v2::error::Error::MalformedResponse(
+                                "collection schema has no primary key".into(),
+                            )
+                        })?,
+                )
+            } else {
+                None
+            };
+            if !request.has_ids() {
+                let raw = request.into_proto(&database, None)?;
+                return self
+                    .delete_once(&database, &canonical_collection_name, raw)
+                    .await;
+            }
+            let chunk_size = request.chunk_size;
+            let ids = std::mem::take(&mut request.ids);
+            let deletes = ids.chunks(chunk_size).into_iter().map(|chunk| {
+                let mut chunk_request = request.clone();
+                chunk_request.ids = chunk;
+                let raw = chunk_request.into_proto(&database, primary_field_name.as_deref());
+                let (database, collection) =
+                    (database.as_str(), canonical_collection_name.as_str());
+                async move { self.delete_once(database, collection, raw?).await }
+            });
+            let mut responses = stream::iter(deletes)
+                .buffered(request.max_concurrency)
+                .enumerate();
+            let mut merged: Option<response::dml::DeleteResponse> = None;
+            while let Some((index, response)) = responses.next().await {
+                let response = response?;
+                match merged.as_mut() {
+                    Some(merged) => merged.merge(response, index * chunk_size)?,
+                    None => merged = Some(response),
+                }
+            }
+            Ok(merged.expect("an ID-based delete has at least one chunk"))
+        })
+        .await
+    }
+
+    async fn delete_once(
+        &self,
+        database: &str,
+        collection: &str,
+        raw: milvus::DeleteRequest,
     ) -> Result<response::dml::DeleteResponse> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection_name = request.collection_name.clone();
-        let description = self
-            .get_collection_description(&database, &collection_name)
-            .await?;
-        let canonical_collection_name = if description.collection_name.is_empty() {
-            collection_name.clone()
-        } else {
-            description.collection_name.clone()
-        };
-        let primary_field_name = if request.has_ids() {
-            Some(
-                description
-                    .schema
-                    .as_ref()
-                    .and_then(|schema| schema.fields.iter().find(|field| field.is_primary_key))
-                    .map(|field| field.name.clone())
-                    .ok_or_else(|| {
-                        crate::v2::error::Error::MalformedResponse(
-                            "collection schema has no primary key".into(),
-                        )
-                    })?,
-            )
-        } else {
-            None
-        };
-        let raw = request.into_proto(&database, primary_field_name.as_deref())?;
         let response = self
             .retry_rpc(
+                "delete",
                 || Ok(raw.clone()),
                 RetrySemantics::NonIdempotent,
                 |mut service, request| async move { service.delete(request).await },
@@ -208,7 +267,7 @@ impl ClientV2 {
             )
             .await?;
         status_to_result(&response.status)?;
-        self.update_dml_timestamp(&database, &canonical_collection_name, response.timestamp);
+        self.update_dml_timestamp(database, collection, response.timestamp);
         Ok(response::dml::DmlResponse::from_proto(response))
     }
 }
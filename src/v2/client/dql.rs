@@ -16,10 +16,12 @@
 
 //! ClientV2 query and search operations.
 
+use super::iterator::vector_field_names;
 use super::ClientV2;
 use crate::v2::error::status_to_result;
 use crate::v2::error::{Error, Result};
-use crate::v2::{request, response};
+use crate::v2::{request, response, MetricType};
+use futures::stream::{self, StreamExt, TryStreamExt};
 
 impl ClientV2 {
     /// Queries entities that match a filter expression or a typed primary-key selection.
@@ -27,25 +29,37 @@ impl ClientV2 {
     /// Output fields and consistency are controlled by the request. Session consistency uses the
     /// endpoint/database/collection timestamp recorded by successful DML; other consistency levels
     /// use the corresponding Milvus guarantee semantics. The response owns decoded rows, while
-    /// its row iterator provides a borrowing traversal for allocation-sensitive callers.
+    /// its row iterator provides a borrowing traversal for allocation-sensitive callers. With a
+    /// result cache configured, Bounded and Eventually reads may be answered from the cache.
     pub async fn query(
         &self,
         request: request::dql::QueryRequest,
     ) -> Result<response::dql::QueryResponse> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let guarantee = self
-            .deduce_guarantee_timestamp(&database, &collection, request.consistency_level)
-            .await?;
-        let primary_field = if request.ids.is_empty() {
-            None
-        } else {
-            Some(self.primary_field_name(&database, &collection).await?)
-        };
-        let raw = request.into_proto(&database, primary_field.as_deref(), guarantee)?;
-        let response = rpc_with_retry!(self, query, raw)?;
-        status_to_result(&response.status)?;
-        response::dql::QueryResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let guarantee = self
+                .deduce_guarantee_timestamp(&database, &collection, request.consistency_level)
+                .await?;
+            let primary_field = if request.ids.is_empty() {
+                None
+            } else {
+                Some(self.primary_field_name(&database, &collection).await?)
+            };
+            let dense_vectors = request.dense_vectors;
+            let consistency = request.consistency_level;
+            let raw = request.into_proto(&database, primary_field.as_deref(), guarantee)?;
+            let response = self
+                .cached_read(consistency, &database, &collection, raw, |raw| async move {
+                    let response = rpc_with_retry!(self, query, raw)?;
+                    status_to_result(&response.status)?;
+                    Ok(response)
+                })
+                .await?;
+            response::dql::QueryResponse::from_proto_with_dense_vectors(response, dense_vectors)
+        })
+        .await
     }
 
     /// Retrieves entities by their primary-key values.
@@ -53,40 +67,88 @@ impl ClientV2 {
     /// The client resolves the collection's primary-key field from its schema, so callers provide
     /// IDs rather than a protobuf field name. The request cannot combine IDs with a filter; use
     /// [`ClientV2::query`] for expression-based selection.
+    ///
+    /// Large ID lists are split into chunks of the request's chunk size and queried with bounded
+    /// concurrency at one guarantee timestamp. Rows are returned in the order of the requested
+    /// IDs, and IDs that matched no entity are listed in the response's missing IDs.
     pub async fn get(
         &self,
-        request: request::dql::GetRequest,
+        mut request: request::dql::GetRequest,
     ) -> Result<response::dql::GetResponse> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let guarantee = self
-            .deduce_guarantee_timestamp(&database, &collection, request.consistency_level)
-            .await?;
-        let primary_field = self.primary_field_name(&database, &collection).await?;
-        let raw = request.into_proto(&database, &primary_field, guarantee)?;
-        let response = rpc_with_retry!(self, query, raw)?;
-        status_to_result(&response.status)?;
-        response::dql::QueryResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let guarantee = self
+                .deduce_guarantee_timestamp(&database, &collection, request.consistency_level)
+                .await?;
+            let primary_field = self.primary_field_name(&database, &collection).await?;
+            let dense_vectors = request.dense_vectors;
+            let consistency = request.consistency_level;
+            let ids = std::mem::take(&mut request.ids);
+            let queries = ids.chunks(request.chunk_size).into_iter().map(|chunk| {
+                let mut chunk_request = request.clone();
+                chunk_request.ids = chunk;
+                let raw = chunk_request.into_proto(&database, &primary_field, guarantee);
+                let (database, collection) = (database.as_str(), collection.as_str());
+                async move {
+                    let response = self
+                        .cached_read(consistency, database, collection, raw?, |raw| async move {
+                            let response = rpc_with_retry!(self, query, raw)?;
+                            status_to_result(&response.status)?;
+                            Ok(response)
+                        })
+                        .await?;
+                    response::dql::QueryResponse::from_proto_with_dense_vectors(
+                        response,
+                        dense_vectors,
+                    )
+                }
+            });
+            let responses = stream::iter(queries)
+                .buffered(request.max_concurrency)
+                .try_collect::<Vec<_>>()
+                .await?;
+            response::dql::QueryResponse::merge_get(responses, &ids, &primary_field)
+        })
+        .await
     }
 
     /// Searches vector fields and returns ranked hits for each query vector.
     ///
     /// The collection must have a compatible vector index or be loaded according to the server's
     /// search requirements. Search consistency follows the request and the shared DML timestamp
-    /// cache; decoded hits expose IDs, scores, and requested output fields.
+    /// cache; decoded hits expose IDs, scores, and requested output fields. Text queries are
+    /// embedded on the client when the request carries an embedding. A score range is converted
+    /// into the radius and range filter of the request's metric, or of the vector field's index
+    /// when the request names none. With a result cache configured, Bounded and Eventually reads
+    /// may be answered from the cache.
     pub async fn search(
         &self,
-        request: request::dql::SearchRequest,
+        mut request: request::dql::SearchRequest,
     ) -> Result<response::dql::SearchResponse> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let guarantee = self
-            .deduce_guarantee_timestamp(&database, &collection, request.consistency_level)
-            .await?;
-        let raw = request.into_proto(&database, guarantee)?;
-        let response = rpc_with_retry!(self, search, raw)?;
-        status_to_result(&response.status)?;
-        response::dql::SearchResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            self.embed_search(&database, &mut request).await?;
+            self.resolve_score_range(&database, &mut request).await?;
+            let collection = request.collection_name.clone();
+            let guarantee = self
+                .deduce_guarantee_timestamp(&database, &collection, request.consistency_level)
+                .await?;
+            let dense_vectors = request.dense_vectors;
+            let consistency = request.consistency_level;
+            let raw = request.into_proto(&database, guarantee)?;
+            let response = self
+                .cached_read(consistency, &database, &collection, raw, |raw| async move {
+                    let response = rpc_with_retry!(self, search, raw)?;
+                    status_to_result(&response.status)?;
+                    Ok(response)
+                })
+                .await?;
+            response::dql::SearchResponse::from_proto_with_dense_vectors(response, dense_vectors)
+        })
+        .await
     }
 
     /// Executes multiple vector searches and combines them with the requested reranking strategy.
@@ -97,18 +159,27 @@ impl ClientV2 {
         &self,
         request: request::dql::HybridSearchRequest,
     ) -> Result<response::dql::HybridSearchResponse> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let guarantee = self
-            .deduce_guarantee_timestamp(&database, &collection, request.consistency_level)
-            .await?;
-        let raw = request.into_proto(&database, guarantee)?;
-        let response = rpc_with_retry!(self, hybrid_search, raw)?;
-        status_to_result(&response.status)?;
-        response::dql::SearchResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let guarantee = self
+                .deduce_guarantee_timestamp(&database, &collection, request.consistency_level)
+                .await?;
+            let dense_vectors = request.dense_vectors;
+            let raw = request.into_proto(&database, guarantee)?;
+            let response = rpc_with_retry!(self, hybrid_search, raw)?;
+            status_to_result(&response.status)?;
+            response::dql::SearchResponse::from_proto_with_dense_vectors(response, dense_vectors)
+        })
+        .await
     }
 
-    async fn primary_field_name(&self, database: &str, collection: &str) -> Result<String> {
+    pub(super) async fn primary_field_name(
+        &self,
+        database: &str,
+        collection: &str,
+    ) -> Result<String> {
         let description = self
             .get_collection_description(database, collection)
             .await?;
@@ -119,4 +190,53 @@ impl ClientV2 {
             .map(|field| field.name.clone())
             .ok_or_else(|| Error::MalformedResponse("collection schema has no primary key".into()))
     }
+
+    /// Converts the request's score range into a radius and range filter, describing the vector
+    /// field's index when the range needs a metric type the request does not name.
+    pub(super) async fn resolve_score_range(
+        &self,
+        database: &str,
+        request: &mut request::dql::SearchRequest,
+    ) -> Result<()> {
+        let Some(range) = request.score_range else {
+            return Ok(());
+        };
+        let metric = match request
+            .metric_type
+            .filter(|metric| *metric != MetricType::Default)
+        {
+            Some(metric) => metric,
+            None if range.needs_metric_type() => {
+                let vector_field = if request.vector_field.is_empty() {
+                    self.single_vector_field(database, &request.collection_name)
+                        .await?
+                } else {
+                    request.vector_field.clone()
+                };
+                self.index_metric_type(database, &request.collection_name, &vector_field)
+                    .await?
+            }
+            None => MetricType::Default,
+        };
+        request.apply_score_range(metric)
+    }
+
+    async fn single_vector_field(&self, database: &str, collection: &str) -> Result<String> {
+        let description = self
+            .get_collection_description(database, collection)
+            .await?;
+        let schema = description.schema.as_ref().ok_or_else(|| {
+            Error::MalformedResponse("collection description has no schema".into())
+        })?;
+        match vector_field_names(schema).as_slice() {
+            [field] => Ok(field.clone()),
+            [] => Err(Error::MalformedResponse(
+                "collection has no vector field".into(),
+            )),
+            _ => Err(Error::validation(
+                "vector_field".into(),
+                "must be specified when the collection has multiple vector fields".into(),
+            )),
+        }
+    }
 }
@@ -0,0 +1,160 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side embedding of insert text fields and search text queries.
+
+use super::ClientV2;
+use crate::proto::schema;
+use crate::v2::embedding::{Embedding, TextEmbedding, TextKind};
+use crate::v2::error::{Error, Result};
+use crate::v2::request::dml::InsertRequest;
+use crate::v2::request::dql::SearchRequest;
+use crate::v2::types::{FieldData, SearchVectors};
+use serde_json::{json, Value};
+
+impl ClientV2 {
+    /// Fills the vector fields of the request's embeddings from their source text fields.
+    pub(super) async fn embed_insert(
+        &self,
+        database: &str,
+        request: &mut InsertRequest,
+    ) -> Result<()> {
+        if request.embeddings.is_empty() {
+            return Ok(());
+        }
+        let description = self
+            .get_collection_description(database, &request.collection_name)
+            .await?;
+        let collection = collection_schema(description.schema.as_ref())?;
+        for embedding in request.embeddings.clone() {
+            let field = embedding.vector_field_schema(collection)?;
+            let texts = source_texts(request, &embedding.source_field)?;
+            let vectors = embedding.embed(TextKind::Document, &texts).await?;
+            for vector in &vectors {
+                embedding.check(&field, vector)?;
+            }
+            fill_vector_field(request, &embedding, vectors)?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the request's text queries with vectors when it carries an embedding.
+    pub(super) async fn embed_search(
+        &self,
+        database: &str,
+        request: &mut SearchRequest,
+    ) -> Result<()> {
+        let Some(embedding) = request.embedding.clone() else {
+            return Ok(());
+        };
+        let SearchVectors::EmbeddedText(texts) = &request.vectors else {
+            return Ok(());
+        };
+        let description = self
+            .get_collection_description(database, &request.collection_name)
+            .await?;
+        let field =
+            embedding.vector_field_schema(collection_schema(description.schema.as_ref())?)?;
+        let vectors = embedding.embed(TextKind::Query, texts).await?;
+        for vector in &vectors {
+            embedding.check(&field, vector)?;
+        }
+        request.vectors = match embedding.into_column(vectors)? {
+            FieldData::SparseFloatVector { values, .. } => SearchVectors::SparseFloat(values),
+            FieldData::FloatVector { values, .. } => SearchVectors::Float(values),
+            _ => unreachable!("embeddings convert into vector columns"),
+        };
+        Ok(())
+    }
+}
+
+fn collection_schema(
+    schema: Option<&schema::CollectionSchema>,
+) -> Result<&schema::CollectionSchema> {
+    schema.ok_or_else(|| Error::MalformedResponse("describe collection returned no schema".into()))
+}
+
+/// Reads the text of `field_name` from every row or from its VarChar column.
+fn source_texts(request: &InsertRequest, field_name: &str) -> Result<Vec<String>> {
+    if request.columns.is_empty() {
+        return request
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                row.get(field_name)
+                    .and_then(Value::as_str)
+                    .map(str::to_owned)
+                    .ok_or_else(|| {
+                        Error::validation(
+                            format!("rows[{index}].{field_name}"),
+                            "embedding source must be a string".into(),
+                        )
+                    })
+            })
+            .collect();
+    }
+    request
+        .columns
+        .iter()
+        .find_map(|column| match column {
+            FieldData::VarChar { name, values } if name == field_name => Some(values.clone()),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            Error::validation(
+                field_name.to_owned(),
+                "embedding source must be a non-nullable VarChar column".into(),
+            )
+        })
+}
+
+/// Adds the embedded vectors to the request as a column or as one value per row.
+fn fill_vector_field(
+    request: &mut InsertRequest,
+    embedding: &TextEmbedding,
+    vectors: Vec<Embedding>,
+) -> Result<()> {
+    let field_name = &embedding.vector_field;
+    let provided = if request.columns.is_empty() {
+        request.rows.iter().any(|row| row.contains_key(field_name))
+    } else {
+        request
+            .columns
+            .iter()
+            .any(|column| column.name() == field_name)
+    };
+    if provided {
+        return Err(Error::validation(
+            field_name.clone(),
+            "vector field is provided and also filled by an embedding".into(),
+        ));
+    }
+    if !request.columns.is_empty() {
+        request.columns.push(embedding.into_column(vectors)?);
+        return Ok(());
+    }
+    for (row, vector) in request.rows.iter_mut().zip(vectors) {
+        let value = match vector {
+            Embedding::Dense(values) => Value::from(values),
+            Embedding::Sparse(values) => {
+                json!({ "indices": values.indices(), "values": values.values() })
+            }
+        };
+        row.insert(field_name.clone(), value);
+    }
+    Ok(())
+}
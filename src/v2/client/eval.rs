@@ -0,0 +1,213 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! ClientV2 recall evaluation of search configurations.
+
+use super::ClientV2;
+use crate::v2::error::{Error, Result};
+use crate::v2::eval::{
+    recall_at_k, ConfigReport, ExactTopK, GroundTruth, QuerySampler, RecallEvaluation, RecallReport,
+};
+use crate::v2::request::dql::{QueryIteratorRequest, QueryRequest};
+use crate::v2::response::dql::SearchResponse;
+use crate::v2::types::{FieldData, MetricType};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use std::collections::HashMap;
+use std::time::Instant;
+
+impl ClientV2 {
+    /// Measures recall@k, latency, and throughput of each search configuration in `evaluation`.
+    ///
+    /// Query vectors are sampled from the collection unless supplied, the exact neighbors come
+    /// from the evaluation's ground-truth source, and every query is then searched once per
+    /// configuration with the evaluation's concurrency. The metric defaults to the one of the
+    /// vector field's index.
+    pub async fn evaluate_recall(&self, evaluation: RecallEvaluation) -> Result<RecallReport> {
+        let database = self.effective_database(evaluation.database_name.as_deref());
+        let metric_type = match evaluation
+            .metric_type
+            .filter(|metric| *metric != MetricType::Default)
+        {
+            Some(metric) => metric,
+            None => {
+                self.index_metric_type(
+                    &database,
+                    &evaluation.collection_name,
+                    &evaluation.vector_field,
+                )
+                .await?
+            }
+        };
+        let primary_field = self
+            .primary_field_name(&database, &evaluation.collection_name)
+            .await?;
+
+        let queries = if evaluation.queries.is_empty() {
+            let mut sampler = QuerySampler::new(evaluation.sample_size, evaluation.seed);
+            self.export_vectors(&evaluation, &primary_field, |_, vector| {
+                sampler.offer(vector);
+                Ok(())
+            })
+            .await?;
+            sampler.into_samples()
+        } else {
+            evaluation.queries.clone()
+        };
+        if queries.is_empty() {
+            return Err(Error::validation(
+                "sample_size".into(),
+                "the collection has no vectors to sample queries from".into(),
+            ));
+        }
+
+        let truth = match &evaluation.ground_truth {
+            GroundTruth::BruteForce => {
+                let mut exact = ExactTopK::new(metric_type, &queries, evaluation.top_k)?;
+                self.export_vectors(&evaluation, &primary_field, |key, vector| {
+                    exact.offer(key, vector)
+                })
+                .await?;
+                exact.into_keys()
+            }
+            GroundTruth::FlatCollection(collection) => {
+                self.search_all(
+                    &evaluation,
+                    collection,
+                    metric_type,
+                    &HashMap::new(),
+                    &queries,
+                )
+                .await?
+                .1
+            }
+        };
+
+        let mut configs = Vec::with_capacity(evaluation.configs.len());
+        for config in &evaluation.configs {
+            let started = Instant::now();
+            let (latencies, found) = self
+                .search_all(
+                    &evaluation,
+                    &evaluation.collection_name,
+                    metric_type,
+                    &config.params,
+                    &queries,
+                )
+                .await?;
+            let elapsed = started.elapsed();
+            configs.push(ConfigReport::new(
+                config,
+                recall_at_k(&truth, &found),
+                latencies,
+                elapsed,
+            ));
+        }
+        Ok(RecallReport::new(
+            &evaluation,
+            metric_type,
+            queries.len(),
+            configs,
+        ))
+    }
+
+    /// Searches every query on its own and returns each search's latency and hit keys.
+    async fn search_all(
+        &self,
+        evaluation: &RecallEvaluation,
+        collection: &str,
+        metric_type: MetricType,
+        params: &HashMap<String, String>,
+        queries: &[Vec<f32>],
+    ) -> Result<(Vec<std::time::Duration>, Vec<Vec<String>>)> {
+        let searches = queries.iter().map(|query| async move {
+            let request = evaluation.search_request(collection, metric_type, params, query)?;
+            let started = Instant::now();
+            let response = self.search(request).await?;
+            Ok::<_, Error>((started.elapsed(), hit_keys(response)?))
+        });
+        let outcomes = stream::iter(searches)
+            .buffered(evaluation.concurrency)
+            .try_collect::<Vec<_>>()
+            .await?;
+        Ok(outcomes.into_iter().unzip())
+    }
+
+    /// Streams the primary key and vector of every row matching the evaluation's filter.
+    async fn export_vectors(
+        &self,
+        evaluation: &RecallEvaluation,
+        primary_field: &str,
+        mut visit: impl FnMut(&str, &[f32]) -> Result<()>,
+    ) -> Result<()> {
+        let mut query = QueryRequest::builder()
+            .collection_name(&evaluation.collection_name)
+            .filter(&evaluation.filter)
+            .output_fields([primary_field, evaluation.vector_field.as_str()]);
+        if let Some(database_name) = &evaluation.database_name {
+            query = query.database_name(database_name);
+        }
+        if let Some(level) = evaluation.consistency_level {
+            query = query.consistency_level(level);
+        }
+        let request = QueryIteratorRequest::builder()
+            .query(query.build()?)
+            .batch_size(evaluation.batch_size)
+            .build()?;
+        let mut iterator = self.query_iterator(request).await?;
+        while let Some(page) = iterator.next().await? {
+            let results = page.results();
+            let (Some(keys), Some(vectors)) = (
+                results.get_output_field(primary_field),
+                results.get_output_field(&evaluation.vector_field),
+            ) else {
+                return Err(Error::MalformedResponse(
+                    "query iterator page is missing the primary key or vector field".into(),
+                ));
+            };
+            let FieldData::FloatVector { values, .. } = vectors.inner() else {
+                return Err(Error::validation(
+                    "vector_field".into(),
+                    "recall evaluation supports FloatVector fields".into(),
+                ));
+            };
+            let mut values = values.iter();
+            for row in 0..keys.len() {
+                if vectors
+                    .valid_data()
+                    .is_some_and(|valid| !valid.get(row).copied().unwrap_or(false))
+                {
+                    continue;
+                }
+                let vector = values.next().ok_or_else(|| {
+                    Error::MalformedResponse("vector column is shorter than its rows".into())
+                })?;
+                visit(&keys.value_at(row)?.to_string(), vector)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the primary keys of the single query's hits, nearest first.
+fn hit_keys(response: SearchResponse) -> Result<Vec<String>> {
+    let Some(result) = response.results().get_results().first() else {
+        return Ok(Vec::new());
+    };
+    let ids = result.get_ids();
+    (0..ids.len())
+        .map(|index| Ok(ids.value_at(index)?.to_string()))
+        .collect()
+}
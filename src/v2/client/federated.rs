@@ -0,0 +1,293 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Search across several collections with a client-side merge.
+//!
+//! Every target is searched for `offset + limit` hits with no offset, so the merged ranking can
+//! apply the caller's offset and limit across targets. Hits are ordered by a key that is higher for
+//! closer matches: the score itself for similarity metrics and the negated score for distance
+//! metrics. When targets mix distance and similarity metrics, raw scores are not comparable, so
+//! every hit is ranked and reported by its [`normalize_score`] value instead. Ties keep target
+//! order, then the target's own order.
+
+use super::ClientV2;
+use crate::v2::error::{Error, Result};
+use crate::v2::request::dql::{FederatedSearchRequest, SearchRequest};
+use crate::v2::rerank::normalize_score;
+use crate::v2::response::dql::{
+    select_result_rows, FederatedFailure, FederatedHit, FederatedSearchResponse, SearchResponse,
+};
+use crate::v2::types::{FailurePolicy, MetricType, SearchResults};
+use futures::stream::{self, StreamExt};
+
+impl ClientV2 {
+    /// Runs one search against several collections, possibly in different databases, and merges
+    /// the hits into one ranking per query vector.
+    ///
+    /// Targets are searched concurrently, up to the request's concurrency limit. Each target's
+    /// metric type comes from the target, then from the search, and otherwise from describing the
+    /// index on the search's vector field. Under [`FailurePolicy::FailFast`] the first failure is
+    /// returned as soon as it completes and the remaining searches are cancelled; under
+    /// [`FailurePolicy::BestEffort`] failed targets are reported in the response unless every
+    /// target failed.
+    pub async fn federated_search(
+        &self,
+        request: FederatedSearchRequest,
+    ) -> Result<FederatedSearchResponse> {
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let offset = usize::try_from(request.search.offset).unwrap_or_default();
+            let limit = usize::try_from(request.search.limit).unwrap_or_default();
+            let searches = request
+                .all_targets()
+                .into_iter()
+                .enumerate()
+                .map(|(index, target)| {
+                    let mut search = request.search.clone();
+                    search.database_name = target.database_name;
+                    search.collection_name = target.collection_name;
+                    search.limit += search.offset;
+                    search.offset = 0;
+                    let metric_type = target.metric_type;
+                    async move {
+                        let outcome = async move {
+                            let metric_type = self.target_metric_type(&search, metric_type).await?;
+                            Ok::<_, Error>((metric_type, self.search(search).await?))
+                        }
+                        .await;
+                        (index, outcome)
+                    }
+                });
+            let mut outcomes = stream::iter(searches).buffer_unordered(request.max_concurrency);
+            let mut responses = Vec::new();
+            let mut failures = Vec::new();
+            while let Some((target, outcome)) = outcomes.next().await {
+                match outcome {
+                    Ok((metric_type, response)) => responses.push((target, metric_type, response)),
+                    Err(error) if request.failure_policy == FailurePolicy::BestEffort => {
+                        failures.push(FederatedFailure { target, error })
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            responses.sort_by_key(|(target, _, _)| *target);
+            failures.sort_by_key(|failure| failure.target);
+            if responses.is_empty() {
+                if let Some(failure) = failures.into_iter().next() {
+                    return Err(failure.error);
+                }
+            }
+            let (results, sources) = merge_target_results(responses, offset, limit)?;
+            Ok(FederatedSearchResponse {
+                results,
+                sources,
+                failures,
+            })
+        })
+        .await
+    }
+
+    async fn target_metric_type(
+        &self,
+        search: &SearchRequest,
+        metric_type: Option<MetricType>,
+    ) -> Result<MetricType> {
+        let known = metric_type
+            .or(search.metric_type)
+            .filter(|metric| *metric != MetricType::Default);
+        if let Some(metric) = known {
+            return Ok(metric);
+        }
+        let database = self.effective_database(search.database_name.as_deref());
+        self.index_metric_type(&database, &search.collection_name, &search.vector_field)
+            .await
+    }
+}
+
+/// Merges per-target responses into one ranking per query vector, applying `offset` and `limit`.
+fn merge_target_results(
+    responses: Vec<(usize, MetricType, SearchResponse)>,
+    offset: usize,
+    limit: usize,
+) -> Result<(SearchResults, Vec<Vec<FederatedHit>>)> {
+    let query_count = responses
+        .first()
+        .map_or(0, |(_, _, response)| response.results.results.len());
+    if responses
+        .iter()
+        .any(|(_, _, response)| response.results.results.len() != query_count)
+    {
+        return Err(Error::MalformedResponse(
+            "federated search targets returned different numbers of query results".into(),
+        ));
+    }
+    let mixed_directions = responses
+        .windows(2)
+        .any(|pair| pair[0].1.is_distance() != pair[1].1.is_distance());
+    let mut per_target = responses
+        .into_iter()
+        .map(|(target, metric_type, response)| {
+            (target, metric_type, response.results.results.into_iter())
+        })
+        .collect::<Vec<_>>();
+
+    let mut results = Vec::with_capacity(query_count);
+    let mut sources = Vec::with_capacity(query_count);
+    for _ in 0..query_count {
+        let mut parts = Vec::with_capacity(per_target.len());
+        let mut hits = Vec::new();
+        for (part, (target, metric_type, remaining)) in per_target.iter_mut().enumerate() {
+            let result = remaining.next().expect("query counts were checked");
+            for (row, &score) in result.scores.iter().enumerate() {
+                let key = if mixed_directions {
+                    normalize_score(*metric_type, score)
+                } else if metric_type.is_distance() {
+                    -score
+                } else {
+                    score
+                };
+                hits.push((key, part, row, *target, score));
+            }
+            parts.push(result);
+        }
+        hits.sort_by(|left, right| right.0.total_cmp(&left.0));
+        let hits = hits
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect::<Vec<_>>();
+        let rows = hits
+            .iter()
+            .map(|&(_, part, row, _, _)| (part, row))
+            .collect::<Vec<_>>();
+        let scores = hits
+            .iter()
+            .map(|&(key, _, _, _, score)| if mixed_directions { key } else { score })
+            .collect();
+        results.push(select_result_rows(parts, &rows, scores)?);
+        sources.push(
+            hits.iter()
+                .map(|&(_, _, _, target, score)| FederatedHit { target, score })
+                .collect(),
+        );
+    }
+    Ok((SearchResults::new().results(results), sources))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::types::{FieldData, Ids, SingleResult};
+
+    fn response(ids: Vec<i64>, scores: Vec<f32>, tags: Vec<&str>) -> SearchResponse {
+        let result = SingleResult::new()
+            .ids(Ids::Int64(ids))
+            .scores(scores)
+            .output_fields(vec![FieldData::VarChar {
+                name: "tag".into(),
+                values: tags.into_iter().map(Into::into).collect(),
+            }])
+            .output_field_names(["tag"]);
+        SearchResponse::builder()
+            .results(SearchResults::new().results(vec![result]))
+            .build()
+    }
+
+    #[test]
+    fn similarity_hits_are_merged_by_score() {
+        let (results, sources) = merge_target_results(
+            vec![
+                (
+                    0,
+                    MetricType::Cosine,
+                    response(vec![1, 2], vec![0.9, 0.5], vec!["a", "b"]),
+                ),
+                (
+                    2,
+                    MetricType::Ip,
+                    response(vec![7, 8], vec![0.7, 0.1], vec!["c", "d"]),
+                ),
+            ],
+            1,
+            2,
+        )
+        .unwrap();
+        let merged = &results.get_results()[0];
+        assert_eq!(merged.get_ids(), &Ids::Int64(vec![7, 2]));
+        assert_eq!(merged.get_scores(), [0.7, 0.5]);
+        assert_eq!(
+            merged.get_output_fields(),
+            [FieldData::VarChar {
+                name: "tag".into(),
+                values: vec!["c".into(), "b".into()],
+            }]
+        );
+        assert_eq!(
+            sources[0],
+            [
+                FederatedHit {
+                    target: 2,
+                    score: 0.7
+                },
+                FederatedHit {
+                    target: 0,
+                    score: 0.5
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn mixed_metric_directions_are_ranked_by_normalized_scores() {
+        let (results, _) = merge_target_results(
+            vec![
+                (0, MetricType::L2, response(vec![1], vec![0.2], vec!["a"])),
+                (1, MetricType::L2, response(vec![2], vec![0.1], vec!["b"])),
+            ],
+            0,
+            10,
+        )
+        .unwrap();
+        assert_eq!(results.get_results()[0].get_scores(), [0.1, 0.2]);
+
+        let (results, sources) = merge_target_results(
+            vec![
+                (0, MetricType::L2, response(vec![1], vec![3.0], vec!["a"])),
+                (
+                    1,
+                    MetricType::Cosine,
+                    response(vec![2], vec![0.1], vec!["b"]),
+                ),
+            ],
+            0,
+            10,
+        )
+        .unwrap();
+        assert_eq!(results.get_results()[0].get_ids(), &Ids::Int64(vec![2, 1]));
+        assert_eq!(
+            results.get_results()[0].get_scores(),
+            [
+                normalize_score(MetricType::Cosine, 0.1),
+                normalize_score(MetricType::L2, 3.0)
+            ]
+        );
+        assert_eq!(sources[0][1].original_score(), 3.0);
+    }
+}
@@ -0,0 +1,298 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hedged attempts for latency-sensitive idempotent reads.
+//!
+//! A hedged attempt sends the original request, then one duplicate per hedge delay until a
+//! response arrives or the hedge limit is reached. The first response wins and dropping the
+//! remaining futures cancels their gRPC streams. Transport failures are only reported once every
+//! in-flight request has failed, leaving retries to the enclosing retry loop.
+
+use super::ClientV2;
+use crate::v2::error::Result;
+use crate::v2::retry::RetrySemantics;
+use crate::v2::types::{HedgingConfig, HedgingMetrics};
+use futures::stream::{FuturesUnordered, StreamExt};
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tonic::{Response, Status};
+
+/// RPC methods eligible for hedging; `get` is sent as a `query` RPC.
+const HEDGED_METHODS: &[&str] = &["search", "hybrid_search", "query"];
+/// Latency samples kept per method for the percentile delay.
+const LATENCY_WINDOW: usize = 256;
+/// Samples required before the percentile replaces the fixed delay.
+const MIN_LATENCY_SAMPLES: usize = 16;
+
+type Outcome<Resp> = std::result::Result<Response<Resp>, Status>;
+
+impl ClientV2 {
+    /// Returns hedging counters shared by this client and its clones, or `None` when hedging is
+    /// not enabled.
+    pub fn hedging_metrics(&self) -> Option<HedgingMetrics> {
+        self.hedging.as_ref().map(|hedging| hedging.metrics())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Hedging
+///////////////////////////////////////////////////////////////////////////////
+#[derive(Debug)]
+pub(super) struct Hedging {
+    config: HedgingConfig,
+    latencies: Mutex<BTreeMap<&'static str, VecDeque<Duration>>>,
+    hedged_attempts: AtomicU64,
+    hedges_sent: AtomicU64,
+    hedge_wins: AtomicU64,
+    cancelled_requests: AtomicU64,
+}
+
+impl Hedging {
+    pub(super) fn new(config: HedgingConfig) -> Self {
+        Self {
+            config,
+            latencies: Mutex::new(BTreeMap::new()),
+            hedged_attempts: AtomicU64::new(0),
+            hedges_sent: AtomicU64::new(0),
+            hedge_wins: AtomicU64::new(0),
+            cancelled_requests: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns whether attempts of `method` may be hedged.
+    pub(super) fn applies(&self, method: &str, semantics: RetrySemantics) -> bool {
+        semantics == RetrySemantics::Idempotent
+            && self.config.max_hedges > 0
+            && HEDGED_METHODS.contains(&method)
+    }
+
+    /// Runs one hedged attempt and returns the first response, or the last transport failure
+    /// when every request failed.
+    pub(super) async fn run<Resp, Call, CallFuture>(
+        &self,
+        method: &'static str,
+        call: &mut Call,
+    ) -> Result<Outcome<Resp>>
+    where
+        Call: FnMut() -> Result<CallFuture>,
+        CallFuture: Future<Output = Outcome<Resp>>,
+    {
+        let delay = self.delay(method);
+        let mut in_flight = FuturesUnordered::new();
+        in_flight.push(timed(0, call()?));
+        let mut hedges = 0usize;
+        let mut last_sent = Instant::now();
+        loop {
+            let hedge_due = hedges < self.config.max_hedges;
+            let timer = async {
+                if hedge_due {
+                    tokio::time::sleep_until((last_sent + delay).into()).await;
+                } else {
+                    futures::future::pending::<()>().await;
+                }
+            };
+            tokio::select! {
+                Some((index, latency, outcome)) = in_flight.next() => {
+                    if outcome.is_err() && !in_flight.is_empty() {
+                        continue;
+                    }
+                    if outcome.is_ok() {
+                        self.record_latency(method, latency);
+                    }
+                    if index > 0 && outcome.is_ok() {
+                        self.hedge_wins.fetch_add(1, Ordering::Relaxed);
+                    }
+                    let cancelled = in_flight.len() as u64;
+                    self.cancelled_requests.fetch_add(cancelled, Ordering::Relaxed);
+                    trace_debug!(target: "milvus_sdk::hedging", method, hedges, winner = index, cancelled, latency_ms = latency.as_millis(), "Milvus hedged attempt finished");
+                    return Ok(outcome);
+                }
+                _ = timer => {
+                    hedges += 1;
+                    if hedges == 1 {
+                        self.hedged_attempts.fetch_add(1, Ordering::Relaxed);
+                    }
+                    self.hedges_sent.fetch_add(1, Ordering::Relaxed);
+                    trace_debug!(target: "milvus_sdk::hedging", method, hedge = hedges, delay_ms = delay.as_millis(), "sending hedged Milvus request");
+                    in_flight.push(timed(hedges, call()?));
+                    last_sent = Instant::now();
+                }
+            }
+        }
+    }
+
+    /// Returns the hedge delay for `method`: the configured latency percentile of recent
+    /// responses, never below the configured delay.
+    fn delay(&self, method: &str) -> Duration {
+        let Some(percentile) = self
+            .config
+            .percentile
+            .filter(|percentile| *percentile > 0.0 && *percentile <= 1.0)
+        else {
+            return self.config.delay;
+        };
+        let latencies = self.latencies.lock();
+        let Some(samples) = latencies
+            .get(method)
+            .filter(|samples| samples.len() >= MIN_LATENCY_SAMPLES)
+        else {
+            return self.config.delay;
+        };
+        let mut sorted = samples.iter().copied().collect::<Vec<_>>();
+        sorted.sort_unstable();
+        let rank = ((percentile * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len());
+        sorted[rank - 1].max(self.config.delay)
+    }
+
+    fn record_latency(&self, method: &'static str, latency: Duration) {
+        let mut latencies = self.latencies.lock();
+        let samples = latencies.entry(method).or_default();
+        if samples.len() == LATENCY_WINDOW {
+            samples.pop_front();
+        }
+        samples.push_back(latency);
+    }
+
+    fn metrics(&self) -> HedgingMetrics {
+        HedgingMetrics {
+            hedged_attempts: self.hedged_attempts.load(Ordering::Relaxed),
+            hedges_sent: self.hedges_sent.load(Ordering::Relaxed),
+            hedge_wins: self.hedge_wins.load(Ordering::Relaxed),
+            cancelled_requests: self.cancelled_requests.load(Ordering::Relaxed),
+        }
+    }
+}
+
+async fn timed<Resp>(
+    index: usize,
+    call: impl Future<Output = Outcome<Resp>>,
+) -> (usize, Duration, Outcome<Resp>) {
+    let started = Instant::now();
+    let outcome = call.await;
+    (index, started.elapsed(), outcome)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    fn hedging(config: HedgingConfig) -> Hedging {
+        Hedging::new(config)
+    }
+
+    #[test]
+    fn hedging_only_applies_to_idempotent_reads() {
+        let hedging = hedging(HedgingConfig::new());
+        assert!(hedging.applies("search", RetrySemantics::Idempotent));
+        assert!(hedging.applies("hybrid_search", RetrySemantics::Idempotent));
+        assert!(hedging.applies("query", RetrySemantics::Idempotent));
+        assert!(!hedging.applies("query", RetrySemantics::NonIdempotent));
+        assert!(!hedging.applies("insert", RetrySemantics::Idempotent));
+
+        let disabled = Hedging::new(HedgingConfig::new().max_hedges(0));
+        assert!(!disabled.applies("search", RetrySemantics::Idempotent));
+    }
+
+    #[test]
+    fn percentile_delay_tracks_observed_latencies_above_the_floor() {
+        let hedging = hedging(
+            HedgingConfig::new()
+                .delay(Duration::from_millis(5))
+                .percentile(Some(0.9)),
+        );
+        assert_eq!(hedging.delay("search"), Duration::from_millis(5));
+
+        for millis in 1..=20 {
+            hedging.record_latency("search", Duration::from_millis(millis));
+        }
+        assert_eq!(hedging.delay("search"), Duration::from_millis(18));
+        assert_eq!(hedging.delay("query"), Duration::from_millis(5));
+
+        let fixed = Hedging::new(HedgingConfig::new().percentile(None));
+        for _ in 0..20 {
+            fixed.record_latency("search", Duration::from_secs(1));
+        }
+        assert_eq!(fixed.delay("search"), Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn slow_primary_is_cancelled_when_a_hedge_answers_first() {
+        let hedging = hedging(
+            HedgingConfig::new()
+                .delay(Duration::from_millis(10))
+                .percentile(None)
+                .max_hedges(2),
+        );
+        let sent = Arc::new(AtomicUsize::new(0));
+        let mut call = || -> Result<_> {
+            let index = sent.fetch_add(1, Ordering::SeqCst);
+            Ok(async move {
+                if index == 0 {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+                Ok::<_, Status>(Response::new(index))
+            })
+        };
+
+        let outcome = hedging.run("search", &mut call).await.unwrap();
+        assert_eq!(outcome.unwrap().into_inner(), 1);
+        assert_eq!(sent.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            hedging.metrics(),
+            HedgingMetrics {
+                hedged_attempts: 1,
+                hedges_sent: 1,
+                hedge_wins: 1,
+                cancelled_requests: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn failures_wait_for_the_remaining_requests() {
+        let hedging = hedging(
+            HedgingConfig::new()
+                .delay(Duration::from_millis(10))
+                .percentile(None),
+        );
+        let sent = Arc::new(AtomicUsize::new(0));
+        let mut call = || -> Result<_> {
+            let index = sent.fetch_add(1, Ordering::SeqCst);
+            Ok(async move {
+                if index == 0 {
+                    tokio::time::sleep(Duration::from_millis(30)).await;
+                    Err(Status::unavailable("primary failed"))
+                } else {
+                    tokio::time::sleep(Duration::from_millis(40)).await;
+                    Ok(Response::new(index))
+                }
+            })
+        };
+
+        let outcome = hedging.run("query", &mut call).await.unwrap();
+        assert_eq!(outcome.unwrap().into_inner(), 1);
+        assert_eq!(hedging.metrics().get_cancelled_requests(), 0);
+    }
+}
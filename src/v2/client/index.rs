@@ -34,20 +34,31 @@ impl ClientV2 {
     /// options when the caller must wait until the index is ready; otherwise this method returns
     /// after the create request is accepted.
     pub async fn create_index(&self, request: request::index::CreateIndexRequest) -> Result<()> {
-        let database = self.current_database();
-        let (database, collection, index_params, sync, timeout_ms) = request.into_parts(&database);
-        for index_param in index_params {
-            let field_name = index_param.field_name.clone();
-            let index_name = index_param.index_name.clone();
-            let raw = index_param.into_proto(database.clone(), collection.clone());
-            let status = status_rpc_with_retry!(Idempotent, self, create_index, raw)?;
-            self.status(status)?;
-            if sync {
-                self.wait_for_index(&database, &collection, &field_name, &index_name, timeout_ms)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let (database, collection, index_params, sync, timeout_ms) =
+                request.into_parts(&database);
+            for index_param in index_params {
+                let field_name = index_param.field_name.clone();
+                let index_name = index_param.index_name.clone();
+                let raw = index_param.into_proto(database.clone(), collection.clone());
+                let status = status_rpc_with_retry!(Idempotent, self, create_index, raw)?;
+                self.status(status)?;
+                if sync {
+                    self.wait_for_index(
+                        &database,
+                        &collection,
+                        &field_name,
+                        &index_name,
+                        timeout_ms,
+                    )
                     .await?;
+                }
             }
-        }
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 
     /// Retrieves index definitions and server-side parameters for a collection field.
@@ -55,18 +66,30 @@ impl ClientV2 {
         &self,
         request: request::index::DescribeIndexRequest,
     ) -> Result<response::index::DescribeIndexResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, describe_index, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        response::index::DescribeIndexResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(self, describe_index, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            response::index::DescribeIndexResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Drops the index associated with a collection field.
     pub async fn drop_index(&self, request: request::index::DropIndexRequest) -> Result<()> {
-        let database = self.current_database();
-        let status =
-            status_rpc_with_retry!(Idempotent, self, drop_index, request.into_proto(&database))?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                drop_index,
+                request.into_proto(&database)
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Lists index names and metadata associated with a collection.
@@ -74,10 +97,15 @@ impl ClientV2 {
         &self,
         request: request::index::ListIndexesRequest,
     ) -> Result<response::index::ListIndexesResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, get_index_statistics, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        response::index::ListIndexesResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response =
+                rpc_with_retry!(self, get_index_statistics, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            response::index::ListIndexesResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Updates mutable properties of an existing index.
@@ -85,10 +113,18 @@ impl ClientV2 {
         &self,
         request: request::index::AlterIndexPropertiesRequest,
     ) -> Result<()> {
-        let database = self.current_database();
-        let status =
-            status_rpc_with_retry!(Idempotent, self, alter_index, request.into_proto(&database))?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                alter_index,
+                request.into_proto(&database)
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Removes the requested mutable properties from an index.
@@ -96,10 +132,18 @@ impl ClientV2 {
         &self,
         request: request::index::DropIndexPropertiesRequest,
     ) -> Result<()> {
-        let database = self.current_database();
-        let status =
-            status_rpc_with_retry!(Idempotent, self, alter_index, request.into_proto(&database))?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                alter_index,
+                request.into_proto(&database)
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     async fn wait_for_index(
@@ -17,6 +17,7 @@
 //! Internal schema caching, validation, and request conversion helpers.
 
 use super::cache::{COLLECTION_TS_CACHE, SCHEMA_CACHE};
+use super::scope::CallScope;
 use super::ClientV2;
 use crate::proto::{common, milvus, schema};
 use crate::v2::error::status_to_result;
@@ -74,6 +75,11 @@ impl ClientV2 {
     pub(super) fn rpc_request<T>(&self, message: T) -> Request<T> {
         let mut request = Request::new(message);
         let timeout = *self.rpc_timeout.read();
+        let timeout = match CallScope::current().remaining() {
+            // Never ask the server for more time than the enclosing call has left.
+            Some(remaining) if timeout.is_zero() || remaining < timeout => remaining,
+            _ => timeout,
+        };
         if !timeout.is_zero() {
             request.set_timeout(timeout);
         }
@@ -273,7 +279,7 @@ impl ClientV2 {
     }
 }
 
-fn canonical_collection_name(
+pub(super) fn canonical_collection_name(
     description: &milvus::DescribeCollectionResponse,
     requested: &str,
 ) -> String {
@@ -16,13 +16,18 @@
 
 //! Query and search iterators for paginated V2 reads.
 
+use super::result_cache::CacheableRequest;
 use super::ClientV2;
 use crate::proto::{common, milvus, schema};
 use crate::v2::error::status_to_result;
 use crate::v2::error::{Error, Result};
+use crate::v2::request::options::RequestOptions;
 use crate::v2::{request, response};
 use crate::v2::{DataType, IndexDesc, MetricType};
-use std::time::{SystemTime, UNIX_EPOCH};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 const MAX_BATCH_SIZE: usize = 16_384;
 const HYBRID_TIMESTAMP_LOGICAL_BITS: u32 = 18;
@@ -39,12 +44,22 @@ struct SearchIteratorCollectionInfo {
     vector_field_names: Vec<String>,
 }
 
+/// Search iterator input resolved against the collection before the first page is requested.
+struct SearchIteratorPlan {
+    info: SearchIteratorCollectionInfo,
+    inferred_vector_field: bool,
+    vector_field: String,
+    raw: milvus::SearchRequest,
+    v2_request: milvus::SearchRequest,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // QueryIterator
 ///////////////////////////////////////////////////////////////////////////////
 /// Iterator that retrieves query results in stable, paginated batches.
 pub struct QueryIterator {
     client: ClientV2,
+    options: RequestOptions,
     request: milvus::QueryRequest,
     batch_size: usize,
     remaining: Option<usize>,
@@ -57,9 +72,10 @@ pub struct QueryIterator {
 }
 
 impl QueryIterator {
-    fn finished(client: ClientV2, batch_size: usize) -> Self {
+    fn finished(client: ClientV2, options: RequestOptions, batch_size: usize) -> Self {
         Self {
             client,
+            options,
             request: milvus::QueryRequest::default(),
             batch_size,
             remaining: Some(0),
@@ -76,7 +92,16 @@ impl QueryIterator {
     ///
     /// Pages advance a primary-key cursor and are decoded on demand rather than materializing the
     /// entire result set.
+    ///
+    /// Each page honors the timeout and cancellation token of the request that created the
+    /// iterator.
     pub async fn next(&mut self) -> Result<Option<response::dql::QueryResponse>> {
+        let client = self.client.clone();
+        let options = self.options.clone();
+        client.scoped(&options, self.next_page()).await
+    }
+
+    async fn next_page(&mut self) -> Result<Option<response::dql::QueryResponse>> {
         if self.finished || self.remaining == Some(0) {
             return Ok(None);
         }
@@ -250,6 +275,7 @@ impl SearchIterator {
 /// Legacy range-search iterator used when the server does not support Search Iterator V2.
 pub struct SearchIteratorV1 {
     client: ClientV2,
+    options: RequestOptions,
     request: milvus::SearchRequest,
     batch_size: usize,
     remaining: Option<usize>,
@@ -268,7 +294,16 @@ pub struct SearchIteratorV1 {
 
 impl SearchIteratorV1 {
     /// Retrieves the next legacy search-result batch, or `None` when iteration is complete.
+    ///
+    /// Each page honors the timeout and cancellation token of the request that created the
+    /// iterator.
     pub async fn next(&mut self) -> Result<Option<response::dql::SearchResponse>> {
+        let client = self.client.clone();
+        let options = self.options.clone();
+        client.scoped(&options, self.next_page()).await
+    }
+
+    async fn next_page(&mut self) -> Result<Option<response::dql::SearchResponse>> {
         if self.finished || self.remaining == Some(0) {
             return Ok(None);
         }
@@ -340,7 +375,7 @@ impl SearchIteratorV1 {
         let mut request = self.request.clone();
         request.dsl = self.next_filter();
         let coefficient = coefficient.max(1) as f64;
-        let next_radius = if metric_distance_increases(self.metric) {
+        let next_radius = if self.metric.is_distance() {
             let next = self.tail_band + self.width * coefficient;
             self.requested_radius
                 .map_or(next, |radius| next.min(radius))
@@ -416,29 +451,50 @@ impl SearchIteratorV1 {
 /// Token/bound-based iterator supported by newer Milvus servers.
 pub struct SearchIteratorV2 {
     client: ClientV2,
+    options: RequestOptions,
     request: milvus::SearchRequest,
     batch_size: usize,
     remaining: Option<usize>,
     token: Option<String>,
+    last_bound: Option<f32>,
     primary_field_name: String,
+    database_name: String,
+    collection_id: i64,
+    fingerprint: u64,
+    expires_at_ms: u64,
     finished: bool,
 }
 
 impl SearchIteratorV2 {
-    fn finished(client: ClientV2, batch_size: usize) -> Self {
+    fn finished(client: ClientV2, options: RequestOptions, batch_size: usize) -> Self {
         Self {
             client,
+            options,
             request: milvus::SearchRequest::default(),
             batch_size,
             remaining: Some(0),
             token: None,
+            last_bound: None,
             primary_field_name: String::new(),
+            database_name: String::new(),
+            collection_id: 0,
+            fingerprint: 0,
+            expires_at_ms: 0,
             finished: true,
         }
     }
 
     /// Retrieves the next token-based search-result batch, or `None` when iteration is complete.
+    ///
+    /// Each page honors the timeout and cancellation token of the request that created the
+    /// iterator.
     pub async fn next(&mut self) -> Result<Option<response::dql::SearchResponse>> {
+        let client = self.client.clone();
+        let options = self.options.clone();
+        client.scoped(&options, self.next_page()).await
+    }
+
+    async fn next_page(&mut self) -> Result<Option<response::dql::SearchResponse>> {
         if self.finished || self.remaining == Some(0) {
             return Ok(None);
         }
@@ -484,7 +540,8 @@ impl SearchIteratorV2 {
                 results.primary_field_name = self.primary_field_name.clone();
             }
         }
-        let response = response::dql::SearchResponse::from_proto_with_row_limit(raw, Some(size))?;
+        let response =
+            response::dql::SearchResponse::from_proto_with_row_limit(raw, Some(size), false)?;
         if response.results().len() != 1 {
             return Err(Error::MalformedResponse(
                 "search iterator server response must contain exactly one result".into(),
@@ -498,16 +555,143 @@ impl SearchIteratorV2 {
         let next_remaining = self.remaining.map(|left| left.saturating_sub(count));
         self.request = request;
         self.token = Some(next_token);
+        self.last_bound = Some(iterator_bound);
         self.remaining = next_remaining;
         trace_debug!(target: "milvus_sdk::iterator", kind = "search_v2", rows = count, remaining = ?self.remaining, "completed token-based search iterator page");
         Ok(Some(response))
     }
 
+    /// Returns a token from which [`ClientV2::search_page`] continues this iteration, or `None`
+    /// once the iteration is known to be complete.
+    ///
+    /// The token carries the iterator's pinned MVCC timestamp, server token, and last score bound,
+    /// so the continuation sees the same snapshot as this iterator.
+    pub fn page_token(&self) -> Option<SearchPageToken> {
+        if self.finished || self.remaining == Some(0) {
+            return None;
+        }
+        Some(SearchPageToken {
+            database_name: self.database_name.clone(),
+            collection_id: self.collection_id,
+            fingerprint: self.fingerprint,
+            iterator_id: self.token.clone(),
+            last_bound: self.last_bound,
+            session_ts: self.request.guarantee_timestamp,
+            remaining: self.remaining,
+            expires_at_ms: self.expires_at_ms,
+        })
+    }
+
     /// Releases token-based search iterator state.
     pub async fn close(&mut self) -> Result<()> {
         self.finished = true;
         Ok(())
     }
+
+    async fn into_page(mut self) -> Result<SearchPage> {
+        let response = self.next().await?;
+        Ok(SearchPage {
+            response,
+            next_token: self.page_token(),
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SearchPageToken
+///////////////////////////////////////////////////////////////////////////////
+/// Serializable position of a token-based search, resumable by [`ClientV2::search_page`] from
+/// any client or process.
+///
+/// A token pins the MVCC timestamp of the search's first page, so every later page reads the
+/// same snapshot regardless of concurrent writes, and it expires after the request's
+/// page-token TTL. Tokens are not signed; sign or encrypt them before handing them to untrusted
+/// callers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SearchPageToken {
+    pub(crate) database_name: String,
+    pub(crate) collection_id: i64,
+    pub(crate) fingerprint: u64,
+    pub(crate) iterator_id: Option<String>,
+    pub(crate) last_bound: Option<f32>,
+    pub(crate) session_ts: u64,
+    pub(crate) remaining: Option<usize>,
+    pub(crate) expires_at_ms: u64,
+}
+
+impl SearchPageToken {
+    /// Encodes the token as URL-safe text, suitable for a query parameter or header.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("search page tokens always serialize to JSON");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// Decodes a token produced by [`SearchPageToken::encode`].
+    pub fn decode(text: &str) -> Result<Self> {
+        let json = URL_SAFE_NO_PAD.decode(text).map_err(|error| {
+            Error::validation("token".into(), format!("is not valid base64: {error}"))
+        })?;
+        serde_json::from_slice(&json).map_err(|error| {
+            Error::validation(
+                "token".into(),
+                format!("is not a search page token: {error}"),
+            )
+        })
+    }
+
+    /// Returns the MVCC timestamp every page of the search reads at.
+    pub fn get_session_ts(&self) -> u64 {
+        self.session_ts
+    }
+
+    /// Returns how many more results the search may return, or `None` when it has no limit.
+    pub fn get_remaining(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Returns the time after which the token is rejected.
+    pub fn get_expires_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.expires_at_ms)
+    }
+
+    /// Returns whether the token has expired.
+    pub fn is_expired(&self) -> bool {
+        page_token_expiry(Duration::ZERO) >= self.expires_at_ms
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SearchPage
+///////////////////////////////////////////////////////////////////////////////
+/// One page returned by [`ClientV2::search_page`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SearchPage {
+    pub(crate) response: Option<response::dql::SearchResponse>,
+    pub(crate) next_token: Option<SearchPageToken>,
+}
+
+impl SearchPage {
+    /// Returns the page's results, or `None` when the search had no more results.
+    pub fn get_response(&self) -> Option<&response::dql::SearchResponse> {
+        self.response.as_ref()
+    }
+
+    /// Returns the token for the next page, or `None` when the search is complete.
+    pub fn get_next_token(&self) -> Option<&SearchPageToken> {
+        self.next_token.as_ref()
+    }
+
+    /// Splits the page into its results and next-page token.
+    pub fn into_parts(
+        self,
+    ) -> (
+        Option<response::dql::SearchResponse>,
+        Option<SearchPageToken>,
+    ) {
+        (self.response, self.next_token)
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -605,139 +789,372 @@ impl ClientV2 {
         &self,
         request: request::dql::QueryIteratorRequest,
     ) -> Result<QueryIterator> {
-        let request::dql::QueryIteratorRequest {
-            query,
-            batch_size,
-            reduce_stop_for_best,
-        } = request;
-        validate_batch_size(batch_size)?;
-        if query.limit == Some(0) {
-            return Ok(QueryIterator::finished(self.clone(), batch_size));
-        }
-        let database = self.effective_database(query.database_name.as_deref());
-        let collection = query.collection_name.clone();
-        let description = self
-            .get_collection_description(&database, &collection)
-            .await?;
-        if description.collection_id <= 0 {
-            return Err(Error::MalformedResponse(
-                "query iterator collection has no valid collection ID".into(),
-            ));
-        }
-        let collection_schema = description.schema.as_ref().ok_or_else(|| {
-            Error::MalformedResponse("collection description has no schema".into())
-        })?;
-        let primary_field = collection_schema
-            .fields
-            .iter()
-            .find(|field| field.is_primary_key)
-            .ok_or_else(|| {
-                Error::MalformedResponse(
-                    "query iterator collection has no primary-key field".into(),
-                )
+        let options = request.request_options().clone();
+        self.scoped(&options.clone(), async move {
+            let request::dql::QueryIteratorRequest {
+                query,
+                batch_size,
+                reduce_stop_for_best,
+                ..
+            } = request;
+            validate_batch_size(batch_size)?;
+            if query.limit == Some(0) {
+                return Ok(QueryIterator::finished(self.clone(), options, batch_size));
+            }
+            let database = self.effective_database(query.database_name.as_deref());
+            let collection = query.collection_name.clone();
+            let description = self
+                .get_collection_description(&database, &collection)
+                .await?;
+            if description.collection_id <= 0 {
+                return Err(Error::MalformedResponse(
+                    "query iterator collection has no valid collection ID".into(),
+                ));
+            }
+            let collection_schema = description.schema.as_ref().ok_or_else(|| {
+                Error::MalformedResponse("collection description has no schema".into())
             })?;
-        let primary_field_type = data_type_from_proto(primary_field.data_type)?;
-        if !matches!(primary_field_type, DataType::Int64 | DataType::VarChar) {
-            return Err(Error::MalformedResponse(
-                "query iterator primary key must be Int64 or VarChar".into(),
-            ));
-        }
-        let guarantee = self
-            .deduce_guarantee_timestamp(&database, &collection, query.consistency_level)
-            .await?;
-        let offset = query.offset.unwrap_or(0).max(0) as usize;
-        let remaining = match query.limit {
-            Some(value) if value >= 0 => Some(value as usize),
-            Some(_) | None => None,
-        };
-        let mut raw = query.into_proto(&database, None, guarantee)?;
-        let original_filter = raw.expr.clone();
-        set_param(&mut raw.query_params, "offset", "0".into());
-        set_param(
-            &mut raw.query_params,
-            "collection_id",
-            description.collection_id.to_string(),
-        );
-        set_param(
-            &mut raw.query_params,
-            "reduce_stop_for_best",
-            if reduce_stop_for_best {
-                "True"
-            } else {
-                "False"
+            let primary_field = collection_schema
+                .fields
+                .iter()
+                .find(|field| field.is_primary_key)
+                .ok_or_else(|| {
+                    Error::MalformedResponse(
+                        "query iterator collection has no primary-key field".into(),
+                    )
+                })?;
+            let primary_field_type = data_type_from_proto(primary_field.data_type)?;
+            if !matches!(primary_field_type, DataType::Int64 | DataType::VarChar) {
+                return Err(Error::MalformedResponse(
+                    "query iterator primary key must be Int64 or VarChar".into(),
+                ));
             }
-            .into(),
-        );
+            let guarantee = self
+                .deduce_guarantee_timestamp(&database, &collection, query.consistency_level)
+                .await?;
+            let offset = query.offset.unwrap_or(0).max(0) as usize;
+            let remaining = match query.limit {
+                Some(value) if value >= 0 => Some(value as usize),
+                Some(_) | None => None,
+            };
+            let mut raw = query.into_proto(&database, None, guarantee)?;
+            let original_filter = raw.expr.clone();
+            set_param(&mut raw.query_params, "offset", "0".into());
+            set_param(
+                &mut raw.query_params,
+                "collection_id",
+                description.collection_id.to_string(),
+            );
+            set_param(
+                &mut raw.query_params,
+                "reduce_stop_for_best",
+                if reduce_stop_for_best {
+                    "True"
+                } else {
+                    "False"
+                }
+                .into(),
+            );
 
-        let mut probe = raw.clone();
-        probe.output_fields.clear();
-        probe.partition_names.clear();
-        set_param(&mut probe.query_params, "limit", "1".into());
-        set_param(&mut probe.query_params, "iterator", "true".into());
-        let probe_response = rpc_with_retry!(self, query, probe)?;
-        status_to_result(&probe_response.status)?;
-        raw.guarantee_timestamp = iterator_session_timestamp(probe_response.session_ts);
-
-        let mut iterator = QueryIterator {
-            client: self.clone(),
-            request: raw,
-            batch_size,
-            remaining,
-            original_filter,
-            primary_field_name: primary_field.name.clone(),
-            primary_field_type,
-            cursor: None,
-            cache: None,
-            finished: false,
-        };
-        iterator.seek_to_offset(offset).await?;
-        Ok(iterator)
+            let mut probe = raw.clone();
+            probe.output_fields.clear();
+            probe.partition_names.clear();
+            set_param(&mut probe.query_params, "limit", "1".into());
+            set_param(&mut probe.query_params, "iterator", "true".into());
+            let probe_response = rpc_with_retry!(self, query, probe)?;
+            status_to_result(&probe_response.status)?;
+            raw.guarantee_timestamp = iterator_session_timestamp(probe_response.session_ts);
+
+            let mut iterator = QueryIterator {
+                client: self.clone(),
+                options,
+                request: raw,
+                batch_size,
+                remaining,
+                original_filter,
+                primary_field_name: primary_field.name.clone(),
+                primary_field_type,
+                cursor: None,
+                cache: None,
+                finished: false,
+            };
+            iterator.seek_to_offset(offset).await?;
+            Ok(iterator)
+        })
+        .await
     }
 
     /// Creates an iterator that retrieves search results in batches while preserving the server
     /// search token/bound and one MVCC session timestamp across pages.
+    ///
+    /// A search with a score range, such as one built with `between`, iterates every hit inside
+    /// the range when no limit is set; the range is resolved against the vector field's metric
+    /// before the first page.
     pub async fn search_iterator(
         &self,
         mut request: request::dql::SearchIteratorRequest,
     ) -> Result<SearchIterator> {
-        validate_batch_size(request.batch_size)?;
-        if request.limit == Some(0) {
-            return Ok(SearchIterator::V2(SearchIteratorV2::finished(
-                self.clone(),
-                request.batch_size,
-            )));
-        }
-        let database = self.effective_database(request.search.database_name.as_deref());
-        let collection = request.search.collection_name.clone();
+        let options = request.request_options().clone();
+        self.scoped(&options.clone(), async move {
+            validate_batch_size(request.batch_size)?;
+            if request.limit == Some(0) {
+                return Ok(SearchIterator::V2(SearchIteratorV2::finished(
+                    self.clone(),
+                    options,
+                    request.batch_size,
+                )));
+            }
+            let database = self.effective_database(request.search.database_name.as_deref());
+            let collection = request.search.collection_name.clone();
+            self.resolve_score_range(&database, &mut request.search)
+                .await?;
+            // Search Iterator V2 lets the server deduce an omitted metric. Resolve a
+            // concrete index metric only if the server falls back to the legacy
+            // range-search iterator, which needs it to advance distance bounds.
+            let requested_metric = request
+                .search
+                .metric_type
+                .filter(|metric| *metric != MetricType::Default);
+            let batch_size = request.batch_size;
+            let remaining = request.limit;
+            let expires_at_ms = page_token_expiry(request.page_token_ttl);
+            let requested_radius = request.search.radius;
+            let requested_range_filter = request.search.range_filter;
+            let ef = request
+                .search
+                .extra_params
+                .get("ef")
+                .and_then(|value| value.parse::<usize>().ok());
+            let consistency_level = request.search.consistency_level;
+            let plan = self.plan_search_iterator(&database, request).await?;
+            let original_filter = plan.raw.dsl.clone();
+
+            let mut probe = plan.v2_request.clone();
+            probe.guarantee_timestamp = 0;
+            set_param(&mut probe.search_params, "topk", "1".into());
+            set_search_extra_param(&mut probe.search_params, "search_iter_batch_size", "1");
+            let probe = rpc_with_retry!(self, search, probe)?;
+            status_to_result(&probe.status)?;
+            if search_iterator_v2_metadata(&probe).is_ok() {
+                let fingerprint = search_page_fingerprint(&plan.v2_request, remaining);
+                let mut v2_request = plan.v2_request;
+                v2_request.guarantee_timestamp = iterator_session_timestamp(probe.session_ts);
+                return Ok(SearchIterator::V2(SearchIteratorV2 {
+                    client: self.clone(),
+                    options,
+                    request: v2_request,
+                    batch_size,
+                    remaining,
+                    token: None,
+                    last_bound: None,
+                    primary_field_name: plan.info.primary_field_name,
+                    database_name: database,
+                    collection_id: plan.info.collection_id,
+                    fingerprint,
+                    expires_at_ms,
+                    finished: false,
+                }));
+            }
+            let mut raw = plan.raw;
+            let mut vector_field = plan.vector_field;
+
+            let description = self
+                .get_collection_description(&database, &collection)
+                .await?;
+            let legacy_info = search_iterator_collection_info(&description)?;
+            if plan.inferred_vector_field {
+                vector_field = single_search_iterator_vector_field(&legacy_info)?;
+                set_param(&mut raw.search_params, "anns_field", vector_field.clone());
+            }
+            set_search_extra_param(
+                &mut raw.search_params,
+                "collection_id",
+                &legacy_info.collection_id.to_string(),
+            );
+
+            let metric = match requested_metric {
+                Some(metric) => metric,
+                None => {
+                    self.index_metric_type(&database, &collection, &vector_field)
+                        .await?
+                }
+            };
+            validate_search_iterator_range(requested_radius, requested_range_filter, metric)?;
+            set_param(
+                &mut raw.search_params,
+                "metric_type",
+                metric.as_str().into(),
+            );
+            raw.guarantee_timestamp = self
+                .deduce_guarantee_timestamp(&database, &collection, consistency_level)
+                .await?;
+            let mut initial = rpc_with_retry!(self, search, raw.clone())?;
+            status_to_result(&initial.status)?;
+            raw.guarantee_timestamp = iterator_session_timestamp(initial.session_ts);
+            if let Some(results) = &mut initial.results {
+                if results.primary_field_name.is_empty() {
+                    results.primary_field_name = legacy_info.primary_field_name.clone();
+                }
+            }
+            let initial = response::dql::SearchResponse::from_proto(initial)?;
+            let initial_count = initial.row_count()?;
+            let (filtered_ids, filtered_distance, tail_band, width) = if initial_count == 0 {
+                let filtered_ids = match legacy_info.primary_field_type {
+                    DataType::Int64 => LegacyFilteredIds::Int64(Vec::new()),
+                    DataType::VarChar => LegacyFilteredIds::VarChar(Vec::new()),
+                    _ => unreachable!("validated search iterator primary-key type"),
+                };
+                (filtered_ids, None, 0.0, LEGACY_SEARCH_MIN_WIDTH)
+            } else {
+                legacy_initial_state(&initial, metric)?
+            };
+            let iterator = SearchIteratorV1 {
+                client: self.clone(),
+                options,
+                request: raw,
+                batch_size,
+                remaining,
+                metric,
+                requested_radius,
+                ef,
+                original_filter,
+                primary_field_name: legacy_info.primary_field_name,
+                filtered_ids,
+                filtered_distance,
+                tail_band,
+                width,
+                cache: (initial_count > 0).then_some(initial),
+                finished: initial_count == 0,
+            };
+            Ok(SearchIterator::V1(iterator))
+        })
+        .await
+    }
+
+    /// Retrieves one page of a token-based search together with a token for the next page.
+    ///
+    /// Pass `None` for the first page and the previous page's [`SearchPageToken`] afterwards,
+    /// along with the same `request` each time. Unlike an in-process [`SearchIteratorV2`], the
+    /// token can be handed to a stateless web tier, whose next call may reach another client or
+    /// process. The first page pins the MVCC timestamp, so later pages read the same snapshot
+    /// regardless of concurrent writes and are not capped by the server's offset limit. A page
+    /// without a response and next token marks the end of the results.
+    ///
+    /// Tokens are rejected once they expire, when `request` differs from the one that issued
+    /// them, or when the collection was recreated. Text queries are embedded again for every
+    /// page, so their embedding function must be deterministic. Search pages require Search
+    /// Iterator V2 support, available since Milvus 2.5.2.
+    pub async fn search_page(
+        &self,
+        request: request::dql::SearchIteratorRequest,
+        token: Option<SearchPageToken>,
+    ) -> Result<SearchPage> {
+        let iterator = match token {
+            Some(token) => self.resume_search_iterator(request, token).await?,
+            None => match self.search_iterator(request).await? {
+                SearchIterator::V2(iterator) => iterator,
+                SearchIterator::V1(_) => {
+                    return Err(Error::MalformedResponse(
+                        "server does not provide a Search Iterator V2 token; Milvus 2.5.2 or later is required for search pages"
+                            .into(),
+                    ))
+                }
+            },
+        };
+        iterator.into_page().await
+    }
+
+    /// Rebuilds the token-based iterator that issued `token` from the same request.
+    async fn resume_search_iterator(
+        &self,
+        mut request: request::dql::SearchIteratorRequest,
+        token: SearchPageToken,
+    ) -> Result<SearchIteratorV2> {
+        let options = request.request_options().clone();
+        self.scoped(&options.clone(), async move {
+            validate_batch_size(request.batch_size)?;
+            if token.is_expired() {
+                return Err(Error::validation(
+                    "token".into(),
+                    "search page token has expired".into(),
+                ));
+            }
+            let database = self.effective_database(request.search.database_name.as_deref());
+            if database != token.database_name {
+                return Err(Error::validation(
+                    "token".into(),
+                    "search page token was issued for another database".into(),
+                ));
+            }
+            self.resolve_score_range(&database, &mut request.search)
+                .await?;
+            let batch_size = request.batch_size;
+            let limit = request.limit;
+            let plan = self.plan_search_iterator(&database, request).await?;
+            if plan.info.collection_id != token.collection_id {
+                return Err(Error::validation(
+                    "token".into(),
+                    "collection was recreated after the search page token was issued".into(),
+                ));
+            }
+            if search_page_fingerprint(&plan.v2_request, limit) != token.fingerprint {
+                return Err(Error::validation(
+                    "token".into(),
+                    "search page token was issued for a different search request".into(),
+                ));
+            }
+            let mut request = plan.v2_request;
+            request.guarantee_timestamp = token.session_ts;
+            if let Some(iterator_id) = &token.iterator_id {
+                set_search_extra_param(&mut request.search_params, "search_iter_id", iterator_id);
+            }
+            if let Some(bound) = token.last_bound {
+                set_search_extra_param(
+                    &mut request.search_params,
+                    "search_iter_last_bound",
+                    &format_iterator_bound(bound),
+                );
+            }
+            Ok(SearchIteratorV2 {
+                client: self.clone(),
+                options,
+                request,
+                batch_size,
+                remaining: token.remaining,
+                token: token.iterator_id,
+                last_bound: token.last_bound,
+                primary_field_name: plan.info.primary_field_name,
+                database_name: database,
+                collection_id: token.collection_id,
+                fingerprint: token.fingerprint,
+                expires_at_ms: token.expires_at_ms,
+                finished: false,
+            })
+        })
+        .await
+    }
+
+    /// Resolves the vector field and embeds text queries, then builds the first-page request of
+    /// a search iterator and its Search Iterator V2 variant.
+    async fn plan_search_iterator(
+        &self,
+        database: &str,
+        mut request: request::dql::SearchIteratorRequest,
+    ) -> Result<SearchIteratorPlan> {
         let description = self
-            .describe_collection_uncached(&database, &collection)
+            .describe_collection_uncached(database, &request.search.collection_name)
             .await?;
-        let direct_info = search_iterator_collection_info(&description)?;
+        let info = search_iterator_collection_info(&description)?;
         let inferred_vector_field = request.search.vector_field.is_empty();
-        if request.search.vector_field.is_empty() {
-            request.search.vector_field = single_search_iterator_vector_field(&direct_info)?;
+        if inferred_vector_field {
+            request.search.vector_field = single_search_iterator_vector_field(&info)?;
         }
-        let requested_metric = request
-            .search
-            .metric_type
-            .filter(|metric| *metric != MetricType::Default);
-        // Search Iterator V2 lets the server deduce an omitted metric. Resolve a
-        // concrete index metric only if the server falls back to the legacy
-        // range-search iterator, which needs it to advance distance bounds.
+        self.embed_search(database, &mut request.search).await?;
         validate_search_iterator_input(&request.search, request.batch_size)?;
         let batch_size = request.batch_size;
-        let remaining = request.limit;
-        let mut vector_field = request.search.vector_field.clone();
-        let requested_radius = request.search.radius;
-        let requested_range_filter = request.search.range_filter;
-        let ef = request
-            .search
-            .extra_params
-            .get("ef")
-            .and_then(|value| value.parse::<usize>().ok());
+        let vector_field = request.search.vector_field.clone();
         request.search.limit = batch_size as i64;
-        let consistency_level = request.search.consistency_level;
-        let mut raw = request.search.into_proto(&database, 0)?;
+        let mut raw = request.search.into_proto(database, 0)?;
         if raw.nq != 1 {
             return Err(Error::validation(
                 "vectors".into(),
@@ -747,11 +1164,10 @@ impl ClientV2 {
         set_search_extra_param(
             &mut raw.search_params,
             "collection_id",
-            &direct_info.collection_id.to_string(),
+            &info.collection_id.to_string(),
         );
         set_search_extra_param(&mut raw.search_params, "iterator", "True");
         set_param(&mut raw.search_params, "topk", batch_size.to_string());
-        let original_filter = raw.dsl.clone();
 
         let mut v2_request = raw.clone();
         set_search_extra_param(&mut v2_request.search_params, "search_iter_v2", "True");
@@ -760,96 +1176,17 @@ impl ClientV2 {
             "search_iter_batch_size",
             &batch_size.to_string(),
         );
-        let mut probe = v2_request.clone();
-        probe.guarantee_timestamp = 0;
-        set_param(&mut probe.search_params, "topk", "1".into());
-        set_search_extra_param(&mut probe.search_params, "search_iter_batch_size", "1");
-        let probe = rpc_with_retry!(self, search, probe)?;
-        status_to_result(&probe.status)?;
-        if search_iterator_v2_metadata(&probe).is_ok() {
-            v2_request.guarantee_timestamp = iterator_session_timestamp(probe.session_ts);
-            return Ok(SearchIterator::V2(SearchIteratorV2 {
-                client: self.clone(),
-                request: v2_request,
-                batch_size,
-                remaining,
-                token: None,
-                primary_field_name: direct_info.primary_field_name,
-                finished: false,
-            }));
-        }
-
-        let description = self
-            .get_collection_description(&database, &collection)
-            .await?;
-        let legacy_info = search_iterator_collection_info(&description)?;
-        if inferred_vector_field {
-            vector_field = single_search_iterator_vector_field(&legacy_info)?;
-            set_param(&mut raw.search_params, "anns_field", vector_field.clone());
-        }
-        set_search_extra_param(
-            &mut raw.search_params,
-            "collection_id",
-            &legacy_info.collection_id.to_string(),
-        );
-
-        let metric = match requested_metric {
-            Some(metric) => metric,
-            None => {
-                self.search_iterator_metric(&database, &collection, &vector_field)
-                    .await?
-            }
-        };
-        validate_search_iterator_range(requested_radius, requested_range_filter, metric)?;
-        set_param(
-            &mut raw.search_params,
-            "metric_type",
-            metric.as_str().into(),
-        );
-        raw.guarantee_timestamp = self
-            .deduce_guarantee_timestamp(&database, &collection, consistency_level)
-            .await?;
-        let mut initial = rpc_with_retry!(self, search, raw.clone())?;
-        status_to_result(&initial.status)?;
-        raw.guarantee_timestamp = iterator_session_timestamp(initial.session_ts);
-        if let Some(results) = &mut initial.results {
-            if results.primary_field_name.is_empty() {
-                results.primary_field_name = legacy_info.primary_field_name.clone();
-            }
-        }
-        let initial = response::dql::SearchResponse::from_proto(initial)?;
-        let initial_count = initial.row_count()?;
-        let (filtered_ids, filtered_distance, tail_band, width) = if initial_count == 0 {
-            let filtered_ids = match legacy_info.primary_field_type {
-                DataType::Int64 => LegacyFilteredIds::Int64(Vec::new()),
-                DataType::VarChar => LegacyFilteredIds::VarChar(Vec::new()),
-                _ => unreachable!("validated search iterator primary-key type"),
-            };
-            (filtered_ids, None, 0.0, LEGACY_SEARCH_MIN_WIDTH)
-        } else {
-            legacy_initial_state(&initial, metric)?
-        };
-        let iterator = SearchIteratorV1 {
-            client: self.clone(),
-            request: raw,
-            batch_size,
-            remaining,
-            metric,
-            requested_radius,
-            ef,
-            original_filter,
-            primary_field_name: legacy_info.primary_field_name,
-            filtered_ids,
-            filtered_distance,
-            tail_band,
-            width,
-            cache: (initial_count > 0).then_some(initial),
-            finished: initial_count == 0,
-        };
-        Ok(SearchIterator::V1(iterator))
+        Ok(SearchIteratorPlan {
+            info,
+            inferred_vector_field,
+            vector_field,
+            raw,
+            v2_request,
+        })
     }
 
-    async fn search_iterator_metric(
+    /// Resolves the metric type of the index built on `vector_field`.
+    pub(super) async fn index_metric_type(
         &self,
         database: &str,
         collection: &str,
@@ -964,7 +1301,7 @@ fn validate_search_iterator_range(
     metric: MetricType,
 ) -> Result<()> {
     if let (Some(radius), Some(range_filter)) = (radius, range_filter) {
-        let invalid = if metric_distance_increases(metric) {
+        let invalid = if metric.is_distance() {
             radius <= range_filter
         } else {
             radius >= range_filter
@@ -985,7 +1322,7 @@ fn data_type_from_proto(value: i32) -> Result<DataType> {
     DataType::try_from_proto(value)
 }
 
-fn vector_field_names(collection: &schema::CollectionSchema) -> Vec<String> {
+pub(super) fn vector_field_names(collection: &schema::CollectionSchema) -> Vec<String> {
     let mut names = collection
         .fields
         .iter()
@@ -1006,19 +1343,6 @@ fn vector_field_names(collection: &schema::CollectionSchema) -> Vec<String> {
     names
 }
 
-fn metric_distance_increases(metric: MetricType) -> bool {
-    matches!(
-        metric,
-        MetricType::L2
-            | MetricType::Jaccard
-            | MetricType::MhJaccard
-            | MetricType::Hamming
-            | MetricType::MaxSimL2
-            | MetricType::MaxSimJaccard
-            | MetricType::MaxSimHamming
-    )
-}
-
 fn metric_distance_is_integer(metric: MetricType) -> bool {
     matches!(metric, MetricType::Hamming | MetricType::MaxSimHamming)
 }
@@ -1058,7 +1382,7 @@ fn legacy_page_width(page: &response::dql::SearchResponse, metric: MetricType) -
     let last = *scores.last().ok_or_else(|| {
         Error::MalformedResponse("legacy search iterator page has no scores".into())
     })?;
-    let width = if metric_distance_increases(metric) {
+    let width = if metric.is_distance() {
         f64::from(last - first)
     } else {
         f64::from(first - last)
@@ -1119,6 +1443,25 @@ fn format_iterator_bound(bound: f32) -> String {
     format!("{:.15}", f64::from(bound))
 }
 
+/// Returns the Unix time in milliseconds at which a page token issued now expires.
+fn page_token_expiry(ttl: Duration) -> u64 {
+    let expires_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .saturating_add(ttl);
+    u64::try_from(expires_at.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Fingerprints a first-page Search Iterator V2 request and its limit with 64-bit FNV-1a, which
+/// unlike the standard hasher is stable across processes and Rust releases.
+fn search_page_fingerprint(request: &milvus::SearchRequest, limit: Option<usize>) -> u64 {
+    let mut key = request.cache_key().request;
+    key.extend(limit.map_or(u64::MAX, |limit| limit as u64).to_le_bytes());
+    key.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
 fn validate_batch_size(batch_size: usize) -> Result<()> {
     if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
         return Err(Error::validation(
@@ -1340,13 +1683,15 @@ fn field_row_count(field: &schema::FieldData) -> usize {
 #[cfg(test)]
 mod search_iterator_v2_tests {
     use super::{
-        format_iterator_bound, hybrid_timestamp_from_millis, search_iterator_result_count,
-        search_iterator_v2_metadata, set_search_extra_param, set_search_numeric_param,
-        validate_search_iterator_input, validate_search_iterator_range, LegacyFilteredIds,
+        format_iterator_bound, hybrid_timestamp_from_millis, page_token_expiry,
+        search_iterator_result_count, search_iterator_v2_metadata, search_page_fingerprint,
+        set_search_extra_param, set_search_numeric_param, validate_search_iterator_input,
+        validate_search_iterator_range, LegacyFilteredIds, SearchPageToken,
     };
     use crate::proto::{common, milvus, schema};
     use crate::v2::request::dql::{SearchRequest, SearchVectors};
     use crate::v2::MetricType;
+    use std::time::Duration;
 
     #[test]
     fn last_bound_uses_double_precision_wire_text() {
@@ -1478,4 +1823,62 @@ mod search_iterator_v2_tests {
         )
         .is_ok());
     }
+
+    #[test]
+    fn page_tokens_round_trip_as_url_safe_text_and_expire() {
+        let token = SearchPageToken {
+            database_name: "default".into(),
+            collection_id: 42,
+            fingerprint: 7,
+            iterator_id: Some("iter/1+2".into()),
+            last_bound: Some(0.1),
+            session_ts: 449_000_000_000_000_000,
+            remaining: Some(30),
+            expires_at_ms: page_token_expiry(Duration::from_secs(60)),
+        };
+        let encoded = token.encode();
+
+        assert!(encoded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(SearchPageToken::decode(&encoded).unwrap(), token);
+        assert!(!token.is_expired());
+        assert!(SearchPageToken::decode("not a token").is_err());
+
+        let expired = SearchPageToken {
+            expires_at_ms: page_token_expiry(Duration::ZERO).saturating_sub(1),
+            ..token
+        };
+        assert!(expired.is_expired());
+    }
+
+    #[test]
+    fn page_fingerprints_ignore_parameter_order_but_track_the_search() {
+        let pair = |key: &str, value: &str| common::KeyValuePair {
+            key: key.into(),
+            value: value.into(),
+        };
+        let request = milvus::SearchRequest {
+            collection_name: "books".into(),
+            search_params: vec![pair("topk", "100"), pair("anns_field", "embedding")],
+            ..Default::default()
+        };
+        let mut reordered = request.clone();
+        reordered.search_params.reverse();
+        assert_eq!(
+            search_page_fingerprint(&request, Some(500)),
+            search_page_fingerprint(&reordered, Some(500))
+        );
+        assert_ne!(
+            search_page_fingerprint(&request, Some(500)),
+            search_page_fingerprint(&request, None)
+        );
+
+        let mut filtered = request.clone();
+        filtered.dsl = "year > 2000".into();
+        assert_ne!(
+            search_page_fingerprint(&request, None),
+            search_page_fingerprint(&filtered, None)
+        );
+    }
 }
@@ -0,0 +1,346 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side rate and concurrency limits shared by every clone of a ClientV2.
+//!
+//! Each configured [`OperationClass`] owns one limiter that combines an in-flight counter with
+//! request and byte token buckets. Server rate-limit responses multiplicatively shrink the
+//! effective limits, and successful calls grow them back additively up to the configured values.
+
+use crate::v2::types::{OperationClass, RateLimitConfig};
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// Lower bound for the adaptive factor so a throttled class keeps making progress.
+const MIN_ADAPTIVE_FACTOR: f64 = 1.0 / 64.0;
+/// Factor recovered after each successful call.
+const ADDITIVE_STEP: f64 = 1.0 / 32.0;
+/// Longest single sleep before the limiter state is re-evaluated.
+const MAX_WAIT_SLICE: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Default)]
+pub(super) struct ClientLimits {
+    classes: BTreeMap<OperationClass, Arc<ClassLimiter>>,
+}
+
+impl ClientLimits {
+    pub(super) fn new(configs: &BTreeMap<OperationClass, RateLimitConfig>) -> Self {
+        Self {
+            classes: configs
+                .iter()
+                .filter(|(_, config)| !config.is_unlimited())
+                .map(|(class, config)| (*class, Arc::new(ClassLimiter::new(config.clone()))))
+                .collect(),
+        }
+    }
+
+    pub(super) fn limiter(&self, method: &str) -> Option<Arc<ClassLimiter>> {
+        OperationClass::of_method(method)
+            .and_then(|class| self.classes.get(&class))
+            .cloned()
+    }
+
+    pub(super) fn on_success(&self, method: &str) {
+        if let Some(limiter) = self.limiter(method) {
+            limiter.record(false);
+        }
+    }
+
+    pub(super) fn on_rate_limited(&self, method: &str) {
+        if let Some(limiter) = self.limiter(method) {
+            limiter.record(true);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct ClassLimiter {
+    config: RateLimitConfig,
+    state: Mutex<LimiterState>,
+    released: Notify,
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    in_flight: usize,
+    factor: f64,
+    request_tokens: f64,
+    byte_tokens: f64,
+    refilled_at: Instant,
+}
+
+enum Admission {
+    Granted,
+    WaitForRelease,
+    WaitFor(Duration),
+}
+
+impl ClassLimiter {
+    fn new(config: RateLimitConfig) -> Self {
+        let state = LimiterState {
+            in_flight: 0,
+            factor: 1.0,
+            request_tokens: request_capacity(&config),
+            byte_tokens: config.bytes_per_second as f64,
+            refilled_at: Instant::now(),
+        };
+        Self {
+            config,
+            state: Mutex::new(state),
+            released: Notify::new(),
+        }
+    }
+
+    /// Waits until one request of `bytes` encoded bytes may be sent.
+    pub(super) async fn acquire(
+        self: Arc<Self>,
+        method: &'static str,
+        bytes: usize,
+    ) -> LimitPermit {
+        let started = Instant::now();
+        let mut queued = false;
+        loop {
+            let released = self.released.notified();
+            match self.try_admit(bytes) {
+                Admission::Granted => break,
+                Admission::WaitForRelease => released.await,
+                Admission::WaitFor(delay) => tokio::time::sleep(delay).await,
+            }
+            queued = true;
+        }
+        if queued {
+            trace_debug!(
+                target: "milvus_sdk::limits",
+                method,
+                bytes,
+                queue_ms = started.elapsed().as_millis(),
+                "Milvus RPC waited for client-side limits"
+            );
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = (method, started);
+        LimitPermit { limiter: self }
+    }
+
+    fn try_admit(&self, bytes: usize) -> Admission {
+        let mut state = self.state.lock();
+        state.refill(&self.config, Instant::now());
+        let factor = state.effective_factor(&self.config);
+
+        if self.config.max_in_flight > 0 {
+            let limit = ((self.config.max_in_flight as f64 * factor).floor() as usize).max(1);
+            if state.in_flight >= limit {
+                return Admission::WaitForRelease;
+            }
+        }
+
+        let mut delay = Duration::ZERO;
+        let request_rate = request_rate(&self.config) * factor;
+        if request_rate > 0.0 && state.request_tokens < 1.0 {
+            delay = delay.max(wait_time(1.0 - state.request_tokens, request_rate));
+        }
+        let byte_rate = self.config.bytes_per_second as f64 * factor;
+        if byte_rate > 0.0 {
+            let needed = (bytes as f64).min(self.config.bytes_per_second as f64);
+            if state.byte_tokens < needed {
+                delay = delay.max(wait_time(needed - state.byte_tokens, byte_rate));
+            }
+        }
+        if !delay.is_zero() {
+            return Admission::WaitFor(delay);
+        }
+
+        if request_rate > 0.0 {
+            state.request_tokens -= 1.0;
+        }
+        if byte_rate > 0.0 {
+            state.byte_tokens -= bytes as f64;
+        }
+        state.in_flight += 1;
+        Admission::Granted
+    }
+
+    fn record(&self, rate_limited: bool) {
+        if !self.config.adaptive {
+            return;
+        }
+        let mut state = self.state.lock();
+        state.refill(&self.config, Instant::now());
+        if rate_limited {
+            state.factor = (state.factor / 2.0).max(MIN_ADAPTIVE_FACTOR);
+            trace_debug!(
+                target: "milvus_sdk::limits",
+                factor = state.factor,
+                "Milvus rate-limit response reduced client-side limits"
+            );
+        } else if state.factor < 1.0 {
+            state.factor = (state.factor + ADDITIVE_STEP).min(1.0);
+            drop(state);
+            self.released.notify_waiters();
+        }
+    }
+
+    #[cfg(test)]
+    fn factor(&self) -> f64 {
+        self.state.lock().factor
+    }
+}
+
+impl LimiterState {
+    fn effective_factor(&self, config: &RateLimitConfig) -> f64 {
+        if config.adaptive {
+            self.factor
+        } else {
+            1.0
+        }
+    }
+
+    fn refill(&mut self, config: &RateLimitConfig, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.refilled_at)
+            .as_secs_f64();
+        self.refilled_at = now;
+        let factor = self.effective_factor(config);
+        let request_rate = request_rate(config);
+        if request_rate > 0.0 {
+            self.request_tokens = (self.request_tokens + elapsed * request_rate * factor)
+                .min(request_capacity(config));
+        }
+        if config.bytes_per_second > 0 {
+            let byte_rate = config.bytes_per_second as f64;
+            self.byte_tokens = (self.byte_tokens + elapsed * byte_rate * factor).min(byte_rate);
+        }
+    }
+}
+
+/// Releases one in-flight slot when the request completes.
+#[derive(Debug)]
+pub(super) struct LimitPermit {
+    limiter: Arc<ClassLimiter>,
+}
+
+impl Drop for LimitPermit {
+    fn drop(&mut self) {
+        let mut state = self.limiter.state.lock();
+        state.in_flight = state.in_flight.saturating_sub(1);
+        drop(state);
+        self.limiter.released.notify_one();
+    }
+}
+
+fn request_rate(config: &RateLimitConfig) -> f64 {
+    if config.requests_per_second.is_finite() && config.requests_per_second > 0.0 {
+        config.requests_per_second
+    } else {
+        0.0
+    }
+}
+
+fn request_capacity(config: &RateLimitConfig) -> f64 {
+    request_rate(config).max(1.0)
+}
+
+fn wait_time(deficit: f64, rate: f64) -> Duration {
+    Duration::try_from_secs_f64(deficit / rate)
+        .unwrap_or(MAX_WAIT_SLICE)
+        .min(MAX_WAIT_SLICE)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(config: RateLimitConfig) -> Arc<ClassLimiter> {
+        Arc::new(ClassLimiter::new(config))
+    }
+
+    #[tokio::test]
+    async fn concurrency_limit_queues_until_a_permit_is_released() {
+        let limiter = limiter(RateLimitConfig::new().max_in_flight(1));
+        let first = Arc::clone(&limiter).acquire("insert", 0).await;
+
+        let queued = tokio::time::timeout(
+            Duration::from_millis(20),
+            Arc::clone(&limiter).acquire("insert", 0),
+        )
+        .await;
+        assert!(queued.is_err());
+
+        drop(first);
+        tokio::time::timeout(Duration::from_secs(1), limiter.acquire("insert", 0))
+            .await
+            .expect("released slot admits the next request");
+    }
+
+    #[tokio::test]
+    async fn request_rate_spaces_out_requests_beyond_the_burst() {
+        let limiter = limiter(RateLimitConfig::new().requests_per_second(20.0));
+        for _ in 0..20 {
+            drop(Arc::clone(&limiter).acquire("search", 0).await);
+        }
+
+        let started = Instant::now();
+        drop(limiter.acquire("search", 0).await);
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn rate_limit_responses_shrink_and_successes_recover_the_limits() {
+        let limiter = limiter(RateLimitConfig::new().max_in_flight(8));
+        limiter.record(true);
+        limiter.record(true);
+        assert_eq!(limiter.factor(), 0.25);
+
+        limiter.record(false);
+        assert_eq!(limiter.factor(), 0.25 + ADDITIVE_STEP);
+
+        for _ in 0..64 {
+            limiter.record(false);
+        }
+        assert_eq!(limiter.factor(), 1.0);
+
+        for _ in 0..64 {
+            limiter.record(true);
+        }
+        assert_eq!(limiter.factor(), MIN_ADAPTIVE_FACTOR);
+    }
+
+    #[test]
+    fn non_adaptive_limits_ignore_rate_limit_responses() {
+        let limiter = limiter(RateLimitConfig::new().max_in_flight(8).adaptive(false));
+        limiter.record(true);
+        assert_eq!(limiter.factor(), 1.0);
+    }
+
+    #[test]
+    fn unconfigured_classes_are_not_limited() {
+        let mut configs = BTreeMap::new();
+        configs.insert(OperationClass::Dml, RateLimitConfig::new().max_in_flight(2));
+        configs.insert(OperationClass::Dql, RateLimitConfig::new());
+        let limits = ClientLimits::new(&configs);
+
+        assert!(limits.limiter("insert").is_some());
+        assert!(limits.limiter("search").is_none());
+        assert!(limits.limiter("describe_collection").is_none());
+    }
+}
@@ -27,14 +27,18 @@ impl ClientV2 {
         &self,
         request: request::partition::CreatePartitionRequest,
     ) -> Result<()> {
-        let database = self.current_database();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            create_partition,
-            request.into_proto(&database)
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                create_partition,
+                request.into_proto(&database)
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Drops a partition and its associated index and segment data.
@@ -42,14 +46,18 @@ impl ClientV2 {
         &self,
         request: request::partition::DropPartitionRequest,
     ) -> Result<()> {
-        let database = self.current_database();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            drop_partition,
-            request.into_proto(&database)
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                drop_partition,
+                request.into_proto(&database)
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Checks whether a named partition exists.
@@ -57,10 +65,14 @@ impl ClientV2 {
         &self,
         request: request::partition::HasPartitionRequest,
     ) -> Result<response::partition::HasPartitionResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, has_partition, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        Ok(response::partition::HasPartitionResponse(response.value))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(self, has_partition, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            Ok(response::partition::HasPartitionResponse(response.value))
+        })
+        .await
     }
 
     /// Lists partitions defined for a collection.
@@ -68,10 +80,14 @@ impl ClientV2 {
         &self,
         request: request::partition::ListPartitionsRequest,
     ) -> Result<response::partition::ListPartitionsResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, show_partitions, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        response::partition::ListPartitionsResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(self, show_partitions, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            response::partition::ListPartitionsResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Loads selected partitions into query-node memory.
@@ -82,33 +98,37 @@ impl ClientV2 {
         &self,
         mut request: request::partition::LoadPartitionsRequest,
     ) -> Result<()> {
-        let sync = request.sync;
-        let timeout_ms = request.timeout_ms;
-        let refresh = request.refresh;
-        let database = self.effective_database(request.database_name.as_deref());
-        request.database_name = Some(database.clone());
-        let collection = request.collection_name.clone();
-        let partition_names = request.partition_names.clone();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            load_partitions,
-            request.into_proto(&database)
-        )?;
-        self.status(status)?;
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let sync = request.sync;
+            let timeout_ms = request.timeout_ms;
+            let refresh = request.refresh;
+            let database = self.effective_database(request.database_name.as_deref());
+            request.database_name = Some(database.clone());
+            let collection = request.collection_name.clone();
+            let partition_names = request.partition_names.clone();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                load_partitions,
+                request.into_proto(&database)
+            )?;
+            self.status(status)?;
 
-        if !sync {
-            return Ok(());
-        }
+            if !sync {
+                return Ok(());
+            }
 
-        self.wait_for_collection_loading(
-            &database,
-            &collection,
-            &partition_names,
-            refresh,
-            timeout_ms,
-            "load partitions timed out",
-        )
+            self.wait_for_collection_loading(
+                &database,
+                &collection,
+                &partition_names,
+                refresh,
+                timeout_ms,
+                "load partitions timed out",
+            )
+            .await
+        })
         .await
     }
 
@@ -117,14 +137,18 @@ impl ClientV2 {
         &self,
         request: request::partition::ReleasePartitionsRequest,
     ) -> Result<()> {
-        let database = self.current_database();
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            release_partitions,
-            request.into_proto(&database)
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                release_partitions,
+                request.into_proto(&database)
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Returns server-reported statistics for a partition, including its row count.
@@ -132,15 +156,19 @@ impl ClientV2 {
         &self,
         request: request::partition::GetPartitionStatsRequest,
     ) -> Result<response::partition::GetPartitionStatsResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(
-            self,
-            get_partition_statistics,
-            request.into_proto(&database)
-        )?;
-        status_to_result(&response.status)?;
-        Ok(response::partition::GetPartitionStatsResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(
+                self,
+                get_partition_statistics,
+                request.into_proto(&database)
+            )?;
+            status_to_result(&response.status)?;
+            Ok(response::partition::GetPartitionStatsResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 }
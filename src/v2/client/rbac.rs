@@ -28,9 +28,17 @@ impl ClientV2 {
     /// management calls with authorization disabled; enabling authorization is required when the
     /// deployment must enforce the resulting permissions for subsequent operations.
     pub async fn create_user(&self, request: request::rbac::CreateUserRequest) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, create_credential, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                create_credential,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Changes an existing user's password.
@@ -38,23 +46,47 @@ impl ClientV2 {
         &self,
         request: request::rbac::UpdatePasswordRequest,
     ) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, update_credential, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                update_credential,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Updates mutable properties of a user account.
     pub async fn update_user(&self, request: request::rbac::UpdateUserRequest) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, update_credential, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                update_credential,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Drops a user account.
     pub async fn drop_user(&self, request: request::rbac::DropUserRequest) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, delete_credential, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                delete_credential,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Lists user accounts visible to the caller.
@@ -62,9 +94,13 @@ impl ClientV2 {
         &self,
         request: request::rbac::ListUsersRequest,
     ) -> Result<response::rbac::ListUsersResponse> {
-        let response = rpc_with_retry!(self, list_cred_users, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::rbac::ListUsersResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, list_cred_users, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::rbac::ListUsersResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Lists roles visible to the caller.
@@ -72,42 +108,76 @@ impl ClientV2 {
         &self,
         request: request::rbac::ListRolesRequest,
     ) -> Result<response::rbac::ListRolesResponse> {
-        let response = rpc_with_retry!(self, select_role, request.into_proto())?;
-        status_to_result(&response.status)?;
-        response::rbac::ListRolesResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, select_role, request.into_proto())?;
+            status_to_result(&response.status)?;
+            response::rbac::ListRolesResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Creates a role that can receive privileges.
     pub async fn create_role(&self, request: request::rbac::CreateRoleRequest) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, create_role, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status =
+                status_rpc_with_retry!(NonIdempotent, self, create_role, request.into_proto())?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Updates a role's mutable properties.
     pub async fn alter_role(&self, request: request::rbac::AlterRoleRequest) -> Result<()> {
-        let status = status_rpc_with_retry!(NonIdempotent, self, alter_role, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status =
+                status_rpc_with_retry!(NonIdempotent, self, alter_role, request.into_proto())?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Drops a role.
     pub async fn drop_role(&self, request: request::rbac::DropRoleRequest) -> Result<()> {
-        let status = status_rpc_with_retry!(NonIdempotent, self, drop_role, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status =
+                status_rpc_with_retry!(NonIdempotent, self, drop_role, request.into_proto())?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Assigns a role to a user.
     pub async fn grant_role(&self, request: request::rbac::GrantRoleRequest) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, operate_user_role, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                operate_user_role,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Removes a role assignment from a user.
     pub async fn revoke_role(&self, request: request::rbac::RevokeRoleRequest) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, operate_user_role, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                operate_user_role,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Retrieves a role and its assignments.
@@ -115,12 +185,20 @@ impl ClientV2 {
         &self,
         request: request::rbac::DescribeRoleRequest,
     ) -> Result<response::rbac::DescribeRoleResponse> {
-        let (role_name, grant_request, role_request) = request.into_proto();
-        let grant_response = rpc_with_retry!(self, select_grant, grant_request)?;
-        status_to_result(&grant_response.status)?;
-        let role_response = rpc_with_retry!(self, select_role, role_request)?;
-        status_to_result(&role_response.status)?;
-        response::rbac::DescribeRoleResponse::from_proto(role_name, grant_response, role_response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let (role_name, grant_request, role_request) = request.into_proto();
+            let grant_response = rpc_with_retry!(self, select_grant, grant_request)?;
+            status_to_result(&grant_response.status)?;
+            let role_response = rpc_with_retry!(self, select_role, role_request)?;
+            status_to_result(&role_response.status)?;
+            response::rbac::DescribeRoleResponse::from_proto(
+                role_name,
+                grant_response,
+                role_response,
+            )
+        })
+        .await
     }
 
     /// Retrieves a user and its role assignments.
@@ -128,9 +206,13 @@ impl ClientV2 {
         &self,
         request: request::rbac::DescribeUserRequest,
     ) -> Result<response::rbac::DescribeUserResponse> {
-        let response = rpc_with_retry!(self, select_user, request.into_proto())?;
-        status_to_result(&response.status)?;
-        response::rbac::DescribeUserResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, select_user, request.into_proto())?;
+            status_to_result(&response.status)?;
+            response::rbac::DescribeUserResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Grants a privilege on a resource to a role.
@@ -138,13 +220,17 @@ impl ClientV2 {
         &self,
         request: request::rbac::GrantPrivilegeRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            operate_privilege_v2,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                operate_privilege_v2,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Revokes a privilege from a role.
@@ -152,13 +238,17 @@ impl ClientV2 {
         &self,
         request: request::rbac::RevokePrivilegeRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            operate_privilege_v2,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                operate_privilege_v2,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Creates a named group of privileges.
@@ -166,13 +256,17 @@ impl ClientV2 {
         &self,
         request: request::rbac::CreatePrivilegeGroupRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            create_privilege_group,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                create_privilege_group,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Drops a privilege group.
@@ -180,13 +274,17 @@ impl ClientV2 {
         &self,
         request: request::rbac::DropPrivilegeGroupRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            drop_privilege_group,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                drop_privilege_group,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Lists privilege groups visible to the caller.
@@ -194,11 +292,15 @@ impl ClientV2 {
         &self,
         request: request::rbac::ListPrivilegeGroupsRequest,
     ) -> Result<response::rbac::ListPrivilegeGroupsResponse> {
-        let response = rpc_with_retry!(self, list_privilege_groups, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::rbac::ListPrivilegeGroupsResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, list_privilege_groups, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::rbac::ListPrivilegeGroupsResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Adds privileges to a privilege group.
@@ -206,13 +308,17 @@ impl ClientV2 {
         &self,
         request: request::rbac::AddPrivilegesToGroupRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            operate_privilege_group,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                operate_privilege_group,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Removes privileges from a privilege group.
@@ -220,12 +326,16 @@ impl ClientV2 {
         &self,
         request: request::rbac::RemovePrivilegesFromGroupRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            operate_privilege_group,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                operate_privilege_group,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 }
@@ -27,13 +27,17 @@ impl ClientV2 {
         &self,
         request: request::resource_group::CreateResourceGroupRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            create_resource_group,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                create_resource_group,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Drops a resource group after its resources are no longer assigned to it.
@@ -41,9 +45,17 @@ impl ClientV2 {
         &self,
         request: request::resource_group::DropResourceGroupRequest,
     ) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(Idempotent, self, drop_resource_group, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                drop_resource_group,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Updates the properties or node allocation of resource groups.
@@ -51,13 +63,17 @@ impl ClientV2 {
         &self,
         request: request::resource_group::UpdateResourceGroupsRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            Idempotent,
-            self,
-            update_resource_groups,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                Idempotent,
+                self,
+                update_resource_groups,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Transfers query nodes between resource groups.
@@ -65,9 +81,13 @@ impl ClientV2 {
         &self,
         request: request::resource_group::TransferNodeRequest,
     ) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, transfer_node, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status =
+                status_rpc_with_retry!(NonIdempotent, self, transfer_node, request.into_proto())?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Transfers collection replicas from one resource group to another.
@@ -75,9 +95,17 @@ impl ClientV2 {
         &self,
         request: request::resource_group::TransferReplicaRequest,
     ) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, transfer_replica, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                transfer_replica,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Lists resource groups visible in the current database.
@@ -85,9 +113,13 @@ impl ClientV2 {
         &self,
         request: request::resource_group::ListResourceGroupsRequest,
     ) -> Result<response::resource_group::ListResourceGroupsResponse> {
-        let response = rpc_with_retry!(self, list_resource_groups, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::resource_group::ListResourceGroupsResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, list_resource_groups, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::resource_group::ListResourceGroupsResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Retrieves a resource group's node and replica assignments.
@@ -95,8 +127,12 @@ impl ClientV2 {
         &self,
         request: request::resource_group::DescribeResourceGroupRequest,
     ) -> Result<response::resource_group::DescribeResourceGroupResponse> {
-        let response = rpc_with_retry!(self, describe_resource_group, request.into_proto())?;
-        status_to_result(&response.status)?;
-        response::resource_group::DescribeResourceGroupResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, describe_resource_group, request.into_proto())?;
+            status_to_result(&response.status)?;
+            response::resource_group::DescribeResourceGroupResponse::from_proto(response)
+        })
+        .await
     }
 }
@@ -0,0 +1,478 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional cache of search and query responses for repeated stale-tolerant reads.
+//!
+//! Entries are keyed by the normalized protobuf request, so two requests hit the same entry when
+//! they target the same collection with the same vectors, filter, parameters, and output fields.
+//! Query vectors enter the key as a fixed-size digest, and each entry also keeps the vectors
+//! themselves, which a hit must match byte for byte so that colliding digests never share a
+//! response. The cache holds at most `max_entries` responses and `max_bytes` of encoded
+//! responses, keys, and vectors.
+//! Each entry records the collection's DML timestamp from the shared timestamp cache when its
+//! read was sent; once DML moves that timestamp, or the entry outlives its TTL, it is dropped
+//! on the next lookup.
+
+use super::cache::COLLECTION_TS_CACHE;
+use super::internal::canonical_collection_name;
+use super::ClientV2;
+use crate::proto::milvus;
+use crate::v2::error::Result;
+use crate::v2::types::{ConsistencyLevel, ResultCacheConfig, ResultCacheMetrics};
+use lru::LruCache;
+use parking_lot::Mutex;
+use prost::Message;
+use std::any::Any;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+impl ClientV2 {
+    /// Returns result-cache counters shared by this client and its clones, or `None` when the
+    /// result cache is not enabled.
+    pub fn result_cache_metrics(&self) -> Option<ResultCacheMetrics> {
+        self.result_cache.as_ref().map(|cache| cache.metrics())
+    }
+
+    /// Drops every cached search and query response.
+    pub fn clear_result_cache(&self) {
+        if let Some(cache) = &self.result_cache {
+            cache.clear();
+        }
+    }
+
+    /// Returns the cached response for `raw`, or runs `call` and caches its response when the
+    /// read is cacheable.
+    pub(super) async fn cached_read<Req, Resp, Call, CallFuture>(
+        &self,
+        consistency: Option<ConsistencyLevel>,
+        database: &str,
+        collection: &str,
+        raw: Req,
+        call: Call,
+    ) -> Result<Resp>
+    where
+        Req: CacheableRequest,
+        Resp: Message + Clone + Send + Sync + 'static,
+        Call: FnOnce(Req) -> CallFuture,
+        CallFuture: Future<Output = Result<Resp>>,
+    {
+        let cache = match &self.result_cache {
+            Some(cache)
+                if matches!(
+                    consistency,
+                    Some(ConsistencyLevel::Bounded | ConsistencyLevel::Eventually)
+                ) =>
+            {
+                cache
+            }
+            _ => return call(raw).await,
+        };
+        let description = self
+            .get_collection_description(database, collection)
+            .await?;
+        let collection = canonical_collection_name(&description, collection);
+        let dml_timestamp = COLLECTION_TS_CACHE.get(&self.cache_endpoint, database, &collection);
+        let key = raw.cache_key();
+        if let Some(response) = cache.get::<Resp>(&key, dml_timestamp) {
+            trace_debug!(target: "milvus_sdk::result_cache", database, collection = %collection, "result cache hit");
+            return Ok(response);
+        }
+        let response = call(raw).await?;
+        cache.put(key, response.clone(), dml_timestamp);
+        Ok(response)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// CacheableRequest
+///////////////////////////////////////////////////////////////////////////////
+/// A read request whose response may be served from the result cache.
+pub(super) trait CacheableRequest {
+    /// Returns a key that is equal for requests the server answers identically.
+    fn cache_key(&self) -> ResultCacheKey;
+}
+
+/// Result-cache key: the normalized request with a digest of its query vectors, which locates
+/// the entry, and the query vectors, which a hit must match exactly.
+pub(super) struct ResultCacheKey {
+    pub(super) request: Vec<u8>,
+    vectors: Vec<u8>,
+}
+
+impl CacheableRequest for milvus::SearchRequest {
+    fn cache_key(&self) -> ResultCacheKey {
+        let mut request = self.clone();
+        request.base = None;
+        request
+            .search_params
+            .sort_by(|left, right| left.key.cmp(&right.key));
+        let templates = std::mem::take(&mut request.expr_template_values);
+        let vectors = std::mem::take(&mut request.placeholder_group);
+        ResultCacheKey {
+            request: normalized_key("search", &request, templates, &vectors),
+            vectors,
+        }
+    }
+}
+
+impl CacheableRequest for milvus::QueryRequest {
+    fn cache_key(&self) -> ResultCacheKey {
+        let mut request = self.clone();
+        request.base = None;
+        request
+            .query_params
+            .sort_by(|left, right| left.key.cmp(&right.key));
+        let templates = std::mem::take(&mut request.expr_template_values);
+        ResultCacheKey {
+            request: normalized_key("query", &request, templates, &[]),
+            vectors: Vec::new(),
+        }
+    }
+}
+
+/// Encodes the digest of the query vectors and the request followed by its filter templates in key
+/// order, since protobuf maps encode in iteration order.
+fn normalized_key<M: Message, V: Message>(
+    method: &str,
+    request: &M,
+    templates: impl IntoIterator<Item = (String, V)>,
+    vectors: &[u8],
+) -> Vec<u8> {
+    let mut templates = templates.into_iter().collect::<Vec<_>>();
+    templates.sort_by(|left, right| left.0.cmp(&right.0));
+    let mut key = method.as_bytes().to_vec();
+    key.push(0);
+    key.extend(vectors_digest(vectors));
+    key.extend(request.encode_length_delimited_to_vec());
+    for (name, value) in templates {
+        key.extend(name.len().to_le_bytes());
+        key.extend(name.as_bytes());
+        key.extend(value.encode_length_delimited_to_vec());
+    }
+    key
+}
+
+/// Digests a placeholder group with 128-bit FNV-1a, so large or many query vectors do not grow
+/// the key. The digest only locates an entry; hits also compare the full vectors.
+fn vectors_digest(vectors: &[u8]) -> [u8; 16] {
+    let hash = vectors.iter().fold(
+        0x6c62_272e_07bb_0142_62b8_2175_6295_c58d_u128,
+        |hash, byte| {
+            (hash ^ u128::from(*byte)).wrapping_mul(0x0000_0000_0100_0000_0000_0000_0000_013b)
+        },
+    );
+    hash.to_le_bytes()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ResultCache
+///////////////////////////////////////////////////////////////////////////////
+pub(super) struct ResultCache {
+    config: ResultCacheConfig,
+    entries: Mutex<Entries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+struct Entries {
+    lru: LruCache<Vec<u8>, Entry>,
+    /// Sum of the sizes of the cached entries.
+    bytes: usize,
+}
+
+struct Entry {
+    response: Arc<dyn Any + Send + Sync>,
+    /// Query vectors of the cached read, compared on every hit.
+    vectors: Vec<u8>,
+    dml_timestamp: Option<u64>,
+    cached_at: Instant,
+    /// Key, vectors, and encoded response lengths.
+    size: usize,
+}
+
+impl Entries {
+    fn pop(&mut self, key: &[u8]) {
+        if let Some(entry) = self.lru.pop(key) {
+            self.bytes -= entry.size;
+        }
+    }
+}
+
+impl ResultCache {
+    /// Creates a cache for `config`, or `None` when it allows no entries or bytes.
+    pub(super) fn new(config: ResultCacheConfig) -> Option<Self> {
+        let capacity = NonZeroUsize::new(config.max_entries)?;
+        if config.max_bytes == 0 {
+            return None;
+        }
+        Some(Self {
+            config,
+            entries: Mutex::new(Entries {
+                lru: LruCache::new(capacity),
+                bytes: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        })
+    }
+
+    /// Returns the live entry for `key`, dropping it when it expired or DML has since touched its
+    /// collection. An entry cached for different query vectors is a miss.
+    fn get<Resp: Clone + 'static>(
+        &self,
+        key: &ResultCacheKey,
+        dml_timestamp: Option<u64>,
+    ) -> Option<Resp> {
+        let mut entries = self.entries.lock();
+        let live = entries.lru.get(&key.request).is_some_and(|entry| {
+            entry.dml_timestamp == dml_timestamp && entry.cached_at.elapsed() < self.config.ttl
+        });
+        let response = if live {
+            entries
+                .lru
+                .get(&key.request)
+                .filter(|entry| entry.vectors == key.vectors)
+                .and_then(|entry| entry.response.downcast_ref::<Resp>())
+                .cloned()
+        } else {
+            entries.pop(&key.request);
+            None
+        };
+        let counter = if response.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        response
+    }
+
+    /// Caches `response`, evicting least recently used entries until the cache fits its byte
+    /// limit. A response that alone exceeds the limit is not cached.
+    fn put<Resp: Message + Send + Sync + 'static>(
+        &self,
+        key: ResultCacheKey,
+        response: Resp,
+        dml_timestamp: Option<u64>,
+    ) {
+        let size = key
+            .request
+            .len()
+            .saturating_add(key.vectors.len())
+            .saturating_add(response.encoded_len());
+        let mut entries = self.entries.lock();
+        if size > self.config.max_bytes {
+            entries.pop(&key.request);
+            return;
+        }
+        let entry = Entry {
+            response: Arc::new(response),
+            vectors: key.vectors,
+            dml_timestamp,
+            cached_at: Instant::now(),
+            size,
+        };
+        if let Some((_, removed)) = entries.lru.push(key.request, entry) {
+            entries.bytes -= removed.size;
+        }
+        entries.bytes += size;
+        while entries.bytes > self.config.max_bytes {
+            match entries.lru.pop_lru() {
+                Some((_, evicted)) => entries.bytes -= evicted.size,
+                None => break,
+            }
+        }
+    }
+
+    fn clear(&self) {
+        let mut entries = self.entries.lock();
+        entries.lru.clear();
+        entries.bytes = 0;
+    }
+
+    fn metrics(&self) -> ResultCacheMetrics {
+        let entries = self.entries.lock();
+        ResultCacheMetrics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: entries.lru.len(),
+            bytes: entries.bytes,
+        }
+    }
+}
+
+impl std::fmt::Debug for ResultCache {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("ResultCache")
+            .field("config", &self.config)
+            .field("metrics", &self.metrics())
+            .finish()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{CacheableRequest, ResultCache, ResultCacheKey};
+    use crate::proto::{common, milvus, schema};
+    use crate::v2::types::ResultCacheConfig;
+    use prost::Message;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    #[test]
+    fn search_keys_ignore_parameter_and_template_order() {
+        let pair = |key: &str, value: &str| common::KeyValuePair {
+            key: key.into(),
+            value: value.into(),
+        };
+        let template = |value: i64| schema::TemplateValue {
+            val: Some(schema::template_value::Val::Int64Val(value)),
+        };
+        let request = milvus::SearchRequest {
+            collection_name: "books".into(),
+            dsl: "year > {low} and year < {high}".into(),
+            search_params: vec![pair("topk", "10"), pair("metric_type", "L2")],
+            expr_template_values: HashMap::from([
+                ("low".to_owned(), template(2000)),
+                ("high".to_owned(), template(2010)),
+            ]),
+            ..Default::default()
+        };
+        let mut reordered = request.clone();
+        reordered.search_params.reverse();
+        reordered.base = Some(common::MsgBase {
+            msg_id: 7,
+            ..Default::default()
+        });
+        assert_eq!(request.cache_key(), reordered.cache_key());
+
+        let mut other = request.clone();
+        other
+            .expr_template_values
+            .insert("high".to_owned(), template(2020));
+        assert_ne!(request.cache_key(), other.cache_key());
+    }
+
+    #[test]
+    fn search_keys_digest_query_vectors() {
+        let request = |vectors: Vec<u8>| milvus::SearchRequest {
+            collection_name: "books".into(),
+            placeholder_group: vectors,
+            ..Default::default()
+        };
+        let small = request(vec![1; 16]).cache_key().request;
+        let large = request(vec![1; 1 << 20]).cache_key().request;
+
+        assert_eq!(small.len(), large.len());
+        assert_ne!(small, large);
+        assert_ne!(large, request(vec![2; 1 << 20]).cache_key().request);
+    }
+
+    #[test]
+    fn different_vectors_never_share_an_entry() {
+        let search = |vectors: Vec<u8>| milvus::SearchRequest {
+            collection_name: "books".into(),
+            placeholder_group: vectors,
+            ..Default::default()
+        };
+        let cache = ResultCache::new(ResultCacheConfig::new()).expect("enabled cache");
+        let cached = search(vec![1, 2, 3]).cache_key();
+        // Simulates a digest collision: same request bytes, different query vectors.
+        let colliding = ResultCacheKey {
+            request: cached.request.clone(),
+            vectors: vec![4, 5, 6],
+        };
+        cache.put(cached, response("books"), None);
+
+        assert_eq!(cache.get::<milvus::QueryResults>(&colliding, None), None);
+        assert_eq!(
+            cache.get(&search(vec![1, 2, 3]).cache_key(), None),
+            Some(response("books"))
+        );
+    }
+
+    fn key(request: &[u8]) -> ResultCacheKey {
+        ResultCacheKey {
+            request: request.to_vec(),
+            vectors: Vec::new(),
+        }
+    }
+
+    fn response(collection: &str) -> milvus::QueryResults {
+        milvus::QueryResults {
+            collection_name: collection.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn entries_expire_and_follow_dml_timestamps() {
+        let cache = ResultCache::new(ResultCacheConfig::new().ttl(Duration::from_secs(60)))
+            .expect("enabled cache");
+        cache.put(key(b"key"), response("books"), Some(10));
+        assert_eq!(cache.get(&key(b"key"), Some(10)), Some(response("books")));
+        assert_eq!(
+            cache.get::<milvus::QueryResults>(&key(b"key"), Some(11)),
+            None
+        );
+        assert_eq!(
+            cache.get::<milvus::QueryResults>(&key(b"key"), Some(10)),
+            None
+        );
+
+        let expired =
+            ResultCache::new(ResultCacheConfig::new().ttl(Duration::ZERO)).expect("enabled cache");
+        expired.put(key(b"key"), response("books"), None);
+        assert_eq!(
+            expired.get::<milvus::QueryResults>(&key(b"key"), None),
+            None
+        );
+
+        let metrics = cache.metrics();
+        assert_eq!((metrics.get_hits(), metrics.get_misses()), (1, 2));
+        assert_eq!((metrics.get_entries(), metrics.get_bytes()), (0, 0));
+        assert!(ResultCache::new(ResultCacheConfig::new().max_entries(0)).is_none());
+        assert!(ResultCache::new(ResultCacheConfig::new().max_bytes(0)).is_none());
+    }
+
+    #[test]
+    fn entries_are_evicted_to_fit_the_byte_limit() {
+        let size = b"a".len() + response("books").encoded_len();
+        let cache =
+            ResultCache::new(ResultCacheConfig::new().max_bytes(2 * size)).expect("enabled cache");
+        cache.put(key(b"a"), response("books"), None);
+        cache.put(key(b"b"), response("books"), None);
+        cache.put(key(b"c"), response("books"), None);
+
+        assert_eq!(cache.get::<milvus::QueryResults>(&key(b"a"), None), None);
+        assert!(cache
+            .get::<milvus::QueryResults>(&key(b"c"), None)
+            .is_some());
+        assert_eq!(cache.metrics().get_bytes(), 2 * size);
+
+        cache.put(key(b"d"), response(&"x".repeat(3 * size)), None);
+        assert_eq!(cache.get::<milvus::QueryResults>(&key(b"d"), None), None);
+        assert_eq!(cache.metrics().get_entries(), 2);
+    }
+}
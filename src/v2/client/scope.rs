@@ -0,0 +1,196 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-call deadline, cancellation and retry scope for ClientV2 operations.
+//!
+//! Public operations run inside a task-local scope built from their request options. Nested
+//! operations inherit the enclosing deadline and cancellation tokens, so an internal
+//! `describe_collection` issued by a search cannot outlive the search itself, and a per-request
+//! retry policy also governs the RPCs issued on that request's behalf.
+
+use super::ClientV2;
+use crate::v2::error::{Error, Result};
+use crate::v2::request::options::{CancellationToken, RequestOptions};
+use crate::v2::retry::RetryPolicy;
+use futures::future::{self, Either};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+tokio::task_local! {
+    static CALL_SCOPE: CallScope;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// CallScope
+///////////////////////////////////////////////////////////////////////////////
+#[derive(Debug, Clone, Default)]
+pub(super) struct CallScope {
+    deadline: Option<Instant>,
+    tokens: Vec<CancellationToken>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+}
+
+impl CallScope {
+    /// Returns the scope of the enclosing operation, or an unbounded scope outside any operation.
+    pub(super) fn current() -> Self {
+        CALL_SCOPE.try_with(Clone::clone).unwrap_or_default()
+    }
+
+    fn with_options(mut self, options: &RequestOptions, now: Instant) -> Self {
+        if let Some(deadline) = options
+            .timeout
+            .filter(|timeout| !timeout.is_zero())
+            .and_then(|timeout| now.checked_add(timeout))
+        {
+            self.deadline = Some(self.deadline.map_or(deadline, |outer| outer.min(deadline)));
+        }
+        if let Some(token) = &options.cancellation_token {
+            self.tokens.push(token.clone());
+        }
+        if let Some(policy) = &options.retry_policy {
+            self.retry_policy = Some(Arc::clone(policy));
+        }
+        self
+    }
+
+    /// Returns the retry policy of the innermost operation that set one.
+    pub(super) fn retry_policy(&self) -> Option<Arc<dyn RetryPolicy>> {
+        self.retry_policy.clone()
+    }
+
+    /// Returns the time left before the call deadline, if the call has one.
+    pub(super) fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Fails when the call has been cancelled or its deadline has passed.
+    pub(super) fn check(&self) -> Result<()> {
+        if self.tokens.iter().any(CancellationToken::is_cancelled) {
+            return Err(cancelled());
+        }
+        if self
+            .remaining()
+            .is_some_and(|remaining| remaining.is_zero())
+        {
+            return Err(deadline_exceeded());
+        }
+        Ok(())
+    }
+
+    async fn cancelled(&self) {
+        if self.tokens.is_empty() {
+            return future::pending().await;
+        }
+        future::select_all(self.tokens.iter().map(|token| Box::pin(token.cancelled()))).await;
+    }
+
+    async fn expired(&self) {
+        match self.deadline {
+            Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+            None => future::pending().await,
+        }
+    }
+}
+
+impl ClientV2 {
+    /// Runs one public operation with the deadline and cancellation token of its request.
+    ///
+    /// Dropping the operation future aborts in-flight RPC attempts, retry backoff, and polling.
+    pub(super) async fn scoped<T, F>(&self, options: &RequestOptions, operation: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        if options.is_empty() {
+            return operation.await;
+        }
+        let scope = CallScope::current().with_options(options, Instant::now());
+        scope.check()?;
+        let operation = std::pin::pin!(CALL_SCOPE.scope(scope.clone(), operation));
+        let interrupted = std::pin::pin!(async {
+            let cancelled = std::pin::pin!(scope.cancelled());
+            let expired = std::pin::pin!(scope.expired());
+            match future::select(cancelled, expired).await {
+                Either::Left(_) => cancelled(),
+                Either::Right(_) => deadline_exceeded(),
+            }
+        });
+        match future::select(operation, interrupted).await {
+            Either::Left((result, _)) => result,
+            Either::Right((error, _)) => {
+                trace_debug!(target: "milvus_sdk::scope", error = %error, "Milvus call interrupted");
+                Err(error)
+            }
+        }
+    }
+}
+
+fn cancelled() -> Error {
+    Error::Cancelled("the request cancellation token was triggered".into())
+}
+
+fn deadline_exceeded() -> Error {
+    Error::Timeout("the request timeout elapsed".into())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(timeout: Option<Duration>, token: Option<CancellationToken>) -> RequestOptions {
+        let mut options = RequestOptions::new();
+        options.timeout = timeout;
+        options.cancellation_token = token;
+        options
+    }
+
+    #[test]
+    fn nested_scopes_keep_the_earliest_deadline_and_every_token() {
+        let now = Instant::now();
+        let outer = CallScope::default().with_options(
+            &options(Some(Duration::from_secs(1)), Some(CancellationToken::new())),
+            now,
+        );
+        let inner = outer.clone().with_options(
+            &options(Some(Duration::from_secs(5)), Some(CancellationToken::new())),
+            now,
+        );
+
+        assert_eq!(inner.deadline, Some(now + Duration::from_secs(1)));
+        assert_eq!(inner.tokens.len(), 2);
+
+        outer.tokens[0].cancel();
+        assert!(matches!(inner.check(), Err(Error::Cancelled(_))));
+    }
+
+    #[tokio::test]
+    async fn scope_is_visible_to_nested_operations() {
+        let scope = CallScope::default().with_options(
+            &options(Some(Duration::from_secs(30)), None),
+            Instant::now(),
+        );
+        let remaining = CALL_SCOPE
+            .scope(scope, async { CallScope::current().remaining() })
+            .await;
+        assert!(remaining.is_some_and(|remaining| remaining <= Duration::from_secs(30)));
+        assert!(CallScope::current().remaining().is_none());
+    }
+}
@@ -30,14 +30,18 @@ impl ClientV2 {
         &self,
         request: request::snapshot::CreateSnapshotRequest,
     ) -> Result<()> {
-        let database = self.current_database();
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            create_snapshot,
-            request.into_proto(&database)
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                create_snapshot,
+                request.into_proto(&database)
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Drops a snapshot of a collection.
@@ -45,14 +49,18 @@ impl ClientV2 {
         &self,
         request: request::snapshot::DropSnapshotRequest,
     ) -> Result<()> {
-        let database = self.current_database();
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            drop_snapshot,
-            request.into_proto(&database)
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                drop_snapshot,
+                request.into_proto(&database)
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Lists snapshot names for a collection, or for the whole database when the
@@ -61,12 +69,16 @@ impl ClientV2 {
         &self,
         request: request::snapshot::ListSnapshotsRequest,
     ) -> Result<response::snapshot::ListSnapshotsResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, list_snapshots, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        Ok(response::snapshot::ListSnapshotsResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(self, list_snapshots, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            Ok(response::snapshot::ListSnapshotsResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Describes a snapshot of a collection.
@@ -74,12 +86,16 @@ impl ClientV2 {
         &self,
         request: request::snapshot::DescribeSnapshotRequest,
     ) -> Result<response::snapshot::DescribeSnapshotResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, describe_snapshot, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        Ok(response::snapshot::DescribeSnapshotResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(self, describe_snapshot, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            Ok(response::snapshot::DescribeSnapshotResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Restores a snapshot to a new collection.
@@ -90,17 +106,21 @@ impl ClientV2 {
         &self,
         request: request::snapshot::RestoreSnapshotRequest,
     ) -> Result<response::snapshot::RestoreSnapshotResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(
-            NonIdempotent,
-            self,
-            restore_snapshot,
-            request.into_proto(&database)
-        )?;
-        status_to_result(&response.status)?;
-        Ok(response::snapshot::RestoreSnapshotResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(
+                NonIdempotent,
+                self,
+                restore_snapshot,
+                request.into_proto(&database)
+            )?;
+            status_to_result(&response.status)?;
+            Ok(response::snapshot::RestoreSnapshotResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Retrieves the state and progress of a restore-snapshot job.
@@ -108,9 +128,13 @@ impl ClientV2 {
         &self,
         request: request::snapshot::GetRestoreSnapshotStateRequest,
     ) -> Result<response::snapshot::GetRestoreSnapshotStateResponse> {
-        let response = rpc_with_retry!(self, get_restore_snapshot_state, request.into_proto())?;
-        status_to_result(&response.status)?;
-        response::snapshot::GetRestoreSnapshotStateResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, get_restore_snapshot_state, request.into_proto())?;
+            status_to_result(&response.status)?;
+            response::snapshot::GetRestoreSnapshotStateResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Lists restore-snapshot jobs for a collection, or for the whole database
@@ -119,14 +143,18 @@ impl ClientV2 {
         &self,
         request: request::snapshot::ListRestoreSnapshotJobsRequest,
     ) -> Result<response::snapshot::ListRestoreSnapshotJobsResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(
-            self,
-            list_restore_snapshot_jobs,
-            request.into_proto(&database)
-        )?;
-        status_to_result(&response.status)?;
-        Ok(response::snapshot::ListRestoreSnapshotJobsResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(
+                self,
+                list_restore_snapshot_jobs,
+                request.into_proto(&database)
+            )?;
+            status_to_result(&response.status)?;
+            Ok(response::snapshot::ListRestoreSnapshotJobsResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Pins the data referenced by a snapshot so it is not reclaimed.
@@ -138,17 +166,21 @@ impl ClientV2 {
         &self,
         request: request::snapshot::PinSnapshotDataRequest,
     ) -> Result<response::snapshot::PinSnapshotDataResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(
-            NonIdempotent,
-            self,
-            pin_snapshot_data,
-            request.into_proto(&database)
-        )?;
-        status_to_result(&response.status)?;
-        Ok(response::snapshot::PinSnapshotDataResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(
+                NonIdempotent,
+                self,
+                pin_snapshot_data,
+                request.into_proto(&database)
+            )?;
+            status_to_result(&response.status)?;
+            Ok(response::snapshot::PinSnapshotDataResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Releases a snapshot data pin created by [`ClientV2::pin_snapshot_data`].
@@ -156,12 +188,16 @@ impl ClientV2 {
         &self,
         request: request::snapshot::UnpinSnapshotDataRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            unpin_snapshot_data,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                unpin_snapshot_data,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 }
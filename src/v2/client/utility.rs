@@ -160,15 +160,19 @@ impl ClientV2 {
         &self,
         request: request::utility::GetServerVersionRequest,
     ) -> Result<response::utility::GetServerVersionResponse> {
-        if request.is_detail_enabled() {
-            let response = rpc_with_retry!(self, connect, request.into_connect_proto())?;
-            status_to_result(&response.status)?;
-            return response::utility::GetServerVersionResponse::from_connect_proto(response);
-        }
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            if request.is_detail_enabled() {
+                let response = rpc_with_retry!(self, connect, request.into_connect_proto())?;
+                status_to_result(&response.status)?;
+                return response::utility::GetServerVersionResponse::from_connect_proto(response);
+            }
 
-        let response = rpc_with_retry!(self, get_version, request.into_get_version_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::GetServerVersionResponse::from_version_proto(response))
+            let response = rpc_with_retry!(self, get_version, request.into_get_version_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::GetServerVersionResponse::from_version_proto(response))
+        })
+        .await
     }
 
     /// Checks whether the connected Milvus server is healthy.
@@ -176,9 +180,13 @@ impl ClientV2 {
         &self,
         request: request::utility::CheckHealthRequest,
     ) -> Result<response::utility::CheckHealthResponse> {
-        let response = rpc_with_retry!(self, check_health, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::CheckHealthResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, check_health, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::CheckHealthResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Flushes pending insert data for a collection into durable storage.
@@ -186,14 +194,18 @@ impl ClientV2 {
         &self,
         mut request: request::utility::FlushRequest,
     ) -> Result<response::utility::FlushResponse> {
-        let database = self.effective_database(request.database_name.as_deref());
-        request.database_name = Some(database.clone());
-        let wait_flushed_ms = request.wait_flushed_ms;
-        let response = rpc_with_retry!(self, flush, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        self.wait_for_flush(&database, &response, wait_flushed_ms)
-            .await?;
-        Ok(response::utility::FlushResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            request.database_name = Some(database.clone());
+            let wait_flushed_ms = request.wait_flushed_ms;
+            let response = rpc_with_retry!(self, flush, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            self.wait_for_flush(&database, &response, wait_flushed_ms)
+                .await?;
+            Ok(response::utility::FlushResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Flushes pending insert data for all collections into durable storage.
@@ -204,14 +216,18 @@ impl ClientV2 {
         &self,
         mut request: request::utility::FlushAllRequest,
     ) -> Result<response::utility::FlushAllResponse> {
-        let database = self.effective_database(request.database_name.as_deref());
-        request.database_name = Some(database.clone());
-        let wait_flushed_ms = request.wait_flushed_ms;
-        let response = rpc_with_retry!(self, flush_all, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        self.wait_for_flush_all(&database, response.flush_all_ts, wait_flushed_ms)
-            .await?;
-        Ok(response::utility::FlushAllResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            request.database_name = Some(database.clone());
+            let wait_flushed_ms = request.wait_flushed_ms;
+            let response = rpc_with_retry!(self, flush_all, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            self.wait_for_flush_all(&database, response.flush_all_ts, wait_flushed_ms)
+                .await?;
+            Ok(response::utility::FlushAllResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Retrieves the state of an earlier flush-all operation.
@@ -219,12 +235,17 @@ impl ClientV2 {
         &self,
         request: request::utility::GetFlushAllStateRequest,
     ) -> Result<response::utility::GetFlushAllStateResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, get_flush_all_state, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::GetFlushAllStateResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response =
+                rpc_with_retry!(self, get_flush_all_state, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::GetFlushAllStateResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Lists persisted segments reported by data nodes.
@@ -232,20 +253,24 @@ impl ClientV2 {
         &self,
         request: request::utility::ListPersistentSegmentsRequest,
     ) -> Result<response::utility::ListPersistentSegmentsResponse> {
-        let database = self.current_database();
-        let collection_name = request.collection_name().to_owned();
-        let response = rpc_with_retry!(
-            self,
-            get_persistent_segment_info,
-            request.into_proto(&database)
-        )?;
-        status_to_result(&response.status)?;
-        Ok(
-            response::utility::ListPersistentSegmentsResponse::from_proto(
-                response,
-                collection_name,
-            ),
-        )
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let collection_name = request.collection_name().to_owned();
+            let response = rpc_with_retry!(
+                self,
+                get_persistent_segment_info,
+                request.into_proto(&database)
+            )?;
+            status_to_result(&response.status)?;
+            Ok(
+                response::utility::ListPersistentSegmentsResponse::from_proto(
+                    response,
+                    collection_name,
+                ),
+            )
+        })
+        .await
     }
 
     /// Lists segments currently loaded on query nodes.
@@ -253,15 +278,19 @@ impl ClientV2 {
         &self,
         request: request::utility::ListQuerySegmentsRequest,
     ) -> Result<response::utility::ListQuerySegmentsResponse> {
-        let database = self.current_database();
-        let collection_name = request.collection_name().to_owned();
-        let response =
-            rpc_with_retry!(self, get_query_segment_info, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::ListQuerySegmentsResponse::from_proto(
-            response,
-            collection_name,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let collection_name = request.collection_name().to_owned();
+            let response =
+                rpc_with_retry!(self, get_query_segment_info, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::ListQuerySegmentsResponse::from_proto(
+                response,
+                collection_name,
+            ))
+        })
+        .await
     }
 
     /// Starts a compaction action for a collection.
@@ -269,17 +298,21 @@ impl ClientV2 {
         &self,
         request: request::utility::CompactRequest,
     ) -> Result<response::utility::CompactResponse> {
-        let database = self.effective_database(request.database_name.as_deref());
-        let collection = request.collection_name.clone();
-        let description = self
-            .describe_collection_uncached(&database, &collection)
-            .await?;
-        let mut raw = request.into_proto(&database);
-        raw.db_name = database;
-        raw.collection_id = description.collection_id;
-        let response = rpc_with_retry!(self, manual_compaction, raw)?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::CompactResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.effective_database(request.database_name.as_deref());
+            let collection = request.collection_name.clone();
+            let description = self
+                .describe_collection_uncached(&database, &collection)
+                .await?;
+            let mut raw = request.into_proto(&database);
+            raw.db_name = database;
+            raw.collection_id = description.collection_id;
+            let response = rpc_with_retry!(self, manual_compaction, raw)?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::CompactResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Starts an asynchronous collection-optimization task.
@@ -290,21 +323,25 @@ impl ClientV2 {
         &self,
         request: request::utility::OptimizeRequest,
     ) -> Result<OptimizeTask> {
-        let task = OptimizeTask::new();
-        if request.async_mode {
-            let client = self.clone();
-            let state = Arc::clone(&task.state);
-            tokio::spawn(async move {
-                let result = client.run_optimize(request, Arc::clone(&state)).await;
-                state.complete(&result);
-            });
-            return Ok(task);
-        }
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let task = OptimizeTask::new();
+            if request.async_mode {
+                let client = self.clone();
+                let state = Arc::clone(&task.state);
+                tokio::spawn(async move {
+                    let result = client.run_optimize(request, Arc::clone(&state)).await;
+                    state.complete(&result);
+                });
+                return Ok(task);
+            }
 
-        let result = self.run_optimize(request, Arc::clone(&task.state)).await;
-        task.state.complete(&result);
-        result?;
-        Ok(task)
+            let result = self.run_optimize(request, Arc::clone(&task.state)).await;
+            task.state.complete(&result);
+            result?;
+            Ok(task)
+        })
+        .await
     }
 
     async fn run_optimize(
@@ -373,6 +410,7 @@ impl ClientV2 {
                     collection_name: collection.clone(),
                     index_name: String::new(),
                     timestamp: 0,
+                    options: Default::default(),
                 })
                 .await?;
             response
@@ -476,6 +514,7 @@ impl ClientV2 {
                 database_name: Some(database.clone()),
                 collection_name: collection.clone(),
                 partition_names: Vec::new(),
+                options: Default::default(),
             })
             .await?;
         if load_state.state == LoadState::Loaded {
@@ -525,6 +564,7 @@ impl ClientV2 {
                         field_name: index.field_name.clone(),
                         index_name: index.index_name.clone(),
                         timestamp: 0,
+                        options: Default::default(),
                     })
                     .await?;
                 let description = response.indexes.first().ok_or_else(|| {
@@ -556,11 +596,15 @@ impl ClientV2 {
         &self,
         request: request::utility::GetCompactionStateRequest,
     ) -> Result<response::utility::GetCompactionStateResponse> {
-        let response = rpc_with_retry!(self, get_compaction_state, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::GetCompactionStateResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, get_compaction_state, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::GetCompactionStateResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Retrieves the execution plans produced for a compaction action.
@@ -568,12 +612,16 @@ impl ClientV2 {
         &self,
         request: request::utility::GetCompactionPlansRequest,
     ) -> Result<response::utility::GetCompactionPlansResponse> {
-        let response =
-            rpc_with_retry!(self, get_compaction_state_with_plans, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::GetCompactionPlansResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response =
+                rpc_with_retry!(self, get_compaction_state_with_plans, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::GetCompactionPlansResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     /// Runs a server-side analyzer and returns its result tokens.
@@ -581,10 +629,14 @@ impl ClientV2 {
         &self,
         request: request::utility::RunAnalyzerRequest,
     ) -> Result<response::utility::RunAnalyzerResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(self, run_analyzer, request.into_proto(&database))?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::RunAnalyzerResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(self, run_analyzer, request.into_proto(&database))?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::RunAnalyzerResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Refreshes an external collection from its external data source.
@@ -595,15 +647,19 @@ impl ClientV2 {
         &self,
         request: request::utility::RefreshExternalCollectionRequest,
     ) -> Result<response::utility::RefreshExternalCollectionResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(
-            NonIdempotent,
-            self,
-            refresh_external_collection,
-            request.into_proto(&database)
-        )?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::RefreshExternalCollectionResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(
+                NonIdempotent,
+                self,
+                refresh_external_collection,
+                request.into_proto(&database)
+            )?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::RefreshExternalCollectionResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Retrieves the state and progress of a refresh-external-collection job.
@@ -611,13 +667,17 @@ impl ClientV2 {
         &self,
         request: request::utility::GetRefreshExternalCollectionProgressRequest,
     ) -> Result<response::utility::GetRefreshExternalCollectionProgressResponse> {
-        let response = rpc_with_retry!(
-            self,
-            get_refresh_external_collection_progress,
-            request.into_proto()
-        )?;
-        status_to_result(&response.status)?;
-        response::utility::GetRefreshExternalCollectionProgressResponse::from_proto(response)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(
+                self,
+                get_refresh_external_collection_progress,
+                request.into_proto()
+            )?;
+            status_to_result(&response.status)?;
+            response::utility::GetRefreshExternalCollectionProgressResponse::from_proto(response)
+        })
+        .await
     }
 
     /// Lists refresh-external-collection jobs for a collection, or for the whole
@@ -626,14 +686,18 @@ impl ClientV2 {
         &self,
         request: request::utility::ListRefreshExternalCollectionJobsRequest,
     ) -> Result<response::utility::ListRefreshExternalCollectionJobsResponse> {
-        let database = self.current_database();
-        let response = rpc_with_retry!(
-            self,
-            list_refresh_external_collection_jobs,
-            request.into_proto(&database)
-        )?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::ListRefreshExternalCollectionJobsResponse::from_proto(response))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let database = self.current_database();
+            let response = rpc_with_retry!(
+                self,
+                list_refresh_external_collection_jobs,
+                request.into_proto(&database)
+            )?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::ListRefreshExternalCollectionJobsResponse::from_proto(response))
+        })
+        .await
     }
 
     /// Registers a named file resource for external-table workflows.
@@ -641,9 +705,17 @@ impl ClientV2 {
         &self,
         request: request::utility::AddFileResourceRequest,
     ) -> Result<()> {
-        let status =
-            status_rpc_with_retry!(NonIdempotent, self, add_file_resource, request.into_proto())?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                add_file_resource,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Removes a registered file resource.
@@ -651,13 +723,17 @@ impl ClientV2 {
         &self,
         request: request::utility::RemoveFileResourceRequest,
     ) -> Result<()> {
-        let status = status_rpc_with_retry!(
-            NonIdempotent,
-            self,
-            remove_file_resource,
-            request.into_proto()
-        )?;
-        self.status(status)
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let status = status_rpc_with_retry!(
+                NonIdempotent,
+                self,
+                remove_file_resource,
+                request.into_proto()
+            )?;
+            self.status(status)
+        })
+        .await
     }
 
     /// Lists all registered file resources.
@@ -665,11 +741,15 @@ impl ClientV2 {
         &self,
         request: request::utility::ListFileResourcesRequest,
     ) -> Result<response::utility::ListFileResourcesResponse> {
-        let response = rpc_with_retry!(self, list_file_resources, request.into_proto())?;
-        status_to_result(&response.status)?;
-        Ok(response::utility::ListFileResourcesResponse::from_proto(
-            response,
-        ))
+        let options = request.request_options().clone();
+        self.scoped(&options, async move {
+            let response = rpc_with_retry!(self, list_file_resources, request.into_proto())?;
+            status_to_result(&response.status)?;
+            Ok(response::utility::ListFileResourcesResponse::from_proto(
+                response,
+            ))
+        })
+        .await
     }
 
     async fn get_flush_state(
@@ -0,0 +1,115 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Column helpers shared by the Arrow and Polars conversions.
+//!
+//! Both libraries store one optional value per row, while [`FieldData`] stores compact values
+//! with a separate validity bitmap. These helpers move between the two layouts and encode the
+//! values neither library has a native type for, so both conversions map columns the same way.
+
+use crate::v2::error::{Error, Result};
+use crate::v2::types::FieldData;
+
+/// Returns the values of a column and its validity bitmap when it is nullable.
+pub(crate) fn nullable_parts(column: &FieldData) -> (&FieldData, Option<&[bool]>) {
+    match column {
+        FieldData::Nullable { data, valid_data } => (data.as_ref(), Some(valid_data.as_slice())),
+        data => (data, None),
+    }
+}
+
+/// Splits optional row values into compact values and a validity bitmap when any row is null.
+pub(crate) fn split<T>(rows: Vec<Option<T>>) -> (Vec<T>, Option<Vec<bool>>) {
+    let validity = rows
+        .iter()
+        .any(Option::is_none)
+        .then(|| rows.iter().map(Option::is_some).collect());
+    (rows.into_iter().flatten().collect(), validity)
+}
+
+/// Expands compact nullable values back to one optional value per row.
+pub(crate) fn expand<'a, T>(values: &'a [T], validity: Option<&'a [bool]>) -> Vec<Option<&'a T>> {
+    match validity {
+        None => values.iter().map(Some).collect(),
+        Some(validity) => {
+            let mut values = values.iter();
+            validity
+                .iter()
+                .map(|valid| if *valid { values.next() } else { None })
+                .collect()
+        }
+    }
+}
+
+/// Renders JSON and struct values as JSON text, one optional string per row.
+pub(crate) fn json_texts<T: serde::Serialize>(
+    values: &[T],
+    validity: Option<&[bool]>,
+) -> Result<Vec<Option<String>>> {
+    expand(values, validity)
+        .into_iter()
+        .map(|value| value.map(serde_json::to_string).transpose())
+        .collect::<std::result::Result<_, _>>()
+        .map_err(Into::into)
+}
+
+/// Encodes a 16-bit vector as little-endian bytes.
+pub(crate) fn half_word_bytes(row: &[u16]) -> Vec<u8> {
+    row.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+/// Decodes little-endian bytes into a 16-bit vector.
+pub(crate) fn half_words(name: &str, bytes: &[u8]) -> Result<Vec<u16>> {
+    if bytes.len() % 2 != 0 {
+        return Err(Error::validation(
+            name.to_owned(),
+            "16-bit vectors need an even number of bytes".into(),
+        ));
+    }
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|word| u16::from_le_bytes([word[0], word[1]]))
+        .collect())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_round_trip_through_compact_values() {
+        let (values, validity) = split(vec![Some(1), None, Some(3)]);
+        assert_eq!(values, [1, 3]);
+        assert_eq!(validity.as_deref(), Some([true, false, true].as_slice()));
+        assert_eq!(
+            expand(&values, validity.as_deref()),
+            [Some(&1), None, Some(&3)]
+        );
+        assert_eq!(split(vec![Some(1)]).1, None);
+    }
+
+    #[test]
+    fn half_words_round_trip_through_little_endian_bytes() {
+        let bytes = half_word_bytes(&[0x3c00, 0x0001]);
+        assert_eq!(bytes, [0x00, 0x3c, 0x01, 0x00]);
+        assert_eq!(half_words("vector", &bytes).unwrap(), [0x3c00, 0x0001]);
+        assert!(half_words("vector", &bytes[..3]).is_err());
+    }
+}
@@ -0,0 +1,491 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side text embedding for inserts and searches.
+//!
+//! Server-side embedding functions need a model provider configured on the cluster. A
+//! [`TextEmbedding`] instead binds an in-process [`EmbeddingFunction`] to a text source field and
+//! the vector field it fills. Attached to an insert or upsert, it reads the source field's text
+//! from every row or column and adds the vector field before the data is validated against the
+//! schema. Attached to a search, it turns [`SearchVectors::EmbeddedText`] queries into vectors for
+//! the bound vector field.
+//!
+//! Texts are deduplicated, embedded in batches of at most [`EmbeddingFunction::batch_size`], and
+//! kept in a bounded cache shared by every clone of the binding. Every embedding is checked
+//! against the vector field's [`FieldSchema`]: dense embeddings need a `FloatVector` field of the
+//! same dimension and sparse embeddings a `SparseFloatVector` field.
+//!
+//! ```no_run
+//! # async fn example(client: milvus::v2::ClientV2) -> milvus::v2::error::Result<()> {
+//! use milvus::v2::embedding::{BoxFuture, Embedding, EmbeddingFunction, TextEmbedding};
+//! use milvus::v2::error::Result;
+//! use milvus::v2::prelude::*;
+//!
+//! #[derive(Debug)]
+//! struct Model;
+//!
+//! impl EmbeddingFunction for Model {
+//!     fn embed_documents<'a>(
+//!         &'a self,
+//!         texts: &'a [String],
+//!     ) -> BoxFuture<'a, Result<Vec<Embedding>>> {
+//!         Box::pin(async move {
+//!             // Call the model here.
+//!             Ok(texts.iter().map(|_| Embedding::Dense(vec![0.0; 384])).collect())
+//!         })
+//!     }
+//! }
+//!
+//! let embedding = TextEmbedding::new("text", "text_vector", Model);
+//! client
+//!     .insert(
+//!         InsertRequest::builder()
+//!             .collection_name("docs")
+//!             .row(serde_json::json!({"id": 1, "text": "vector databases"}))
+//!             .embedding(embedding.clone())
+//!             .build()?,
+//!     )
+//!     .await?;
+//! client
+//!     .search(
+//!         SearchRequest::builder()
+//!             .collection_name("docs")
+//!             .vectors(SearchVectors::EmbeddedText(vec!["what is a vector database".into()]))
+//!             .embedding(embedding)
+//!             .build()?,
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`SearchVectors::EmbeddedText`]: crate::v2::types::SearchVectors::EmbeddedText
+
+use crate::proto::schema;
+use crate::v2::error::{Error, Result};
+use crate::v2::types::{DataType, FieldData, FieldSchema, SparseVector};
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+pub use futures::future::BoxFuture;
+
+const DEFAULT_CACHE_CAPACITY: usize = 1_024;
+
+///////////////////////////////////////////////////////////////////////////////
+// Embedding
+///////////////////////////////////////////////////////////////////////////////
+/// Vector produced for one text.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Embedding {
+    /// Dense float vector, stored in a `FloatVector` field.
+    Dense(Vec<f32>),
+    /// Sparse float vector, stored in a `SparseFloatVector` field.
+    Sparse(SparseVector),
+}
+
+impl From<Vec<f32>> for Embedding {
+    fn from(value: Vec<f32>) -> Self {
+        Self::Dense(value)
+    }
+}
+
+impl From<SparseVector> for Embedding {
+    fn from(value: SparseVector) -> Self {
+        Self::Sparse(value)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// EmbeddingFunction
+///////////////////////////////////////////////////////////////////////////////
+/// Turns texts into vectors in-process.
+///
+/// A function is shared by every clone of its [`TextEmbedding`] and may be called concurrently,
+/// so stateful functions must synchronize internally.
+pub trait EmbeddingFunction: Debug + Send + Sync {
+    /// Embeds stored texts, returning one embedding per text in input order.
+    fn embed_documents<'a>(&'a self, texts: &'a [String]) -> BoxFuture<'a, Result<Vec<Embedding>>>;
+
+    /// Embeds search queries, returning one embedding per text in input order.
+    ///
+    /// Defaults to [`Self::embed_documents`]; override it for models that encode queries
+    /// differently from documents.
+    fn embed_queries<'a>(&'a self, texts: &'a [String]) -> BoxFuture<'a, Result<Vec<Embedding>>> {
+        self.embed_documents(texts)
+    }
+
+    /// Returns the largest number of texts passed to one call.
+    fn batch_size(&self) -> usize {
+        32
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// TextEmbedding
+///////////////////////////////////////////////////////////////////////////////
+/// Whether texts are embedded as stored documents or as search queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum TextKind {
+    Document,
+    Query,
+}
+
+type EmbeddingCache = Mutex<LruCache<(TextKind, String), Embedding>>;
+
+/// An [`EmbeddingFunction`] bound to the text field it reads and the vector field it fills.
+///
+/// Clones share the function and the cache. Two bindings compare equal when they bind the same
+/// fields to the same function instance.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct TextEmbedding {
+    pub(crate) source_field: String,
+    pub(crate) vector_field: String,
+    pub(crate) function: Arc<dyn EmbeddingFunction>,
+    pub(crate) cache: Option<Arc<EmbeddingCache>>,
+}
+
+impl std::fmt::Debug for TextEmbedding {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter
+            .debug_struct("TextEmbedding")
+            .field("source_field", &self.source_field)
+            .field("vector_field", &self.vector_field)
+            .field("function", &self.function)
+            .field("cache_capacity", &self.get_cache_capacity())
+            .finish()
+    }
+}
+
+impl PartialEq for TextEmbedding {
+    fn eq(&self, other: &Self) -> bool {
+        self.source_field == other.source_field
+            && self.vector_field == other.vector_field
+            && std::ptr::addr_eq(Arc::as_ptr(&self.function), Arc::as_ptr(&other.function))
+    }
+}
+
+impl TextEmbedding {
+    /// Binds `function` to the text in `source_field` and the vectors in `vector_field`.
+    ///
+    /// The binding caches up to 1024 embeddings.
+    pub fn new(
+        source_field: impl Into<String>,
+        vector_field: impl Into<String>,
+        function: impl EmbeddingFunction + 'static,
+    ) -> Self {
+        Self::from_arc(source_field, vector_field, Arc::new(function))
+    }
+
+    /// Binds a shared `function` to the text in `source_field` and the vectors in `vector_field`.
+    pub fn from_arc(
+        source_field: impl Into<String>,
+        vector_field: impl Into<String>,
+        function: Arc<dyn EmbeddingFunction>,
+    ) -> Self {
+        Self {
+            source_field: source_field.into(),
+            vector_field: vector_field.into(),
+            function,
+            cache: None,
+        }
+        .cache_capacity(DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Replaces the cache with an empty one holding up to `capacity` embeddings and returns the
+    /// updated value. Zero disables caching.
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache = NonZeroUsize::new(capacity)
+            .map(|capacity| Arc::new(Mutex::new(LruCache::new(capacity))));
+        self
+    }
+
+    /// Returns the text field the binding reads.
+    pub fn get_source_field(&self) -> &str {
+        &self.source_field
+    }
+
+    /// Returns the vector field the binding fills.
+    pub fn get_vector_field(&self) -> &str {
+        &self.vector_field
+    }
+
+    /// Returns the bound function.
+    pub fn get_function(&self) -> &Arc<dyn EmbeddingFunction> {
+        &self.function
+    }
+
+    /// Returns how many embeddings the cache holds at most; zero when caching is disabled.
+    pub fn get_cache_capacity(&self) -> usize {
+        self.cache
+            .as_ref()
+            .map_or(0, |cache| cache.lock().cap().get())
+    }
+
+    /// Embeds `texts` in input order, calling the function only for distinct uncached texts.
+    pub(crate) async fn embed(&self, kind: TextKind, texts: &[String]) -> Result<Vec<Embedding>> {
+        let mut embeddings = vec![None; texts.len()];
+        let mut pending = Vec::new();
+        let mut positions = HashMap::<&str, Vec<usize>>::new();
+        for (index, text) in texts.iter().enumerate() {
+            let cached = self
+                .cache
+                .as_ref()
+                .and_then(|cache| cache.lock().get(&(kind, text.clone())).cloned());
+            if cached.is_some() {
+                embeddings[index] = cached;
+                continue;
+            }
+            positions
+                .entry(text.as_str())
+                .or_insert_with(|| {
+                    pending.push(text.clone());
+                    Vec::new()
+                })
+                .push(index);
+        }
+
+        for batch in pending.chunks(self.function.batch_size().max(1)) {
+            let output = match kind {
+                TextKind::Document => self.function.embed_documents(batch).await?,
+                TextKind::Query => self.function.embed_queries(batch).await?,
+            };
+            if output.len() != batch.len() {
+                return Err(Error::validation(
+                    self.vector_field.clone(),
+                    format!(
+                        "embedding function returned {} embeddings for {} texts",
+                        output.len(),
+                        batch.len()
+                    ),
+                ));
+            }
+            for (text, embedding) in batch.iter().zip(output) {
+                if let Some(cache) = &self.cache {
+                    cache.lock().put((kind, text.clone()), embedding.clone());
+                }
+                for &index in &positions[text.as_str()] {
+                    embeddings[index] = Some(embedding.clone());
+                }
+            }
+        }
+        Ok(embeddings
+            .into_iter()
+            .map(|embedding| embedding.expect("every text was embedded"))
+            .collect())
+    }
+
+    /// Returns the schema of the bound vector field.
+    pub(crate) fn vector_field_schema(
+        &self,
+        collection: &schema::CollectionSchema,
+    ) -> Result<FieldSchema> {
+        let field = collection
+            .fields
+            .iter()
+            .find(|field| field.name == self.vector_field)
+            .ok_or_else(|| {
+                Error::validation(
+                    self.vector_field.clone(),
+                    "vector field does not exist in the collection schema".into(),
+                )
+            })?;
+        FieldSchema::from_proto(field.clone())
+    }
+
+    /// Checks that `embedding` fits the bound vector field.
+    pub(crate) fn check(&self, field: &FieldSchema, embedding: &Embedding) -> Result<()> {
+        let reason = match (embedding, field.get_data_type()) {
+            (Embedding::Dense(vector), DataType::FloatVector) => {
+                let dimension = field.get_dimension() as usize;
+                if vector.len() == dimension {
+                    return Ok(());
+                }
+                format!(
+                    "embedding function returned {} dimensions but the field has {dimension}",
+                    vector.len()
+                )
+            }
+            (Embedding::Sparse(_), DataType::SparseFloatVector) => return Ok(()),
+            (Embedding::Dense(_), data_type) => {
+                format!("dense embeddings require a FloatVector field, found {data_type:?}")
+            }
+            (Embedding::Sparse(_), data_type) => {
+                format!("sparse embeddings require a SparseFloatVector field, found {data_type:?}")
+            }
+        };
+        Err(Error::validation(self.vector_field.clone(), reason))
+    }
+
+    /// Converts checked embeddings into a column for the bound vector field.
+    ///
+    /// Fails on the first embedding whose kind differs from the first one.
+    pub(crate) fn into_column(&self, embeddings: Vec<Embedding>) -> Result<FieldData> {
+        let name = self.vector_field.clone();
+        let mismatch = |index: usize| {
+            Error::validation(
+                format!("{name}[{index}]"),
+                "embedder returned both dense and sparse embeddings".into(),
+            )
+        };
+        Ok(
+            if matches!(embeddings.first(), Some(Embedding::Sparse(_))) {
+                FieldData::SparseFloatVector {
+                    values: embeddings
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, embedding)| match embedding {
+                            Embedding::Sparse(vector) => Ok(vector),
+                            Embedding::Dense(_) => Err(mismatch(index)),
+                        })
+                        .collect::<Result<_>>()?,
+                    name,
+                }
+            } else {
+                FieldData::FloatVector {
+                    values: embeddings
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, embedding)| match embedding {
+                            Embedding::Dense(vector) => Ok(vector),
+                            Embedding::Sparse(_) => Err(mismatch(index)),
+                        })
+                        .collect::<Result<_>>()?,
+                    name,
+                }
+            },
+        )
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct Lengths {
+        calls: AtomicUsize,
+    }
+
+    impl EmbeddingFunction for Lengths {
+        fn embed_documents<'a>(
+            &'a self,
+            texts: &'a [String],
+        ) -> BoxFuture<'a, Result<Vec<Embedding>>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                Ok(texts
+                    .iter()
+                    .map(|text| Embedding::Dense(vec![text.len() as f32, 0.0]))
+                    .collect())
+            })
+        }
+
+        fn batch_size(&self) -> usize {
+            2
+        }
+    }
+
+    fn texts(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_owned()).collect()
+    }
+
+    #[tokio::test]
+    async fn texts_are_deduplicated_batched_and_cached() {
+        let function = Arc::new(Lengths::default());
+        let embedding = TextEmbedding::from_arc("text", "vector", function.clone());
+
+        let output = embedding
+            .embed(TextKind::Document, &texts(&["a", "bb", "a", "ccc"]))
+            .await
+            .unwrap();
+        assert_eq!(output[0], Embedding::Dense(vec![1.0, 0.0]));
+        assert_eq!(output[2], output[0]);
+        assert_eq!(output[3], Embedding::Dense(vec![3.0, 0.0]));
+        assert_eq!(function.calls.load(Ordering::SeqCst), 2);
+
+        embedding
+            .clone()
+            .embed(TextKind::Document, &texts(&["bb", "ccc"]))
+            .await
+            .unwrap();
+        assert_eq!(function.calls.load(Ordering::SeqCst), 2);
+        embedding
+            .embed(TextKind::Query, &texts(&["bb"]))
+            .await
+            .unwrap();
+        assert_eq!(function.calls.load(Ordering::SeqCst), 3);
+
+        let uncached = embedding.clone().cache_capacity(0);
+        assert_eq!(uncached, embedding);
+        assert_eq!(uncached.get_cache_capacity(), 0);
+    }
+
+    #[test]
+    fn embeddings_are_checked_against_the_vector_field() {
+        let embedding = TextEmbedding::new("text", "vector", Lengths::default());
+        let dense = FieldSchema::new()
+            .name("vector")
+            .data_type(DataType::FloatVector)
+            .dimension(2);
+        let sparse = FieldSchema::new()
+            .name("vector")
+            .data_type(DataType::SparseFloatVector);
+
+        assert!(embedding
+            .check(&dense, &Embedding::Dense(vec![1.0, 2.0]))
+            .is_ok());
+        let error = embedding
+            .check(&dense, &Embedding::Dense(vec![1.0]))
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("1 dimensions but the field has 2"));
+        assert!(embedding
+            .check(&sparse, &Embedding::Sparse(SparseVector::new()))
+            .is_ok());
+        assert!(embedding
+            .check(&sparse, &Embedding::Dense(vec![1.0, 2.0]))
+            .is_err());
+
+        assert_eq!(
+            embedding
+                .into_column(vec![Embedding::Dense(vec![1.0, 2.0])])
+                .unwrap(),
+            FieldData::FloatVector {
+                name: "vector".into(),
+                values: vec![vec![1.0, 2.0]],
+            }
+        );
+        let error = embedding
+            .into_column(vec![
+                Embedding::Dense(vec![1.0, 2.0]),
+                Embedding::Sparse(SparseVector::new()),
+            ])
+            .unwrap_err();
+        assert!(error.to_string().contains("vector[1]"), "{error}");
+    }
+}
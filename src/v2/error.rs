@@ -82,6 +82,16 @@ pub enum ConversionError {
     /// Represents the Json case.
     Json(#[source] Arc<serde_json::Error>),
 
+    #[error("I/O failed for {path}: {source}")]
+    /// Represents the Io case.
+    Io {
+        /// File the SDK was reading or writing.
+        path: String,
+        #[source]
+        /// Underlying I/O error.
+        source: Arc<std::io::Error>,
+    },
+
     #[error("{0}")]
     /// Represents the Value case.
     Value(String),
@@ -157,6 +167,10 @@ pub enum Error {
     /// Represents the Cancelled case.
     Cancelled(String),
 
+    #[error("circuit breaker is open: {0}")]
+    /// Represents the CircuitOpen case.
+    CircuitOpen(String),
+
     #[error("RPC retry exhausted after {attempts} attempts: {source}")]
     /// Represents the RetryExhausted case.
     RetryExhausted {
@@ -180,6 +194,14 @@ impl Error {
     pub(crate) fn validation(parameter: String, reason: String) -> Self {
         ValidationError::new(parameter, reason).into()
     }
+
+    pub(crate) fn io(path: &std::path::Path, error: std::io::Error) -> Self {
+        ConversionError::Io {
+            path: path.display().to_string(),
+            source: Arc::new(error),
+        }
+        .into()
+    }
 }
 
 impl From<prost::EncodeError> for Error {
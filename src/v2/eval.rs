@@ -0,0 +1,899 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recall and latency evaluation of indexed search against exact ground truth.
+//!
+//! A [`RecallEvaluation`] runs every query vector through each [`SearchConfig`] and compares the
+//! hits with exact nearest neighbors. Query vectors are either supplied or sampled from the
+//! collection, and the exact neighbors come from a brute-force scan of the rows exported with a
+//! query iterator or from searching a FLAT-indexed copy of the collection, as chosen by
+//! [`GroundTruth`]. [`ClientV2::evaluate_recall`](crate::v2::client::ClientV2::evaluate_recall)
+//! returns a [`RecallReport`] with recall@k, latency percentiles, and throughput per
+//! configuration:
+//!
+//! ```no_run
+//! # async fn example(client: milvus::v2::client::ClientV2) -> milvus::v2::error::Result<()> {
+//! use milvus::v2::eval::{RecallEvaluation, SearchConfig};
+//!
+//! let evaluation = RecallEvaluation::builder()
+//!     .collection_name("books")
+//!     .vector_field("embedding")
+//!     .top_k(10)
+//!     .sample_size(200)
+//!     .config(SearchConfig::new("ef=32").param("ef", "32"))
+//!     .config(SearchConfig::new("ef=128").param("ef", "128"))
+//!     .build()?;
+//! let report = client.evaluate_recall(evaluation).await?;
+//! println!("{}", report.to_json()?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Brute-force ground truth supports FloatVector fields with the `L2`, `IP`, and `COSINE`
+//! metrics. Latency is measured per query on the client, so it includes network time; leave the
+//! result cache disabled while evaluating, or repeated queries are answered from memory.
+
+use crate::v2::error::{Error, Result};
+use crate::v2::request::dql::SearchRequest;
+use crate::v2::types::{ConsistencyLevel, MetricType, SearchVectors};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet};
+use std::time::Duration;
+
+///////////////////////////////////////////////////////////////////////////////
+// GroundTruth
+///////////////////////////////////////////////////////////////////////////////
+/// Source of the exact nearest neighbors that search results are compared with.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum GroundTruth {
+    /// Scores every row exported with a query iterator on the client.
+    #[default]
+    BruteForce,
+    /// Searches the named collection, which holds the same rows under a FLAT index.
+    FlatCollection(String),
+}
+
+impl GroundTruth {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::BruteForce => "brute_force",
+            Self::FlatCollection(_) => "flat_collection",
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SearchConfig
+///////////////////////////////////////////////////////////////////////////////
+/// One named set of search parameters, such as `ef` or `nprobe`, to evaluate.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct SearchConfig {
+    pub(crate) name: String,
+    pub(crate) params: HashMap<String, String>,
+}
+
+impl SearchConfig {
+    /// Creates a configuration without parameters; `name` labels it in the report.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            params: HashMap::new(),
+        }
+    }
+
+    /// Adds one search parameter and returns the updated value.
+    pub fn param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+
+    /// Replaces the search parameters and returns the updated value.
+    pub fn params(mut self, value: HashMap<String, String>) -> Self {
+        self.params = value;
+        self
+    }
+
+    /// Returns the configured name.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the configured search parameters.
+    pub fn get_params(&self) -> &HashMap<String, String> {
+        &self.params
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RecallEvaluation
+///////////////////////////////////////////////////////////////////////////////
+/// Parameters for the ClientV2 evaluate_recall operation.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RecallEvaluation {
+    pub(crate) database_name: Option<String>,
+    pub(crate) collection_name: String,
+    pub(crate) vector_field: String,
+    pub(crate) queries: Vec<Vec<f32>>,
+    pub(crate) sample_size: usize,
+    pub(crate) seed: u64,
+    pub(crate) top_k: usize,
+    pub(crate) filter: String,
+    pub(crate) metric_type: Option<MetricType>,
+    pub(crate) ground_truth: GroundTruth,
+    pub(crate) configs: Vec<SearchConfig>,
+    pub(crate) consistency_level: Option<ConsistencyLevel>,
+    pub(crate) concurrency: usize,
+    pub(crate) batch_size: usize,
+}
+
+impl RecallEvaluation {
+    fn empty() -> Self {
+        Self {
+            database_name: None,
+            collection_name: String::new(),
+            vector_field: String::new(),
+            queries: Vec::new(),
+            sample_size: 100,
+            seed: 0,
+            top_k: 10,
+            filter: String::new(),
+            metric_type: None,
+            ground_truth: GroundTruth::default(),
+            configs: Vec::new(),
+            consistency_level: None,
+            concurrency: 1,
+            batch_size: 1_000,
+        }
+    }
+
+    /// Creates a builder for this request.
+    pub fn builder() -> RecallEvaluationBuilder {
+        RecallEvaluationBuilder {
+            value: Self::empty(),
+        }
+    }
+
+    /// Converts this request back into a builder while preserving its current values.
+    pub fn into_builder(self) -> RecallEvaluationBuilder {
+        RecallEvaluationBuilder { value: self }
+    }
+
+    /// Returns the database name.
+    pub fn database_name(&self) -> &Option<String> {
+        &self.database_name
+    }
+
+    /// Returns the collection name.
+    pub fn collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the vector field.
+    pub fn vector_field(&self) -> &str {
+        &self.vector_field
+    }
+
+    /// Returns the supplied query vectors; empty when queries are sampled.
+    pub fn queries(&self) -> &[Vec<f32>] {
+        &self.queries
+    }
+
+    /// Returns the number of query vectors sampled when none are supplied.
+    pub fn sample_size(&self) -> usize {
+        self.sample_size
+    }
+
+    /// Returns the seed of the query sampler.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the number of neighbors compared per query.
+    pub fn top_k(&self) -> usize {
+        self.top_k
+    }
+
+    /// Returns the filter.
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Returns the metric type, or `None` to use the vector index's metric.
+    pub fn metric_type(&self) -> Option<MetricType> {
+        self.metric_type
+    }
+
+    /// Returns the ground-truth source.
+    pub fn ground_truth(&self) -> &GroundTruth {
+        &self.ground_truth
+    }
+
+    /// Returns the search configurations.
+    pub fn configs(&self) -> &[SearchConfig] {
+        &self.configs
+    }
+
+    /// Returns the consistency level.
+    pub fn consistency_level(&self) -> Option<ConsistencyLevel> {
+        self.consistency_level
+    }
+
+    /// Returns the number of searches in flight at once.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Returns the query-iterator batch size used to export rows.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Builds the single-vector search that evaluates `query` on `collection`.
+    pub(crate) fn search_request(
+        &self,
+        collection: &str,
+        metric_type: MetricType,
+        params: &HashMap<String, String>,
+        query: &[f32],
+    ) -> Result<SearchRequest> {
+        let mut builder = SearchRequest::builder()
+            .collection_name(collection)
+            .vector_field(&self.vector_field)
+            .vectors(SearchVectors::Float(vec![query.to_vec()]))
+            .limit(self.top_k as i64)
+            .filter(&self.filter)
+            .metric_type(metric_type)
+            .extra_params(params.clone());
+        if let Some(database_name) = &self.database_name {
+            builder = builder.database_name(database_name);
+        }
+        if let Some(level) = self.consistency_level {
+            builder = builder.consistency_level(level);
+        }
+        builder.build()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RecallEvaluationBuilder
+///////////////////////////////////////////////////////////////////////////////
+/// Builder for RecallEvaluation.
+#[derive(Debug, Clone)]
+pub struct RecallEvaluationBuilder {
+    value: RecallEvaluation,
+}
+
+impl RecallEvaluationBuilder {
+    /// Sets the database name and returns the updated value.
+    pub fn database_name(mut self, value: impl Into<String>) -> Self {
+        self.value.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the collection name and returns the updated value.
+    pub fn collection_name(mut self, value: impl Into<String>) -> Self {
+        self.value.collection_name = value.into();
+        self
+    }
+
+    /// Sets the vector field and returns the updated value.
+    pub fn vector_field(mut self, value: impl Into<String>) -> Self {
+        self.value.vector_field = value.into();
+        self
+    }
+
+    /// Supplies the query vectors and returns the updated value; no queries are sampled then.
+    pub fn queries(mut self, value: Vec<Vec<f32>>) -> Self {
+        self.value.queries = value;
+        self
+    }
+
+    /// Sets the number of query vectors sampled from the collection when none are supplied and
+    /// returns the updated value. Defaults to 100.
+    pub fn sample_size(mut self, value: usize) -> Self {
+        self.value.sample_size = value;
+        self
+    }
+
+    /// Sets the seed of the query sampler and returns the updated value.
+    ///
+    /// The same seed samples the same rows while the collection is unchanged.
+    pub fn seed(mut self, value: u64) -> Self {
+        self.value.seed = value;
+        self
+    }
+
+    /// Sets the number of neighbors compared per query and returns the updated value. Defaults
+    /// to 10.
+    pub fn top_k(mut self, value: usize) -> Self {
+        self.value.top_k = value;
+        self
+    }
+
+    /// Sets a filter applied to the searches and the ground truth and returns the updated value.
+    pub fn filter(mut self, value: impl Into<String>) -> Self {
+        self.value.filter = value.into();
+        self
+    }
+
+    /// Sets the metric type and returns the updated value.
+    pub fn metric_type(mut self, value: MetricType) -> Self {
+        self.value.metric_type = Some(value);
+        self
+    }
+
+    /// Sets the ground-truth source and returns the updated value.
+    pub fn ground_truth(mut self, value: GroundTruth) -> Self {
+        self.value.ground_truth = value;
+        self
+    }
+
+    /// Adds a search configuration and returns the updated value.
+    pub fn config(mut self, value: SearchConfig) -> Self {
+        self.value.configs.push(value);
+        self
+    }
+
+    /// Replaces the search configurations and returns the updated value.
+    pub fn configs(mut self, values: impl IntoIterator<Item = SearchConfig>) -> Self {
+        self.value.configs = values.into_iter().collect();
+        self
+    }
+
+    /// Sets the consistency level and returns the updated value.
+    pub fn consistency_level(mut self, value: ConsistencyLevel) -> Self {
+        self.value.consistency_level = Some(value);
+        self
+    }
+
+    /// Sets the number of searches in flight at once and returns the updated value. Defaults
+    /// to 1, which measures unloaded latency.
+    pub fn concurrency(mut self, value: usize) -> Self {
+        self.value.concurrency = value;
+        self
+    }
+
+    /// Sets the query-iterator batch size used to export rows and returns the updated value.
+    pub fn batch_size(mut self, value: usize) -> Self {
+        self.value.batch_size = value;
+        self
+    }
+
+    /// Validates the configured values and builds the request.
+    pub fn build(self) -> Result<RecallEvaluation> {
+        let value = self.value;
+        for (name, text) in [
+            ("collection_name", &value.collection_name),
+            ("vector_field", &value.vector_field),
+        ] {
+            if text.is_empty() {
+                return Err(Error::validation(name.into(), "must not be empty".into()));
+            }
+        }
+        for (name, count) in [
+            ("top_k", value.top_k),
+            ("concurrency", value.concurrency),
+            ("batch_size", value.batch_size),
+        ] {
+            if count == 0 {
+                return Err(Error::validation(
+                    name.into(),
+                    "must be greater than zero".into(),
+                ));
+            }
+        }
+        if value.queries.is_empty() && value.sample_size == 0 {
+            return Err(Error::validation(
+                "sample_size".into(),
+                "must be greater than zero when no queries are supplied".into(),
+            ));
+        }
+        if let Some(first) = value.queries.first() {
+            if first.is_empty() || value.queries.iter().any(|query| query.len() != first.len()) {
+                return Err(Error::validation(
+                    "queries".into(),
+                    "query vectors must be non-empty and share one dimension".into(),
+                ));
+            }
+        }
+        if value.configs.is_empty() {
+            return Err(Error::validation(
+                "configs".into(),
+                "at least one search configuration is required".into(),
+            ));
+        }
+        let mut names = HashSet::new();
+        if let Some(config) = value
+            .configs
+            .iter()
+            .find(|config| !names.insert(&config.name))
+        {
+            return Err(Error::validation(
+                "configs".into(),
+                format!("search configuration {:?} is repeated", config.name),
+            ));
+        }
+        if matches!(&value.ground_truth, GroundTruth::FlatCollection(name) if name.is_empty()) {
+            return Err(Error::validation(
+                "ground_truth".into(),
+                "FLAT collection name must not be empty".into(),
+            ));
+        }
+        Ok(value)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RecallReport
+///////////////////////////////////////////////////////////////////////////////
+/// Machine-readable outcome of a recall evaluation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RecallReport {
+    pub(crate) collection_name: String,
+    pub(crate) vector_field: String,
+    pub(crate) metric_type: String,
+    pub(crate) ground_truth: String,
+    pub(crate) top_k: usize,
+    pub(crate) query_count: usize,
+    pub(crate) configs: Vec<ConfigReport>,
+}
+
+impl RecallReport {
+    /// Returns the evaluated collection.
+    pub fn get_collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Returns the evaluated vector field.
+    pub fn get_vector_field(&self) -> &str {
+        &self.vector_field
+    }
+
+    /// Returns the metric the ground truth was ranked by, such as `"L2"`.
+    pub fn get_metric_type(&self) -> &str {
+        &self.metric_type
+    }
+
+    /// Returns the ground-truth source, `"brute_force"` or `"flat_collection"`.
+    pub fn get_ground_truth(&self) -> &str {
+        &self.ground_truth
+    }
+
+    /// Returns the number of neighbors compared per query.
+    pub fn get_top_k(&self) -> usize {
+        self.top_k
+    }
+
+    /// Returns the number of query vectors evaluated.
+    pub fn get_query_count(&self) -> usize {
+        self.query_count
+    }
+
+    /// Returns one report per search configuration, in configuration order.
+    pub fn get_configs(&self) -> &[ConfigReport] {
+        &self.configs
+    }
+
+    /// Serializes the report as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|error| Error::conversion(format!("cannot serialize recall report: {error}")))
+    }
+
+    /// Parses a report written by [`Self::to_json`].
+    pub fn from_json(value: &str) -> Result<Self> {
+        serde_json::from_str(value).map_err(|error| {
+            Error::conversion(format!("cannot deserialize recall report: {error}"))
+        })
+    }
+}
+
+/// Recall and performance of one search configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ConfigReport {
+    pub(crate) name: String,
+    pub(crate) params: BTreeMap<String, String>,
+    pub(crate) recall: f64,
+    pub(crate) latency: LatencySummary,
+    pub(crate) qps: f64,
+}
+
+impl ConfigReport {
+    /// Returns the configuration name.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the search parameters.
+    pub fn get_params(&self) -> &BTreeMap<String, String> {
+        &self.params
+    }
+
+    /// Returns recall@k averaged over queries that have at least one exact neighbor.
+    pub fn get_recall(&self) -> f64 {
+        self.recall
+    }
+
+    /// Returns the per-query latency distribution.
+    pub fn get_latency(&self) -> &LatencySummary {
+        &self.latency
+    }
+
+    /// Returns the queries completed per second of wall time.
+    pub fn get_qps(&self) -> f64 {
+        self.qps
+    }
+}
+
+/// Per-query latency distribution in milliseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct LatencySummary {
+    pub(crate) mean_ms: f64,
+    pub(crate) p50_ms: f64,
+    pub(crate) p90_ms: f64,
+    pub(crate) p95_ms: f64,
+    pub(crate) p99_ms: f64,
+    pub(crate) max_ms: f64,
+}
+
+impl LatencySummary {
+    /// Summarizes latency samples using nearest-rank percentiles.
+    pub(crate) fn from_samples(mut samples: Vec<Duration>) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        samples.sort();
+        let millis = |duration: Duration| duration.as_nanos() as f64 / 1_000_000.0;
+        let percentile = |fraction: f64| {
+            let rank = (fraction * samples.len() as f64).ceil() as usize;
+            millis(samples[rank.clamp(1, samples.len()) - 1])
+        };
+        Self {
+            mean_ms: samples.iter().copied().map(millis).sum::<f64>() / samples.len() as f64,
+            p50_ms: percentile(0.50),
+            p90_ms: percentile(0.90),
+            p95_ms: percentile(0.95),
+            p99_ms: percentile(0.99),
+            max_ms: millis(samples[samples.len() - 1]),
+        }
+    }
+
+    /// Returns the mean latency.
+    pub fn get_mean_ms(&self) -> f64 {
+        self.mean_ms
+    }
+
+    /// Returns the median latency.
+    pub fn get_p50_ms(&self) -> f64 {
+        self.p50_ms
+    }
+
+    /// Returns the 90th-percentile latency.
+    pub fn get_p90_ms(&self) -> f64 {
+        self.p90_ms
+    }
+
+    /// Returns the 95th-percentile latency.
+    pub fn get_p95_ms(&self) -> f64 {
+        self.p95_ms
+    }
+
+    /// Returns the 99th-percentile latency.
+    pub fn get_p99_ms(&self) -> f64 {
+        self.p99_ms
+    }
+
+    /// Returns the slowest query's latency.
+    pub fn get_max_ms(&self) -> f64 {
+        self.max_ms
+    }
+}
+
+impl ConfigReport {
+    pub(crate) fn new(
+        config: &SearchConfig,
+        recall: f64,
+        latencies: Vec<Duration>,
+        elapsed: Duration,
+    ) -> Self {
+        let seconds = elapsed.as_secs_f64();
+        Self {
+            name: config.name.clone(),
+            params: config.params.clone().into_iter().collect(),
+            recall,
+            qps: if seconds > 0.0 {
+                latencies.len() as f64 / seconds
+            } else {
+                0.0
+            },
+            latency: LatencySummary::from_samples(latencies),
+        }
+    }
+}
+
+impl RecallReport {
+    pub(crate) fn new(
+        evaluation: &RecallEvaluation,
+        metric_type: MetricType,
+        query_count: usize,
+        configs: Vec<ConfigReport>,
+    ) -> Self {
+        Self {
+            collection_name: evaluation.collection_name.clone(),
+            vector_field: evaluation.vector_field.clone(),
+            metric_type: metric_type.as_str().to_owned(),
+            ground_truth: evaluation.ground_truth.as_str().to_owned(),
+            top_k: evaluation.top_k,
+            query_count,
+            configs,
+        }
+    }
+}
+
+/// Returns recall@k averaged over queries whose ground truth is non-empty.
+pub(crate) fn recall_at_k(truth: &[Vec<String>], found: &[Vec<String>]) -> f64 {
+    let (sum, count) = truth
+        .iter()
+        .zip(found)
+        .filter(|(truth, _)| !truth.is_empty())
+        .fold((0.0, 0usize), |(sum, count), (truth, found)| {
+            let truth = truth.iter().collect::<HashSet<_>>();
+            let hits = found.iter().filter(|key| truth.contains(key)).count();
+            (sum + hits as f64 / truth.len() as f64, count + 1)
+        });
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f64
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ExactTopK
+///////////////////////////////////////////////////////////////////////////////
+/// Keeps the exact `k` nearest rows for every query while rows stream past.
+pub(crate) struct ExactTopK<'a> {
+    metric_type: MetricType,
+    queries: &'a [Vec<f32>],
+    k: usize,
+    heaps: Vec<BinaryHeap<Neighbor>>,
+}
+
+/// A row ranked so that the heap's greatest element is the worst kept neighbor.
+struct Neighbor {
+    rank: f32,
+    key: String,
+}
+
+impl PartialEq for Neighbor {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Neighbor {}
+
+impl PartialOrd for Neighbor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Neighbor {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank.total_cmp(&other.rank)
+    }
+}
+
+impl<'a> ExactTopK<'a> {
+    pub(crate) fn new(metric_type: MetricType, queries: &'a [Vec<f32>], k: usize) -> Result<Self> {
+        if !matches!(
+            metric_type,
+            MetricType::L2 | MetricType::Ip | MetricType::Cosine
+        ) {
+            return Err(Error::validation(
+                "metric_type".into(),
+                format!(
+                    "brute-force ground truth supports L2, IP, and COSINE, not {}",
+                    metric_type.as_str()
+                ),
+            ));
+        }
+        Ok(Self {
+            metric_type,
+            queries,
+            k,
+            heaps: queries
+                .iter()
+                .map(|_| BinaryHeap::with_capacity(k + 1))
+                .collect(),
+        })
+    }
+
+    /// Scores one row against every query.
+    pub(crate) fn offer(&mut self, key: &str, vector: &[f32]) -> Result<()> {
+        for (query, heap) in self.queries.iter().zip(&mut self.heaps) {
+            if query.len() != vector.len() {
+                return Err(Error::validation(
+                    "queries".into(),
+                    format!(
+                        "query dimension {} does not match the vector field dimension {}",
+                        query.len(),
+                        vector.len()
+                    ),
+                ));
+            }
+            let rank = match self.metric_type {
+                MetricType::L2 => query
+                    .iter()
+                    .zip(vector)
+                    .map(|(left, right)| (left - right) * (left - right))
+                    .sum(),
+                MetricType::Ip => -dot(query, vector),
+                _ => {
+                    let norms = dot(query, query).sqrt() * dot(vector, vector).sqrt();
+                    if norms == 0.0 {
+                        0.0
+                    } else {
+                        -dot(query, vector) / norms
+                    }
+                }
+            };
+            if heap.len() == self.k && heap.peek().is_some_and(|worst| worst.rank <= rank) {
+                continue;
+            }
+            heap.push(Neighbor {
+                rank,
+                key: key.to_owned(),
+            });
+            if heap.len() > self.k {
+                heap.pop();
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the keys of each query's neighbors, nearest first.
+    pub(crate) fn into_keys(self) -> Vec<Vec<String>> {
+        self.heaps
+            .into_iter()
+            .map(|heap| {
+                heap.into_sorted_vec()
+                    .into_iter()
+                    .map(|neighbor| neighbor.key)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn dot(left: &[f32], right: &[f32]) -> f32 {
+    left.iter()
+        .zip(right)
+        .map(|(left, right)| left * right)
+        .sum()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// QuerySampler
+///////////////////////////////////////////////////////////////////////////////
+/// Reservoir sampler that picks query vectors uniformly from a stream of rows.
+pub(crate) struct QuerySampler {
+    capacity: usize,
+    seen: u64,
+    state: u64,
+    samples: Vec<Vec<f32>>,
+}
+
+impl QuerySampler {
+    pub(crate) fn new(capacity: usize, seed: u64) -> Self {
+        Self {
+            capacity,
+            seen: 0,
+            state: seed,
+            samples: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub(crate) fn offer(&mut self, vector: &[f32]) {
+        self.seen += 1;
+        if self.samples.len() < self.capacity {
+            self.samples.push(vector.to_vec());
+            return;
+        }
+        let slot = self.next() % self.seen;
+        if let Some(sample) = self.samples.get_mut(slot as usize) {
+            *sample = vector.to_vec();
+        }
+    }
+
+    pub(crate) fn into_samples(self) -> Vec<Vec<f32>> {
+        self.samples
+    }
+
+    /// Advances a SplitMix64 generator.
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_top_k_ranks_by_metric_direction_and_scores_recall() {
+        let queries = vec![vec![1.0, 0.0]];
+        let rows = [("a", [1.0, 0.1]), ("b", [3.0, 0.0]), ("c", [-0.5, 0.0])];
+
+        let mut l2 = ExactTopK::new(MetricType::L2, &queries, 2).unwrap();
+        let mut ip = ExactTopK::new(MetricType::Ip, &queries, 2).unwrap();
+        for (key, vector) in rows {
+            l2.offer(key, &vector).unwrap();
+            ip.offer(key, &vector).unwrap();
+        }
+        let l2 = l2.into_keys();
+        assert_eq!(l2, vec![vec!["a".to_owned(), "c".to_owned()]]);
+        assert_eq!(ip.into_keys(), vec![vec!["b".to_owned(), "a".to_owned()]]);
+
+        let found = vec![vec!["a".to_owned(), "b".to_owned()]];
+        assert_eq!(recall_at_k(&l2, &found), 0.5);
+        assert!(ExactTopK::new(MetricType::Hamming, &queries, 2).is_err());
+    }
+
+    #[test]
+    fn reports_percentiles_and_round_trip_as_json() {
+        let latencies = (1..=100).map(Duration::from_millis).collect::<Vec<_>>();
+        let config = SearchConfig::new("ef=64").param("ef", "64");
+        let report = ConfigReport::new(&config, 0.9, latencies, Duration::from_secs(2));
+        assert_eq!(report.get_latency().get_p50_ms(), 50.0);
+        assert_eq!(report.get_latency().get_p99_ms(), 99.0);
+        assert_eq!(report.get_latency().get_max_ms(), 100.0);
+        assert_eq!(report.get_qps(), 50.0);
+
+        let evaluation = RecallEvaluation::builder()
+            .collection_name("books")
+            .vector_field("embedding")
+            .config(config)
+            .build()
+            .unwrap();
+        let report = RecallReport::new(&evaluation, MetricType::L2, 100, vec![report]);
+        let json = report.to_json().unwrap();
+        assert!(json.contains("\"p95_ms\": 95.0"));
+        assert_eq!(RecallReport::from_json(&json).unwrap(), report);
+
+        let mut sampler = QuerySampler::new(3, 7);
+        for value in 0..50 {
+            sampler.offer(&[value as f32]);
+        }
+        assert_eq!(sampler.into_samples().len(), 3);
+    }
+}
@@ -0,0 +1,569 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed geometry values, enabled by the `geo` feature.
+//!
+//! Geometry columns travel as WKT text. This module builds them from [`geo_types::Geometry`]
+//! values, parses results back, converts to and from 2D WKB, and renders spatial filter
+//! predicates with the geometry quoted as a string literal. With the feature enabled,
+//! `InsertRequestBuilder::build` and `UpsertRequestBuilder::build` also reject geometry columns
+//! containing invalid WKT before anything is sent.
+//!
+//! ```no_run
+//! # use milvus::v2::prelude::*;
+//! use milvus::v2::geo::{self, geo_types::point};
+//!
+//! let column = FieldData::geometries("location", [point!(x: 1.0, y: 2.0)]);
+//! let filter = geo::st_dwithin("location", &point!(x: 1.0, y: 2.0).into(), 500.0)?;
+//! # Ok::<(), milvus::v2::error::Error>(())
+//! ```
+
+use crate::v2::error::{Error, Result};
+use crate::v2::types::{FieldData, ResultRow};
+use geo_types::{
+    Coord, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use wkt::{ToWkt, TryFromWkt};
+
+pub use geo_types::{self, Geometry};
+
+impl FieldData {
+    /// Creates a Geometry column by rendering each value as WKT.
+    pub fn geometries(
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<Geometry<f64>>>,
+    ) -> Self {
+        Self::geometry(
+            name,
+            values
+                .into_iter()
+                .map(|value| value.into().wkt_string())
+                .collect(),
+        )
+    }
+
+    /// Parses a Geometry column into `geo_types` values.
+    ///
+    /// Returns `None` for other column types. Null rows of a nullable column are skipped, as in
+    /// [`FieldData::as_geometry`].
+    pub fn to_geometries(&self) -> Option<Result<Vec<Geometry<f64>>>> {
+        self.as_geometry()
+            .map(|values| values.iter().map(|value| parse_wkt(value)).collect())
+    }
+}
+
+impl ResultRow<'_> {
+    /// Returns a geometry field parsed into a `geo_types` value.
+    pub fn get_geo(&self, name: &str) -> Result<Geometry<f64>> {
+        parse_wkt(self.get_geometry(name)?)
+    }
+}
+
+/// Parses WKT text into a geometry.
+pub fn parse_wkt(value: &str) -> Result<Geometry<f64>> {
+    Geometry::try_from_wkt_str(value)
+        .map_err(|error| Error::conversion(format!("invalid WKT {value:?}: {error}")))
+}
+
+pub(crate) fn validate_wkt_column(column: &FieldData) -> Result<()> {
+    let Some(values) = column.as_geometry() else {
+        return Ok(());
+    };
+    for (index, value) in values.iter().enumerate() {
+        if let Err(error) = Geometry::<f64>::try_from_wkt_str(value) {
+            return Err(Error::validation(
+                format!("{}[{index}]", column.name()),
+                format!("invalid WKT {value:?}: {error}"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Spatial filters
+///////////////////////////////////////////////////////////////////////////////
+
+/// Renders `ST_EQUALS(field, geometry)`.
+pub fn st_equals(field: &str, geometry: &Geometry<f64>) -> String {
+    predicate("ST_EQUALS", field, geometry)
+}
+
+/// Renders `ST_TOUCHES(field, geometry)`.
+pub fn st_touches(field: &str, geometry: &Geometry<f64>) -> String {
+    predicate("ST_TOUCHES", field, geometry)
+}
+
+/// Renders `ST_OVERLAPS(field, geometry)`.
+pub fn st_overlaps(field: &str, geometry: &Geometry<f64>) -> String {
+    predicate("ST_OVERLAPS", field, geometry)
+}
+
+/// Renders `ST_CROSSES(field, geometry)`.
+pub fn st_crosses(field: &str, geometry: &Geometry<f64>) -> String {
+    predicate("ST_CROSSES", field, geometry)
+}
+
+/// Renders `ST_CONTAINS(field, geometry)`: the field contains the geometry.
+pub fn st_contains(field: &str, geometry: &Geometry<f64>) -> String {
+    predicate("ST_CONTAINS", field, geometry)
+}
+
+/// Renders `ST_INTERSECTS(field, geometry)`.
+pub fn st_intersects(field: &str, geometry: &Geometry<f64>) -> String {
+    predicate("ST_INTERSECTS", field, geometry)
+}
+
+/// Renders `ST_WITHIN(field, geometry)`: the field lies within the geometry.
+pub fn st_within(field: &str, geometry: &Geometry<f64>) -> String {
+    predicate("ST_WITHIN", field, geometry)
+}
+
+/// Renders `ST_DWITHIN(field, geometry, distance)`, with the distance in meters.
+///
+/// Fails when `distance` is negative, NaN, or infinite, which the server cannot parse.
+pub fn st_dwithin(field: &str, geometry: &Geometry<f64>, distance: f64) -> Result<String> {
+    if !distance.is_finite() || distance < 0.0 {
+        return Err(Error::validation(
+            "distance".into(),
+            format!("must be a finite, non-negative number of meters, got {distance}"),
+        ));
+    }
+    Ok(format!(
+        "ST_DWITHIN({field}, {}, {distance:?})",
+        literal(&geometry.wkt_string())
+    ))
+}
+
+fn predicate(function: &str, field: &str, geometry: &Geometry<f64>) -> String {
+    format!("{function}({field}, {})", literal(&geometry.wkt_string()))
+}
+
+fn literal(value: &str) -> String {
+    serde_json::to_string(value).expect("strings always serialize to JSON")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// WKB
+///////////////////////////////////////////////////////////////////////////////
+
+const WKB_POINT: u32 = 1;
+const WKB_LINE_STRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTI_POINT: u32 = 4;
+const WKB_MULTI_LINE_STRING: u32 = 5;
+const WKB_MULTI_POLYGON: u32 = 6;
+const WKB_GEOMETRY_COLLECTION: u32 = 7;
+
+/// Deepest nesting of geometry collections accepted when decoding, which bounds the recursion on
+/// malformed input.
+const MAX_WKB_NESTING: usize = 32;
+
+/// Encodes a geometry as little-endian 2D WKB.
+///
+/// `Line`, `Rect` and `Triangle` have no WKB type of their own and are written as a line string
+/// and polygons respectively.
+pub fn to_wkb(geometry: &Geometry<f64>) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    write_geometry(&mut buffer, geometry);
+    buffer
+}
+
+/// Decodes 2D WKB in either byte order.
+pub fn from_wkb(bytes: &[u8]) -> Result<Geometry<f64>> {
+    let mut reader = WkbReader { bytes, offset: 0 };
+    let geometry = reader.geometry(0)?;
+    if reader.offset != bytes.len() {
+        return Err(Error::conversion(format!(
+            "WKB contains {} trailing bytes",
+            bytes.len() - reader.offset
+        )));
+    }
+    Ok(geometry)
+}
+
+/// Decodes 2D WKB and renders it as WKT.
+pub fn wkb_to_wkt(bytes: &[u8]) -> Result<String> {
+    from_wkb(bytes).map(|geometry| geometry.wkt_string())
+}
+
+fn write_geometry(buffer: &mut Vec<u8>, geometry: &Geometry<f64>) {
+    match geometry {
+        Geometry::Point(point) => write_point(buffer, point),
+        Geometry::Line(line) => {
+            write_line_string(buffer, &LineString::new(vec![line.start, line.end]))
+        }
+        Geometry::LineString(line) => write_line_string(buffer, line),
+        Geometry::Polygon(polygon) => write_polygon(buffer, polygon),
+        Geometry::MultiPoint(points) => {
+            write_header(buffer, WKB_MULTI_POINT, points.0.len());
+            points.iter().for_each(|point| write_point(buffer, point));
+        }
+        Geometry::MultiLineString(lines) => {
+            write_header(buffer, WKB_MULTI_LINE_STRING, lines.0.len());
+            lines
+                .iter()
+                .for_each(|line| write_line_string(buffer, line));
+        }
+        Geometry::MultiPolygon(polygons) => {
+            write_header(buffer, WKB_MULTI_POLYGON, polygons.0.len());
+            polygons
+                .iter()
+                .for_each(|polygon| write_polygon(buffer, polygon));
+        }
+        Geometry::GeometryCollection(geometries) => {
+            write_header(buffer, WKB_GEOMETRY_COLLECTION, geometries.0.len());
+            geometries
+                .iter()
+                .for_each(|geometry| write_geometry(buffer, geometry));
+        }
+        Geometry::Rect(rect) => write_polygon(buffer, &rect.to_polygon()),
+        Geometry::Triangle(triangle) => write_polygon(buffer, &triangle.to_polygon()),
+    }
+}
+
+fn write_header(buffer: &mut Vec<u8>, kind: u32, count: usize) {
+    buffer.push(1);
+    buffer.extend_from_slice(&kind.to_le_bytes());
+    if kind != WKB_POINT {
+        write_count(buffer, count);
+    }
+}
+
+fn write_count(buffer: &mut Vec<u8>, count: usize) {
+    let count = u32::try_from(count).expect("WKB element counts fit in u32");
+    buffer.extend_from_slice(&count.to_le_bytes());
+}
+
+fn write_coord(buffer: &mut Vec<u8>, coord: Coord<f64>) {
+    buffer.extend_from_slice(&coord.x.to_le_bytes());
+    buffer.extend_from_slice(&coord.y.to_le_bytes());
+}
+
+fn write_point(buffer: &mut Vec<u8>, point: &Point<f64>) {
+    write_header(buffer, WKB_POINT, 1);
+    write_coord(buffer, point.0);
+}
+
+fn write_line_string(buffer: &mut Vec<u8>, line: &LineString<f64>) {
+    write_header(buffer, WKB_LINE_STRING, line.0.len());
+    line.coords().for_each(|coord| write_coord(buffer, *coord));
+}
+
+fn write_polygon(buffer: &mut Vec<u8>, polygon: &Polygon<f64>) {
+    let rings = if polygon.exterior().0.is_empty() {
+        0
+    } else {
+        1 + polygon.interiors().len()
+    };
+    write_header(buffer, WKB_POLYGON, rings);
+    if rings == 0 {
+        return;
+    }
+    for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+        write_count(buffer, ring.0.len());
+        ring.coords().for_each(|coord| write_coord(buffer, *coord));
+    }
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let end = self.offset + N;
+        let bytes = self.bytes.get(self.offset..end).ok_or_else(|| {
+            Error::conversion(format!("WKB ends unexpectedly at byte {}", self.offset))
+        })?;
+        self.offset = end;
+        Ok(bytes.try_into().expect("slice length matches N"))
+    }
+
+    fn u32(&mut self, little_endian: bool) -> Result<u32> {
+        let bytes = self.take()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self, little_endian: bool) -> Result<f64> {
+        let bytes = self.take()?;
+        Ok(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn count(&mut self, little_endian: bool, item_size: usize) -> Result<usize> {
+        let count = self.u32(little_endian)? as usize;
+        // Every element takes at least `item_size` bytes, so larger counts are malformed and must
+        // not drive the allocation below.
+        if count.saturating_mul(item_size) > self.bytes.len() - self.offset {
+            return Err(Error::conversion(format!(
+                "WKB declares {count} elements but only {} bytes remain",
+                self.bytes.len() - self.offset
+            )));
+        }
+        Ok(count)
+    }
+
+    fn coord(&mut self, little_endian: bool) -> Result<Coord<f64>> {
+        Ok(Coord {
+            x: self.f64(little_endian)?,
+            y: self.f64(little_endian)?,
+        })
+    }
+
+    fn coords(&mut self, little_endian: bool) -> Result<LineString<f64>> {
+        let count = self.count(little_endian, 16)?;
+        (0..count)
+            .map(|_| self.coord(little_endian))
+            .collect::<Result<Vec<_>>>()
+            .map(LineString::new)
+    }
+
+    fn header(&mut self) -> Result<(bool, u32)> {
+        let little_endian = match self.take::<1>()? {
+            [0] => false,
+            [1] => true,
+            [order] => {
+                return Err(Error::conversion(format!(
+                    "invalid WKB byte order marker {order}"
+                )))
+            }
+        };
+        Ok((little_endian, self.u32(little_endian)?))
+    }
+
+    fn expect(&mut self, kind: u32) -> Result<bool> {
+        let (little_endian, found) = self.header()?;
+        if found != kind {
+            return Err(Error::conversion(format!(
+                "expected WKB geometry type {kind}, found {found}"
+            )));
+        }
+        Ok(little_endian)
+    }
+
+    fn point(&mut self, little_endian: bool) -> Result<Point<f64>> {
+        let coord = self.coord(little_endian)?;
+        if coord.x.is_nan() && coord.y.is_nan() {
+            return Err(Error::conversion("empty WKB points are not supported"));
+        }
+        Ok(Point(coord))
+    }
+
+    fn polygon(&mut self, little_endian: bool) -> Result<Polygon<f64>> {
+        let count = self.count(little_endian, 4)?;
+        let mut rings = (0..count)
+            .map(|_| self.coords(little_endian))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(Vec::new()));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    fn geometry(&mut self, depth: usize) -> Result<Geometry<f64>> {
+        let (little_endian, kind) = self.header()?;
+        Ok(match kind {
+            WKB_POINT => self.point(little_endian)?.into(),
+            WKB_LINE_STRING => self.coords(little_endian)?.into(),
+            WKB_POLYGON => self.polygon(little_endian)?.into(),
+            WKB_MULTI_POINT => {
+                let count = self.count(little_endian, 21)?;
+                MultiPoint::new(
+                    (0..count)
+                        .map(|_| {
+                            let little_endian = self.expect(WKB_POINT)?;
+                            self.point(little_endian)
+                        })
+                        .collect::<Result<_>>()?,
+                )
+                .into()
+            }
+            WKB_MULTI_LINE_STRING => {
+                let count = self.count(little_endian, 9)?;
+                MultiLineString::new(
+                    (0..count)
+                        .map(|_| {
+                            let little_endian = self.expect(WKB_LINE_STRING)?;
+                            self.coords(little_endian)
+                        })
+                        .collect::<Result<_>>()?,
+                )
+                .into()
+            }
+            WKB_MULTI_POLYGON => {
+                let count = self.count(little_endian, 9)?;
+                MultiPolygon::new(
+                    (0..count)
+                        .map(|_| {
+                            let little_endian = self.expect(WKB_POLYGON)?;
+                            self.polygon(little_endian)
+                        })
+                        .collect::<Result<_>>()?,
+                )
+                .into()
+            }
+            WKB_GEOMETRY_COLLECTION => {
+                if depth == MAX_WKB_NESTING {
+                    return Err(Error::conversion(format!(
+                        "WKB geometry collections are nested more than {MAX_WKB_NESTING} deep"
+                    )));
+                }
+                let count = self.count(little_endian, 5)?;
+                GeometryCollection::new_from(
+                    (0..count)
+                        .map(|_| self.geometry(depth + 1))
+                        .collect::<Result<_>>()?,
+                )
+                .into()
+            }
+            other => {
+                return Err(Error::conversion(format!(
+                    "unsupported WKB geometry type {other}; only 2D geometries are supported"
+                )))
+            }
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::request::dml::InsertRequest;
+    use geo_types::{line_string, point, polygon, Rect};
+
+    #[test]
+    fn geometry_columns_round_trip_through_wkt() {
+        let column = FieldData::geometries(
+            "location",
+            [
+                Geometry::from(point!(x: 1.5, y: -2.0)),
+                line_string![(x: 0.0, y: 0.0), (x: 1.0, y: 1.0)].into(),
+            ],
+        );
+        assert_eq!(
+            column.as_geometry(),
+            Some(&["POINT(1.5 -2)".to_owned(), "LINESTRING(0 0,1 1)".to_owned()][..])
+        );
+        let parsed = column.to_geometries().unwrap().unwrap();
+        assert_eq!(parsed[0], Geometry::Point(point!(x: 1.5, y: -2.0)));
+
+        let nullable = FieldData::nullable(column, vec![true, false, true]).unwrap();
+        assert_eq!(nullable.to_geometries().unwrap().unwrap(), parsed);
+        assert!(FieldData::int64("id", vec![1]).to_geometries().is_none());
+        assert!(FieldData::geometry("location", vec!["POINT (1".into()])
+            .to_geometries()
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn insert_build_rejects_invalid_wkt() {
+        let columns = |value: &str| {
+            vec![
+                FieldData::int64("id", vec![1]),
+                FieldData::geometry("location", vec![value.into()]),
+            ]
+        };
+        assert!(InsertRequest::builder()
+            .collection_name("places")
+            .columns(columns("POINT (1 2)"))
+            .build()
+            .is_ok());
+        let error = InsertRequest::builder()
+            .collection_name("places")
+            .columns(columns("POINT (1 2"))
+            .build()
+            .unwrap_err();
+        assert!(error.to_string().contains("location[0]"), "{error}");
+    }
+
+    #[test]
+    fn spatial_filters_quote_the_geometry() {
+        let area: Geometry<f64> = polygon![
+            (x: 0.0, y: 0.0),
+            (x: 1.0, y: 0.0),
+            (x: 1.0, y: 1.0),
+        ]
+        .into();
+        assert_eq!(
+            st_intersects("location", &area),
+            r#"ST_INTERSECTS(location, "POLYGON((0 0,1 0,1 1,0 0))")"#
+        );
+        assert_eq!(
+            st_contains("location", &point!(x: 0.5, y: 0.5).into()),
+            r#"ST_CONTAINS(location, "POINT(0.5 0.5)")"#
+        );
+        assert_eq!(
+            st_dwithin("location", &point!(x: 1.0, y: 2.0).into(), 500.0).unwrap(),
+            r#"ST_DWITHIN(location, "POINT(1 2)", 500.0)"#
+        );
+        for distance in [-1.0, f64::NAN, f64::INFINITY] {
+            assert!(st_dwithin("location", &point!(x: 1.0, y: 2.0).into(), distance).is_err());
+        }
+    }
+
+    #[test]
+    fn wkb_round_trips_and_reads_both_byte_orders() {
+        let geometries: Vec<Geometry<f64>> = vec![
+            point!(x: 1.0, y: 2.0).into(),
+            line_string![(x: 0.0, y: 0.0), (x: 3.0, y: 4.0)].into(),
+            polygon![(x: 0.0, y: 0.0), (x: 2.0, y: 0.0), (x: 2.0, y: 2.0)].into(),
+            MultiPoint::new(vec![point!(x: 1.0, y: 1.0), point!(x: 2.0, y: 2.0)]).into(),
+            GeometryCollection::new_from(vec![point!(x: 5.0, y: 6.0).into()]).into(),
+        ];
+        for geometry in &geometries {
+            assert_eq!(&from_wkb(&to_wkb(geometry)).unwrap(), geometry);
+        }
+        assert_eq!(
+            to_wkb(&Rect::new((0.0, 0.0), (1.0, 1.0)).into()),
+            to_wkb(&Rect::new((0.0, 0.0), (1.0, 1.0)).to_polygon().into())
+        );
+
+        let big_endian = [
+            0, 0, 0, 0, 1, 0x3f, 0xf0, 0, 0, 0, 0, 0, 0, 0x40, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert_eq!(wkb_to_wkt(&big_endian).unwrap(), "POINT(1 2)");
+        assert!(from_wkb(&big_endian[..20]).is_err());
+        assert!(from_wkb(&[1, 2, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]).is_err());
+        assert!(from_wkb(&[1, 0xe9, 0x03, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn deeply_nested_collections_are_rejected_without_recursing() {
+        let nested = |depth: usize| {
+            let mut bytes = [1, 7, 0, 0, 0, 1, 0, 0, 0].repeat(depth);
+            bytes.extend_from_slice(&[1, 7, 0, 0, 0, 0, 0, 0, 0]);
+            bytes
+        };
+        assert!(from_wkb(&nested(MAX_WKB_NESTING - 1)).is_ok());
+        let error = from_wkb(&nested(MAX_WKB_NESTING)).unwrap_err();
+        assert!(error.to_string().contains("nested"), "{error}");
+        assert!(wkb_to_wkt(&nested(1_000_000)).is_err());
+    }
+}
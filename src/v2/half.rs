@@ -0,0 +1,151 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Native half-precision vectors, enabled by the `half` feature.
+//!
+//! Float16 and BFloat16 columns store IEEE bit patterns as `u16`. The methods in this module
+//! accept and return [`f16`] and [`bf16`] instead; both types share the `u16` layout, so rows are
+//! reinterpreted in place rather than converted element by element. Use `f16::from_f32` and
+//! `bf16::from_f32` for round-to-nearest-even conversion from `f32`.
+//!
+//! ```no_run
+//! # use milvus::v2::prelude::*;
+//! use milvus::v2::half::f16;
+//!
+//! let column = FieldData::f16_vector("embedding", vec![vec![f16::from_f32(0.5); 4]]);
+//! let vectors = SearchVectors::f16(vec![vec![f16::from_f32(0.5); 4]]);
+//! ```
+
+use crate::v2::types::{FieldData, SearchVectors};
+use half::slice::HalfBitsSliceExt;
+use half::vec::{HalfBitsVecExt, HalfFloatVecExt};
+
+pub use half::{bf16, f16};
+
+impl FieldData {
+    /// Creates a Float16 vector column from native `f16` rows.
+    pub fn f16_vector(name: impl Into<String>, values: Vec<Vec<f16>>) -> Self {
+        Self::float16_vector(name, to_bits(values))
+    }
+
+    /// Creates a BFloat16 vector column from native `bf16` rows.
+    pub fn bf16_vector(name: impl Into<String>, values: Vec<Vec<bf16>>) -> Self {
+        Self::bfloat16_vector(name, to_bits(values))
+    }
+
+    /// Borrows the rows of a Float16 vector column as native `f16` slices.
+    pub fn as_f16_vectors(&self) -> Option<Vec<&[f16]>> {
+        self.as_float16_vectors()
+            .map(|values| values.iter().map(|row| row.reinterpret_cast()).collect())
+    }
+
+    /// Borrows the rows of a BFloat16 vector column as native `bf16` slices.
+    pub fn as_bf16_vectors(&self) -> Option<Vec<&[bf16]>> {
+        self.as_bfloat16_vectors()
+            .map(|values| values.iter().map(|row| row.reinterpret_cast()).collect())
+    }
+
+    /// Moves the rows of a Float16 vector column out as native `f16` vectors.
+    pub fn into_f16_vectors(self) -> Option<Vec<Vec<f16>>> {
+        match self {
+            Self::Float16Vector { values, .. } => Some(
+                values
+                    .into_iter()
+                    .map(HalfBitsVecExt::reinterpret_into)
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Moves the rows of a BFloat16 vector column out as native `bf16` vectors.
+    pub fn into_bf16_vectors(self) -> Option<Vec<Vec<bf16>>> {
+        match self {
+            Self::BFloat16Vector { values, .. } => Some(
+                values
+                    .into_iter()
+                    .map(HalfBitsVecExt::reinterpret_into)
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+impl SearchVectors {
+    /// Creates Float16 query vectors from native `f16` rows.
+    pub fn f16(values: Vec<Vec<f16>>) -> Self {
+        Self::Float16(to_bits(values))
+    }
+
+    /// Creates BFloat16 query vectors from native `bf16` rows.
+    pub fn bf16(values: Vec<Vec<bf16>>) -> Self {
+        Self::BFloat16(to_bits(values))
+    }
+}
+
+fn to_bits<H: HalfFloatVecExt>(values: Vec<H>) -> Vec<Vec<u16>> {
+    values.into_iter().map(H::reinterpret_into).collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::utils::{f32_to_bf16, f32_to_f16};
+
+    #[test]
+    fn native_rows_share_the_bit_pattern_columns() {
+        let rows = vec![vec![f16::from_f32(1.0), f16::from_f32(-0.5)]];
+        let column = FieldData::f16_vector("embedding", rows.clone());
+        assert_eq!(
+            column.as_float16_vectors(),
+            Some(&[vec![0x3c00, 0xb800]][..])
+        );
+        assert_eq!(column.as_f16_vectors(), Some(vec![rows[0].as_slice()]));
+        assert_eq!(column.as_bf16_vectors(), None);
+        assert_eq!(column.into_f16_vectors(), Some(rows));
+
+        let rows = vec![vec![bf16::from_f32(1.0)]];
+        assert_eq!(
+            SearchVectors::bf16(rows.clone()),
+            SearchVectors::BFloat16(vec![vec![0x3f80]])
+        );
+        assert_eq!(
+            FieldData::bf16_vector("embedding", rows.clone()).into_bf16_vectors(),
+            Some(rows)
+        );
+    }
+
+    #[test]
+    fn sdk_conversions_round_like_the_half_crate() {
+        for value in [
+            0.1_f32,
+            -3.3,
+            65519.0,
+            65520.0,
+            1.0e-6,
+            2.0_f32.powi(-25),
+            1.0 + 2.0_f32.powi(-8),
+        ] {
+            assert_eq!(f32_to_f16(value), f16::from_f32(value).to_bits());
+            assert_eq!(f32_to_bf16(value), bf16::from_f32(value).to_bits());
+        }
+    }
+}
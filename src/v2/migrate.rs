@@ -0,0 +1,901 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Declarative schema migrations for existing collections.
+//!
+//! A [`Migration`] describes the schema, indexes, and collection properties a collection should
+//! have. Planning compares it with `describe_collection` and `list_indexes` and produces a
+//! [`MigrationPlan`] of the operations Milvus can apply in place, in this order: add fields,
+//! alter field properties, add or alter functions, create indexes, alter collection properties.
+//!
+//! Changes that cannot be applied in place are reported together as one validation error. They
+//! include removing fields, functions, or indexes, changing a field's data type, vector
+//! dimension, primary-key, partition-key, clustering-key, nullability, default value, or
+//! function-output settings, removing a user-set type parameter that cannot be altered (`dim`,
+//! the analyzer settings, and `enable_match`), adding a field that is not nullable, and changing
+//! an existing index or struct field. Otherwise only the type parameters, index parameters, and
+//! properties named by the desired definition are compared, so server-side defaults do not show
+//! up as changes. Descriptions are not compared.
+//!
+//! ```no_run
+//! # use milvus::v2::prelude::*;
+//! use milvus::v2::migrate::Migration;
+//!
+//! # async fn example(client: &ClientV2, schema: CollectionSchema) -> Result<()> {
+//! let plan = Migration::new("articles")
+//!     .schema(schema)
+//!     .index_param(
+//!         IndexParam::new()
+//!             .field_name("embedding")
+//!             .index_type(IndexType::Hnsw)
+//!             .metric_type(MetricType::Cosine),
+//!     )
+//!     .property("collection.ttl.seconds", "86400")
+//!     .plan(client)
+//!     .await?;
+//! println!("{plan}");
+//! plan.apply(client, |progress| println!("{progress}")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::v2::error::{Error, Result};
+use crate::v2::request::collection::{
+    AddCollectionFieldRequest, AddCollectionFunctionRequest, AlterCollectionFieldPropertiesRequest,
+    AlterCollectionFunctionRequest, AlterCollectionPropertiesRequest, DescribeCollectionRequest,
+};
+use crate::v2::request::index::{CreateIndexRequest, ListIndexesRequest};
+use crate::v2::types::{
+    CollectionDesc, CollectionSchema, FieldSchema, Function, IndexDesc, IndexParam, MetricType,
+    StructFieldSchema,
+};
+use crate::v2::ClientV2;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Field type parameters that `alter_collection_field_properties` can change in place.
+const ALTERABLE_TYPE_PARAMS: [&str; 3] = ["max_length", "max_capacity", "mmap.enabled"];
+
+/// Field type parameters that users set at creation and that no alter RPC can change, so a
+/// desired schema that omits one while the live field has it cannot be applied.
+const FIXED_TYPE_PARAMS: [&str; 5] = [
+    "dim",
+    "enable_analyzer",
+    "analyzer_params",
+    "multi_analyzer_params",
+    "enable_match",
+];
+
+///////////////////////////////////////////////////////////////////////////////
+// Migration
+///////////////////////////////////////////////////////////////////////////////
+/// Desired definition of an existing collection.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Migration {
+    pub(crate) database_name: Option<String>,
+    pub(crate) collection_name: String,
+    pub(crate) schema: CollectionSchema,
+    pub(crate) index_params: Vec<IndexParam>,
+    pub(crate) properties: HashMap<String, String>,
+}
+
+impl Migration {
+    /// Creates a migration for `collection_name` with an empty desired schema.
+    pub fn new(collection_name: impl Into<String>) -> Self {
+        Self {
+            database_name: None,
+            collection_name: collection_name.into(),
+            schema: CollectionSchema::new(),
+            index_params: Vec::new(),
+            properties: HashMap::new(),
+        }
+    }
+
+    /// Sets the database name and returns the updated value.
+    pub fn database_name(mut self, value: impl Into<String>) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the desired schema and returns the updated value.
+    pub fn schema(mut self, value: CollectionSchema) -> Self {
+        self.schema = value;
+        self
+    }
+
+    /// Sets the complete desired index set and returns the updated value.
+    pub fn index_params(mut self, value: Vec<IndexParam>) -> Self {
+        self.index_params = value;
+        self
+    }
+
+    /// Adds one desired index and returns the updated value.
+    pub fn index_param(mut self, value: IndexParam) -> Self {
+        self.index_params.push(value);
+        self
+    }
+
+    /// Sets the desired collection properties and returns the updated value.
+    ///
+    /// Properties that are not named here are left unchanged.
+    pub fn properties(mut self, value: HashMap<String, String>) -> Self {
+        self.properties = value;
+        self
+    }
+
+    /// Sets one desired collection property and returns the updated value.
+    pub fn property(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.properties.insert(key.into(), value.into());
+        self
+    }
+
+    /// Describes the live collection and its indexes and plans the migration.
+    pub async fn plan(&self, client: &ClientV2) -> Result<MigrationPlan> {
+        let mut describe =
+            DescribeCollectionRequest::builder().collection_name(&self.collection_name);
+        let mut list = ListIndexesRequest::builder().collection_name(&self.collection_name);
+        if let Some(database_name) = &self.database_name {
+            describe = describe.database_name(database_name);
+            list = list.database_name(database_name);
+        }
+        let description = client.describe_collection(describe.build()?).await?;
+        let indexes = client.list_indexes(list.build()?).await?;
+        self.diff(description.description(), indexes.indexes())
+    }
+
+    /// Plans the migration against an already described collection and its indexes.
+    pub fn diff(&self, current: &CollectionDesc, indexes: &[IndexDesc]) -> Result<MigrationPlan> {
+        let mut diff = Diff::default();
+        diff.fields(&current.schema, &self.schema);
+        diff.functions(&current.schema, &self.schema);
+        diff.indexes(indexes, &self.index_params);
+        let properties = changed(&current.properties, &self.properties);
+        if !properties.is_empty() {
+            diff.steps
+                .push(MigrationStep::AlterCollectionProperties(properties));
+        }
+        if !diff.conflicts.is_empty() {
+            return Err(Error::validation(
+                self.collection_name.clone(),
+                format!(
+                    "schema change cannot be applied in place: {}",
+                    diff.conflicts.join("; ")
+                ),
+            ));
+        }
+        diff.steps.sort_by_key(MigrationStep::order);
+        Ok(MigrationPlan {
+            database_name: self.database_name.clone(),
+            collection_name: self.collection_name.clone(),
+            steps: diff.steps,
+        })
+    }
+}
+
+#[derive(Default)]
+struct Diff {
+    steps: Vec<MigrationStep>,
+    conflicts: Vec<String>,
+}
+
+impl Diff {
+    fn fields(&mut self, current: &CollectionSchema, desired: &CollectionSchema) {
+        if current.enable_dynamic_field != desired.enable_dynamic_field {
+            self.conflicts
+                .push("the dynamic field setting cannot change".into());
+        }
+        for field in &current.fields {
+            if !field.name.starts_with('$') && !desired.fields.iter().any(|f| f.name == field.name)
+            {
+                self.conflicts
+                    .push(format!("field `{}` cannot be removed", field.name));
+            }
+        }
+        for field in &desired.fields {
+            match current.fields.iter().find(|f| f.name == field.name) {
+                Some(live) => {
+                    if is_function_output(current, &field.name)
+                        != is_function_output(desired, &field.name)
+                    {
+                        self.conflicts.push(format!(
+                            "whether field `{}` is a function output cannot change",
+                            field.name
+                        ));
+                    }
+                    self.field(live, field);
+                }
+                None if !field.nullable => self
+                    .conflicts
+                    .push(format!("added field `{}` must be nullable", field.name)),
+                None if field.is_primary_key || field.is_partition_key => {
+                    self.conflicts.push(format!(
+                        "added field `{}` cannot be a primary or partition key",
+                        field.name
+                    ))
+                }
+                None => self.steps.push(MigrationStep::AddField(field.clone())),
+            }
+        }
+        for field in &current.struct_fields {
+            match desired.struct_fields.iter().find(|f| f.name == field.name) {
+                None => self
+                    .conflicts
+                    .push(format!("struct field `{}` cannot be removed", field.name)),
+                Some(desired) if !same_struct_field(field, desired) => self
+                    .conflicts
+                    .push(format!("struct field `{}` cannot change", field.name)),
+                Some(_) => {}
+            }
+        }
+        for field in &desired.struct_fields {
+            if !current.struct_fields.iter().any(|f| f.name == field.name) {
+                self.conflicts
+                    .push(format!("struct field `{}` cannot be added", field.name));
+            }
+        }
+    }
+
+    fn field(&mut self, live: &FieldSchema, desired: &FieldSchema) {
+        let name = &desired.name;
+        for setting in fixed_settings_changed(live, desired) {
+            self.conflicts
+                .push(format!("the {setting} of field `{name}` cannot change"));
+        }
+        for key in live.type_params.keys() {
+            if !desired.type_params.contains_key(key) && FIXED_TYPE_PARAMS.contains(&key.as_str()) {
+                self.conflicts.push(format!(
+                    "type parameter `{key}` of field `{name}` cannot be removed"
+                ));
+            }
+        }
+        let mut properties = HashMap::new();
+        for (key, value) in &desired.type_params {
+            if live.type_params.get(key) == Some(value) {
+                continue;
+            }
+            if ALTERABLE_TYPE_PARAMS.contains(&key.as_str()) {
+                properties.insert(key.clone(), value.clone());
+            } else if key == "dim" {
+                self.conflicts
+                    .push(format!("the dimension of field `{name}` cannot change"));
+            } else {
+                self.conflicts.push(format!(
+                    "type parameter `{key}` of field `{name}` cannot change"
+                ));
+            }
+        }
+        if !properties.is_empty() {
+            self.steps.push(MigrationStep::AlterFieldProperties {
+                field_name: name.clone(),
+                properties,
+            });
+        }
+    }
+
+    fn functions(&mut self, current: &CollectionSchema, desired: &CollectionSchema) {
+        for function in &current.functions {
+            if !desired.functions.iter().any(|f| f.name == function.name) {
+                self.conflicts
+                    .push(format!("function `{}` cannot be removed", function.name));
+            }
+        }
+        for function in &desired.functions {
+            let Some(live) = current.functions.iter().find(|f| f.name == function.name) else {
+                self.steps
+                    .push(MigrationStep::AddFunction(function.clone()));
+                continue;
+            };
+            if live.function_type != function.function_type
+                || live.input_fields != function.input_fields
+                || live.output_fields != function.output_fields
+            {
+                self.conflicts.push(format!(
+                    "the type or fields of function `{}` cannot change",
+                    function.name
+                ));
+            } else if !changed(&live.params, &function.params).is_empty() {
+                self.steps
+                    .push(MigrationStep::AlterFunction(function.clone()));
+            }
+        }
+    }
+
+    fn indexes(&mut self, current: &[IndexDesc], desired: &[IndexParam]) {
+        let matches = |live: &IndexDesc, index: &IndexParam| {
+            if index.index_name.is_empty() {
+                live.field_name == index.field_name
+            } else {
+                live.index_name == index.index_name
+            }
+        };
+        for live in current {
+            if !desired.iter().any(|index| matches(live, index)) {
+                self.conflicts
+                    .push(format!("index `{}` cannot be removed", live.index_name));
+            }
+        }
+        for index in desired {
+            let Some(live) = current.iter().find(|live| matches(live, index)) else {
+                self.steps.push(MigrationStep::CreateIndex(index.clone()));
+                continue;
+            };
+            if live.field_name != index.field_name
+                || live.index_type != index.index_type
+                || index
+                    .metric_type
+                    .is_some_and(|metric_type| metric_type != live.metric_type)
+                || !changed(&live.extra_params, &index.extra_params).is_empty()
+            {
+                self.conflicts.push(format!(
+                    "index `{}` cannot change; drop and recreate it",
+                    live.index_name
+                ));
+            }
+        }
+    }
+}
+
+/// Returns the settings of a field that differ and that no alter RPC can change.
+///
+/// Descriptions are ignored, and index parameters are compared only for the keys `desired` sets.
+fn fixed_settings_changed(live: &FieldSchema, desired: &FieldSchema) -> Vec<&'static str> {
+    [
+        ("data type", live.data_type != desired.data_type),
+        ("element type", live.element_type != desired.element_type),
+        ("primary key", live.is_primary_key != desired.is_primary_key),
+        ("auto ID", live.auto_id != desired.auto_id),
+        (
+            "partition key",
+            live.is_partition_key != desired.is_partition_key,
+        ),
+        (
+            "clustering key",
+            live.is_clustering_key != desired.is_clustering_key,
+        ),
+        ("nullability", live.nullable != desired.nullable),
+        ("default value", live.default_value != desired.default_value),
+        (
+            "index parameters",
+            !changed(&live.index_params, &desired.index_params).is_empty(),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(setting, differs)| differs.then_some(setting))
+    .collect()
+}
+
+/// Compares struct fields by their capacity and the schema of their nested fields, ignoring
+/// descriptions.
+fn same_struct_field(live: &StructFieldSchema, desired: &StructFieldSchema) -> bool {
+    live.max_capacity == desired.max_capacity
+        && live.fields.len() == desired.fields.len()
+        && live
+            .fields
+            .iter()
+            .zip(&desired.fields)
+            .all(|(live, desired)| {
+                live.name == desired.name
+                    && live.type_params == desired.type_params
+                    && fixed_settings_changed(live, desired).is_empty()
+            })
+}
+
+fn is_function_output(schema: &CollectionSchema, field_name: &str) -> bool {
+    schema
+        .functions
+        .iter()
+        .any(|function| function.output_fields.iter().any(|name| name == field_name))
+}
+
+/// Returns the desired entries whose value differs from the current one.
+fn changed(
+    current: &HashMap<String, String>,
+    desired: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    desired
+        .iter()
+        .filter(|(key, value)| current.get(*key) != Some(value))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// MigrationStep
+///////////////////////////////////////////////////////////////////////////////
+/// One operation of a migration plan.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum MigrationStep {
+    /// Adds a nullable field with `add_collection_field`.
+    AddField(FieldSchema),
+    /// Changes field type parameters with `alter_collection_field_properties`.
+    AlterFieldProperties {
+        /// Field to alter.
+        field_name: String,
+        /// Properties to set.
+        properties: HashMap<String, String>,
+    },
+    /// Adds a function with `add_collection_function`.
+    AddFunction(Function),
+    /// Changes function parameters with `alter_collection_function`.
+    AlterFunction(Function),
+    /// Creates an index with `create_index`.
+    CreateIndex(IndexParam),
+    /// Changes collection properties with `alter_collection_properties`.
+    AlterCollectionProperties(HashMap<String, String>),
+}
+
+impl MigrationStep {
+    fn order(&self) -> u8 {
+        match self {
+            Self::AddField(_) => 0,
+            Self::AlterFieldProperties { .. } => 1,
+            Self::AddFunction(_) | Self::AlterFunction(_) => 2,
+            Self::CreateIndex(_) => 3,
+            Self::AlterCollectionProperties(_) => 4,
+        }
+    }
+
+    async fn apply(&self, client: &ClientV2, plan: &MigrationPlan) -> Result<()> {
+        let database = plan.database_name.as_deref();
+        let collection = plan.collection_name.as_str();
+        macro_rules! scoped {
+            ($builder:expr) => {{
+                let builder = $builder.collection_name(collection);
+                let builder = match database {
+                    Some(database) => builder.database_name(database),
+                    None => builder,
+                };
+                builder.build()?
+            }};
+        }
+        match self {
+            Self::AddField(field) => {
+                client
+                    .add_collection_field(scoped!(
+                        AddCollectionFieldRequest::builder().field(field.clone())
+                    ))
+                    .await
+            }
+            Self::AlterFieldProperties {
+                field_name,
+                properties,
+            } => {
+                client
+                    .alter_collection_field_properties(scoped!(
+                        AlterCollectionFieldPropertiesRequest::builder()
+                            .field_name(field_name)
+                            .properties(properties.clone())
+                    ))
+                    .await
+            }
+            Self::AddFunction(function) => {
+                client
+                    .add_collection_function(scoped!(
+                        AddCollectionFunctionRequest::builder().function(function.clone())
+                    ))
+                    .await
+            }
+            Self::AlterFunction(function) => {
+                client
+                    .alter_collection_function(scoped!(
+                        AlterCollectionFunctionRequest::builder().function(function.clone())
+                    ))
+                    .await
+            }
+            Self::CreateIndex(index) => {
+                client
+                    .create_index(scoped!(
+                        CreateIndexRequest::builder().index_param(index.clone())
+                    ))
+                    .await
+            }
+            Self::AlterCollectionProperties(properties) => {
+                client
+                    .alter_collection_properties(scoped!(
+                        AlterCollectionPropertiesRequest::builder().properties(properties.clone())
+                    ))
+                    .await
+            }
+        }
+    }
+}
+
+impl fmt::Display for MigrationStep {
+    /// Renders the step as one line of a dry-run listing.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddField(field) => {
+                write!(f, "add field `{}` ({:?}", field.name, field.data_type)?;
+                if let Some(element_type) = field.element_type {
+                    write!(f, " of {element_type:?}")?;
+                }
+                write!(f, ", nullable)")
+            }
+            Self::AlterFieldProperties {
+                field_name,
+                properties,
+            } => write!(
+                f,
+                "alter field `{field_name}` properties: {}",
+                render(properties)
+            ),
+            Self::AddFunction(function) | Self::AlterFunction(function) => write!(
+                f,
+                "{} function `{}` ({:?}: {} -> {})",
+                if matches!(self, Self::AddFunction(_)) {
+                    "add"
+                } else {
+                    "alter"
+                },
+                function.name,
+                function.function_type,
+                function.input_fields.join(", "),
+                function.output_fields.join(", ")
+            ),
+            Self::CreateIndex(index) => {
+                write!(
+                    f,
+                    "create index on `{}` ({}",
+                    index.field_name,
+                    index.index_type.as_str()
+                )?;
+                if let Some(metric_type) = index.metric_type.filter(|m| *m != MetricType::Default) {
+                    write!(f, ", {}", metric_type.as_str())?;
+                }
+                if !index.extra_params.is_empty() {
+                    write!(f, ", {}", render(&index.extra_params))?;
+                }
+                write!(f, ")")
+            }
+            Self::AlterCollectionProperties(properties) => {
+                write!(f, "alter collection properties: {}", render(properties))
+            }
+        }
+    }
+}
+
+fn render(properties: &HashMap<String, String>) -> String {
+    properties
+        .iter()
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// MigrationPlan
+///////////////////////////////////////////////////////////////////////////////
+/// Ordered operations that bring a collection to its desired definition.
+///
+/// The `Display` implementation renders a numbered dry-run listing.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct MigrationPlan {
+    pub(crate) database_name: Option<String>,
+    pub(crate) collection_name: String,
+    pub(crate) steps: Vec<MigrationStep>,
+}
+
+impl MigrationPlan {
+    /// Returns the planned steps in execution order.
+    pub fn steps(&self) -> &[MigrationStep] {
+        &self.steps
+    }
+
+    /// Returns whether the collection already matches its desired definition.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Executes the steps in order, calling `progress` before each one.
+    ///
+    /// Execution stops at the first failing step; steps already applied are not rolled back, and
+    /// planning again yields the remaining steps.
+    pub async fn apply(
+        &self,
+        client: &ClientV2,
+        mut progress: impl FnMut(&MigrationProgress<'_>),
+    ) -> Result<()> {
+        for (index, step) in self.steps.iter().enumerate() {
+            progress(&MigrationProgress {
+                step,
+                index,
+                total: self.steps.len(),
+            });
+            step.apply(client, self).await?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for MigrationPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.steps.is_empty() {
+            return write!(f, "collection `{}` is up to date", self.collection_name);
+        }
+        write!(
+            f,
+            "migration plan for collection `{}`:",
+            self.collection_name
+        )?;
+        for (index, step) in self.steps.iter().enumerate() {
+            write!(f, "\n{}. {step}", index + 1)?;
+        }
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// MigrationProgress
+///////////////////////////////////////////////////////////////////////////////
+/// Step about to run during [`MigrationPlan::apply`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct MigrationProgress<'a> {
+    pub(crate) step: &'a MigrationStep,
+    pub(crate) index: usize,
+    pub(crate) total: usize,
+}
+
+impl MigrationProgress<'_> {
+    /// Returns the step about to run.
+    pub fn step(&self) -> &MigrationStep {
+        self.step
+    }
+
+    /// Returns the zero-based position of the step.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of steps in the plan.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+}
+
+impl fmt::Display for MigrationProgress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}/{}] {}", self.index + 1, self.total, self.step)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::types::{DataType, DefaultValue, FunctionType, IndexType};
+
+    fn live_schema() -> CollectionSchema {
+        CollectionSchema::new()
+            .add_field(
+                FieldSchema::new()
+                    .name("id")
+                    .data_type(DataType::Int64)
+                    .primary_key(true),
+            )
+            .add_field(
+                FieldSchema::new()
+                    .name("text")
+                    .data_type(DataType::VarChar)
+                    .max_length(256)
+                    .enable_analyzer(true),
+            )
+            .add_field(
+                FieldSchema::new()
+                    .name("embedding")
+                    .data_type(DataType::FloatVector)
+                    .dimension(8),
+            )
+    }
+
+    fn live_index() -> IndexDesc {
+        IndexDesc::new()
+            .index_name("embedding")
+            .field_name("embedding")
+            .index_type(IndexType::Hnsw)
+            .metric_type(MetricType::Cosine)
+            .extra_params(HashMap::from([("M".into(), "16".into())]))
+    }
+
+    fn desired_index() -> IndexParam {
+        IndexParam::new()
+            .field_name("embedding")
+            .index_type(IndexType::Hnsw)
+            .metric_type(MetricType::Cosine)
+    }
+
+    fn current() -> CollectionDesc {
+        CollectionDesc::new()
+            .collection_name("articles")
+            .schema(live_schema())
+            .properties(HashMap::from([(
+                "collection.ttl.seconds".into(),
+                "0".into(),
+            )]))
+    }
+
+    #[test]
+    fn additive_changes_are_planned_in_order() {
+        let schema = live_schema()
+            .add_field(
+                FieldSchema::new()
+                    .name("sparse")
+                    .data_type(DataType::SparseFloatVector)
+                    .nullable(true),
+            )
+            .add_function(
+                Function::new()
+                    .name("bm25")
+                    .function_type(FunctionType::Bm25)
+                    .input_fields(["text"])
+                    .output_fields(["sparse"]),
+            );
+        let mut schema = schema;
+        schema.fields[1] = schema.fields[1].clone().max_length(512);
+        let plan = Migration::new("articles")
+            .schema(schema)
+            .index_params(vec![
+                desired_index(),
+                IndexParam::new()
+                    .field_name("sparse")
+                    .index_type(IndexType::SparseInvertedIndex)
+                    .metric_type(MetricType::Bm25),
+            ])
+            .property("collection.ttl.seconds", "60")
+            .diff(&current(), &[live_index()])
+            .unwrap();
+
+        assert_eq!(
+            plan.to_string(),
+            "migration plan for collection `articles`:\n\
+             1. add field `sparse` (SparseFloatVector, nullable)\n\
+             2. alter field `text` properties: max_length=512\n\
+             3. add function `bm25` (Bm25: text -> sparse)\n\
+             4. create index on `sparse` (SPARSE_INVERTED_INDEX, BM25)\n\
+             5. alter collection properties: collection.ttl.seconds=60"
+        );
+        let progress = MigrationProgress {
+            step: &plan.steps()[0],
+            index: 0,
+            total: plan.steps().len(),
+        };
+        assert_eq!(
+            progress.to_string(),
+            "[1/5] add field `sparse` (SparseFloatVector, nullable)"
+        );
+    }
+
+    #[test]
+    fn matching_definitions_produce_an_empty_plan() {
+        let plan = Migration::new("articles")
+            .schema(live_schema())
+            .index_param(desired_index())
+            .property("collection.ttl.seconds", "0")
+            .diff(&current(), &[live_index()])
+            .unwrap();
+        assert!(plan.is_empty());
+        assert_eq!(plan.to_string(), "collection `articles` is up to date");
+    }
+
+    #[test]
+    fn changes_that_need_a_rebuild_are_reported_together() {
+        let mut schema = live_schema();
+        schema.fields[2] = schema.fields[2].clone().dimension(16);
+        schema.fields[0] = schema.fields[0].clone().primary_key(false);
+        schema.fields.push(
+            FieldSchema::new()
+                .name("category")
+                .data_type(DataType::VarChar)
+                .max_length(32),
+        );
+        let error = Migration::new("articles")
+            .schema(schema)
+            .index_param(desired_index().index_type(IndexType::DiskAnn))
+            .diff(&current(), &[live_index()])
+            .unwrap_err()
+            .to_string();
+        for expected in [
+            "primary key of field `id`",
+            "dimension of field `embedding`",
+            "added field `category` must be nullable",
+            "index `embedding` cannot change",
+        ] {
+            assert!(error.contains(expected), "{error}");
+        }
+
+        let removed = Migration::new("articles")
+            .schema(CollectionSchema::new().add_field(live_schema().fields[0].clone()))
+            .diff(&current(), &[live_index()])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            removed.contains("field `text` cannot be removed"),
+            "{removed}"
+        );
+        assert!(
+            removed.contains("index `embedding` cannot be removed"),
+            "{removed}"
+        );
+    }
+
+    #[test]
+    fn field_comparison_ignores_descriptions_only() {
+        let chunks = |description: &str, max_capacity| {
+            StructFieldSchema::new()
+                .name("chunks")
+                .description(description)
+                .max_capacity(max_capacity)
+                .add_field(
+                    FieldSchema::new()
+                        .name("page")
+                        .data_type(DataType::Int32)
+                        .description(description),
+                )
+        };
+        let mut live = live_schema().add_struct_field(chunks("live", 8));
+        live.fields[1]
+            .type_params
+            .insert("server.default".into(), "on".into());
+        let current = current().schema(live);
+        let mut described = live_schema().add_struct_field(chunks("desired", 8));
+        described.fields[1] = described.fields[1].clone().description("body text");
+        let plan = Migration::new("articles")
+            .schema(described)
+            .index_param(desired_index())
+            .property("collection.ttl.seconds", "0")
+            .diff(&current, &[live_index()])
+            .unwrap();
+        assert!(plan.is_empty());
+
+        let mut schema = live_schema()
+            .add_struct_field(chunks("live", 16))
+            .add_function(
+                Function::new()
+                    .name("embed")
+                    .function_type(FunctionType::TextEmbedding)
+                    .input_fields(["text"])
+                    .output_fields(["embedding"]),
+            );
+        schema.fields[0] = schema.fields[0]
+            .clone()
+            .default_value(DefaultValue::Int64(0));
+        schema.fields[1].type_params.remove("enable_analyzer");
+        schema.fields[2] = schema.fields[2].clone().index_param("mmap.enabled", "true");
+        let error = Migration::new("articles")
+            .schema(schema)
+            .index_param(desired_index())
+            .diff(&current, &[live_index()])
+            .unwrap_err()
+            .to_string();
+        for expected in [
+            "default value of field `id`",
+            "type parameter `enable_analyzer` of field `text` cannot be removed",
+            "index parameters of field `embedding`",
+            "whether field `embedding` is a function output",
+            "struct field `chunks` cannot change",
+        ] {
+            assert!(error.contains(expected), "{error}");
+        }
+    }
+}
@@ -42,18 +42,39 @@
 
 #![warn(missing_docs)]
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod bm25;
 pub mod bulk_import;
 pub mod client;
+#[cfg(any(feature = "arrow", feature = "polars"))]
+mod columnar;
+pub mod embedding;
 pub mod error;
+pub mod eval;
+#[cfg(feature = "geo")]
+pub mod geo;
+#[cfg(feature = "half")]
+pub mod half;
+pub mod migrate;
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+#[cfg(feature = "polars")]
+pub mod polars;
 pub mod prelude;
 pub mod request;
+pub mod rerank;
 pub mod response;
+pub mod retry;
+#[cfg(feature = "sprs")]
+pub mod sprs;
 pub mod types;
 pub mod utils;
 
 pub use bulk_import::*;
 pub use client::{
-    ClientV2, OptimizeTask, QueryIterator, SearchIterator, SearchIteratorV1, SearchIteratorV2,
+    ClientV2, OptimizeTask, QueryIterator, SearchBatcher, SearchIterator, SearchIteratorV1,
+    SearchIteratorV2, SearchPage, SearchPageToken,
 };
 pub use types::*;
 pub use utils::*;
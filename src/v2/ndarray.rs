@@ -0,0 +1,392 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `ndarray` interoperability, enabled by the `ndarray` feature.
+//!
+//! Vector insert columns and query vectors are built from an [`ArrayView2`] holding one vector per
+//! row. The element type selects the Milvus vector type, and the column count is checked against
+//! the `dim` of the target [`FieldSchema`]:
+//!
+//! | Element | Milvus field | Columns |
+//! | --- | --- | --- |
+//! | `f32` | `FloatVector` | `dim` |
+//! | `u8` | `BinaryVector` | `dim / 8` |
+//! | `i8` | `Int8Vector` | `dim` |
+//! | [`f16`] | `Float16Vector` | `dim` |
+//! | [`bf16`] | `BFloat16Vector` | `dim` |
+//!
+//! Float vectors are stored as [`DenseVectors`], so inserting or searching an `f32` array copies
+//! it once into the wire buffer. Result columns convert back with [`FieldData::to_array2`]; float
+//! vectors decoded with `dense_vectors(true)` can also be borrowed in place with
+//! [`FieldData::as_array_view`].
+//!
+//! ```no_run
+//! # use milvus::v2::prelude::*;
+//! use milvus::v2::ndarray::Array2;
+//!
+//! # fn example(embeddings: &Array2<f32>, field: &FieldSchema) -> Result<()> {
+//! let column = FieldData::from_array2(field, embeddings.view())?;
+//! let vectors = SearchVectors::from_array2(field, embeddings.view())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::v2::error::{Error, Result};
+use crate::v2::types::{DataType, DenseVectors, FieldData, FieldSchema, SearchVectors};
+
+pub use half::{bf16, f16};
+pub use ndarray::{Array2, ArrayView2};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for u8 {}
+    impl Sealed for i8 {}
+    impl Sealed for half::f16 {}
+    impl Sealed for half::bf16 {}
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// VectorElement
+///////////////////////////////////////////////////////////////////////////////
+/// Array element types that store the rows of one Milvus vector type.
+pub trait VectorElement: Copy + sealed::Sealed {
+    /// Vector field type whose rows hold elements of this type.
+    const DATA_TYPE: DataType;
+
+    #[doc(hidden)]
+    fn row_width(dim: usize) -> usize {
+        dim
+    }
+
+    #[doc(hidden)]
+    fn column(name: String, rows: ArrayView2<'_, Self>) -> FieldData;
+
+    #[doc(hidden)]
+    fn search_vectors(rows: ArrayView2<'_, Self>) -> SearchVectors;
+
+    #[doc(hidden)]
+    fn to_array(column: &FieldData) -> Option<Result<Array2<Self>>>;
+}
+
+impl VectorElement for f32 {
+    const DATA_TYPE: DataType = DataType::FloatVector;
+
+    fn column(name: String, rows: ArrayView2<'_, Self>) -> FieldData {
+        FieldData::dense_float_vector(name, dense_vectors(rows))
+    }
+
+    fn search_vectors(rows: ArrayView2<'_, Self>) -> SearchVectors {
+        SearchVectors::DenseFloat(dense_vectors(rows))
+    }
+
+    fn to_array(column: &FieldData) -> Option<Result<Array2<Self>>> {
+        if let Some(values) = column.as_dense_float_vectors() {
+            return Some(Ok(values.as_array_view().to_owned()));
+        }
+        column
+            .as_float_vectors()
+            .map(|values| stack(column.name(), values, |value| value))
+    }
+}
+
+impl VectorElement for u8 {
+    const DATA_TYPE: DataType = DataType::BinaryVector;
+
+    fn row_width(dim: usize) -> usize {
+        dim.div_ceil(8)
+    }
+
+    fn column(name: String, rows: ArrayView2<'_, Self>) -> FieldData {
+        FieldData::binary_vector(name, nested(rows, |value| value))
+    }
+
+    fn search_vectors(rows: ArrayView2<'_, Self>) -> SearchVectors {
+        SearchVectors::Binary(nested(rows, |value| value))
+    }
+
+    fn to_array(column: &FieldData) -> Option<Result<Array2<Self>>> {
+        column
+            .as_binary_vectors()
+            .map(|values| stack(column.name(), values, |value| value))
+    }
+}
+
+impl VectorElement for i8 {
+    const DATA_TYPE: DataType = DataType::Int8Vector;
+
+    fn column(name: String, rows: ArrayView2<'_, Self>) -> FieldData {
+        FieldData::int8_vector(name, nested(rows, |value| value))
+    }
+
+    fn search_vectors(rows: ArrayView2<'_, Self>) -> SearchVectors {
+        SearchVectors::Int8(nested(rows, |value| value))
+    }
+
+    fn to_array(column: &FieldData) -> Option<Result<Array2<Self>>> {
+        column
+            .as_int8_vectors()
+            .map(|values| stack(column.name(), values, |value| value))
+    }
+}
+
+impl VectorElement for f16 {
+    const DATA_TYPE: DataType = DataType::Float16Vector;
+
+    fn column(name: String, rows: ArrayView2<'_, Self>) -> FieldData {
+        FieldData::float16_vector(name, nested(rows, f16::to_bits))
+    }
+
+    fn search_vectors(rows: ArrayView2<'_, Self>) -> SearchVectors {
+        SearchVectors::Float16(nested(rows, f16::to_bits))
+    }
+
+    fn to_array(column: &FieldData) -> Option<Result<Array2<Self>>> {
+        column
+            .as_float16_vectors()
+            .map(|values| stack(column.name(), values, f16::from_bits))
+    }
+}
+
+impl VectorElement for bf16 {
+    const DATA_TYPE: DataType = DataType::BFloat16Vector;
+
+    fn column(name: String, rows: ArrayView2<'_, Self>) -> FieldData {
+        FieldData::bfloat16_vector(name, nested(rows, bf16::to_bits))
+    }
+
+    fn search_vectors(rows: ArrayView2<'_, Self>) -> SearchVectors {
+        SearchVectors::BFloat16(nested(rows, bf16::to_bits))
+    }
+
+    fn to_array(column: &FieldData) -> Option<Result<Array2<Self>>> {
+        column
+            .as_bfloat16_vectors()
+            .map(|values| stack(column.name(), values, bf16::from_bits))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Conversions
+///////////////////////////////////////////////////////////////////////////////
+
+impl FieldData {
+    /// Builds the insert column for vector `field` from an array holding one vector per row.
+    pub fn from_array2<T: VectorElement>(
+        field: &FieldSchema,
+        values: ArrayView2<'_, T>,
+    ) -> Result<Self> {
+        check_shape(field, &values)?;
+        Ok(T::column(field.name.clone(), values))
+    }
+
+    /// Copies the vectors of this column into an array holding one vector per row.
+    ///
+    /// Fails when `T` does not match the vector type of the column or when the column contains
+    /// null rows.
+    pub fn to_array2<T: VectorElement>(&self) -> Result<Array2<T>> {
+        if let Self::Nullable { data, valid_data } = self {
+            if valid_data.iter().any(|valid| !valid) {
+                return Err(Error::conversion(format!(
+                    "vector field {} contains null rows",
+                    self.name()
+                )));
+            }
+            return data.to_array2();
+        }
+        T::to_array(self).unwrap_or_else(|| {
+            Err(Error::conversion(format!(
+                "field {} is not a {:?} column",
+                self.name(),
+                T::DATA_TYPE
+            )))
+        })
+    }
+
+    /// Borrows the vectors of a [`FieldData::DenseFloatVector`] column without copying.
+    pub fn as_array_view(&self) -> Option<ArrayView2<'_, f32>> {
+        self.as_dense_float_vectors()
+            .map(DenseVectors::as_array_view)
+    }
+}
+
+impl SearchVectors {
+    /// Builds the query vectors for vector `field` from an array holding one vector per row.
+    pub fn from_array2<T: VectorElement>(
+        field: &FieldSchema,
+        values: ArrayView2<'_, T>,
+    ) -> Result<Self> {
+        check_shape(field, &values)?;
+        Ok(T::search_vectors(values))
+    }
+}
+
+impl DenseVectors {
+    /// Borrows the buffer as an array holding one vector per row.
+    pub fn as_array_view(&self) -> ArrayView2<'_, f32> {
+        ArrayView2::from_shape((self.len(), self.dim), &self.data)
+            .expect("buffer length is a multiple of the dimension")
+    }
+}
+
+fn check_shape<T: VectorElement>(field: &FieldSchema, values: &ArrayView2<'_, T>) -> Result<()> {
+    if field.data_type != T::DATA_TYPE {
+        return Err(Error::validation(
+            field.name.clone(),
+            format!(
+                "a {:?} field cannot be built from {:?} rows",
+                field.data_type,
+                T::DATA_TYPE
+            ),
+        ));
+    }
+    let dim = field.get_dimension() as usize;
+    if dim == 0 {
+        return Err(Error::validation(
+            field.name.clone(),
+            "the schema does not declare a vector dimension".into(),
+        ));
+    }
+    let width = T::row_width(dim);
+    if values.ncols() != width {
+        return Err(Error::validation(
+            field.name.clone(),
+            format!(
+                "expected {width} columns for the schema dimension {dim}, got {}",
+                values.ncols()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn dense_vectors(rows: ArrayView2<'_, f32>) -> DenseVectors {
+    DenseVectors {
+        dim: rows.ncols(),
+        data: rows.iter().copied().collect(),
+    }
+}
+
+fn nested<T: Copy, U>(rows: ArrayView2<'_, T>, convert: impl Fn(T) -> U) -> Vec<Vec<U>> {
+    rows.rows()
+        .into_iter()
+        .map(|row| row.iter().copied().map(&convert).collect())
+        .collect()
+}
+
+fn stack<T: Copy, U>(name: &str, rows: &[Vec<T>], convert: impl Fn(T) -> U) -> Result<Array2<U>> {
+    let width = rows.first().map_or(0, Vec::len);
+    if rows.iter().any(|row| row.len() != width) {
+        return Err(Error::conversion(format!(
+            "vector field {name} has rows of different dimensions"
+        )));
+    }
+    let data = rows.iter().flatten().copied().map(convert).collect();
+    Array2::from_shape_vec((rows.len(), width), data)
+        .map_err(|error| Error::conversion(format!("cannot build vector array: {error}")))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn vector_field(data_type: DataType, dim: u32) -> FieldSchema {
+        FieldSchema::new()
+            .name("embedding")
+            .data_type(data_type)
+            .dimension(dim)
+    }
+
+    #[test]
+    fn float_arrays_become_dense_columns_and_query_vectors() {
+        let field = vector_field(DataType::FloatVector, 2);
+        let values = array![[0.1f32, 0.2], [0.3, 0.4]];
+
+        let column = FieldData::from_array2(&field, values.view()).unwrap();
+        assert_eq!(column.as_array_view(), Some(values.view()));
+        assert_eq!(column.to_array2::<f32>().unwrap(), values);
+
+        let transposed = array![[0.1f32, 0.3], [0.2, 0.4]];
+        let vectors = SearchVectors::from_array2(&field, transposed.t()).unwrap();
+        assert_eq!(
+            vectors,
+            SearchVectors::DenseFloat(DenseVectors::new(2, vec![0.1, 0.2, 0.3, 0.4]).unwrap())
+        );
+    }
+
+    #[test]
+    fn array_shapes_and_element_types_are_checked_against_the_schema() {
+        let values = array![[0.1f32, 0.2, 0.3]];
+        assert!(
+            FieldData::from_array2(&vector_field(DataType::FloatVector, 2), values.view()).is_err()
+        );
+        assert!(
+            FieldData::from_array2(&vector_field(DataType::Int8Vector, 3), values.view()).is_err()
+        );
+
+        let binary = array![[0xffu8, 0x00], [0x0f, 0xf0]];
+        let column =
+            FieldData::from_array2(&vector_field(DataType::BinaryVector, 16), binary.view())
+                .unwrap();
+        assert_eq!(
+            column.as_binary_vectors(),
+            Some(&[vec![0xff, 0x00], vec![0x0f, 0xf0]][..])
+        );
+        assert!(column.to_array2::<i8>().is_err());
+    }
+
+    #[test]
+    fn half_precision_rows_round_trip_through_bit_patterns() {
+        let values = array![[f16::from_f32(1.0), f16::from_f32(-2.5)]];
+        let column =
+            FieldData::from_array2(&vector_field(DataType::Float16Vector, 2), values.view())
+                .unwrap();
+        assert_eq!(
+            column.as_float16_vectors(),
+            Some(&[vec![0x3c00, 0xc100]][..])
+        );
+        assert_eq!(column.to_array2::<f16>().unwrap(), values);
+
+        let values = array![[bf16::from_f32(1.0)]];
+        let vectors =
+            SearchVectors::from_array2(&vector_field(DataType::BFloat16Vector, 1), values.view())
+                .unwrap();
+        assert_eq!(vectors, SearchVectors::BFloat16(vec![vec![0x3f80]]));
+    }
+
+    #[test]
+    fn nested_result_columns_are_copied_into_arrays_unless_they_contain_nulls() {
+        let column = FieldData::float_vector("embedding", vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        assert_eq!(
+            column.to_array2::<f32>().unwrap(),
+            array![[1.0, 2.0], [3.0, 4.0]]
+        );
+        assert!(column.as_array_view().is_none());
+
+        let nullable = FieldData::nullable(
+            FieldData::int8_vector("embedding", vec![vec![1, 2]]),
+            vec![true, false],
+        )
+        .unwrap();
+        assert!(nullable.to_array2::<i8>().is_err());
+    }
+}
@@ -0,0 +1,751 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Polars DataFrame conversions, enabled by the `polars` feature.
+//!
+//! Query and search results convert to a [`DataFrame`] with one typed column per field. Search
+//! results produce one row per hit, starting with a `query_index` column that identifies the
+//! query vector, followed by the primary-key and score columns.
+//!
+//! | Milvus field | Polars column |
+//! | --- | --- |
+//! | `Bool`, `Int8`..`Int64`, `Float`, `Double` | matching numeric dtype |
+//! | `VarChar`, `Geometry`, `Timestamptz` | `String` |
+//! | `Json` | `String` holding JSON text; `Struct` is also accepted on insert |
+//! | `Array` | `List` of the element dtype |
+//! | `FloatVector`, `Int8Vector` | `List(Float32)`, `List(Int8)`; `Array` is accepted on insert |
+//! | `BinaryVector`, `Float16Vector`, `BFloat16Vector` | `Binary`, little-endian for 16-bit types |
+//! | `SparseFloatVector` | `List(Struct { index: UInt32, value: Float32 })` |
+//! | `Struct` | `String` holding each row's JSON array |
+//!
+//! [`InsertRequestBuilder::from_dataframe`] infers the Milvus type of each column from its dtype.
+//! Columns whose dtype is ambiguous, such as 16-bit vectors or `List(Float32)` scalar arrays, need
+//! [`InsertRequestBuilder::from_dataframe_with_schema`], which reads every column as the type the
+//! collection declares.
+
+use crate::v2::columnar::{self, expand, half_word_bytes, json_texts, nullable_parts};
+use crate::v2::error::{Error, Result};
+use crate::v2::request::dml::{InsertRequest, InsertRequestBuilder};
+use crate::v2::response::dql::{QueryResponse, SearchResponse};
+use crate::v2::types::{
+    CollectionSchema, DataType, FieldData, FieldSchema, Ids, QueryResults, SearchResults,
+    SparseVector,
+};
+use polars::prelude::{
+    Column, DataType as PolarsType, Field, IntoSeries, NamedFrom, PolarsError, Series,
+    StructChunked,
+};
+use serde_json::Value;
+
+pub use polars::prelude::DataFrame;
+
+impl InsertRequestBuilder {
+    /// Starts an insert request whose columns are read from a DataFrame, inferring each Milvus
+    /// type from the column dtype.
+    ///
+    /// `List(Float32)` and `Array(Float32)` columns become float vectors, `Binary` columns binary
+    /// vectors, `Struct` columns JSON, and other lists scalar arrays. Columns containing nulls
+    /// become nullable columns.
+    pub fn from_dataframe(frame: &DataFrame) -> Result<Self> {
+        let columns = frame
+            .get_columns()
+            .iter()
+            .map(|column| {
+                let series = column.as_materialized_series();
+                let (data_type, element_type) = infer(series.dtype()).ok_or_else(|| {
+                    Error::validation(
+                        series.name().to_string(),
+                        format!("cannot infer a Milvus type for dtype {}", series.dtype()),
+                    )
+                })?;
+                let (column, validity) = read_column(series, data_type, element_type)?;
+                match validity {
+                    Some(validity) => column.with_validity(validity),
+                    None => Ok(column),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(InsertRequest::builder().columns(columns))
+    }
+
+    /// Starts an insert request whose columns are read from a DataFrame as the types declared by
+    /// the collection schema.
+    pub fn from_dataframe_with_schema(
+        frame: &DataFrame,
+        schema: &CollectionSchema,
+    ) -> Result<Self> {
+        let columns = frame
+            .get_columns()
+            .iter()
+            .map(|column| {
+                let series = column.as_materialized_series();
+                let field = schema
+                    .fields
+                    .iter()
+                    .find(|field| field.name == series.name().as_str())
+                    .ok_or_else(|| {
+                        Error::validation(
+                            series.name().to_string(),
+                            "column is not present in collection schema".into(),
+                        )
+                    })?;
+                column_from_schema(series, field)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(InsertRequest::builder().columns(columns))
+    }
+}
+
+impl QueryResults {
+    /// Converts the query output fields into a DataFrame with one column per field.
+    pub fn to_dataframe(&self) -> Result<DataFrame> {
+        let columns = self
+            .output_fields
+            .iter()
+            .map(column_to_polars)
+            .collect::<Result<Vec<_>>>()?;
+        DataFrame::new(columns).map_err(polars_error)
+    }
+}
+
+impl QueryResponse {
+    /// Converts the query results into a DataFrame; see [`QueryResults::to_dataframe`].
+    pub fn to_dataframe(&self) -> Result<DataFrame> {
+        self.results.to_dataframe()
+    }
+}
+
+impl SearchResults {
+    /// Converts the hits of every query into one DataFrame with a leading `query_index` column.
+    pub fn to_dataframe(&self) -> Result<DataFrame> {
+        let hits = self.flatten()?;
+        let ids = match hits.ids {
+            Ids::Int64(values) => Series::new(hits.id_name.as_str().into(), values),
+            Ids::VarChar(values) => Series::new(hits.id_name.as_str().into(), values),
+        };
+        let mut columns = vec![
+            Column::from(Series::new("query_index".into(), hits.query_index)),
+            Column::from(ids),
+            Column::from(Series::new(hits.score_name.as_str().into(), hits.scores)),
+        ];
+        for field in &hits.output_fields {
+            columns.push(column_to_polars(field)?);
+        }
+        DataFrame::new(columns).map_err(polars_error)
+    }
+}
+
+impl SearchResponse {
+    /// Converts the search hits into a DataFrame; see [`SearchResults::to_dataframe`].
+    pub fn to_dataframe(&self) -> Result<DataFrame> {
+        self.results.to_dataframe()
+    }
+}
+
+fn polars_error(error: PolarsError) -> Error {
+    Error::conversion(format!("Polars conversion failed: {error}"))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// DataFrame -> FieldData
+///////////////////////////////////////////////////////////////////////////////
+
+/// Reads a numeric column cast to `$dtype`, failing instead of producing nulls for values that
+/// do not fit.
+macro_rules! numbers {
+    ($series:expr, $dtype:ident, $accessor:ident) => {
+        $series
+            .strict_cast(&PolarsType::$dtype)
+            .and_then(|series| Ok(series.$accessor()?.into_iter().collect::<Vec<_>>()))
+            .map_err(polars_error)?
+    };
+}
+
+/// Returns the Milvus type and array element type implied by a Polars dtype.
+fn infer(dtype: &PolarsType) -> Option<(DataType, Option<DataType>)> {
+    let scalar = |dtype: &PolarsType| match dtype {
+        PolarsType::Boolean => Some(DataType::Bool),
+        PolarsType::Int8 => Some(DataType::Int8),
+        PolarsType::Int16 => Some(DataType::Int16),
+        PolarsType::Int32 => Some(DataType::Int32),
+        PolarsType::Int64 => Some(DataType::Int64),
+        PolarsType::Float32 => Some(DataType::Float),
+        PolarsType::Float64 => Some(DataType::Double),
+        PolarsType::String => Some(DataType::VarChar),
+        _ => None,
+    };
+    match dtype {
+        PolarsType::Binary => Some((DataType::BinaryVector, None)),
+        PolarsType::Struct(_) => Some((DataType::Json, None)),
+        PolarsType::List(inner) | PolarsType::Array(inner, _) => match inner.as_ref() {
+            PolarsType::Float32 => Some((DataType::FloatVector, None)),
+            PolarsType::Struct(_) => Some((DataType::SparseFloatVector, None)),
+            inner => scalar(inner).map(|element| (DataType::Array, Some(element))),
+        },
+        dtype => scalar(dtype).map(|data_type| (data_type, None)),
+    }
+}
+
+fn column_from_schema(series: &Series, field: &FieldSchema) -> Result<FieldData> {
+    let (column, validity) = read_column(series, field.data_type, field.element_type)?;
+    match validity {
+        None => Ok(column),
+        Some(_) if !field.nullable => Err(Error::validation(
+            field.name.clone(),
+            "column contains nulls but the field is not nullable".into(),
+        )),
+        Some(validity) => column.with_validity(validity),
+    }
+}
+
+/// Reads a column as `data_type`, returning compact values and a validity bitmap when any row is
+/// null.
+fn read_column(
+    series: &Series,
+    data_type: DataType,
+    element_type: Option<DataType>,
+) -> Result<(FieldData, Option<Vec<bool>>)> {
+    let name = series.name().to_string();
+    let invalid = |reason: String| Error::validation(name.clone(), reason);
+    let column = match data_type {
+        DataType::Bool => split(bools(series)?, |values| FieldData::boolean(&name, values)),
+        DataType::Int8 => split(numbers!(series, Int8, i8), |values| {
+            FieldData::int8(&name, values)
+        }),
+        DataType::Int16 => split(numbers!(series, Int16, i16), |values| {
+            FieldData::int16(&name, values)
+        }),
+        DataType::Int32 => split(numbers!(series, Int32, i32), |values| {
+            FieldData::int32(&name, values)
+        }),
+        DataType::Int64 => split(numbers!(series, Int64, i64), |values| {
+            FieldData::int64(&name, values)
+        }),
+        DataType::Float => split(numbers!(series, Float32, f32), |values| {
+            FieldData::float(&name, values)
+        }),
+        DataType::Double => split(numbers!(series, Float64, f64), |values| {
+            FieldData::double(&name, values)
+        }),
+        DataType::VarChar => split(strings(series)?, |values| FieldData::varchar(&name, values)),
+        DataType::Geometry => split(strings(series)?, |values| {
+            FieldData::geometry(&name, values)
+        }),
+        DataType::Timestamptz => split(strings(series)?, |values| {
+            FieldData::timestamptz(&name, values)
+        }),
+        DataType::Json => split(json_column(series)?, |values| {
+            FieldData::json(&name, values)
+        }),
+        DataType::Array => {
+            let element_type = element_type.unwrap_or_default();
+            match element_type {
+                DataType::Bool => split(lists(series, bools)?, |values| {
+                    FieldData::array_bool(&name, values)
+                }),
+                DataType::Int8 => split(
+                    lists(series, |row| Ok(numbers!(row, Int8, i8)))?,
+                    |values| FieldData::array_int8(&name, values),
+                ),
+                DataType::Int16 => split(
+                    lists(series, |row| Ok(numbers!(row, Int16, i16)))?,
+                    |values| FieldData::array_int16(&name, values),
+                ),
+                DataType::Int32 => split(
+                    lists(series, |row| Ok(numbers!(row, Int32, i32)))?,
+                    |values| FieldData::array_int32(&name, values),
+                ),
+                DataType::Int64 => split(
+                    lists(series, |row| Ok(numbers!(row, Int64, i64)))?,
+                    |values| FieldData::array_int64(&name, values),
+                ),
+                DataType::Float => split(
+                    lists(series, |row| Ok(numbers!(row, Float32, f32)))?,
+                    |values| FieldData::array_float(&name, values),
+                ),
+                DataType::Double => split(
+                    lists(series, |row| Ok(numbers!(row, Float64, f64)))?,
+                    |values| FieldData::array_double(&name, values),
+                ),
+                DataType::VarChar => split(lists(series, strings)?, |values| {
+                    FieldData::array_varchar(&name, values)
+                }),
+                other => {
+                    return Err(invalid(format!(
+                        "{other:?} array elements cannot be read from Polars columns"
+                    )))
+                }
+            }
+        }
+        DataType::FloatVector => split(
+            lists(series, |row| Ok(numbers!(row, Float32, f32)))?,
+            |values| FieldData::float_vector(&name, values),
+        ),
+        DataType::Int8Vector => split(
+            lists(series, |row| Ok(numbers!(row, Int8, i8)))?,
+            |values| FieldData::int8_vector(&name, values),
+        ),
+        DataType::BinaryVector => split(binary(series)?, |values| {
+            FieldData::binary_vector(&name, values)
+        }),
+        DataType::Float16Vector => split(half_words(series)?, |values| {
+            FieldData::float16_vector(&name, values)
+        }),
+        DataType::BFloat16Vector => split(half_words(series)?, |values| {
+            FieldData::bfloat16_vector(&name, values)
+        }),
+        DataType::SparseFloatVector => split(sparse_rows(series)?, |values| {
+            FieldData::sparse_float_vector(&name, values)
+        }),
+        DataType::Struct | DataType::Unknown => {
+            return Err(invalid(format!(
+                "{data_type:?} fields cannot be read from Polars columns"
+            )))
+        }
+    };
+    Ok(column)
+}
+
+fn split<T>(
+    rows: Vec<Option<T>>,
+    column: impl FnOnce(Vec<T>) -> FieldData,
+) -> (FieldData, Option<Vec<bool>>) {
+    let (values, validity) = columnar::split(rows);
+    (column(values), validity)
+}
+
+fn bools(series: &Series) -> Result<Vec<Option<bool>>> {
+    series
+        .strict_cast(&PolarsType::Boolean)
+        .and_then(|series| Ok(series.bool()?.into_iter().collect()))
+        .map_err(polars_error)
+}
+
+fn strings(series: &Series) -> Result<Vec<Option<String>>> {
+    series
+        .str()
+        .map(|values| {
+            values
+                .into_iter()
+                .map(|value| value.map(str::to_owned))
+                .collect()
+        })
+        .map_err(polars_error)
+}
+
+fn binary(series: &Series) -> Result<Vec<Option<Vec<u8>>>> {
+    series
+        .binary()
+        .map(|values| {
+            values
+                .into_iter()
+                .map(|row| row.map(<[u8]>::to_vec))
+                .collect()
+        })
+        .map_err(polars_error)
+}
+
+fn half_words(series: &Series) -> Result<Vec<Option<Vec<u16>>>> {
+    binary(series)?
+        .into_iter()
+        .map(|row| {
+            row.map(|bytes| columnar::half_words(series.name(), &bytes))
+                .transpose()
+        })
+        .collect()
+}
+
+/// Reads every list row with `read`, rejecting null elements inside a row.
+fn lists<T>(
+    series: &Series,
+    read: impl Fn(&Series) -> Result<Vec<Option<T>>>,
+) -> Result<Vec<Option<Vec<T>>>> {
+    let series = match series.dtype() {
+        PolarsType::Array(inner, _) => series
+            .cast(&PolarsType::List(inner.clone()))
+            .map_err(polars_error)?,
+        _ => series.clone(),
+    };
+    let rows = series.list().map_err(polars_error)?;
+    rows.into_iter()
+        .map(|row| {
+            row.map(|row| {
+                read(&row)?
+                    .into_iter()
+                    .map(|value| {
+                        value.ok_or_else(|| {
+                            Error::validation(
+                                series.name().to_string(),
+                                "list elements cannot be null".into(),
+                            )
+                        })
+                    })
+                    .collect()
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+fn sparse_rows(series: &Series) -> Result<Vec<Option<SparseVector>>> {
+    lists(series, |row| {
+        let entries = row.struct_().map_err(polars_error)?;
+        let field = |name: &str, dtype: PolarsType| {
+            entries
+                .field_by_name(name)
+                .and_then(|field| field.strict_cast(&dtype))
+                .map_err(polars_error)
+        };
+        let indices = field("index", PolarsType::UInt32)?;
+        let values = field("value", PolarsType::Float32)?;
+        let indices = indices.u32().map_err(polars_error)?;
+        let values = values.f32().map_err(polars_error)?;
+        Ok(indices
+            .into_iter()
+            .zip(values)
+            .map(|entry| match entry {
+                (Some(index), Some(value)) => Some((index, value)),
+                _ => None,
+            })
+            .collect())
+    })
+    .map(|rows| {
+        rows.into_iter()
+            .map(|row| row.map(|entries| entries.into_iter().collect()))
+            .collect()
+    })
+}
+
+/// Reads JSON text columns, or converts struct and list columns to JSON objects and arrays.
+fn json_column(series: &Series) -> Result<Vec<Option<Value>>> {
+    if series.dtype() == &PolarsType::String {
+        return strings(series)?
+            .into_iter()
+            .map(|text| {
+                text.map(|text| {
+                    serde_json::from_str(&text).map_err(|error| {
+                        Error::validation(
+                            series.name().to_string(),
+                            format!("invalid JSON: {error}"),
+                        )
+                    })
+                })
+                .transpose()
+            })
+            .collect();
+    }
+    json_values(series)
+}
+
+fn json_values(series: &Series) -> Result<Vec<Option<Value>>> {
+    let dtype = series.dtype();
+    let values = if dtype.is_bool() {
+        bools(series)?
+            .into_iter()
+            .map(|value| value.map(Value::from))
+            .collect()
+    } else if dtype.is_integer() {
+        numbers!(series, Int64, i64)
+            .into_iter()
+            .map(|value| value.map(Value::from))
+            .collect()
+    } else if dtype.is_float() {
+        numbers!(series, Float64, f64)
+            .into_iter()
+            .map(|value| value.map(Value::from))
+            .collect()
+    } else {
+        match dtype {
+            PolarsType::String => strings(series)?
+                .into_iter()
+                .map(|value| value.map(Value::from))
+                .collect(),
+            PolarsType::Null => vec![None; series.len()],
+            PolarsType::List(_) | PolarsType::Array(..) => lists(series, json_values)?
+                .into_iter()
+                .map(|value| value.map(Value::from))
+                .collect(),
+            PolarsType::Struct(_) => {
+                let entries = series.struct_().map_err(polars_error)?;
+                let fields = entries
+                    .fields_as_series()
+                    .iter()
+                    .map(|field| Ok((field.name().to_string(), json_values(field)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                series
+                    .is_null()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(row, is_null)| {
+                        (is_null != Some(true)).then(|| {
+                            Value::Object(
+                                fields
+                                    .iter()
+                                    .map(|(name, values)| {
+                                        (name.clone(), values[row].clone().unwrap_or(Value::Null))
+                                    })
+                                    .collect(),
+                            )
+                        })
+                    })
+                    .collect()
+            }
+            dtype => {
+                return Err(Error::validation(
+                    series.name().to_string(),
+                    format!("dtype {dtype} cannot be converted to JSON"),
+                ))
+            }
+        }
+    };
+    Ok(values)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// FieldData -> DataFrame
+///////////////////////////////////////////////////////////////////////////////
+
+/// Expands compact values to owned optional rows, as the Polars series constructors take them.
+fn owned_rows<T: Clone>(values: &[T], validity: Option<&[bool]>) -> Vec<Option<T>> {
+    expand(values, validity)
+        .into_iter()
+        .map(|value| value.cloned())
+        .collect()
+}
+
+fn list_series<T>(name: &str, rows: Vec<Option<Vec<T>>>) -> Series
+where
+    Series: NamedFrom<Vec<T>, [T]>,
+{
+    let rows = rows
+        .into_iter()
+        .map(|row| row.map(|row| Series::new("".into(), row)))
+        .collect::<Vec<_>>();
+    Series::new(name.into(), rows)
+}
+
+fn column_to_polars(column: &FieldData) -> Result<Column> {
+    let (data, validity) = nullable_parts(column);
+    let name = column.name().into();
+    let series = match data {
+        FieldData::Bool { values, .. } => Series::new(name, owned_rows(values, validity)),
+        FieldData::Int8 { values, .. } => Series::new(name, owned_rows(values, validity)),
+        FieldData::Int16 { values, .. } => Series::new(name, owned_rows(values, validity)),
+        FieldData::Int32 { values, .. } => Series::new(name, owned_rows(values, validity)),
+        FieldData::Int64 { values, .. } => Series::new(name, owned_rows(values, validity)),
+        FieldData::Float { values, .. } => Series::new(name, owned_rows(values, validity)),
+        FieldData::Double { values, .. } => Series::new(name, owned_rows(values, validity)),
+        FieldData::VarChar { values, .. }
+        | FieldData::Geometry { values, .. }
+        | FieldData::Timestamptz { values, .. } => Series::new(name, owned_rows(values, validity)),
+        FieldData::Json { values, .. } => Series::new(name, json_texts(values, validity)?),
+        FieldData::Struct { values, .. } => Series::new(name, json_texts(values, validity)?),
+        FieldData::ArrayBool { values, .. } => {
+            list_series(column.name(), owned_rows(values, validity))
+        }
+        FieldData::ArrayInt8 { values, .. } | FieldData::Int8Vector { values, .. } => {
+            list_series(column.name(), owned_rows(values, validity))
+        }
+        FieldData::ArrayInt16 { values, .. } => {
+            list_series(column.name(), owned_rows(values, validity))
+        }
+        FieldData::ArrayInt32 { values, .. } => {
+            list_series(column.name(), owned_rows(values, validity))
+        }
+        FieldData::ArrayInt64 { values, .. } => {
+            list_series(column.name(), owned_rows(values, validity))
+        }
+        FieldData::ArrayFloat { values, .. } | FieldData::FloatVector { values, .. } => {
+            list_series(column.name(), owned_rows(values, validity))
+        }
+        FieldData::ArrayDouble { values, .. } => {
+            list_series(column.name(), owned_rows(values, validity))
+        }
+        FieldData::DenseFloatVector { values, .. } => {
+            list_series(column.name(), owned_rows(&values.to_rows(), validity))
+        }
+        FieldData::ArrayVarChar { values, .. } => {
+            list_series(column.name(), owned_rows(values, validity))
+        }
+        FieldData::BinaryVector { values, .. } => Series::new(name, owned_rows(values, validity)),
+        FieldData::Float16Vector { values, .. } | FieldData::BFloat16Vector { values, .. } => {
+            let bytes = values
+                .iter()
+                .map(|row| half_word_bytes(row))
+                .collect::<Vec<_>>();
+            Series::new(name, owned_rows(&bytes, validity))
+        }
+        FieldData::SparseFloatVector { values, .. } => {
+            let rows = owned_rows(values, validity)
+                .into_iter()
+                .map(|row| row.map(|row| sparse_entries(&row)).transpose())
+                .collect::<Result<Vec<_>>>()?;
+            let series = Series::new(name, rows);
+            if series.is_empty() {
+                series
+                    .cast(&PolarsType::List(Box::new(sparse_dtype())))
+                    .map_err(polars_error)?
+            } else {
+                series
+            }
+        }
+        FieldData::Nullable { .. } => {
+            return Err(Error::conversion(format!(
+                "field {} has nested validity",
+                column.name()
+            )))
+        }
+    };
+    Ok(Column::from(series))
+}
+
+fn sparse_dtype() -> PolarsType {
+    PolarsType::Struct(vec![
+        Field::new("index".into(), PolarsType::UInt32),
+        Field::new("value".into(), PolarsType::Float32),
+    ])
+}
+
+fn sparse_entries(row: &SparseVector) -> Result<Series> {
+    let indices = Series::new("index".into(), row.indices().to_vec());
+    let values = Series::new("value".into(), row.values().to_vec());
+    StructChunked::from_series("".into(), row.len(), [indices, values].iter())
+        .map(|entries| entries.into_series())
+        .map_err(polars_error)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::types::SingleResult;
+    use serde_json::json;
+
+    fn columns() -> Vec<FieldData> {
+        vec![
+            FieldData::int64("id", vec![1, 2]),
+            FieldData::varchar("title", vec!["first".into()])
+                .with_validity(vec![true, false])
+                .unwrap(),
+            FieldData::json("meta", vec![json!({"year": 2020}), json!({"year": 2021})]),
+            FieldData::array_int32("tags", vec![vec![1, 2], vec![]]),
+            FieldData::float_vector("vector", vec![vec![0.1, 0.2], vec![0.3, 0.4]]),
+        ]
+    }
+
+    #[test]
+    fn query_results_round_trip_through_inferred_dataframes() {
+        let results = QueryResults::new().output_fields(columns());
+        let frame = results.to_dataframe().unwrap();
+        assert_eq!(frame.height(), 2);
+        assert_eq!(
+            frame.column("vector").unwrap().dtype(),
+            &PolarsType::List(Box::new(PolarsType::Float32))
+        );
+
+        let schema = CollectionSchema::new()
+            .add_field(FieldSchema::new().name("id").data_type(DataType::Int64))
+            .add_field(
+                FieldSchema::new()
+                    .name("title")
+                    .data_type(DataType::VarChar)
+                    .nullable(true),
+            )
+            .add_field(FieldSchema::new().name("meta").data_type(DataType::Json))
+            .add_field(
+                FieldSchema::new()
+                    .name("tags")
+                    .data_type(DataType::Array)
+                    .element_type(DataType::Int32),
+            )
+            .add_field(
+                FieldSchema::new()
+                    .name("vector")
+                    .data_type(DataType::FloatVector)
+                    .dimension(2),
+            );
+        let request = InsertRequestBuilder::from_dataframe_with_schema(&frame, &schema)
+            .unwrap()
+            .collection_name("books")
+            .build()
+            .unwrap();
+        assert_eq!(request.columns(), columns().as_slice());
+
+        let inferred = InsertRequestBuilder::from_dataframe(&frame)
+            .unwrap()
+            .collection_name("books")
+            .build()
+            .unwrap();
+        assert_eq!(inferred.columns()[4], columns()[4]);
+        assert_eq!(inferred.columns()[3], columns()[3]);
+    }
+
+    #[test]
+    fn struct_columns_are_inserted_as_json() {
+        let year = Series::new("year".into(), [2020i64, 2021]);
+        let title = Series::new("title".into(), ["a", "b"]);
+        let meta = StructChunked::from_series("meta".into(), 2, [year, title].iter())
+            .unwrap()
+            .into_series();
+        let frame = DataFrame::new(vec![Column::from(meta)]).unwrap();
+
+        let request = InsertRequestBuilder::from_dataframe(&frame)
+            .unwrap()
+            .collection_name("books")
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.columns()[0],
+            FieldData::json(
+                "meta",
+                vec![
+                    json!({"year": 2020, "title": "a"}),
+                    json!({"year": 2021, "title": "b"}),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn search_dataframes_have_one_row_per_hit() {
+        let results = SearchResults::new().results(vec![
+            SingleResult::new()
+                .ids(Ids::VarChar(vec!["a".into(), "b".into()]))
+                .scores(vec![0.9, 0.8])
+                .primary_field_name("pk"),
+            SingleResult::new()
+                .ids(Ids::VarChar(vec!["c".into()]))
+                .scores(vec![0.7])
+                .primary_field_name("pk"),
+        ]);
+        let frame = results.to_dataframe().unwrap();
+        let names = frame
+            .get_column_names()
+            .into_iter()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["query_index", "pk", "score"]);
+        let query_index = frame.column("query_index").unwrap();
+        let query_index = query_index.as_materialized_series().u32().unwrap();
+        assert_eq!(
+            query_index.into_iter().collect::<Vec<_>>(),
+            [Some(0), Some(0), Some(1)]
+        );
+    }
+}
@@ -18,8 +18,17 @@
 
 use crate::proto::milvus;
 use crate::v2::error::Result;
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::required;
 
+request_options! {
+    CreateAliasRequest => CreateAliasRequestBuilder,
+    AlterAliasRequest => AlterAliasRequestBuilder,
+    DropAliasRequest => DropAliasRequestBuilder,
+    DescribeAliasRequest => DescribeAliasRequestBuilder,
+    ListAliasesRequest => ListAliasesRequestBuilder,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // CreateAliasRequest
 ///////////////////////////////////////////////////////////////////////////////
@@ -30,6 +39,7 @@ pub struct CreateAliasRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) alias: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl CreateAliasRequest {
@@ -38,6 +48,7 @@ impl CreateAliasRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             alias: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -124,6 +135,7 @@ pub struct AlterAliasRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) alias: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl AlterAliasRequest {
@@ -132,6 +144,7 @@ impl AlterAliasRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             alias: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -217,6 +230,7 @@ impl AlterAliasRequestBuilder {
 pub struct DropAliasRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) alias: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropAliasRequest {
@@ -224,6 +238,7 @@ impl DropAliasRequest {
         Self {
             database_name: Default::default(),
             alias: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -296,6 +311,7 @@ impl DropAliasRequestBuilder {
 pub struct DescribeAliasRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) alias: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DescribeAliasRequest {
@@ -303,6 +319,7 @@ impl DescribeAliasRequest {
         Self {
             database_name: Default::default(),
             alias: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -375,6 +392,7 @@ impl DescribeAliasRequestBuilder {
 pub struct ListAliasesRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl ListAliasesRequest {
@@ -382,6 +400,7 @@ impl ListAliasesRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
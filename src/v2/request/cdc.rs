@@ -18,7 +18,15 @@
 
 use crate::proto::{common, milvus};
 use crate::v2::error::{Error, Result};
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::required;
+
+request_options! {
+    UpdateReplicateConfigurationRequest => UpdateReplicateConfigurationRequestBuilder,
+    GetReplicateConfigurationRequest => GetReplicateConfigurationRequestBuilder,
+    GetReplicateInfoRequest => GetReplicateInfoRequestBuilder,
+    DumpMessagesRequest => DumpMessagesRequestBuilder,
+}
 pub use crate::v2::types::{
     CrossClusterTopology, ReplicateCluster, ReplicateConfiguration, ReplicateMessageId, WalName,
 };
@@ -32,6 +40,7 @@ pub use crate::v2::types::{
 pub struct UpdateReplicateConfigurationRequest {
     pub(crate) configuration: ReplicateConfiguration,
     pub(crate) force_promote: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl UpdateReplicateConfigurationRequest {
@@ -39,6 +48,7 @@ impl UpdateReplicateConfigurationRequest {
         Self {
             configuration: ReplicateConfiguration::new(),
             force_promote: false,
+            options: Default::default(),
         }
     }
 }
@@ -106,19 +116,25 @@ impl UpdateReplicateConfigurationRequestBuilder {
 // GetReplicateConfigurationRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 get_replicate_configuration operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct GetReplicateConfigurationRequest;
+pub struct GetReplicateConfigurationRequest {
+    pub(crate) options: RequestOptions,
+}
 
 impl GetReplicateConfigurationRequest {
     /// Creates a builder for this request.
     pub fn builder() -> GetReplicateConfigurationRequestBuilder {
-        GetReplicateConfigurationRequestBuilder
+        GetReplicateConfigurationRequestBuilder {
+            value: Self {
+                options: RequestOptions::default(),
+            },
+        }
     }
 
     /// Converts this request back into a builder while preserving its current values.
     pub fn into_builder(self) -> GetReplicateConfigurationRequestBuilder {
-        GetReplicateConfigurationRequestBuilder
+        GetReplicateConfigurationRequestBuilder { value: self }
     }
 
     pub(crate) fn into_proto(self) -> milvus::GetReplicateConfigurationRequest {
@@ -130,13 +146,15 @@ impl GetReplicateConfigurationRequest {
 // GetReplicateConfigurationRequestBuilder
 ///////////////////////////////////////////////////////////////////////////////
 /// Builder for GetReplicateConfigurationRequest.
-#[derive(Debug, Clone, Copy)]
-pub struct GetReplicateConfigurationRequestBuilder;
+#[derive(Debug, Clone)]
+pub struct GetReplicateConfigurationRequestBuilder {
+    value: GetReplicateConfigurationRequest,
+}
 
 impl GetReplicateConfigurationRequestBuilder {
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<GetReplicateConfigurationRequest> {
-        Ok(GetReplicateConfigurationRequest)
+        Ok(self.value)
     }
 }
 
@@ -149,6 +167,7 @@ impl GetReplicateConfigurationRequestBuilder {
 pub struct GetReplicateInfoRequest {
     pub(crate) source_cluster_id: String,
     pub(crate) target_physical_channel: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetReplicateInfoRequest {
@@ -156,6 +175,7 @@ impl GetReplicateInfoRequest {
         Self {
             source_cluster_id: Default::default(),
             target_physical_channel: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -234,6 +254,7 @@ pub struct DumpMessagesRequest {
     pub(crate) start_time_tick: u64,
     pub(crate) end_time_tick: u64,
     pub(crate) include_start_message: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl DumpMessagesRequest {
@@ -244,6 +265,7 @@ impl DumpMessagesRequest {
             start_time_tick: 0,
             end_time_tick: 0,
             include_start_message: false,
+            options: Default::default(),
         }
     }
 }
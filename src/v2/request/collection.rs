@@ -18,19 +18,50 @@
 
 use crate::proto::{common, milvus};
 use crate::v2::error::{Error, Result};
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::{non_empty_strings, positive_i32, required};
+use crate::v2::response::collection::DescribeCollectionResponse;
+use crate::v2::response::index::DescribeIndexResponse;
 use crate::v2::types::{
-    CollectionSchema, ConsistencyLevel, DataType, FieldSchema, Function, IndexParam, IndexType,
-    MetricType,
+    CollectionDesc, CollectionSchema, ConsistencyLevel, DataType, FieldSchema, Function, IndexDesc,
+    IndexParam, IndexType, MetricType,
 };
 use std::collections::{HashMap, HashSet};
 
+request_options! {
+    CreateCollectionRequest => CreateCollectionRequestBuilder,
+    CreateSimpleCollectionRequest => CreateSimpleCollectionRequestBuilder,
+    DropCollectionRequest => DropCollectionRequestBuilder,
+    HasCollectionRequest => HasCollectionRequestBuilder,
+    ReleaseCollectionRequest => ReleaseCollectionRequestBuilder,
+    DescribeCollectionRequest => DescribeCollectionRequestBuilder,
+    LoadCollectionRequest => LoadCollectionRequestBuilder,
+    RefreshLoadRequest => RefreshLoadRequestBuilder,
+    BatchDescribeCollectionsRequest => BatchDescribeCollectionsRequestBuilder,
+    GetCollectionStatsRequest => GetCollectionStatsRequestBuilder,
+    ListCollectionsRequest => ListCollectionsRequestBuilder,
+    GetLoadStateRequest => GetLoadStateRequestBuilder,
+    AlterCollectionPropertiesRequest => AlterCollectionPropertiesRequestBuilder,
+    DropCollectionPropertiesRequest => DropCollectionPropertiesRequestBuilder,
+    AlterCollectionFieldPropertiesRequest => AlterCollectionFieldPropertiesRequestBuilder,
+    DropCollectionFieldPropertiesRequest => DropCollectionFieldPropertiesRequestBuilder,
+    AddCollectionFieldRequest => AddCollectionFieldRequestBuilder,
+    AddCollectionFunctionRequest => AddCollectionFunctionRequestBuilder,
+    AlterCollectionFunctionRequest => AlterCollectionFunctionRequestBuilder,
+    DropCollectionFunctionRequest => DropCollectionFunctionRequestBuilder,
+    TruncateCollectionRequest => TruncateCollectionRequestBuilder,
+    DescribeReplicasRequest => DescribeReplicasRequestBuilder,
+    RenameCollectionRequest => RenameCollectionRequestBuilder,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // CreateCollectionRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 create_collection operation.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "CreateCollectionRequestSpec"))]
 pub struct CreateCollectionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
@@ -40,7 +71,13 @@ pub struct CreateCollectionRequest {
     pub(crate) num_shards: i32,
     pub(crate) consistency_level: ConsistencyLevel,
     pub(crate) index_params: Vec<IndexParam>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::v2::types::serialize_sorted")
+    )]
     pub(crate) properties: HashMap<String, String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) options: RequestOptions,
 }
 
 impl CreateCollectionRequest {
@@ -135,6 +172,7 @@ impl CreateCollectionRequest {
             consistency_level: ConsistencyLevel::Bounded,
             index_params: Vec::new(),
             properties: HashMap::new(),
+            options: Default::default(),
         }
     }
 }
@@ -171,7 +209,128 @@ impl From<CreateSimpleCollectionRequest> for CreateCollectionRequest {
                 .index_type(IndexType::AutoIndex)
                 .metric_type(value.metric_type)],
             properties: HashMap::new(),
+            options: value.options,
+        }
+    }
+}
+
+impl TryFrom<CollectionDesc> for CreateCollectionRequest {
+    type Error = Error;
+
+    /// Recreates the definition of a described collection without indexes.
+    fn try_from(value: CollectionDesc) -> Result<Self> {
+        Self::try_from((value, Vec::new()))
+    }
+}
+
+impl TryFrom<(CollectionDesc, Vec<IndexDesc>)> for CreateCollectionRequest {
+    type Error = Error;
+
+    /// Recreates the definition of a described collection together with its described indexes.
+    fn try_from((value, indexes): (CollectionDesc, Vec<IndexDesc>)) -> Result<Self> {
+        let num_shards = i32::try_from(value.num_shards).map_err(|_| {
+            Error::validation(
+                "num_shards".into(),
+                format!("{} does not fit in an i32", value.num_shards),
+            )
+        })?;
+        let has_partition_key = value
+            .schema
+            .fields
+            .iter()
+            .any(|field| field.is_partition_key);
+        CreateCollectionRequest {
+            database_name: (!value.database_name.is_empty()).then_some(value.database_name),
+            collection_name: value.collection_name,
+            description: (!value.description.is_empty()).then_some(value.description),
+            schema: Some(value.schema),
+            num_partitions: if has_partition_key {
+                value.num_partitions
+            } else {
+                0
+            },
+            num_shards,
+            consistency_level: value.consistency_level,
+            index_params: indexes.into_iter().map(IndexParam::from).collect(),
+            properties: value.properties,
+            options: RequestOptions::default(),
+        }
+        .into_builder()
+        .build()
+    }
+}
+
+impl TryFrom<DescribeCollectionResponse> for CreateCollectionRequest {
+    type Error = Error;
+
+    fn try_from(value: DescribeCollectionResponse) -> Result<Self> {
+        value.description.try_into()
+    }
+}
+
+impl TryFrom<(DescribeCollectionResponse, DescribeIndexResponse)> for CreateCollectionRequest {
+    type Error = Error;
+
+    fn try_from(
+        (collection, indexes): (DescribeCollectionResponse, DescribeIndexResponse),
+    ) -> Result<Self> {
+        (collection.description, indexes.indexes).try_into()
+    }
+}
+
+/// Serialized form of [`CreateCollectionRequest`], validated through its builder when read.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct CreateCollectionRequestSpec {
+    database_name: Option<String>,
+    collection_name: String,
+    description: Option<String>,
+    schema: Option<CollectionSchema>,
+    num_partitions: i64,
+    num_shards: i32,
+    consistency_level: ConsistencyLevel,
+    index_params: Vec<IndexParam>,
+    properties: HashMap<String, String>,
+}
+
+#[cfg(feature = "serde")]
+impl Default for CreateCollectionRequestSpec {
+    fn default() -> Self {
+        let value = CreateCollectionRequest::empty();
+        Self {
+            database_name: value.database_name,
+            collection_name: value.collection_name,
+            description: value.description,
+            schema: value.schema,
+            num_partitions: value.num_partitions,
+            num_shards: value.num_shards,
+            consistency_level: value.consistency_level,
+            index_params: value.index_params,
+            properties: value.properties,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CreateCollectionRequestSpec> for CreateCollectionRequest {
+    type Error = Error;
+
+    fn try_from(value: CreateCollectionRequestSpec) -> Result<Self> {
+        CreateCollectionRequest {
+            database_name: value.database_name,
+            collection_name: value.collection_name,
+            description: value.description,
+            schema: value.schema,
+            num_partitions: value.num_partitions,
+            num_shards: value.num_shards,
+            consistency_level: value.consistency_level,
+            index_params: value.index_params,
+            properties: value.properties,
+            options: RequestOptions::default(),
         }
+        .into_builder()
+        .build()
     }
 }
 
@@ -319,6 +478,7 @@ pub struct CreateSimpleCollectionRequest {
     pub(crate) enable_dynamic_field: bool,
     pub(crate) consistency_level: ConsistencyLevel,
     pub(crate) metric_type: MetricType,
+    pub(crate) options: RequestOptions,
 }
 
 impl CreateSimpleCollectionRequest {
@@ -404,6 +564,7 @@ impl CreateSimpleCollectionRequest {
             enable_dynamic_field: true,
             consistency_level: ConsistencyLevel::Bounded,
             metric_type: MetricType::Cosine,
+            options: Default::default(),
         }
     }
 }
@@ -523,6 +684,7 @@ impl CreateSimpleCollectionRequestBuilder {
 pub struct DropCollectionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropCollectionRequest {
@@ -530,6 +692,7 @@ impl DropCollectionRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -604,6 +767,7 @@ impl DropCollectionRequestBuilder {
 pub struct HasCollectionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl HasCollectionRequest {
@@ -611,6 +775,7 @@ impl HasCollectionRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -685,6 +850,7 @@ impl HasCollectionRequestBuilder {
 pub struct ReleaseCollectionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl ReleaseCollectionRequest {
@@ -692,6 +858,7 @@ impl ReleaseCollectionRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -766,6 +933,7 @@ impl ReleaseCollectionRequestBuilder {
 pub struct DescribeCollectionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DescribeCollectionRequest {
@@ -773,6 +941,7 @@ impl DescribeCollectionRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -856,6 +1025,7 @@ pub struct LoadCollectionRequest {
     pub(crate) load_fields: Vec<String>,
     pub(crate) skip_load_dynamic_field: bool,
     pub(crate) resource_groups: Vec<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl LoadCollectionRequest {
@@ -941,6 +1111,7 @@ impl LoadCollectionRequest {
             load_fields: Vec::new(),
             skip_load_dynamic_field: false,
             resource_groups: Vec::new(),
+            options: Default::default(),
         }
     }
 }
@@ -1035,6 +1206,7 @@ pub struct RefreshLoadRequest {
     /// Overall refresh wait timeout in milliseconds. A value less than or
     /// equal to zero waits indefinitely.
     pub(crate) timeout_ms: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl RefreshLoadRequest {
@@ -1088,6 +1260,7 @@ impl RefreshLoadRequest {
             collection_name: String::new(),
             sync: true,
             timeout_ms: 60_000,
+            options: Default::default(),
         }
     }
 }
@@ -1146,6 +1319,7 @@ pub struct BatchDescribeCollectionsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_names: Vec<String>,
     pub(crate) collection_ids: Vec<i64>,
+    pub(crate) options: RequestOptions,
 }
 
 impl BatchDescribeCollectionsRequest {
@@ -1154,6 +1328,7 @@ impl BatchDescribeCollectionsRequest {
             database_name: Default::default(),
             collection_names: Default::default(),
             collection_ids: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1255,6 +1430,7 @@ impl BatchDescribeCollectionsRequestBuilder {
 pub struct GetCollectionStatsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetCollectionStatsRequest {
@@ -1262,6 +1438,7 @@ impl GetCollectionStatsRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1336,6 +1513,7 @@ impl GetCollectionStatsRequestBuilder {
 pub struct ListCollectionsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) only_loaded: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl ListCollectionsRequest {
@@ -1343,6 +1521,7 @@ impl ListCollectionsRequest {
         Self {
             database_name: Default::default(),
             only_loaded: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1418,6 +1597,7 @@ pub struct GetLoadStateRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) partition_names: Vec<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetLoadStateRequest {
@@ -1426,6 +1606,7 @@ impl GetLoadStateRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             partition_names: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1529,6 +1710,7 @@ pub struct AlterCollectionPropertiesRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) properties: HashMap<String, String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl AlterCollectionPropertiesRequest {
@@ -1537,6 +1719,7 @@ impl AlterCollectionPropertiesRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             properties: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1639,6 +1822,7 @@ pub struct DropCollectionPropertiesRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) property_keys: HashSet<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropCollectionPropertiesRequest {
@@ -1647,6 +1831,7 @@ impl DropCollectionPropertiesRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             property_keys: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1750,6 +1935,7 @@ pub struct AlterCollectionFieldPropertiesRequest {
     pub(crate) collection_name: String,
     pub(crate) field_name: String,
     pub(crate) properties: HashMap<String, String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl AlterCollectionFieldPropertiesRequest {
@@ -1759,6 +1945,7 @@ impl AlterCollectionFieldPropertiesRequest {
             collection_name: Default::default(),
             field_name: Default::default(),
             properties: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1874,6 +2061,7 @@ pub struct DropCollectionFieldPropertiesRequest {
     pub(crate) collection_name: String,
     pub(crate) field_name: String,
     pub(crate) property_keys: HashSet<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropCollectionFieldPropertiesRequest {
@@ -1883,6 +2071,7 @@ impl DropCollectionFieldPropertiesRequest {
             collection_name: Default::default(),
             field_name: Default::default(),
             property_keys: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1997,6 +2186,7 @@ pub struct AddCollectionFieldRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) field: Option<FieldSchema>,
+    pub(crate) options: RequestOptions,
 }
 
 impl AddCollectionFieldRequest {
@@ -2005,6 +2195,7 @@ impl AddCollectionFieldRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             field: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -2110,6 +2301,7 @@ pub struct AddCollectionFunctionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) function: Option<Function>,
+    pub(crate) options: RequestOptions,
 }
 
 impl AddCollectionFunctionRequest {
@@ -2118,6 +2310,7 @@ impl AddCollectionFunctionRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             function: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -2213,6 +2406,7 @@ pub struct AlterCollectionFunctionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) function: Option<Function>,
+    pub(crate) options: RequestOptions,
 }
 
 impl AlterCollectionFunctionRequest {
@@ -2221,6 +2415,7 @@ impl AlterCollectionFunctionRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             function: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -2321,6 +2516,7 @@ pub struct DropCollectionFunctionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) function_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropCollectionFunctionRequest {
@@ -2329,6 +2525,7 @@ impl DropCollectionFunctionRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             function_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -2418,6 +2615,7 @@ impl DropCollectionFunctionRequestBuilder {
 pub struct TruncateCollectionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl TruncateCollectionRequest {
@@ -2425,6 +2623,7 @@ impl TruncateCollectionRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -2500,6 +2699,7 @@ pub struct DescribeReplicasRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) with_shard_nodes: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl DescribeReplicasRequest {
@@ -2508,6 +2708,7 @@ impl DescribeReplicasRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             with_shard_nodes: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -2598,6 +2799,7 @@ pub struct RenameCollectionRequest {
     pub(crate) collection_name: String,
     pub(crate) new_collection_name: String,
     pub(crate) new_database_name: Option<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl RenameCollectionRequest {
@@ -2607,6 +2809,7 @@ impl RenameCollectionRequest {
             collection_name: Default::default(),
             new_collection_name: Default::default(),
             new_database_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -18,9 +18,19 @@
 
 use crate::proto::{common, milvus};
 use crate::v2::error::{Error, Result};
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::required;
 use std::collections::{HashMap, HashSet};
 
+request_options! {
+    CreateDatabaseRequest => CreateDatabaseRequestBuilder,
+    DropDatabaseRequest => DropDatabaseRequestBuilder,
+    ListDatabasesRequest => ListDatabasesRequestBuilder,
+    AlterDatabasePropertiesRequest => AlterDatabasePropertiesRequestBuilder,
+    DropDatabasePropertiesRequest => DropDatabasePropertiesRequestBuilder,
+    DescribeDatabaseRequest => DescribeDatabaseRequestBuilder,
+}
+
 fn properties(values: HashMap<String, String>) -> Vec<common::KeyValuePair> {
     values
         .into_iter()
@@ -37,6 +47,7 @@ fn properties(values: HashMap<String, String>) -> Vec<common::KeyValuePair> {
 pub struct CreateDatabaseRequest {
     pub(crate) database_name: String,
     pub(crate) properties: HashMap<String, String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl CreateDatabaseRequest {
@@ -44,6 +55,7 @@ impl CreateDatabaseRequest {
         Self {
             database_name: Default::default(),
             properties: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -115,12 +127,14 @@ impl CreateDatabaseRequestBuilder {
 #[non_exhaustive]
 pub struct DropDatabaseRequest {
     pub(crate) database_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropDatabaseRequest {
     fn empty() -> Self {
         Self {
             database_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -176,19 +190,25 @@ impl DropDatabaseRequestBuilder {
 // ListDatabasesRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 list_databases operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct ListDatabasesRequest;
+pub struct ListDatabasesRequest {
+    pub(crate) options: RequestOptions,
+}
 
 impl ListDatabasesRequest {
     /// Creates a builder for this request.
     pub fn builder() -> ListDatabasesRequestBuilder {
-        ListDatabasesRequestBuilder
+        ListDatabasesRequestBuilder {
+            value: Self {
+                options: RequestOptions::default(),
+            },
+        }
     }
 
     /// Converts this request back into a builder while preserving its current values.
     pub fn into_builder(self) -> ListDatabasesRequestBuilder {
-        ListDatabasesRequestBuilder
+        ListDatabasesRequestBuilder { value: self }
     }
 
     pub(crate) fn into_proto(self) -> milvus::ListDatabasesRequest {
@@ -200,13 +220,15 @@ impl ListDatabasesRequest {
 // ListDatabasesRequestBuilder
 ///////////////////////////////////////////////////////////////////////////////
 /// Builder for ListDatabasesRequest.
-#[derive(Debug, Clone, Copy)]
-pub struct ListDatabasesRequestBuilder;
+#[derive(Debug, Clone)]
+pub struct ListDatabasesRequestBuilder {
+    value: ListDatabasesRequest,
+}
 
 impl ListDatabasesRequestBuilder {
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<ListDatabasesRequest> {
-        Ok(ListDatabasesRequest)
+        Ok(self.value)
     }
 }
 
@@ -219,6 +241,7 @@ impl ListDatabasesRequestBuilder {
 pub struct AlterDatabasePropertiesRequest {
     pub(crate) database_name: String,
     pub(crate) properties: HashMap<String, String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl AlterDatabasePropertiesRequest {
@@ -226,6 +249,7 @@ impl AlterDatabasePropertiesRequest {
         Self {
             database_name: Default::default(),
             properties: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -312,6 +336,7 @@ impl AlterDatabasePropertiesRequestBuilder {
 pub struct DropDatabasePropertiesRequest {
     pub(crate) database_name: String,
     pub(crate) property_keys: HashSet<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropDatabasePropertiesRequest {
@@ -319,6 +344,7 @@ impl DropDatabasePropertiesRequest {
         Self {
             database_name: Default::default(),
             property_keys: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -404,12 +430,14 @@ impl DropDatabasePropertiesRequestBuilder {
 #[non_exhaustive]
 pub struct DescribeDatabaseRequest {
     pub(crate) database_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DescribeDatabaseRequest {
     fn empty() -> Self {
         Self {
             database_name: Default::default(),
+            options: Default::default(),
         }
     }
 
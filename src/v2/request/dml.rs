@@ -17,12 +17,20 @@
 //! Request types for insert, upsert, and delete operations.
 
 use crate::proto::milvus;
+use crate::v2::embedding::TextEmbedding;
 use crate::v2::error::{Error, Result};
-use crate::v2::request::validation::required;
+use crate::v2::request::options::RequestOptions;
+use crate::v2::request::validation::{positive_usize, required};
 use crate::v2::types::{FieldData, Ids};
 use serde_json::Value;
 use std::collections::HashMap;
 
+request_options! {
+    InsertRequest => InsertRequestBuilder,
+    UpsertRequest => UpsertRequestBuilder,
+    DeleteRequest => DeleteRequestBuilder,
+}
+
 pub use crate::v2::types::{EntityRow, FieldPartialUpdateOp, FieldPartialUpdateOpType};
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -37,6 +45,8 @@ pub struct InsertRequest {
     pub(crate) partition_name: String,
     pub(crate) columns: Vec<FieldData>,
     pub(crate) rows: Vec<EntityRow>,
+    pub(crate) embeddings: Vec<TextEmbedding>,
+    pub(crate) options: RequestOptions,
 }
 
 impl InsertRequest {
@@ -47,6 +57,8 @@ impl InsertRequest {
             partition_name: Default::default(),
             columns: Default::default(),
             rows: Default::default(),
+            embeddings: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -92,6 +104,11 @@ impl InsertRequest {
         &self.rows
     }
 
+    /// Returns the client-side embeddings that fill vector fields from text fields.
+    pub fn embeddings(&self) -> &[TextEmbedding] {
+        &self.embeddings
+    }
+
     pub(crate) fn to_proto_with_fields(
         &self,
         fields_data: Vec<crate::proto::schema::FieldData>,
@@ -167,9 +184,20 @@ impl InsertRequestBuilder {
         self
     }
 
+    /// Adds a client-side embedding that fills its vector field from its source text field.
+    ///
+    /// The vector field is computed when the request is sent, so rows and columns omit it. An
+    /// upsert built from this request applies the same embeddings.
+    pub fn embedding(mut self, value: TextEmbedding) -> Self {
+        self.value.embeddings.push(value);
+        self
+    }
+
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<InsertRequest> {
         required("collection_name", &self.value.collection_name)?;
+        validate_geometry_columns(&self.value.columns)?;
+        validate_embeddings(&self.value.embeddings)?;
         match (self.value.columns.is_empty(), self.rows.is_empty()) {
             (false, false) => Err(Error::validation(
                 "data".into(),
@@ -212,6 +240,7 @@ pub struct UpsertRequest {
     pub(crate) insert: InsertRequest,
     pub(crate) partial_update: bool,
     pub(crate) field_ops: Vec<FieldPartialUpdateOp>,
+    pub(crate) options: RequestOptions,
 }
 
 impl UpsertRequest {
@@ -220,6 +249,7 @@ impl UpsertRequest {
             insert: InsertRequest::empty(),
             partial_update: Default::default(),
             field_ops: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -295,6 +325,7 @@ impl UpsertRequestBuilder {
         for operation in &self.value.field_ops {
             required("field_ops.field_name", operation.get_field_name())?;
         }
+        validate_geometry_columns(&self.value.insert.columns)?;
         match (
             self.value.insert.columns.is_empty(),
             self.value.insert.rows.is_empty(),
@@ -325,6 +356,9 @@ pub struct DeleteRequest {
     pub(crate) filter: String,
     pub(crate) filter_templates: HashMap<String, Value>,
     pub(crate) ids: Ids,
+    pub(crate) chunk_size: usize,
+    pub(crate) max_concurrency: usize,
+    pub(crate) options: RequestOptions,
 }
 
 impl DeleteRequest {
@@ -370,6 +404,16 @@ impl DeleteRequest {
         &self.ids
     }
 
+    /// Returns the maximum number of primary keys sent in one delete. Defaults to 10,000.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the maximum number of chunk deletes in flight at once. Defaults to 4.
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
     pub(crate) fn has_ids(&self) -> bool {
         !self.ids.is_empty()
     }
@@ -421,6 +465,9 @@ impl DeleteRequest {
             filter: String::new(),
             filter_templates: HashMap::new(),
             ids: Ids::default(),
+            chunk_size: 10_000,
+            max_concurrency: 4,
+            options: Default::default(),
         }
     }
 }
@@ -471,9 +518,26 @@ impl DeleteRequestBuilder {
         self
     }
 
+    /// Sets the maximum number of primary keys sent in one delete and returns the updated value.
+    ///
+    /// Larger key lists are split into chunks that are deleted separately. Filter-based deletes
+    /// are never split.
+    pub fn chunk_size(mut self, value: usize) -> Self {
+        self.value.chunk_size = value;
+        self
+    }
+
+    /// Sets the maximum number of chunk deletes in flight at once and returns the updated value.
+    pub fn max_concurrency(mut self, value: usize) -> Self {
+        self.value.max_concurrency = value;
+        self
+    }
+
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<DeleteRequest> {
         required("collection_name", &self.value.collection_name)?;
+        positive_usize("chunk_size", self.value.chunk_size)?;
+        positive_usize("max_concurrency", self.value.max_concurrency)?;
         match (self.value.filter.is_empty(), self.value.ids.is_empty()) {
             (true, true) => Err(Error::validation(
                 "condition".into(),
@@ -505,6 +569,38 @@ pub(crate) fn validate_columns(columns: &[FieldData]) -> Result<usize> {
     Ok(count)
 }
 
+#[cfg(feature = "geo")]
+fn validate_geometry_columns(columns: &[FieldData]) -> Result<()> {
+    columns
+        .iter()
+        .try_for_each(crate::v2::geo::validate_wkt_column)
+}
+
+#[cfg(not(feature = "geo"))]
+fn validate_geometry_columns(_columns: &[FieldData]) -> Result<()> {
+    Ok(())
+}
+
+fn validate_embeddings(embeddings: &[TextEmbedding]) -> Result<()> {
+    for (index, embedding) in embeddings.iter().enumerate() {
+        required("embedding.source_field", &embedding.source_field)?;
+        required("embedding.vector_field", &embedding.vector_field)?;
+        if embeddings[..index]
+            .iter()
+            .any(|other| other.vector_field == embedding.vector_field)
+        {
+            return Err(Error::validation(
+                "embeddings".into(),
+                format!(
+                    "vector field {:?} is filled by more than one embedding",
+                    embedding.vector_field
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn json_template(value: Value) -> Result<crate::proto::schema::TemplateValue> {
     use crate::proto::schema::{
         template_value, BoolArray, DoubleArray, LongArray, StringArray, TemplateArrayValue,
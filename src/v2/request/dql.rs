@@ -22,23 +22,36 @@
 //! fields, and use `limit` to control the number of rows per query vector.
 
 use crate::proto::{common, milvus, schema};
+pub use crate::v2::embedding::TextEmbedding;
 use crate::v2::error::{Error, Result};
 use crate::v2::request::dml::json_template;
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::{
     non_empty_strings, non_negative_i64, positive_i64, positive_usize, required, required_slice,
 };
 pub use crate::v2::types::Ids;
 use crate::v2::types::{
-    encode_sparse_vector, validate_sparse_vector, ConsistencyLevel, Function, FunctionScore,
-    MetricType,
+    encode_sparse_vector, validate_sparse_vector, ConsistencyLevel, DenseVectors, Function,
+    FunctionScore, MetricType,
 };
 pub use crate::v2::types::{
-    EmbeddingList, HighlightQuery, HighlightType, Highlighter, LexicalHighlighter, SearchVectors,
-    SemanticHighlighter,
+    EmbeddingList, FailurePolicy, HighlightQuery, HighlightType, Highlighter, LexicalHighlighter,
+    ScoreRange, SearchTarget, SearchVectors, SemanticHighlighter,
 };
 use prost::Message;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::time::Duration;
+
+request_options! {
+    QueryRequest => QueryRequestBuilder,
+    GetRequest => GetRequestBuilder,
+    SearchRequest => SearchRequestBuilder,
+    HybridSearchRequest => HybridSearchRequestBuilder,
+    QueryIteratorRequest => QueryIteratorRequestBuilder,
+    SearchIteratorRequest => SearchIteratorRequestBuilder,
+    FederatedSearchRequest => FederatedSearchRequestBuilder,
+}
 
 ///////////////////////////////////////////////////////////////////////////////
 // QueryRequest
@@ -60,6 +73,8 @@ pub struct QueryRequest {
     pub(crate) timezone: String,
     pub(crate) consistency_level: Option<ConsistencyLevel>,
     pub(crate) extra_params: HashMap<String, String>,
+    pub(crate) dense_vectors: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl QueryRequest {
@@ -78,6 +93,8 @@ impl QueryRequest {
             timezone: Default::default(),
             consistency_level: Default::default(),
             extra_params: Default::default(),
+            dense_vectors: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -158,6 +175,11 @@ impl QueryRequest {
         &self.extra_params
     }
 
+    /// Returns whether float vector fields are decoded into contiguous [`DenseVectors`].
+    pub fn uses_dense_vectors(&self) -> bool {
+        self.dense_vectors
+    }
+
     pub(crate) fn into_proto(
         self,
         default_db: &str,
@@ -308,6 +330,12 @@ impl QueryRequestBuilder {
         self
     }
 
+    /// Decodes float vector fields as contiguous [`DenseVectors`] instead of one `Vec` per row.
+    pub fn dense_vectors(mut self, value: bool) -> Self {
+        self.value.dense_vectors = value;
+        self
+    }
+
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<QueryRequest> {
         validate_query_request(&self.value)?;
@@ -328,6 +356,10 @@ pub struct GetRequest {
     pub(crate) ids: Ids,
     pub(crate) output_fields: Vec<String>,
     pub(crate) consistency_level: Option<ConsistencyLevel>,
+    pub(crate) dense_vectors: bool,
+    pub(crate) chunk_size: usize,
+    pub(crate) max_concurrency: usize,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetRequest {
@@ -339,6 +371,10 @@ impl GetRequest {
             ids: Default::default(),
             output_fields: Default::default(),
             consistency_level: Default::default(),
+            dense_vectors: Default::default(),
+            chunk_size: 10_000,
+            max_concurrency: 4,
+            options: Default::default(),
         }
     }
 
@@ -384,6 +420,21 @@ impl GetRequest {
         self.consistency_level
     }
 
+    /// Returns whether float vector fields are decoded into contiguous [`DenseVectors`].
+    pub fn uses_dense_vectors(&self) -> bool {
+        self.dense_vectors
+    }
+
+    /// Returns the maximum number of primary keys sent in one query. Defaults to 10,000.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the maximum number of chunk queries in flight at once. Defaults to 4.
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
     pub(crate) fn into_proto(
         self,
         default_db: &str,
@@ -463,10 +514,33 @@ impl GetRequestBuilder {
         self
     }
 
+    /// Decodes float vector fields as contiguous [`DenseVectors`] instead of one `Vec` per row.
+    pub fn dense_vectors(mut self, value: bool) -> Self {
+        self.value.dense_vectors = value;
+        self
+    }
+
+    /// Sets the maximum number of primary keys sent in one query and returns the updated value.
+    ///
+    /// Larger key lists are split into chunks that are queried separately and merged back in
+    /// the order of [`ids`](Self::ids).
+    pub fn chunk_size(mut self, value: usize) -> Self {
+        self.value.chunk_size = value;
+        self
+    }
+
+    /// Sets the maximum number of chunk queries in flight at once and returns the updated value.
+    pub fn max_concurrency(mut self, value: usize) -> Self {
+        self.value.max_concurrency = value;
+        self
+    }
+
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<GetRequest> {
         required("collection_name", &self.value.collection_name)?;
         non_empty_strings("partition_names", &self.value.partition_names)?;
+        positive_usize("chunk_size", self.value.chunk_size)?;
+        positive_usize("max_concurrency", self.value.max_concurrency)?;
         if self.value.ids.is_empty() {
             return Err(Error::validation(
                 "ids".into(),
@@ -502,12 +576,16 @@ pub struct SearchRequest {
     pub(crate) strict_group_size: bool,
     pub(crate) radius: Option<f64>,
     pub(crate) range_filter: Option<f64>,
+    pub(crate) score_range: Option<ScoreRange>,
     pub(crate) metric_type: Option<MetricType>,
     pub(crate) extra_params: HashMap<String, String>,
     pub(crate) rerank: Option<FunctionScore>,
     pub(crate) timezone: String,
     pub(crate) highlighter: Option<Highlighter>,
     pub(crate) consistency_level: Option<ConsistencyLevel>,
+    pub(crate) dense_vectors: bool,
+    pub(crate) embedding: Option<TextEmbedding>,
+    pub(crate) options: RequestOptions,
 }
 
 impl SearchRequest {
@@ -613,6 +691,11 @@ impl SearchRequest {
         self.range_filter
     }
 
+    /// Returns the metric-independent score range.
+    pub fn score_range(&self) -> Option<ScoreRange> {
+        self.score_range
+    }
+
     /// Returns the metric type.
     pub fn metric_type(&self) -> Option<MetricType> {
         self.metric_type
@@ -623,6 +706,23 @@ impl SearchRequest {
         &self.extra_params
     }
 
+    /// Replaces the score range with the radius and range filter it means for `metric`.
+    pub(crate) fn apply_score_range(&mut self, metric: MetricType) -> Result<()> {
+        let Some(range) = self.score_range.take() else {
+            return Ok(());
+        };
+        if range.needs_metric_type() && metric == MetricType::Default {
+            return Err(Error::validation(
+                "score_range".into(),
+                "a range between two scores requires a known metric type".into(),
+            ));
+        }
+        let (radius, range_filter) = range.to_radius_and_range_filter(metric);
+        self.radius = Some(radius);
+        self.range_filter = range_filter;
+        Ok(())
+    }
+
     /// Returns the rerank.
     pub fn rerank(&self) -> &Option<FunctionScore> {
         &self.rerank
@@ -643,12 +743,23 @@ impl SearchRequest {
         self.consistency_level
     }
 
+    /// Returns whether float vector fields are decoded into contiguous [`DenseVectors`].
+    pub fn uses_dense_vectors(&self) -> bool {
+        self.dense_vectors
+    }
+
+    /// Returns the client-side embedding applied to text queries.
+    pub fn embedding(&self) -> Option<&TextEmbedding> {
+        self.embedding.as_ref()
+    }
+
     #[allow(deprecated)]
     pub(crate) fn into_proto(
-        self,
+        mut self,
         default_db: &str,
         guarantee_timestamp: u64,
     ) -> Result<milvus::SearchRequest> {
+        self.apply_score_range(self.metric_type.unwrap_or_default())?;
         if self.limit <= 0 {
             return Err(Error::validation(
                 "limit".into(),
@@ -690,6 +801,14 @@ impl SearchRequest {
                         None,
                     )
                 }
+                SearchVectors::DenseFloat(vectors) => {
+                    validate_dense_float_search_vectors("vectors", &vectors)?;
+                    (
+                        common::PlaceholderType::FloatVector,
+                        encode_dense_float_search_vectors(&vectors),
+                        None,
+                    )
+                }
                 SearchVectors::Binary(vectors) => {
                     validate_dense_search_vectors("vectors", &vectors)?;
                     (common::PlaceholderType::BinaryVector, vectors, None)
@@ -713,7 +832,7 @@ impl SearchRequest {
                 SearchVectors::SparseFloat(vectors) => (
                     common::PlaceholderType::SparseFloatVector,
                     vectors
-                        .into_iter()
+                        .iter()
                         .map(|values| encode_sparse_vector("vectors", values))
                         .collect::<Result<Vec<_>>>()?,
                     None,
@@ -909,12 +1028,16 @@ impl SearchRequest {
             strict_group_size: false,
             radius: None,
             range_filter: None,
+            score_range: None,
             metric_type: None,
             extra_params: HashMap::new(),
             rerank: None,
             timezone: String::new(),
             highlighter: None,
             consistency_level: None,
+            dense_vectors: Default::default(),
+            embedding: None,
+            options: Default::default(),
         }
     }
 }
@@ -1039,6 +1162,29 @@ impl SearchRequestBuilder {
         self
     }
 
+    /// Keeps only matches closer than `bound`: scores below it for distance metrics such as L2
+    /// and above it for similarity metrics such as IP and COSINE.
+    ///
+    /// Cannot be combined with [`Self::radius`] or [`Self::range_filter`].
+    pub fn within(self, bound: f64) -> Self {
+        self.score_range(ScoreRange::Within(bound))
+    }
+
+    /// Keeps only scores between `min` and `max`, whichever metric the vector field uses.
+    ///
+    /// The radius and range filter are derived from the request's metric type or, without one,
+    /// from the index described on the vector field. `min` must be less than `max`, and the
+    /// range cannot be combined with [`Self::radius`] or [`Self::range_filter`].
+    pub fn between(self, min: f64, max: f64) -> Self {
+        self.score_range(ScoreRange::Between { min, max })
+    }
+
+    /// Sets the metric-independent score range and returns the updated value.
+    pub fn score_range(mut self, value: ScoreRange) -> Self {
+        self.value.score_range = Some(value);
+        self
+    }
+
     /// Sets the metric type and returns the updated value.
     pub fn metric_type(mut self, value: MetricType) -> Self {
         self.value.metric_type = Some(value);
@@ -1075,8 +1221,28 @@ impl SearchRequestBuilder {
         self
     }
 
+    /// Decodes float vector fields as contiguous [`DenseVectors`] instead of one `Vec` per row.
+    pub fn dense_vectors(mut self, value: bool) -> Self {
+        self.value.dense_vectors = value;
+        self
+    }
+
+    /// Embeds [`SearchVectors::EmbeddedText`] queries on the client with `value` and searches its
+    /// vector field.
+    ///
+    /// The vector field defaults to the binding's; setting a different one fails [`Self::build`].
+    pub fn embedding(mut self, value: TextEmbedding) -> Self {
+        self.value.embedding = Some(value);
+        self
+    }
+
     /// Validates the configured values and builds the request.
-    pub fn build(self) -> Result<SearchRequest> {
+    pub fn build(mut self) -> Result<SearchRequest> {
+        if let Some(embedding) = &self.value.embedding {
+            if self.value.vector_field.is_empty() {
+                self.value.vector_field = embedding.vector_field.clone();
+            }
+        }
         validate_search_request(&self.value)?;
         Ok(self.value)
     }
@@ -1302,6 +1468,8 @@ pub struct HybridSearchRequest {
     pub(crate) strict_group_size: bool,
     pub(crate) output_fields: Vec<String>,
     pub(crate) consistency_level: Option<ConsistencyLevel>,
+    pub(crate) dense_vectors: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl HybridSearchRequest {
@@ -1322,6 +1490,8 @@ impl HybridSearchRequest {
             strict_group_size: false,
             output_fields: Vec::new(),
             consistency_level: None,
+            dense_vectors: Default::default(),
+            options: Default::default(),
         }
     }
 }
@@ -1414,6 +1584,11 @@ impl HybridSearchRequest {
         self.consistency_level
     }
 
+    /// Returns whether float vector fields are decoded into contiguous [`DenseVectors`].
+    pub fn uses_dense_vectors(&self) -> bool {
+        self.dense_vectors
+    }
+
     pub(crate) fn into_proto(
         self,
         default_db: &str,
@@ -1590,6 +1765,12 @@ impl HybridSearchRequestBuilder {
         self
     }
 
+    /// Decodes float vector fields as contiguous [`DenseVectors`] instead of one `Vec` per row.
+    pub fn dense_vectors(mut self, value: bool) -> Self {
+        self.value.dense_vectors = value;
+        self
+    }
+
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<HybridSearchRequest> {
         required("collection_name", &self.value.collection_name)?;
@@ -1619,6 +1800,7 @@ pub struct QueryIteratorRequest {
     pub(crate) query: QueryRequest,
     pub(crate) batch_size: usize,
     pub(crate) reduce_stop_for_best: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl QueryIteratorRequest {
@@ -1661,6 +1843,7 @@ impl QueryIteratorRequest {
             query: QueryRequest::empty(),
             batch_size: 1_000,
             reduce_stop_for_best: true,
+            options: Default::default(),
         }
     }
 }
@@ -1717,6 +1900,8 @@ pub struct SearchIteratorRequest {
     pub(crate) search: SearchRequest,
     pub(crate) batch_size: usize,
     pub(crate) limit: Option<usize>,
+    pub(crate) page_token_ttl: Duration,
+    pub(crate) options: RequestOptions,
 }
 
 impl SearchIteratorRequest {
@@ -1746,6 +1931,11 @@ impl SearchIteratorRequest {
     pub fn limit(&self) -> Option<usize> {
         self.limit
     }
+
+    /// Returns how long search page tokens issued for this request stay valid.
+    pub fn page_token_ttl(&self) -> Duration {
+        self.page_token_ttl
+    }
 }
 
 impl SearchIteratorRequest {
@@ -1754,6 +1944,8 @@ impl SearchIteratorRequest {
             search: SearchRequest::empty(),
             batch_size: 1_000,
             limit: None,
+            page_token_ttl: Duration::from_secs(600),
+            options: Default::default(),
         }
     }
 }
@@ -1786,6 +1978,13 @@ impl SearchIteratorRequestBuilder {
         self
     }
 
+    /// Sets how long search page tokens stay valid, counted from the first page, and returns the
+    /// updated value. Defaults to ten minutes.
+    pub fn page_token_ttl(mut self, value: Duration) -> Self {
+        self.value.page_token_ttl = value;
+        self
+    }
+
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<SearchIteratorRequest> {
         validate_search_request(&self.value.search)?;
@@ -1796,6 +1995,162 @@ impl SearchIteratorRequestBuilder {
             ));
         }
         positive_usize("batch_size", self.value.batch_size)?;
+        if self.value.page_token_ttl.is_zero() {
+            return Err(Error::validation(
+                "page_token_ttl".into(),
+                "must be greater than zero".into(),
+            ));
+        }
+        Ok(self.value)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// FederatedSearchRequest
+///////////////////////////////////////////////////////////////////////////////
+/// Parameters for the ClientV2 federated_search operation.
+///
+/// The search runs against its own collection and every added target, each of which may live in
+/// another database. Target `0` in the response provenance is the search's own collection and
+/// added targets follow in order.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FederatedSearchRequest {
+    pub(crate) search: SearchRequest,
+    pub(crate) targets: Vec<SearchTarget>,
+    pub(crate) failure_policy: FailurePolicy,
+    pub(crate) max_concurrency: usize,
+    pub(crate) options: RequestOptions,
+}
+
+impl FederatedSearchRequest {
+    /// Creates a builder for this request.
+    pub fn builder() -> FederatedSearchRequestBuilder {
+        FederatedSearchRequestBuilder {
+            value: Self::empty(),
+        }
+    }
+
+    /// Converts this request back into a builder while preserving its current values.
+    pub fn into_builder(self) -> FederatedSearchRequestBuilder {
+        FederatedSearchRequestBuilder { value: self }
+    }
+
+    /// Returns the search.
+    pub fn search(&self) -> &SearchRequest {
+        &self.search
+    }
+
+    /// Returns the added targets.
+    pub fn targets(&self) -> &[SearchTarget] {
+        &self.targets
+    }
+
+    /// Returns the failure policy.
+    pub fn failure_policy(&self) -> FailurePolicy {
+        self.failure_policy
+    }
+
+    /// Returns the maximum number of targets searched at once.
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Returns the search's own collection followed by the added targets.
+    pub(crate) fn all_targets(&self) -> Vec<SearchTarget> {
+        let own = SearchTarget {
+            database_name: self.search.database_name.clone(),
+            collection_name: self.search.collection_name.clone(),
+            metric_type: None,
+        };
+        std::iter::once(own)
+            .chain(self.targets.iter().cloned())
+            .collect()
+    }
+}
+
+impl FederatedSearchRequest {
+    fn empty() -> Self {
+        Self {
+            search: SearchRequest::empty(),
+            targets: Vec::new(),
+            failure_policy: FailurePolicy::default(),
+            max_concurrency: 16,
+            options: Default::default(),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// FederatedSearchRequestBuilder
+///////////////////////////////////////////////////////////////////////////////
+/// Builder for FederatedSearchRequest.
+#[derive(Debug, Clone)]
+pub struct FederatedSearchRequestBuilder {
+    value: FederatedSearchRequest,
+}
+
+impl FederatedSearchRequestBuilder {
+    /// Sets the search and returns the updated value.
+    pub fn search(mut self, value: SearchRequest) -> Self {
+        self.value.search = value;
+        self
+    }
+
+    /// Adds one target and returns the updated value.
+    pub fn target(mut self, value: impl Into<SearchTarget>) -> Self {
+        self.value.targets.push(value.into());
+        self
+    }
+
+    /// Sets the added targets and returns the updated value.
+    pub fn targets(mut self, values: impl IntoIterator<Item = impl Into<SearchTarget>>) -> Self {
+        self.value.targets = values.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the failure policy and returns the updated value.
+    pub fn failure_policy(mut self, value: FailurePolicy) -> Self {
+        self.value.failure_policy = value;
+        self
+    }
+
+    /// Sets the maximum number of targets searched at once and returns the updated value.
+    pub fn max_concurrency(mut self, value: usize) -> Self {
+        self.value.max_concurrency = value;
+        self
+    }
+
+    /// Validates the configured values and builds the request.
+    pub fn build(self) -> Result<FederatedSearchRequest> {
+        let value = &self.value;
+        validate_search_request(&value.search)?;
+        if !value.search.ids.is_empty() {
+            return Err(Error::validation(
+                "ids".into(),
+                "federated search does not support IDs as search targets".into(),
+            ));
+        }
+        if !value.search.group_by_field.is_empty() {
+            return Err(Error::validation(
+                "group_by_field".into(),
+                "federated search does not support grouping".into(),
+            ));
+        }
+        for target in &value.targets {
+            required("collection_name", &target.collection_name)?;
+        }
+        let metric_known = value
+            .search
+            .metric_type
+            .is_some_and(|metric| metric != MetricType::Default);
+        if value.search.vector_field.is_empty() && !metric_known {
+            return Err(Error::validation(
+                "vector_field".into(),
+                "is required to resolve target metric types when metric_type is not set".into(),
+            ));
+        }
+        positive_usize("max_concurrency", value.max_concurrency)?;
         Ok(self.value)
     }
 }
@@ -1854,6 +2209,18 @@ fn validate_search_request(value: &SearchRequest) -> Result<()> {
     validate_search_extra_params(&value.extra_params)?;
     validate_finite_range_parameter("radius", value.radius)?;
     validate_finite_range_parameter("range_filter", value.range_filter)?;
+    if let Some(range) = value.score_range {
+        if value.radius.is_some() || value.range_filter.is_some() {
+            return Err(Error::validation(
+                "score_range".into(),
+                "cannot be combined with radius or range_filter".into(),
+            ));
+        }
+        range.validate()?;
+    }
+    if let Some(embedding) = &value.embedding {
+        validate_search_embedding(value, embedding)?;
+    }
     if !value.ids.is_empty() {
         if search_vectors_are_empty(&value.vectors) {
             return Ok(());
@@ -1866,9 +2233,30 @@ fn validate_search_request(value: &SearchRequest) -> Result<()> {
     validate_search_vectors(&value.vectors)
 }
 
+fn validate_search_embedding(value: &SearchRequest, embedding: &TextEmbedding) -> Result<()> {
+    required("embedding.vector_field", &embedding.vector_field)?;
+    if value.vector_field != embedding.vector_field {
+        return Err(Error::validation(
+            "vector_field".into(),
+            format!(
+                "must match the embedding's vector field {:?}",
+                embedding.vector_field
+            ),
+        ));
+    }
+    if !matches!(value.vectors, SearchVectors::EmbeddedText(_)) {
+        return Err(Error::validation(
+            "vectors".into(),
+            "an embedding requires EmbeddedText queries".into(),
+        ));
+    }
+    Ok(())
+}
+
 fn search_vectors_are_empty(vectors: &SearchVectors) -> bool {
     match vectors {
         SearchVectors::Float(values) => values.is_empty(),
+        SearchVectors::DenseFloat(values) => values.is_empty(),
         SearchVectors::Binary(values) => values.is_empty(),
         SearchVectors::Float16(values) => values.is_empty(),
         SearchVectors::BFloat16(values) => values.is_empty(),
@@ -1904,6 +2292,7 @@ fn validate_search_extra_params(extra_params: &HashMap<String, String>) -> Resul
 fn validate_search_vectors(vectors: &SearchVectors) -> Result<()> {
     match vectors {
         SearchVectors::Float(values) => validate_float_search_vectors("vectors", values),
+        SearchVectors::DenseFloat(values) => validate_dense_float_search_vectors("vectors", values),
         SearchVectors::Binary(values) => validate_dense_search_vectors("vectors", values),
         SearchVectors::Float16(values) => validate_dense_search_vectors("vectors", values),
         SearchVectors::BFloat16(values) => validate_dense_search_vectors("vectors", values),
@@ -1969,6 +2358,39 @@ fn validate_float_search_vectors(name: &str, vectors: &[Vec<f32>]) -> Result<()>
     Ok(())
 }
 
+fn validate_dense_float_search_vectors(name: &str, vectors: &DenseVectors) -> Result<()> {
+    if vectors.is_empty() {
+        return Err(Error::validation(
+            name.into(),
+            "at least one query vector is required".into(),
+        ));
+    }
+    if vectors.as_slice().iter().any(|value| !value.is_finite()) {
+        return Err(Error::validation(
+            name.into(),
+            "query vectors must contain only finite values".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Encodes one placeholder value per query row, writing each row's little-endian bytes straight
+/// from the contiguous buffer into a value sized for the row.
+fn encode_dense_float_search_vectors(vectors: &DenseVectors) -> Vec<Vec<u8>> {
+    let row_bytes = vectors.dim * std::mem::size_of::<f32>();
+    vectors
+        .data
+        .chunks_exact(vectors.dim)
+        .map(|row| {
+            let mut bytes = Vec::with_capacity(row_bytes);
+            for value in row {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            bytes
+        })
+        .collect()
+}
+
 fn encode_u16_search_vectors(vectors: Vec<Vec<u16>>) -> Vec<Vec<u8>> {
     vectors
         .into_iter()
@@ -1995,8 +2417,8 @@ fn set_search_param(params: &mut Vec<common::KeyValuePair>, key: &str, value: im
 #[cfg(test)]
 mod search_request_tests {
     use super::{
-        EmbeddingList, HighlightQuery, HybridSearchRequest, LexicalHighlighter, SearchRequest,
-        SearchVectors, SubSearchRequest,
+        DenseVectors, EmbeddingList, HighlightQuery, HybridSearchRequest, LexicalHighlighter,
+        ScoreRange, SearchRequest, SearchVectors, SubSearchRequest,
     };
     use crate::proto::{common, milvus};
     use crate::v2::types::{
@@ -2308,6 +2730,38 @@ mod search_request_tests {
         }
     }
 
+    #[test]
+    fn dense_float_search_vectors_encode_like_nested_float_vectors() {
+        let encode = |vectors| {
+            let request = SearchRequest::builder()
+                .collection_name("books")
+                .vectors(vectors)
+                .build()
+                .expect("valid request")
+                .into_proto("default", 0)
+                .unwrap();
+            let milvus::search_request::SearchInput::PlaceholderGroup(bytes) =
+                request.search_input.unwrap()
+            else {
+                panic!("expected placeholder input")
+            };
+            common::PlaceholderGroup::decode(bytes.as_slice()).unwrap()
+        };
+        let dense = DenseVectors::new(2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+
+        assert_eq!(
+            encode(SearchVectors::DenseFloat(dense)),
+            encode(SearchVectors::Float(vec![vec![1.0, 2.0], vec![3.0, 4.0]]))
+        );
+        assert!(SearchRequest::builder()
+            .collection_name("books")
+            .vectors(SearchVectors::DenseFloat(
+                DenseVectors::new(1, vec![f32::NAN]).unwrap()
+            ))
+            .build()
+            .is_err());
+    }
+
     #[test]
     fn search_builders_reject_non_finite_float_inputs() {
         for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
@@ -2355,6 +2809,72 @@ mod search_request_tests {
         }
     }
 
+    #[test]
+    fn score_ranges_become_metric_specific_radius_and_range_filter() {
+        let search = |range: ScoreRange, metric: Option<MetricType>| {
+            let mut builder = SearchRequest::builder()
+                .collection_name("books")
+                .vectors(SearchVectors::Float(vec![vec![0.1]]))
+                .score_range(range);
+            if let Some(metric) = metric {
+                builder = builder.metric_type(metric);
+            }
+            builder.build()
+        };
+        let between = ScoreRange::Between { min: 0.2, max: 0.8 };
+
+        let mut l2 = search(between, None).expect("valid range");
+        l2.apply_score_range(MetricType::L2).unwrap();
+        assert_eq!((l2.radius(), l2.range_filter()), (Some(0.8), Some(0.2)));
+        assert_eq!(l2.score_range(), None);
+
+        let mut ip = search(between, None).expect("valid range");
+        ip.apply_score_range(MetricType::Ip).unwrap();
+        assert_eq!((ip.radius(), ip.range_filter()), (Some(0.2), Some(0.8)));
+
+        let cosine = search(between, Some(MetricType::Cosine))
+            .unwrap()
+            .into_proto("default", 0)
+            .unwrap();
+        let params: serde_json::Value = serde_json::from_str(
+            &cosine
+                .search_params
+                .iter()
+                .find(|param| param.key == "params")
+                .unwrap()
+                .value,
+        )
+        .unwrap();
+        assert_eq!(
+            (params["radius"].as_f64(), params["range_filter"].as_f64()),
+            (Some(0.2), Some(0.8))
+        );
+
+        let within = search(ScoreRange::Within(1.5), None)
+            .unwrap()
+            .into_proto("default", 0)
+            .unwrap();
+        assert!(within
+            .search_params
+            .iter()
+            .any(|param| param.key == "radius" && param.value == "1.5"));
+        assert!(search(between, None)
+            .unwrap()
+            .into_proto("default", 0)
+            .is_err());
+
+        assert!(search(ScoreRange::Between { min: 0.8, max: 0.2 }, None).is_err());
+        assert!(search(ScoreRange::Between { min: 0.5, max: 0.5 }, None).is_err());
+        assert!(search(ScoreRange::Within(f64::NAN), None).is_err());
+        assert!(SearchRequest::builder()
+            .collection_name("books")
+            .vectors(SearchVectors::Float(vec![vec![0.1]]))
+            .between(0.2, 0.8)
+            .radius(0.9)
+            .build()
+            .is_err());
+    }
+
     #[test]
     fn search_builders_reject_invalid_sparse_rows() {
         for sparse in [
@@ -2794,6 +3314,7 @@ mod builder_value_tests {
         let expected_strict_group_size: bool = false;
         let expected_radius: Option<f64> = None;
         let expected_range_filter: Option<f64> = None;
+        let expected_score_range: Option<ScoreRange> = None;
         let expected_metric_type: Option<MetricType> = None;
         let expected_extra_params: HashMap<String, String> = Default::default();
         let expected_rerank: Option<FunctionScore> = None;
@@ -2828,6 +3349,7 @@ mod builder_value_tests {
         );
         assert_eq!(value.radius().to_owned(), expected_radius);
         assert_eq!(value.range_filter().to_owned(), expected_range_filter);
+        assert_eq!(value.score_range(), expected_score_range);
         assert_eq!(value.metric_type().to_owned(), expected_metric_type);
         assert_eq!(value.extra_params().to_owned(), expected_extra_params);
         assert_eq!(value.rerank().to_owned(), expected_rerank);
@@ -3158,6 +3680,7 @@ mod builder_value_tests {
         assert_eq!(value.search().to_owned(), expected_search);
         assert_eq!(value.batch_size().to_owned(), expected_batch_size);
         assert_eq!(value.limit().to_owned(), expected_limit);
+        assert_eq!(value.page_token_ttl(), Duration::from_secs(600));
     }
 
     #[test]
@@ -3173,12 +3696,20 @@ mod builder_value_tests {
             .search(search.clone())
             .batch_size(batch_size.clone())
             .limit(limit.clone())
+            .page_token_ttl(Duration::from_secs(30))
             .build()
             .expect("valid request");
 
         assert_eq!(value.search().to_owned(), search);
         assert_eq!(value.batch_size().to_owned(), batch_size);
         assert_eq!(value.limit().to_owned(), Some(limit));
+        assert_eq!(value.page_token_ttl(), Duration::from_secs(30));
+
+        assert!(value
+            .into_builder()
+            .page_token_ttl(Duration::ZERO)
+            .build()
+            .is_err());
     }
 
     #[test]
@@ -3210,4 +3741,71 @@ mod builder_value_tests {
             .build()
             .is_err());
     }
+
+    #[test]
+    fn federated_search_request_lists_its_own_collection_first() {
+        let search = SearchRequest::builder()
+            .collection_name("tenant_a")
+            .vector_field("embedding")
+            .vectors(SearchVectors::Float(vec![vec![0.1, 0.2]]))
+            .build()
+            .expect("valid search request");
+        let value = FederatedSearchRequest::builder()
+            .search(search.clone())
+            .target(("archive", "tenant_b"))
+            .target(SearchTarget::new("tenant_c").metric_type(MetricType::L2))
+            .failure_policy(FailurePolicy::BestEffort)
+            .build()
+            .expect("valid federated search request");
+
+        let targets = value.all_targets();
+        assert_eq!(targets[0], SearchTarget::new("tenant_a"));
+        assert_eq!(targets[1].get_database_name(), Some("archive"));
+        assert_eq!(targets[2].get_metric_type(), Some(MetricType::L2));
+
+        let unresolved = search.into_builder().vector_field("").build().unwrap();
+        assert!(FederatedSearchRequest::builder()
+            .search(unresolved)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn search_request_embedding_selects_its_vector_field() {
+        use crate::v2::embedding::{BoxFuture, Embedding, EmbeddingFunction};
+
+        #[derive(Debug)]
+        struct Zeros;
+
+        impl EmbeddingFunction for Zeros {
+            fn embed_documents<'a>(
+                &'a self,
+                texts: &'a [String],
+            ) -> BoxFuture<'a, Result<Vec<Embedding>>> {
+                Box::pin(async move { Ok(vec![Embedding::Dense(vec![0.0]); texts.len()]) })
+            }
+        }
+
+        let embedding = TextEmbedding::new("text", "text_vector", Zeros);
+        let value = SearchRequest::builder()
+            .collection_name("docs")
+            .vectors(SearchVectors::EmbeddedText(vec!["milvus".into()]))
+            .embedding(embedding.clone())
+            .build()
+            .expect("valid search request");
+        assert_eq!(value.vector_field(), "text_vector");
+        assert_eq!(value.embedding(), Some(&embedding));
+
+        assert!(value
+            .clone()
+            .into_builder()
+            .vector_field("other_vector")
+            .build()
+            .is_err());
+        assert!(value
+            .into_builder()
+            .vectors(SearchVectors::Float(vec![vec![0.1]]))
+            .build()
+            .is_err());
+    }
 }
@@ -18,9 +18,19 @@
 
 use crate::proto::{common, milvus};
 use crate::v2::error::{Error, Result};
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::{required, required_slice};
 use crate::v2::types::{IndexParam, IndexType};
 use std::collections::{HashMap, HashSet};
+
+request_options! {
+    CreateIndexRequest => CreateIndexRequestBuilder,
+    DescribeIndexRequest => DescribeIndexRequestBuilder,
+    ListIndexesRequest => ListIndexesRequestBuilder,
+    DropIndexRequest => DropIndexRequestBuilder,
+    AlterIndexPropertiesRequest => AlterIndexPropertiesRequestBuilder,
+    DropIndexPropertiesRequest => DropIndexPropertiesRequestBuilder,
+}
 fn pairs(v: HashMap<String, String>) -> Vec<common::KeyValuePair> {
     v.into_iter()
         .map(|(key, value)| common::KeyValuePair { key, value })
@@ -39,6 +49,7 @@ pub struct CreateIndexRequest {
     pub(crate) index_params: Vec<IndexParam>,
     pub(crate) sync: bool,
     pub(crate) timeout_ms: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl CreateIndexRequest {
@@ -98,6 +109,7 @@ impl CreateIndexRequest {
             index_params: Vec::new(),
             sync: true,
             timeout_ms: 60_000,
+            options: Default::default(),
         }
     }
 }
@@ -180,6 +192,7 @@ pub struct DescribeIndexRequest {
     pub(crate) field_name: String,
     pub(crate) index_name: String,
     pub(crate) timestamp: u64,
+    pub(crate) options: RequestOptions,
 }
 
 impl DescribeIndexRequest {
@@ -190,6 +203,7 @@ impl DescribeIndexRequest {
             field_name: Default::default(),
             index_name: Default::default(),
             timestamp: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -303,6 +317,7 @@ pub struct ListIndexesRequest {
     pub(crate) collection_name: String,
     pub(crate) index_name: String,
     pub(crate) timestamp: u64,
+    pub(crate) options: RequestOptions,
 }
 
 impl ListIndexesRequest {
@@ -312,6 +327,7 @@ impl ListIndexesRequest {
             collection_name: Default::default(),
             index_name: Default::default(),
             timestamp: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -413,6 +429,7 @@ pub struct DropIndexRequest {
     pub(crate) collection_name: String,
     pub(crate) field_name: String,
     pub(crate) index_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropIndexRequest {
@@ -422,6 +439,7 @@ impl DropIndexRequest {
             collection_name: Default::default(),
             field_name: Default::default(),
             index_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -530,6 +548,7 @@ pub struct AlterIndexPropertiesRequest {
     pub(crate) collection_name: String,
     pub(crate) index_name: String,
     pub(crate) properties: HashMap<String, String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl AlterIndexPropertiesRequest {
@@ -539,6 +558,7 @@ impl AlterIndexPropertiesRequest {
             collection_name: Default::default(),
             index_name: Default::default(),
             properties: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -654,6 +674,7 @@ pub struct DropIndexPropertiesRequest {
     pub(crate) collection_name: String,
     pub(crate) index_name: String,
     pub(crate) property_keys: HashSet<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropIndexPropertiesRequest {
@@ -663,6 +684,7 @@ impl DropIndexPropertiesRequest {
             collection_name: Default::default(),
             index_name: Default::default(),
             property_keys: Default::default(),
+            options: Default::default(),
         }
     }
 
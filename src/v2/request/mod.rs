@@ -33,6 +33,12 @@
 //! Public requests are SDK domain objects. Generated protobuf messages are created only by
 //! crate-private conversion methods in each area module. Use [`crate::v2::response`] for the
 //! corresponding output types.
+//!
+//! Every request also carries [`options::RequestOptions`], set through the builder's
+//! `request_timeout` and `cancellation_token` methods, which bound or cancel a single call.
+
+#[macro_use]
+pub mod options;
 
 pub mod alias;
 pub mod cdc;
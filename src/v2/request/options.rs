@@ -0,0 +1,170 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-call execution options shared by every request type.
+//!
+//! Every request builder accepts a [`RequestOptions::timeout`], a [`CancellationToken`], and a
+//! [`RetryPolicy`] that replaces the client's policy for the call. The timeout bounds the whole
+//! client call, including every RPC attempt, retry backoff, and any polling performed by waiting
+//! operations. Cancelling the token aborts the call with [`crate::v2::error::Error::Cancelled`];
+//! iterators created from a request keep observing the token for every later page.
+//!
+//! ```
+//! # use milvus::v2::prelude::*;
+//! use std::time::Duration;
+//! use tokio_util::sync::CancellationToken;
+//!
+//! let token = CancellationToken::new();
+//! let request = QueryRequest::builder()
+//!     .collection_name("books")
+//!     .filter("id > 0")
+//!     .request_timeout(Duration::from_millis(250))
+//!     .cancellation_token(token.clone())
+//!     .build()?;
+//! assert_eq!(
+//!     request.request_options().timeout(),
+//!     Some(Duration::from_millis(250))
+//! );
+//! # Ok::<(), milvus::v2::error::Error>(())
+//! ```
+
+use crate::v2::retry::RetryPolicy;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// Per-call timeout, cancellation, and retry settings attached to a request.
+///
+/// Options are execution settings rather than request content, so two requests that differ only
+/// in their cancellation tokens or retry policies compare equal.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct RequestOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) cancellation_token: Option<CancellationToken>,
+    pub(crate) retry_policy: Option<Arc<dyn RetryPolicy>>,
+}
+
+impl RequestOptions {
+    /// Creates options without a timeout or cancellation token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the timeout covering every attempt and retry of the call; zero means unbounded.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns the token that cancels the call.
+    pub fn cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancellation_token.as_ref()
+    }
+
+    /// Returns the retry policy that replaces the client's policy for every RPC of the call.
+    pub fn retry_policy(&self) -> Option<&Arc<dyn RetryPolicy>> {
+        self.retry_policy.as_ref()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.timeout.map_or(true, |timeout| timeout.is_zero())
+            && self.cancellation_token.is_none()
+            && self.retry_policy.is_none()
+    }
+}
+
+impl PartialEq for RequestOptions {
+    fn eq(&self, other: &Self) -> bool {
+        self.timeout == other.timeout
+    }
+}
+
+impl Eq for RequestOptions {}
+
+/// Implements per-call option accessors on a request and its builder.
+macro_rules! request_options {
+    ($($request:ident => $builder:ident),+ $(,)?) => {
+        $(
+            impl $request {
+                /// Returns the per-call timeout and cancellation settings.
+                pub fn request_options(&self) -> &$crate::v2::request::options::RequestOptions {
+                    &self.options
+                }
+            }
+
+            impl $builder {
+                /// Sets the timeout covering every attempt, retry, and polling step of the call.
+                ///
+                /// A zero timeout leaves the call unbounded.
+                pub fn request_timeout(mut self, value: ::std::time::Duration) -> Self {
+                    self.value.options.timeout = Some(value);
+                    self
+                }
+
+                /// Sets the token that aborts the call with `Error::Cancelled`.
+                pub fn cancellation_token(
+                    mut self,
+                    value: $crate::v2::request::options::CancellationToken,
+                ) -> Self {
+                    self.value.options.cancellation_token = Some(value);
+                    self
+                }
+
+                /// Replaces the client's retry policy for every RPC of the call, including
+                /// internal describe calls and later iterator pages.
+                pub fn retry_policy(
+                    mut self,
+                    value: impl $crate::v2::retry::RetryPolicy + 'static,
+                ) -> Self {
+                    self.value.options.retry_policy = Some(::std::sync::Arc::new(value));
+                    self
+                }
+            }
+        )+
+    };
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_compare_by_timeout_only() {
+        let with_token = RequestOptions {
+            timeout: Some(Duration::from_secs(1)),
+            cancellation_token: Some(CancellationToken::new()),
+            retry_policy: Some(Arc::new(crate::v2::retry::NoRetry)),
+        };
+        let without_token = RequestOptions {
+            timeout: Some(Duration::from_secs(1)),
+            cancellation_token: None,
+            retry_policy: None,
+        };
+        assert_eq!(with_token, without_token);
+        assert_ne!(with_token, RequestOptions::new());
+        assert!(RequestOptions::new().is_empty());
+        assert!(!RequestOptions {
+            retry_policy: Some(Arc::new(crate::v2::retry::NoRetry)),
+            ..RequestOptions::new()
+        }
+        .is_empty());
+    }
+}
@@ -18,8 +18,19 @@
 
 use crate::proto::milvus;
 use crate::v2::error::Result;
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::{non_empty_strings, positive_i32, required, required_slice};
 
+request_options! {
+    CreatePartitionRequest => CreatePartitionRequestBuilder,
+    DropPartitionRequest => DropPartitionRequestBuilder,
+    HasPartitionRequest => HasPartitionRequestBuilder,
+    GetPartitionStatsRequest => GetPartitionStatsRequestBuilder,
+    ListPartitionsRequest => ListPartitionsRequestBuilder,
+    LoadPartitionsRequest => LoadPartitionsRequestBuilder,
+    ReleasePartitionsRequest => ReleasePartitionsRequestBuilder,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // CreatePartitionRequest
 ///////////////////////////////////////////////////////////////////////////////
@@ -30,6 +41,7 @@ pub struct CreatePartitionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) partition_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl CreatePartitionRequest {
@@ -38,6 +50,7 @@ impl CreatePartitionRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             partition_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -127,6 +140,7 @@ pub struct DropPartitionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) partition_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropPartitionRequest {
@@ -135,6 +149,7 @@ impl DropPartitionRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             partition_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -224,6 +239,7 @@ pub struct HasPartitionRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) partition_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl HasPartitionRequest {
@@ -232,6 +248,7 @@ impl HasPartitionRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             partition_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -321,6 +338,7 @@ pub struct GetPartitionStatsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) partition_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetPartitionStatsRequest {
@@ -329,6 +347,7 @@ impl GetPartitionStatsRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             partition_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -417,6 +436,7 @@ impl GetPartitionStatsRequestBuilder {
 pub struct ListPartitionsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl ListPartitionsRequest {
@@ -424,6 +444,7 @@ impl ListPartitionsRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -508,6 +529,7 @@ pub struct LoadPartitionsRequest {
     pub(crate) load_fields: Vec<String>,
     pub(crate) skip_load_dynamic_field: bool,
     pub(crate) resource_groups: Vec<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl LoadPartitionsRequest {
@@ -600,6 +622,7 @@ impl LoadPartitionsRequest {
             load_fields: Vec::new(),
             skip_load_dynamic_field: false,
             resource_groups: Vec::new(),
+            options: Default::default(),
         }
     }
 }
@@ -741,6 +764,7 @@ pub struct ReleasePartitionsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) partition_names: Vec<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl ReleasePartitionsRequest {
@@ -749,6 +773,7 @@ impl ReleasePartitionsRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             partition_names: Default::default(),
+            options: Default::default(),
         }
     }
 
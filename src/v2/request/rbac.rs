@@ -18,10 +18,34 @@
 
 use crate::proto::milvus;
 use crate::v2::error::{Error, Result};
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::required;
 use base64::Engine;
 use std::collections::HashSet;
 
+request_options! {
+    CreateUserRequest => CreateUserRequestBuilder,
+    UpdatePasswordRequest => UpdatePasswordRequestBuilder,
+    UpdateUserRequest => UpdateUserRequestBuilder,
+    DropUserRequest => DropUserRequestBuilder,
+    ListUsersRequest => ListUsersRequestBuilder,
+    ListRolesRequest => ListRolesRequestBuilder,
+    CreateRoleRequest => CreateRoleRequestBuilder,
+    AlterRoleRequest => AlterRoleRequestBuilder,
+    DropRoleRequest => DropRoleRequestBuilder,
+    GrantRoleRequest => GrantRoleRequestBuilder,
+    RevokeRoleRequest => RevokeRoleRequestBuilder,
+    DescribeRoleRequest => DescribeRoleRequestBuilder,
+    DescribeUserRequest => DescribeUserRequestBuilder,
+    GrantPrivilegeRequest => GrantPrivilegeRequestBuilder,
+    RevokePrivilegeRequest => RevokePrivilegeRequestBuilder,
+    CreatePrivilegeGroupRequest => CreatePrivilegeGroupRequestBuilder,
+    DropPrivilegeGroupRequest => DropPrivilegeGroupRequestBuilder,
+    ListPrivilegeGroupsRequest => ListPrivilegeGroupsRequestBuilder,
+    AddPrivilegesToGroupRequest => AddPrivilegesToGroupRequestBuilder,
+    RemovePrivilegesFromGroupRequest => RemovePrivilegesFromGroupRequestBuilder,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // CreateUserRequest
 ///////////////////////////////////////////////////////////////////////////////
@@ -32,6 +56,7 @@ pub struct CreateUserRequest {
     pub(crate) username: String,
     pub(crate) password: String,
     pub(crate) description: Option<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl std::fmt::Debug for CreateUserRequest {
@@ -51,6 +76,7 @@ impl CreateUserRequest {
             username: Default::default(),
             password: Default::default(),
             description: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -139,6 +165,7 @@ pub struct UpdatePasswordRequest {
     pub(crate) username: String,
     pub(crate) old_password: String,
     pub(crate) new_password: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl std::fmt::Debug for UpdatePasswordRequest {
@@ -158,6 +185,7 @@ impl UpdatePasswordRequest {
             username: Default::default(),
             old_password: Default::default(),
             new_password: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -243,6 +271,7 @@ impl UpdatePasswordRequestBuilder {
 pub struct UpdateUserRequest {
     pub(crate) username: String,
     pub(crate) description: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl UpdateUserRequest {
@@ -250,6 +279,7 @@ impl UpdateUserRequest {
         Self {
             username: Default::default(),
             description: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -322,12 +352,14 @@ impl UpdateUserRequestBuilder {
 #[non_exhaustive]
 pub struct DropUserRequest {
     pub(crate) username: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropUserRequest {
     fn empty() -> Self {
         Self {
             username: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -383,19 +415,25 @@ impl DropUserRequestBuilder {
 // ListUsersRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 list_users operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct ListUsersRequest;
+pub struct ListUsersRequest {
+    pub(crate) options: RequestOptions,
+}
 
 impl ListUsersRequest {
     /// Creates a builder for this request.
     pub fn builder() -> ListUsersRequestBuilder {
-        ListUsersRequestBuilder
+        ListUsersRequestBuilder {
+            value: Self {
+                options: RequestOptions::default(),
+            },
+        }
     }
 
     /// Converts this request back into a builder while preserving its current values.
     pub fn into_builder(self) -> ListUsersRequestBuilder {
-        ListUsersRequestBuilder
+        ListUsersRequestBuilder { value: self }
     }
 
     pub(crate) fn into_proto(self) -> milvus::ListCredUsersRequest {
@@ -407,13 +445,15 @@ impl ListUsersRequest {
 // ListUsersRequestBuilder
 ///////////////////////////////////////////////////////////////////////////////
 /// Builder for ListUsersRequest.
-#[derive(Debug, Clone, Copy)]
-pub struct ListUsersRequestBuilder;
+#[derive(Debug, Clone)]
+pub struct ListUsersRequestBuilder {
+    value: ListUsersRequest,
+}
 
 impl ListUsersRequestBuilder {
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<ListUsersRequest> {
-        Ok(ListUsersRequest)
+        Ok(self.value)
     }
 }
 
@@ -421,19 +461,25 @@ impl ListUsersRequestBuilder {
 // ListRolesRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 list_roles operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct ListRolesRequest;
+pub struct ListRolesRequest {
+    pub(crate) options: RequestOptions,
+}
 
 impl ListRolesRequest {
     /// Creates a builder for this request.
     pub fn builder() -> ListRolesRequestBuilder {
-        ListRolesRequestBuilder
+        ListRolesRequestBuilder {
+            value: Self {
+                options: RequestOptions::default(),
+            },
+        }
     }
 
     /// Converts this request back into a builder while preserving its current values.
     pub fn into_builder(self) -> ListRolesRequestBuilder {
-        ListRolesRequestBuilder
+        ListRolesRequestBuilder { value: self }
     }
 
     pub(crate) fn into_proto(self) -> milvus::SelectRoleRequest {
@@ -449,13 +495,15 @@ impl ListRolesRequest {
 // ListRolesRequestBuilder
 ///////////////////////////////////////////////////////////////////////////////
 /// Builder for ListRolesRequest.
-#[derive(Debug, Clone, Copy)]
-pub struct ListRolesRequestBuilder;
+#[derive(Debug, Clone)]
+pub struct ListRolesRequestBuilder {
+    value: ListRolesRequest,
+}
 
 impl ListRolesRequestBuilder {
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<ListRolesRequest> {
-        Ok(ListRolesRequest)
+        Ok(self.value)
     }
 }
 
@@ -468,6 +516,7 @@ impl ListRolesRequestBuilder {
 pub struct CreateRoleRequest {
     pub(crate) role_name: String,
     pub(crate) description: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl CreateRoleRequest {
@@ -475,6 +524,7 @@ impl CreateRoleRequest {
         Self {
             role_name: Default::default(),
             description: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -549,6 +599,7 @@ impl CreateRoleRequestBuilder {
 pub struct AlterRoleRequest {
     pub(crate) role_name: String,
     pub(crate) description: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl AlterRoleRequest {
@@ -556,6 +607,7 @@ impl AlterRoleRequest {
         Self {
             role_name: Default::default(),
             description: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -628,6 +680,7 @@ impl AlterRoleRequestBuilder {
 pub struct DropRoleRequest {
     pub(crate) role_name: String,
     pub(crate) force: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropRoleRequest {
@@ -635,6 +688,7 @@ impl DropRoleRequest {
         Self {
             role_name: Default::default(),
             force: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -707,6 +761,7 @@ impl DropRoleRequestBuilder {
 pub struct GrantRoleRequest {
     pub(crate) username: String,
     pub(crate) role_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl GrantRoleRequest {
@@ -714,6 +769,7 @@ impl GrantRoleRequest {
         Self {
             username: Default::default(),
             role_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -787,6 +843,7 @@ impl GrantRoleRequestBuilder {
 pub struct RevokeRoleRequest {
     pub(crate) username: String,
     pub(crate) role_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl RevokeRoleRequest {
@@ -794,6 +851,7 @@ impl RevokeRoleRequest {
         Self {
             username: Default::default(),
             role_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -867,6 +925,7 @@ impl RevokeRoleRequestBuilder {
 pub struct DescribeRoleRequest {
     pub(crate) role_name: String,
     pub(crate) database_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DescribeRoleRequest {
@@ -874,6 +933,7 @@ impl DescribeRoleRequest {
         Self {
             role_name: Default::default(),
             database_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -970,6 +1030,7 @@ impl DescribeRoleRequestBuilder {
 pub struct DescribeUserRequest {
     pub(crate) username: String,
     pub(crate) include_roles: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl DescribeUserRequest {
@@ -1011,6 +1072,7 @@ impl DescribeUserRequest {
         Self {
             username: String::new(),
             include_roles: true,
+            options: Default::default(),
         }
     }
 }
@@ -1055,6 +1117,7 @@ pub struct GrantPrivilegeRequest {
     pub(crate) database_name: String,
     pub(crate) collection_name: String,
     pub(crate) privilege: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl GrantPrivilegeRequest {
@@ -1064,6 +1127,7 @@ impl GrantPrivilegeRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             privilege: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1176,6 +1240,7 @@ pub struct RevokePrivilegeRequest {
     pub(crate) database_name: String,
     pub(crate) collection_name: String,
     pub(crate) privilege: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl RevokePrivilegeRequest {
@@ -1185,6 +1250,7 @@ impl RevokePrivilegeRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             privilege: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1294,12 +1360,14 @@ impl RevokePrivilegeRequestBuilder {
 #[non_exhaustive]
 pub struct CreatePrivilegeGroupRequest {
     pub(crate) group_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl CreatePrivilegeGroupRequest {
     fn empty() -> Self {
         Self {
             group_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1359,12 +1427,14 @@ impl CreatePrivilegeGroupRequestBuilder {
 #[non_exhaustive]
 pub struct DropPrivilegeGroupRequest {
     pub(crate) group_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropPrivilegeGroupRequest {
     fn empty() -> Self {
         Self {
             group_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1420,19 +1490,25 @@ impl DropPrivilegeGroupRequestBuilder {
 // ListPrivilegeGroupsRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 list_privilege_groups operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct ListPrivilegeGroupsRequest;
+pub struct ListPrivilegeGroupsRequest {
+    pub(crate) options: RequestOptions,
+}
 
 impl ListPrivilegeGroupsRequest {
     /// Creates a builder for this request.
     pub fn builder() -> ListPrivilegeGroupsRequestBuilder {
-        ListPrivilegeGroupsRequestBuilder
+        ListPrivilegeGroupsRequestBuilder {
+            value: Self {
+                options: RequestOptions::default(),
+            },
+        }
     }
 
     /// Converts this request back into a builder while preserving its current values.
     pub fn into_builder(self) -> ListPrivilegeGroupsRequestBuilder {
-        ListPrivilegeGroupsRequestBuilder
+        ListPrivilegeGroupsRequestBuilder { value: self }
     }
 
     pub(crate) fn into_proto(self) -> milvus::ListPrivilegeGroupsRequest {
@@ -1444,13 +1520,15 @@ impl ListPrivilegeGroupsRequest {
 // ListPrivilegeGroupsRequestBuilder
 ///////////////////////////////////////////////////////////////////////////////
 /// Builder for ListPrivilegeGroupsRequest.
-#[derive(Debug, Clone, Copy)]
-pub struct ListPrivilegeGroupsRequestBuilder;
+#[derive(Debug, Clone)]
+pub struct ListPrivilegeGroupsRequestBuilder {
+    value: ListPrivilegeGroupsRequest,
+}
 
 impl ListPrivilegeGroupsRequestBuilder {
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<ListPrivilegeGroupsRequest> {
-        Ok(ListPrivilegeGroupsRequest)
+        Ok(self.value)
     }
 }
 
@@ -1463,6 +1541,7 @@ impl ListPrivilegeGroupsRequestBuilder {
 pub struct AddPrivilegesToGroupRequest {
     pub(crate) group_name: String,
     pub(crate) privileges: HashSet<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl AddPrivilegesToGroupRequest {
@@ -1470,6 +1549,7 @@ impl AddPrivilegesToGroupRequest {
         Self {
             group_name: Default::default(),
             privileges: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1553,6 +1633,7 @@ impl AddPrivilegesToGroupRequestBuilder {
 pub struct RemovePrivilegesFromGroupRequest {
     pub(crate) group_name: String,
     pub(crate) privileges: HashSet<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl RemovePrivilegesFromGroupRequest {
@@ -1560,6 +1641,7 @@ impl RemovePrivilegesFromGroupRequest {
         Self {
             group_name: Default::default(),
             privileges: Default::default(),
+            options: Default::default(),
         }
     }
 
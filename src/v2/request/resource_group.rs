@@ -18,10 +18,21 @@
 
 use crate::proto::milvus;
 use crate::v2::error::{Error, Result};
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::{positive_i32, positive_i64, required};
 pub use crate::v2::types::ResourceGroupConfig;
 use std::collections::HashMap;
 
+request_options! {
+    CreateResourceGroupRequest => CreateResourceGroupRequestBuilder,
+    DropResourceGroupRequest => DropResourceGroupRequestBuilder,
+    UpdateResourceGroupsRequest => UpdateResourceGroupsRequestBuilder,
+    TransferNodeRequest => TransferNodeRequestBuilder,
+    TransferReplicaRequest => TransferReplicaRequestBuilder,
+    ListResourceGroupsRequest => ListResourceGroupsRequestBuilder,
+    DescribeResourceGroupRequest => DescribeResourceGroupRequestBuilder,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // CreateResourceGroupRequest
 ///////////////////////////////////////////////////////////////////////////////
@@ -31,6 +42,7 @@ use std::collections::HashMap;
 pub struct CreateResourceGroupRequest {
     pub(crate) name: String,
     pub(crate) config: ResourceGroupConfig,
+    pub(crate) options: RequestOptions,
 }
 
 impl CreateResourceGroupRequest {
@@ -38,6 +50,7 @@ impl CreateResourceGroupRequest {
         Self {
             name: String::new(),
             config: ResourceGroupConfig::new(),
+            options: Default::default(),
         }
     }
 }
@@ -111,12 +124,14 @@ impl CreateResourceGroupRequestBuilder {
 #[non_exhaustive]
 pub struct DropResourceGroupRequest {
     pub(crate) group_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropResourceGroupRequest {
     fn empty() -> Self {
         Self {
             group_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -176,12 +191,14 @@ impl DropResourceGroupRequestBuilder {
 #[non_exhaustive]
 pub struct UpdateResourceGroupsRequest {
     pub(crate) groups: HashMap<String, ResourceGroupConfig>,
+    pub(crate) options: RequestOptions,
 }
 
 impl UpdateResourceGroupsRequest {
     fn empty() -> Self {
         Self {
             groups: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -258,6 +275,7 @@ pub struct TransferNodeRequest {
     pub(crate) source_group: String,
     pub(crate) target_group: String,
     pub(crate) node_count: i32,
+    pub(crate) options: RequestOptions,
 }
 
 impl TransferNodeRequest {
@@ -266,6 +284,7 @@ impl TransferNodeRequest {
             source_group: Default::default(),
             target_group: Default::default(),
             node_count: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -355,6 +374,7 @@ pub struct TransferReplicaRequest {
     pub(crate) source_group: String,
     pub(crate) target_group: String,
     pub(crate) replica_count: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl TransferReplicaRequest {
@@ -415,6 +435,7 @@ impl TransferReplicaRequest {
             source_group: String::new(),
             target_group: String::new(),
             replica_count: 1,
+            options: Default::default(),
         }
     }
 }
@@ -473,19 +494,25 @@ impl TransferReplicaRequestBuilder {
 // ListResourceGroupsRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 list_resource_groups operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct ListResourceGroupsRequest;
+pub struct ListResourceGroupsRequest {
+    pub(crate) options: RequestOptions,
+}
 
 impl ListResourceGroupsRequest {
     /// Creates a builder for this request.
     pub fn builder() -> ListResourceGroupsRequestBuilder {
-        ListResourceGroupsRequestBuilder
+        ListResourceGroupsRequestBuilder {
+            value: Self {
+                options: RequestOptions::default(),
+            },
+        }
     }
 
     /// Converts this request back into a builder while preserving its current values.
     pub fn into_builder(self) -> ListResourceGroupsRequestBuilder {
-        ListResourceGroupsRequestBuilder
+        ListResourceGroupsRequestBuilder { value: self }
     }
 
     pub(crate) fn into_proto(self) -> milvus::ListResourceGroupsRequest {
@@ -497,13 +524,15 @@ impl ListResourceGroupsRequest {
 // ListResourceGroupsRequestBuilder
 ///////////////////////////////////////////////////////////////////////////////
 /// Builder for ListResourceGroupsRequest.
-#[derive(Debug, Clone, Copy)]
-pub struct ListResourceGroupsRequestBuilder;
+#[derive(Debug, Clone)]
+pub struct ListResourceGroupsRequestBuilder {
+    value: ListResourceGroupsRequest,
+}
 
 impl ListResourceGroupsRequestBuilder {
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<ListResourceGroupsRequest> {
-        Ok(ListResourceGroupsRequest)
+        Ok(self.value)
     }
 }
 
@@ -515,12 +544,14 @@ impl ListResourceGroupsRequestBuilder {
 #[non_exhaustive]
 pub struct DescribeResourceGroupRequest {
     pub(crate) group_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DescribeResourceGroupRequest {
     fn empty() -> Self {
         Self {
             group_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -18,8 +18,21 @@
 
 use crate::proto::milvus;
 use crate::v2::error::Result;
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::{non_negative_i64, positive_i64, required};
 
+request_options! {
+    CreateSnapshotRequest => CreateSnapshotRequestBuilder,
+    DropSnapshotRequest => DropSnapshotRequestBuilder,
+    ListSnapshotsRequest => ListSnapshotsRequestBuilder,
+    DescribeSnapshotRequest => DescribeSnapshotRequestBuilder,
+    RestoreSnapshotRequest => RestoreSnapshotRequestBuilder,
+    GetRestoreSnapshotStateRequest => GetRestoreSnapshotStateRequestBuilder,
+    ListRestoreSnapshotJobsRequest => ListRestoreSnapshotJobsRequestBuilder,
+    PinSnapshotDataRequest => PinSnapshotDataRequestBuilder,
+    UnpinSnapshotDataRequest => UnpinSnapshotDataRequestBuilder,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // CreateSnapshotRequest
 ///////////////////////////////////////////////////////////////////////////////
@@ -32,6 +45,7 @@ pub struct CreateSnapshotRequest {
     pub(crate) snapshot_name: String,
     pub(crate) description: String,
     pub(crate) compaction_protection_seconds: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl CreateSnapshotRequest {
@@ -42,6 +56,7 @@ impl CreateSnapshotRequest {
             snapshot_name: Default::default(),
             description: Default::default(),
             compaction_protection_seconds: 0,
+            options: Default::default(),
         }
     }
 
@@ -156,6 +171,7 @@ pub struct DropSnapshotRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) snapshot_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DropSnapshotRequest {
@@ -164,6 +180,7 @@ impl DropSnapshotRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             snapshot_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -251,6 +268,7 @@ impl DropSnapshotRequestBuilder {
 pub struct ListSnapshotsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl ListSnapshotsRequest {
@@ -258,6 +276,7 @@ impl ListSnapshotsRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -330,6 +349,7 @@ pub struct DescribeSnapshotRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
     pub(crate) snapshot_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl DescribeSnapshotRequest {
@@ -338,6 +358,7 @@ impl DescribeSnapshotRequest {
             database_name: Default::default(),
             collection_name: Default::default(),
             snapshot_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -426,6 +447,7 @@ pub struct RestoreSnapshotRequest {
     pub(crate) source_collection_name: String,
     pub(crate) target_database_name: Option<String>,
     pub(crate) target_collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl RestoreSnapshotRequest {
@@ -436,6 +458,7 @@ impl RestoreSnapshotRequest {
             source_collection_name: Default::default(),
             target_database_name: Default::default(),
             target_collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -546,15 +569,19 @@ impl RestoreSnapshotRequestBuilder {
 // GetRestoreSnapshotStateRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 get_restore_snapshot_state operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct GetRestoreSnapshotStateRequest {
     pub(crate) job_id: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetRestoreSnapshotStateRequest {
     fn empty() -> Self {
-        Self { job_id: 0 }
+        Self {
+            job_id: 0,
+            options: Default::default(),
+        }
     }
 
     /// Creates a builder for this request.
@@ -617,6 +644,7 @@ impl GetRestoreSnapshotStateRequestBuilder {
 pub struct ListRestoreSnapshotJobsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl ListRestoreSnapshotJobsRequest {
@@ -624,6 +652,7 @@ impl ListRestoreSnapshotJobsRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -697,6 +726,7 @@ pub struct PinSnapshotDataRequest {
     pub(crate) collection_name: String,
     pub(crate) snapshot_name: String,
     pub(crate) ttl_seconds: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl PinSnapshotDataRequest {
@@ -706,6 +736,7 @@ impl PinSnapshotDataRequest {
             collection_name: Default::default(),
             snapshot_name: Default::default(),
             ttl_seconds: 0,
+            options: Default::default(),
         }
     }
 
@@ -799,15 +830,19 @@ impl PinSnapshotDataRequestBuilder {
 // UnpinSnapshotDataRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 unpin_snapshot_data operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct UnpinSnapshotDataRequest {
     pub(crate) pin_id: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl UnpinSnapshotDataRequest {
     fn empty() -> Self {
-        Self { pin_id: 0 }
+        Self {
+            pin_id: 0,
+            options: Default::default(),
+        }
     }
 
     /// Creates a builder for this request.
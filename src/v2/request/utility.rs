@@ -18,22 +18,48 @@
 
 use crate::proto::milvus;
 use crate::v2::error::{Error, Result};
+use crate::v2::request::options::RequestOptions;
 use crate::v2::request::validation::{non_empty_strings, positive_i64, required, required_slice};
 use std::collections::HashMap;
 
+request_options! {
+    GetServerVersionRequest => GetServerVersionRequestBuilder,
+    CheckHealthRequest => CheckHealthRequestBuilder,
+    FlushRequest => FlushRequestBuilder,
+    FlushAllRequest => FlushAllRequestBuilder,
+    GetFlushAllStateRequest => GetFlushAllStateRequestBuilder,
+    ListPersistentSegmentsRequest => ListPersistentSegmentsRequestBuilder,
+    ListQuerySegmentsRequest => ListQuerySegmentsRequestBuilder,
+    CompactRequest => CompactRequestBuilder,
+    OptimizeRequest => OptimizeRequestBuilder,
+    GetCompactionStateRequest => GetCompactionStateRequestBuilder,
+    GetCompactionPlansRequest => GetCompactionPlansRequestBuilder,
+    RunAnalyzerRequest => RunAnalyzerRequestBuilder,
+    RefreshExternalCollectionRequest => RefreshExternalCollectionRequestBuilder,
+    GetRefreshExternalCollectionProgressRequest => GetRefreshExternalCollectionProgressRequestBuilder,
+    ListRefreshExternalCollectionJobsRequest => ListRefreshExternalCollectionJobsRequestBuilder,
+    AddFileResourceRequest => AddFileResourceRequestBuilder,
+    RemoveFileResourceRequest => RemoveFileResourceRequestBuilder,
+    ListFileResourcesRequest => ListFileResourcesRequestBuilder,
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // GetServerVersionRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 server_version operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct GetServerVersionRequest {
     pub(crate) detail: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetServerVersionRequest {
     fn empty() -> Self {
-        Self { detail: false }
+        Self {
+            detail: false,
+            options: Default::default(),
+        }
     }
 
     /// Creates a builder for this request.
@@ -88,19 +114,25 @@ impl GetServerVersionRequestBuilder {
 // CheckHealthRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 check_health operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct CheckHealthRequest;
+pub struct CheckHealthRequest {
+    pub(crate) options: RequestOptions,
+}
 
 impl CheckHealthRequest {
     /// Creates a builder for this request.
     pub fn builder() -> CheckHealthRequestBuilder {
-        CheckHealthRequestBuilder
+        CheckHealthRequestBuilder {
+            value: Self {
+                options: RequestOptions::default(),
+            },
+        }
     }
 
     /// Converts this request back into a builder while preserving its current values.
     pub fn into_builder(self) -> CheckHealthRequestBuilder {
-        CheckHealthRequestBuilder
+        CheckHealthRequestBuilder { value: self }
     }
 
     pub(crate) fn into_proto(self) -> milvus::CheckHealthRequest {
@@ -112,13 +144,15 @@ impl CheckHealthRequest {
 // CheckHealthRequestBuilder
 ///////////////////////////////////////////////////////////////////////////////
 /// Builder for CheckHealthRequest.
-#[derive(Debug, Clone, Copy)]
-pub struct CheckHealthRequestBuilder;
+#[derive(Debug, Clone)]
+pub struct CheckHealthRequestBuilder {
+    value: CheckHealthRequest,
+}
 
 impl CheckHealthRequestBuilder {
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<CheckHealthRequest> {
-        Ok(CheckHealthRequest)
+        Ok(self.value)
     }
 }
 
@@ -134,6 +168,7 @@ pub struct FlushRequest {
     /// Maximum time to wait for all returned segments to be flushed.
     /// Zero waits indefinitely; negative values are invalid.
     pub(crate) wait_flushed_ms: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl FlushRequest {
@@ -142,6 +177,7 @@ impl FlushRequest {
             database_name: Default::default(),
             collection_names: Default::default(),
             wait_flushed_ms: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -236,6 +272,7 @@ pub struct FlushAllRequest {
     /// Maximum time to wait for the cluster-wide flush to complete.
     /// Zero waits indefinitely; negative values are invalid.
     pub(crate) wait_flushed_ms: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl FlushAllRequest {
@@ -243,6 +280,7 @@ impl FlushAllRequest {
         Self {
             database_name: Default::default(),
             wait_flushed_ms: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -323,6 +361,7 @@ pub struct GetFlushAllStateRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) flush_all_timestamp: u64,
     pub(crate) channel_timestamps: HashMap<String, u64>,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetFlushAllStateRequest {
@@ -331,6 +370,7 @@ impl GetFlushAllStateRequest {
             database_name: Default::default(),
             flush_all_timestamp: Default::default(),
             channel_timestamps: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -416,6 +456,7 @@ impl GetFlushAllStateRequestBuilder {
 pub struct ListPersistentSegmentsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl ListPersistentSegmentsRequest {
@@ -423,6 +464,7 @@ impl ListPersistentSegmentsRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -497,6 +539,7 @@ impl ListPersistentSegmentsRequestBuilder {
 pub struct ListQuerySegmentsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl ListQuerySegmentsRequest {
@@ -504,6 +547,7 @@ impl ListQuerySegmentsRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -580,6 +624,7 @@ pub struct CompactRequest {
     pub(crate) collection_name: String,
     pub(crate) target_size: i64,
     pub(crate) clustering_compaction: bool,
+    pub(crate) options: RequestOptions,
 }
 
 impl CompactRequest {
@@ -589,6 +634,7 @@ impl CompactRequest {
             collection_name: Default::default(),
             target_size: Default::default(),
             clustering_compaction: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -701,6 +747,7 @@ pub struct OptimizeRequest {
     /// Overall task timeout in milliseconds. A value less than or equal to
     /// zero means no overall timeout.
     pub(crate) timeout_ms: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl OptimizeRequest {
@@ -711,6 +758,7 @@ impl OptimizeRequest {
             target_size: Default::default(),
             async_mode: Default::default(),
             timeout_ms: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -806,16 +854,18 @@ impl OptimizeRequestBuilder {
 // GetCompactionStateRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 get_compaction_state operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct GetCompactionStateRequest {
     pub(crate) compaction_id: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetCompactionStateRequest {
     fn empty() -> Self {
         Self {
             compaction_id: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -870,16 +920,18 @@ impl GetCompactionStateRequestBuilder {
 // GetCompactionPlansRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 get_compaction_plans operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct GetCompactionPlansRequest {
     pub(crate) compaction_id: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetCompactionPlansRequest {
     fn empty() -> Self {
         Self {
             compaction_id: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -945,6 +997,7 @@ pub struct RunAnalyzerRequest {
     pub(crate) collection_name: String,
     pub(crate) field_name: String,
     pub(crate) analyzer_names: Vec<String>,
+    pub(crate) options: RequestOptions,
 }
 
 impl RunAnalyzerRequest {
@@ -958,6 +1011,7 @@ impl RunAnalyzerRequest {
             collection_name: Default::default(),
             field_name: Default::default(),
             analyzer_names: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1113,6 +1167,7 @@ pub struct RefreshExternalCollectionRequest {
     pub(crate) collection_name: String,
     pub(crate) external_source: String,
     pub(crate) external_spec: Option<serde_json::Value>,
+    pub(crate) options: RequestOptions,
 }
 
 impl RefreshExternalCollectionRequest {
@@ -1122,6 +1177,7 @@ impl RefreshExternalCollectionRequest {
             collection_name: Default::default(),
             external_source: Default::default(),
             external_spec: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1217,15 +1273,19 @@ impl RefreshExternalCollectionRequestBuilder {
 // GetRefreshExternalCollectionProgressRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 get_refresh_external_collection_progress operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct GetRefreshExternalCollectionProgressRequest {
     pub(crate) job_id: i64,
+    pub(crate) options: RequestOptions,
 }
 
 impl GetRefreshExternalCollectionProgressRequest {
     fn empty() -> Self {
-        Self { job_id: 0 }
+        Self {
+            job_id: 0,
+            options: Default::default(),
+        }
     }
 
     /// Creates a builder for this request.
@@ -1285,6 +1345,7 @@ impl GetRefreshExternalCollectionProgressRequestBuilder {
 pub struct ListRefreshExternalCollectionJobsRequest {
     pub(crate) database_name: Option<String>,
     pub(crate) collection_name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl ListRefreshExternalCollectionJobsRequest {
@@ -1292,6 +1353,7 @@ impl ListRefreshExternalCollectionJobsRequest {
         Self {
             database_name: Default::default(),
             collection_name: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -1366,6 +1428,7 @@ impl ListRefreshExternalCollectionJobsRequestBuilder {
 pub struct AddFileResourceRequest {
     pub(crate) name: String,
     pub(crate) path: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl AddFileResourceRequest {
@@ -1373,6 +1436,7 @@ impl AddFileResourceRequest {
         Self {
             name: String::new(),
             path: String::new(),
+            options: Default::default(),
         }
     }
 
@@ -1445,12 +1509,14 @@ impl AddFileResourceRequestBuilder {
 #[non_exhaustive]
 pub struct RemoveFileResourceRequest {
     pub(crate) name: String,
+    pub(crate) options: RequestOptions,
 }
 
 impl RemoveFileResourceRequest {
     fn empty() -> Self {
         Self {
             name: String::new(),
+            options: Default::default(),
         }
     }
 
@@ -1506,19 +1572,25 @@ impl RemoveFileResourceRequestBuilder {
 // ListFileResourcesRequest
 ///////////////////////////////////////////////////////////////////////////////
 /// Parameters for the ClientV2 list_file_resources operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct ListFileResourcesRequest;
+pub struct ListFileResourcesRequest {
+    pub(crate) options: RequestOptions,
+}
 
 impl ListFileResourcesRequest {
     /// Creates a builder for this request.
     pub fn builder() -> ListFileResourcesRequestBuilder {
-        ListFileResourcesRequestBuilder
+        ListFileResourcesRequestBuilder {
+            value: Self {
+                options: RequestOptions::default(),
+            },
+        }
     }
 
     /// Converts this request back into a builder while preserving its current values.
     pub fn into_builder(self) -> ListFileResourcesRequestBuilder {
-        ListFileResourcesRequestBuilder
+        ListFileResourcesRequestBuilder { value: self }
     }
 
     pub(crate) fn into_proto(self) -> milvus::ListFileResourcesRequest {
@@ -1530,13 +1602,15 @@ impl ListFileResourcesRequest {
 // ListFileResourcesRequestBuilder
 ///////////////////////////////////////////////////////////////////////////////
 /// Builder for ListFileResourcesRequest.
-#[derive(Debug, Clone, Copy)]
-pub struct ListFileResourcesRequestBuilder;
+#[derive(Debug, Clone)]
+pub struct ListFileResourcesRequestBuilder {
+    value: ListFileResourcesRequest,
+}
 
 impl ListFileResourcesRequestBuilder {
     /// Validates the configured values and builds the request.
     pub fn build(self) -> Result<ListFileResourcesRequest> {
-        Ok(ListFileResourcesRequest)
+        Ok(self.value)
     }
 }
 
@@ -0,0 +1,718 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client-side reranking over result sets from separate searches.
+//!
+//! [`rerank`] fuses several [`RankedResults`], for example separate `search` calls, a federated
+//! search, or hits from an external engine, into one ranking per query vector. Hits are matched
+//! across inputs by primary key and each distinct hit becomes a [`Candidate`] that a [`Reranker`]
+//! scores. The built-in rerankers mirror the server-side strategies: [`RrfReranker`],
+//! [`WeightedReranker`], and [`DecayReranker`]. Implement [`Reranker`] to score candidates with a
+//! cross-encoder or another model:
+//!
+//! ```no_run
+//! use milvus::v2::error::Result;
+//! use milvus::v2::rerank::{BoxFuture, Candidate, Reranker};
+//!
+//! struct CrossEncoder {
+//!     queries: Vec<String>,
+//! }
+//!
+//! impl CrossEncoder {
+//!     async fn score_pairs(&self, query: &str, documents: &[&str]) -> Result<Vec<f32>> {
+//!         // Call the model here.
+//!         Ok(vec![0.0; documents.len()])
+//!     }
+//! }
+//!
+//! impl Reranker for CrossEncoder {
+//!     fn score<'a>(
+//!         &'a self,
+//!         query: usize,
+//!         candidates: &'a [Candidate<'a>],
+//!     ) -> BoxFuture<'a, Result<Vec<f32>>> {
+//!         Box::pin(async move {
+//!             let documents = candidates
+//!                 .iter()
+//!                 .map(|candidate| candidate.row().get_str("text"))
+//!                 .collect::<Result<Vec<_>>>()?;
+//!             self.score_pairs(&self.queries[query], &documents).await
+//!         })
+//!     }
+//! }
+//! ```
+//!
+//! The fused results keep the output fields of each hit's first appearance, so every input must
+//! report the same output fields.
+
+use crate::v2::error::{Error, Result};
+use crate::v2::response::dql::select_result_rows;
+use crate::v2::types::{MetricType, ResultRow, ResultValue, SearchResults};
+use std::collections::HashMap;
+use std::f32::consts::PI;
+
+pub use futures::future::BoxFuture;
+
+///////////////////////////////////////////////////////////////////////////////
+// RankedResults
+///////////////////////////////////////////////////////////////////////////////
+/// One input ranking: results for every query vector and the metric that scored them.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RankedResults {
+    pub(crate) results: SearchResults,
+    pub(crate) metric_type: MetricType,
+}
+
+impl RankedResults {
+    /// Creates an input ranking.
+    pub fn new(results: SearchResults, metric_type: MetricType) -> Self {
+        Self {
+            results,
+            metric_type,
+        }
+    }
+
+    /// Returns the results.
+    pub fn results(&self) -> &SearchResults {
+        &self.results
+    }
+
+    /// Returns the metric type that scored the results.
+    pub fn metric_type(&self) -> MetricType {
+        self.metric_type
+    }
+}
+
+impl From<(SearchResults, MetricType)> for RankedResults {
+    fn from((results, metric_type): (SearchResults, MetricType)) -> Self {
+        Self::new(results, metric_type)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Candidate
+///////////////////////////////////////////////////////////////////////////////
+/// Position of a candidate within one input ranking.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct Rank {
+    pub(crate) rank: usize,
+    pub(crate) score: f32,
+    pub(crate) metric_type: MetricType,
+}
+
+impl Rank {
+    /// Returns the zero-based position within the input.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns the score the input reported.
+    pub fn score(&self) -> f32 {
+        self.score
+    }
+
+    /// Returns the metric type of the input.
+    pub fn metric_type(&self) -> MetricType {
+        self.metric_type
+    }
+}
+
+/// One distinct hit of a query, matched across every input by primary key.
+#[derive(Debug, Clone)]
+pub struct Candidate<'a> {
+    row: ResultRow<'a>,
+    origin: (usize, usize),
+    ranks: Vec<Option<Rank>>,
+}
+
+impl<'a> Candidate<'a> {
+    /// Returns the hit's row in the first input it appears in, with its output fields.
+    pub fn row(&self) -> &ResultRow<'a> {
+        &self.row
+    }
+
+    /// Returns the hit's position in each input, or `None` for inputs that did not return it.
+    pub fn ranks(&self) -> &[Option<Rank>] {
+        &self.ranks
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reranker
+///////////////////////////////////////////////////////////////////////////////
+/// Scores the candidates of one query vector; higher scores rank first.
+pub trait Reranker: Send + Sync {
+    /// Returns one score per candidate, in candidate order.
+    ///
+    /// `query` is the index of the query vector within the inputs.
+    fn score<'a>(
+        &'a self,
+        query: usize,
+        candidates: &'a [Candidate<'a>],
+    ) -> BoxFuture<'a, Result<Vec<f32>>>;
+}
+
+/// Fuses several input rankings into one ranking per query vector, keeping the `limit` best hits.
+///
+/// Every input must hold the same number of query results. Ties keep the order in which hits first
+/// appear across the inputs.
+pub async fn rerank(
+    reranker: &dyn Reranker,
+    inputs: Vec<RankedResults>,
+    limit: usize,
+) -> Result<SearchResults> {
+    let query_count = inputs.first().map_or(0, |input| input.results.len());
+    if inputs
+        .iter()
+        .any(|input| input.results.len() != query_count)
+    {
+        return Err(Error::validation(
+            "inputs".into(),
+            "every input must hold results for the same number of query vectors".into(),
+        ));
+    }
+
+    let mut selections = Vec::with_capacity(query_count);
+    for query in 0..query_count {
+        let candidates = candidates(&inputs, query)?;
+        let scores = reranker.score(query, &candidates).await?;
+        if scores.len() != candidates.len() {
+            return Err(Error::validation(
+                "reranker".into(),
+                format!(
+                    "returned {} scores for {} candidates",
+                    scores.len(),
+                    candidates.len()
+                ),
+            ));
+        }
+        let mut order = (0..candidates.len()).collect::<Vec<_>>();
+        order.sort_by(|&left, &right| scores[right].total_cmp(&scores[left]));
+        order.truncate(limit);
+        selections.push((
+            order
+                .iter()
+                .map(|&index| candidates[index].origin)
+                .collect::<Vec<_>>(),
+            order.iter().map(|&index| scores[index]).collect::<Vec<_>>(),
+        ));
+    }
+
+    let mut per_input = inputs
+        .into_iter()
+        .map(|input| input.results.results.into_iter())
+        .collect::<Vec<_>>();
+    let mut results = Vec::with_capacity(query_count);
+    for (rows, scores) in selections {
+        let parts = per_input
+            .iter_mut()
+            .map(|results| results.next().expect("query counts were checked"))
+            .collect();
+        results.push(select_result_rows(parts, &rows, scores)?);
+    }
+    Ok(SearchResults::new().results(results))
+}
+
+fn candidates(inputs: &[RankedResults], query: usize) -> Result<Vec<Candidate<'_>>> {
+    let mut candidates: Vec<Candidate<'_>> = Vec::new();
+    let mut positions = HashMap::new();
+    for (input_index, input) in inputs.iter().enumerate() {
+        let result = &input.results.results[query];
+        for (rank, row) in result.rows()?.enumerate() {
+            let score = result.scores.get(rank).copied().ok_or_else(|| {
+                Error::validation(
+                    "inputs".into(),
+                    format!("input {input_index} has fewer scores than rows"),
+                )
+            })?;
+            let entry = Rank {
+                rank,
+                score,
+                metric_type: input.metric_type,
+            };
+            let key = result.ids.value_at(rank)?.to_string();
+            let position = *positions.entry(key).or_insert_with(|| {
+                candidates.push(Candidate {
+                    row,
+                    origin: (input_index, rank),
+                    ranks: vec![None; inputs.len()],
+                });
+                candidates.len() - 1
+            });
+            candidates[position].ranks[input_index].get_or_insert(entry);
+        }
+    }
+    Ok(candidates)
+}
+
+/// Maps a raw score onto `[0, 1]`, higher meaning closer, using the normalization Milvus applies
+/// in weighted reranking.
+///
+/// `COSINE` scores are shifted from `[-1, 1]`, `IP` scores are squashed with an arctangent, `BM25`
+/// scores use a one-sided arctangent, and distances are inverted with an arctangent.
+pub fn normalize_score(metric_type: MetricType, score: f32) -> f32 {
+    match metric_type {
+        MetricType::Cosine | MetricType::MaxSimCosine => (1.0 + score) / 2.0,
+        MetricType::Bm25 => 2.0 * score.atan() / PI,
+        metric_type if metric_type.is_distance() => 1.0 - 2.0 * score.atan() / PI,
+        _ => 0.5 + score.atan() / PI,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RrfReranker
+///////////////////////////////////////////////////////////////////////////////
+/// Reciprocal rank fusion: a candidate scores `1 / (k + rank)` per input, with one-based ranks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct RrfReranker {
+    pub(crate) k: f32,
+}
+
+impl RrfReranker {
+    /// Creates a reranker with the Milvus default `k` of 60.
+    pub fn new() -> Self {
+        Self { k: 60.0 }
+    }
+
+    /// Sets the smoothing constant and returns the updated value.
+    pub fn k(mut self, value: f32) -> Self {
+        self.k = value;
+        self
+    }
+
+    /// Returns the configured smoothing constant.
+    pub fn get_k(&self) -> f32 {
+        self.k
+    }
+}
+
+impl Default for RrfReranker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reranker for RrfReranker {
+    fn score<'a>(
+        &'a self,
+        _query: usize,
+        candidates: &'a [Candidate<'a>],
+    ) -> BoxFuture<'a, Result<Vec<f32>>> {
+        let scores = candidates
+            .iter()
+            .map(|candidate| {
+                candidate
+                    .ranks
+                    .iter()
+                    .flatten()
+                    .map(|rank| 1.0 / (self.k + rank.rank as f32 + 1.0))
+                    .sum()
+            })
+            .collect();
+        Box::pin(async move { Ok(scores) })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// WeightedReranker
+///////////////////////////////////////////////////////////////////////////////
+/// Weighted sum of input scores, normalized per metric by default.
+///
+/// Inputs that did not return a candidate contribute nothing to its score.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct WeightedReranker {
+    pub(crate) weights: Vec<f32>,
+    pub(crate) normalize: bool,
+}
+
+impl WeightedReranker {
+    /// Creates a reranker with one weight per input.
+    pub fn new(weights: Vec<f32>) -> Self {
+        Self {
+            weights,
+            normalize: true,
+        }
+    }
+
+    /// Sets whether scores pass through [`normalize_score`] and returns the updated value.
+    pub fn normalize(mut self, value: bool) -> Self {
+        self.normalize = value;
+        self
+    }
+
+    /// Returns the configured weights.
+    pub fn get_weights(&self) -> &[f32] {
+        &self.weights
+    }
+}
+
+impl Reranker for WeightedReranker {
+    fn score<'a>(
+        &'a self,
+        _query: usize,
+        candidates: &'a [Candidate<'a>],
+    ) -> BoxFuture<'a, Result<Vec<f32>>> {
+        let scores = candidates
+            .iter()
+            .map(|candidate| {
+                if candidate.ranks.len() != self.weights.len() {
+                    return Err(Error::validation(
+                        "weights".into(),
+                        format!(
+                            "{} weights do not match {} inputs",
+                            self.weights.len(),
+                            candidate.ranks.len()
+                        ),
+                    ));
+                }
+                Ok(candidate
+                    .ranks
+                    .iter()
+                    .zip(&self.weights)
+                    .filter_map(|(rank, weight)| {
+                        let rank = rank.as_ref()?;
+                        let score = if self.normalize {
+                            normalize_score(rank.metric_type, rank.score)
+                        } else {
+                            rank.score
+                        };
+                        Some(weight * score)
+                    })
+                    .sum())
+            })
+            .collect();
+        Box::pin(async move { scores })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// DecayReranker
+///////////////////////////////////////////////////////////////////////////////
+/// Shape of the decay curve applied by a [`DecayReranker`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecayFunction {
+    #[default]
+    /// Gaussian decay.
+    Gauss,
+    /// Exponential decay.
+    Exp,
+    /// Linear decay that reaches zero.
+    Linear,
+}
+
+/// Multiplies another reranker's scores by a decay of a numeric output field's distance from an
+/// origin.
+///
+/// Values within `offset` of `origin` keep their full score, and values `offset + scale` away keep
+/// the `decay` fraction of it, as in the server-side decay reranker. The field may hold any
+/// integer or floating-point type, or a JSON number.
+#[non_exhaustive]
+pub struct DecayReranker {
+    pub(crate) base: Box<dyn Reranker>,
+    pub(crate) field_name: String,
+    pub(crate) function: DecayFunction,
+    pub(crate) origin: f64,
+    pub(crate) scale: f64,
+    pub(crate) offset: f64,
+    pub(crate) decay: f64,
+}
+
+impl DecayReranker {
+    /// Creates a Gaussian decay over `field_name` applied to the scores of `base`.
+    pub fn new(
+        base: impl Reranker + 'static,
+        field_name: impl Into<String>,
+        origin: f64,
+        scale: f64,
+    ) -> Self {
+        Self {
+            base: Box::new(base),
+            field_name: field_name.into(),
+            function: DecayFunction::default(),
+            origin,
+            scale,
+            offset: 0.0,
+            decay: 0.5,
+        }
+    }
+
+    /// Sets the decay function and returns the updated value.
+    pub fn function(mut self, value: DecayFunction) -> Self {
+        self.function = value;
+        self
+    }
+
+    /// Sets the offset and returns the updated value.
+    pub fn offset(mut self, value: f64) -> Self {
+        self.offset = value;
+        self
+    }
+
+    /// Sets the decay and returns the updated value.
+    pub fn decay(mut self, value: f64) -> Self {
+        self.decay = value;
+        self
+    }
+
+    fn factor(&self, value: f64) -> f64 {
+        let distance = ((value - self.origin).abs() - self.offset).max(0.0);
+        match self.function {
+            DecayFunction::Gauss => (self.decay.ln() * distance.powi(2) / self.scale.powi(2)).exp(),
+            DecayFunction::Exp => (self.decay.ln() * distance / self.scale).exp(),
+            DecayFunction::Linear => {
+                let reach = self.scale / (1.0 - self.decay);
+                ((reach - distance) / reach).max(0.0)
+            }
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        if !(self.scale.is_finite() && self.scale > 0.0) {
+            return Err(Error::validation(
+                "scale".into(),
+                "must be finite and greater than zero".into(),
+            ));
+        }
+        if !(self.offset.is_finite() && self.offset >= 0.0) {
+            return Err(Error::validation(
+                "offset".into(),
+                "must be finite and non-negative".into(),
+            ));
+        }
+        if !(self.decay > 0.0 && self.decay < 1.0) {
+            return Err(Error::validation(
+                "decay".into(),
+                "must be between 0 and 1, exclusive".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Reranker for DecayReranker {
+    fn score<'a>(
+        &'a self,
+        query: usize,
+        candidates: &'a [Candidate<'a>],
+    ) -> BoxFuture<'a, Result<Vec<f32>>> {
+        Box::pin(async move {
+            self.validate()?;
+            let scores = self.base.score(query, candidates).await?;
+            candidates
+                .iter()
+                .zip(scores)
+                .map(|(candidate, score)| {
+                    let value = numeric_field(candidate.row(), &self.field_name)?;
+                    Ok(score * self.factor(value) as f32)
+                })
+                .collect()
+        })
+    }
+}
+
+/// Reads an integer, floating-point, or JSON number field as `f64`.
+fn numeric_field(row: &ResultRow<'_>, name: &str) -> Result<f64> {
+    match row.get(name)? {
+        ResultValue::Int8(value) => Ok(f64::from(value)),
+        ResultValue::Int16(value) => Ok(f64::from(value)),
+        ResultValue::Int32(value) => Ok(f64::from(value)),
+        ResultValue::Int64(value) => Ok(value as f64),
+        ResultValue::Float(value) => Ok(f64::from(value)),
+        ResultValue::Double(value) => Ok(value),
+        ResultValue::Json(value) if value.is_number() => value
+            .as_f64()
+            .ok_or_else(|| Error::validation(name.into(), "number is out of range".into())),
+        _ => Err(Error::validation(
+            name.into(),
+            "decay field must be numeric".into(),
+        )),
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v2::types::{FieldData, Ids, SingleResult};
+
+    fn input(ids: Vec<i64>, scores: Vec<f32>, metric_type: MetricType) -> RankedResults {
+        let years = ids.iter().map(|id| 2000 + id).collect();
+        let result = SingleResult::new()
+            .ids(Ids::Int64(ids))
+            .scores(scores)
+            .output_fields(vec![FieldData::Int64 {
+                name: "year".into(),
+                values: years,
+            }])
+            .output_field_names(["year"]);
+        RankedResults::new(SearchResults::new().results(vec![result]), metric_type)
+    }
+
+    fn ids(results: &SearchResults) -> &Ids {
+        results.get_results()[0].get_ids()
+    }
+
+    #[tokio::test]
+    async fn rrf_fuses_ranks_across_inputs() {
+        let results = rerank(
+            &RrfReranker::new().k(1.0),
+            vec![
+                input(vec![1, 2, 3], vec![0.9, 0.8, 0.7], MetricType::Cosine),
+                input(vec![3, 4], vec![0.1, 0.2], MetricType::L2),
+            ],
+            3,
+        )
+        .await
+        .unwrap();
+        assert_eq!(ids(&results), &Ids::Int64(vec![3, 1, 2]));
+        assert_eq!(
+            results.get_results()[0].get_scores()[0],
+            1.0 / 4.0 + 1.0 / 2.0
+        );
+        assert_eq!(
+            results.get_results()[0].get_output_fields(),
+            [FieldData::Int64 {
+                name: "year".into(),
+                values: vec![2003, 2001, 2002],
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn weighted_scores_are_normalized_per_metric() {
+        assert_eq!(normalize_score(MetricType::Cosine, 0.0), 0.5);
+        assert_eq!(normalize_score(MetricType::L2, 0.0), 1.0);
+        assert!(normalize_score(MetricType::L2, 10.0) < normalize_score(MetricType::L2, 1.0));
+
+        let inputs = vec![
+            input(vec![1, 2], vec![0.8, 0.6], MetricType::Cosine),
+            input(vec![2, 1], vec![0.0, 5.0], MetricType::L2),
+        ];
+        let results = rerank(&WeightedReranker::new(vec![0.5, 0.5]), inputs.clone(), 2)
+            .await
+            .unwrap();
+        assert_eq!(ids(&results), &Ids::Int64(vec![2, 1]));
+
+        assert!(rerank(&WeightedReranker::new(vec![1.0]), inputs, 2)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn decay_scales_base_scores_by_field_distance() {
+        let reranker = DecayReranker::new(RrfReranker::new(), "year", 2003.0, 1.0).decay(0.5);
+        let results = rerank(
+            &reranker,
+            vec![input(vec![1, 3], vec![0.9, 0.8], MetricType::Ip)],
+            2,
+        )
+        .await
+        .unwrap();
+        assert_eq!(ids(&results), &Ids::Int64(vec![3, 1]));
+        let scores = results.get_results()[0].get_scores();
+        assert_eq!(scores[0], 1.0 / 62.0);
+        assert!((scores[1] - 1.0 / 61.0 * 0.0625).abs() < 1e-6);
+
+        let linear = DecayReranker::new(RrfReranker::new(), "year", 0.0, 1.0)
+            .function(DecayFunction::Linear)
+            .offset(1.0);
+        assert_eq!(linear.factor(0.5), 1.0);
+        assert_eq!(linear.factor(2.0), 0.5);
+        assert_eq!(linear.factor(4.0), 0.0);
+        assert!(DecayReranker::new(RrfReranker::new(), "year", 0.0, 0.0)
+            .validate()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn decay_reads_every_numeric_field_type() {
+        let columns = [
+            FieldData::Int8 {
+                name: "year".into(),
+                values: vec![1, 3],
+            },
+            FieldData::Int16 {
+                name: "year".into(),
+                values: vec![1, 3],
+            },
+            FieldData::Int32 {
+                name: "year".into(),
+                values: vec![1, 3],
+            },
+            FieldData::Float {
+                name: "year".into(),
+                values: vec![1.0, 3.0],
+            },
+            FieldData::Double {
+                name: "year".into(),
+                values: vec![1.0, 3.0],
+            },
+            FieldData::VarChar {
+                name: "year".into(),
+                values: vec!["1".into(), "3".into()],
+            },
+        ];
+        let reranker = DecayReranker::new(RrfReranker::new(), "year", 3.0, 1.0).decay(0.5);
+        for column in columns {
+            let numeric = !matches!(column, FieldData::VarChar { .. });
+            let result = SingleResult::new()
+                .ids(Ids::Int64(vec![1, 2]))
+                .scores(vec![0.9, 0.8])
+                .output_fields(vec![column])
+                .output_field_names(["year"]);
+            let input =
+                RankedResults::new(SearchResults::new().results(vec![result]), MetricType::Ip);
+            let reranked = rerank(&reranker, vec![input], 2).await;
+
+            assert_eq!(reranked.is_ok(), numeric);
+            if let Ok(results) = reranked {
+                assert_eq!(ids(&results), &Ids::Int64(vec![2, 1]));
+            }
+        }
+    }
+
+    struct Truncating;
+
+    impl Reranker for Truncating {
+        fn score<'a>(
+            &'a self,
+            _query: usize,
+            _candidates: &'a [Candidate<'a>],
+        ) -> BoxFuture<'a, Result<Vec<f32>>> {
+            Box::pin(async { Ok(vec![1.0]) })
+        }
+    }
+
+    #[tokio::test]
+    async fn reranker_must_score_every_candidate() {
+        let error = rerank(
+            &Truncating,
+            vec![input(vec![1, 2], vec![0.9, 0.8], MetricType::Ip)],
+            2,
+        )
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("1 scores for 2 candidates"));
+    }
+}
@@ -30,6 +30,8 @@ use std::collections::HashMap;
 /// Response returned by the ClientV2 describe_collection operation.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct DescribeCollectionResponse {
     pub(crate) description: CollectionDesc,
 }
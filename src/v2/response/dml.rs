@@ -17,6 +17,7 @@
 //! Response types returned by data-manipulation operations.
 
 use crate::proto::milvus;
+use crate::v2::error::{Error, Result};
 pub use crate::v2::types::Ids;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -118,6 +119,32 @@ impl DmlResponse {
             timestamp: value.timestamp,
         }
     }
+
+    /// Folds the response of a later chunk into this one; `offset` is the chunk's first row.
+    pub(crate) fn merge(&mut self, other: Self, offset: usize) -> Result<()> {
+        let offset = u32::try_from(offset).map_err(|_| {
+            Error::MalformedResponse("chunk offset does not fit the index type".into())
+        })?;
+        if self.ids.is_empty() {
+            self.ids = other.ids;
+        } else if !other.ids.is_empty() {
+            self.ids.append(other.ids)?;
+        }
+        self.succeeded_indices.extend(
+            other
+                .succeeded_indices
+                .into_iter()
+                .map(|index| index + offset),
+        );
+        self.failed_indices
+            .extend(other.failed_indices.into_iter().map(|index| index + offset));
+        self.acknowledged &= other.acknowledged;
+        self.insert_count += other.insert_count;
+        self.delete_count += other.delete_count;
+        self.upsert_count += other.upsert_count;
+        self.timestamp = self.timestamp.max(other.timestamp);
+        Ok(())
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
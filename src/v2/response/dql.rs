@@ -23,18 +23,19 @@
 use crate::proto::{milvus, schema};
 use crate::v2::error::{Error, Result};
 use crate::v2::types::Ids;
-use crate::v2::types::{DataType, FieldData, SparseVector};
+use crate::v2::types::{DataType, DenseVectors, FieldData, SparseVector};
 pub use crate::v2::types::{HighlightResult, QueryResults, SearchResults, SingleResult};
 use std::collections::{HashMap, HashSet};
 
-fn field_data(value: schema::FieldData) -> Result<FieldData> {
+fn field_data(value: schema::FieldData, dense_vectors: bool) -> Result<FieldData> {
     let field_name = value.field_name.clone();
-    decode_field_data(value).ok_or_else(|| {
+    decode_field_data(value, dense_vectors).ok_or_else(|| {
         Error::MalformedResponse(format!("failed to decode response field {field_name:?}"))
     })
 }
 
-fn decode_field_data(value: schema::FieldData) -> Option<FieldData> {
+/// Decodes one response column; `dense_vectors` keeps float vectors in their contiguous buffer.
+fn decode_field_data(value: schema::FieldData, dense_vectors: bool) -> Option<FieldData> {
     use schema::{field_data::Field, scalar_field, vector_field};
     let data_type = schema::DataType::try_from(value.r#type).ok();
     let valid_data = value.valid_data;
@@ -140,6 +141,27 @@ fn decode_field_data(value: schema::FieldData) -> Option<FieldData> {
                     values: v.data,
                 })
             }
+            #[cfg(feature = "geo")]
+            scalar_field::Data::GeometryData(v)
+                if matches!(data_type, Some(schema::DataType::Geometry)) =>
+            {
+                let values = if v.data.len() == valid_data.len() {
+                    v.data
+                        .into_iter()
+                        .zip(&valid_data)
+                        .filter_map(|(bytes, valid)| (*valid).then_some(bytes))
+                        .collect()
+                } else {
+                    v.data
+                };
+                Some(FieldData::Geometry {
+                    name,
+                    values: values
+                        .iter()
+                        .map(|bytes| crate::v2::geo::wkb_to_wkt(bytes).ok())
+                        .collect::<Option<_>>()?,
+                })
+            }
             scalar_field::Data::TimestamptzData(v)
                 if matches!(data_type, Some(schema::DataType::Timestamptz)) =>
             {
@@ -175,9 +197,19 @@ fn decode_field_data(value: schema::FieldData) -> Option<FieldData> {
                         && dimension > 0
                         && v.data.len() % dimension == 0 =>
                 {
-                    Some(FieldData::FloatVector {
-                        name,
-                        values: v.data.chunks(dimension).map(<[f32]>::to_vec).collect(),
+                    Some(if dense_vectors {
+                        FieldData::DenseFloatVector {
+                            name,
+                            values: DenseVectors {
+                                dim: dimension,
+                                data: v.data,
+                            },
+                        }
+                    } else {
+                        FieldData::FloatVector {
+                            name,
+                            values: v.data.chunks(dimension).map(<[f32]>::to_vec).collect(),
+                        }
                     })
                 }
                 Some(vector_field::Data::BinaryVector(v))
@@ -234,7 +266,9 @@ fn decode_field_data(value: schema::FieldData) -> Option<FieldData> {
                             .collect::<Option<Vec<_>>>()?,
                     })
                 }
-                None if valid_count == 0 => empty_vector_field_data(name, data_type, dimension),
+                None if valid_count == 0 => {
+                    empty_vector_field_data(name, data_type, dimension, dense_vectors)
+                }
                 _ => None,
             }
         }
@@ -261,8 +295,18 @@ fn empty_vector_field_data(
     name: String,
     data_type: Option<schema::DataType>,
     dimension: usize,
+    dense_vectors: bool,
 ) -> Option<FieldData> {
     Some(match data_type? {
+        schema::DataType::FloatVector if dimension > 0 && dense_vectors => {
+            FieldData::DenseFloatVector {
+                name,
+                values: DenseVectors {
+                    dim: dimension,
+                    data: Vec::new(),
+                },
+            }
+        }
         schema::DataType::FloatVector if dimension > 0 => FieldData::FloatVector {
             name,
             values: Vec::new(),
@@ -511,22 +555,21 @@ fn compact_field_data(data: FieldData, valid_data: &[bool]) -> Option<FieldData>
             name,
             values: compact(values, valid_data)?,
         },
+        FieldData::DenseFloatVector { name, values } => FieldData::DenseFloatVector {
+            name,
+            values: values.filter_rows(valid_data)?,
+        },
         FieldData::Nullable { .. } => return None,
     })
 }
 
 fn decode_sparse_vector(bytes: Vec<u8>) -> Option<SparseVector> {
-    if bytes.len() % 8 != 0 {
-        return None;
-    }
-    bytes
-        .chunks_exact(8)
-        .map(|item| {
-            let index = u32::from_le_bytes(item[..4].try_into().ok()?);
-            let value = f32::from_le_bytes(item[4..].try_into().ok()?);
-            value.is_finite().then_some((index, value))
-        })
-        .collect()
+    let vector = SparseVector::from_bytes_unordered(&bytes).ok()?;
+    vector
+        .values()
+        .iter()
+        .all(|value| value.is_finite())
+        .then_some(vector)
 }
 
 fn struct_field_data(name: String, value: schema::StructArrayField) -> Option<FieldData> {
@@ -544,14 +587,17 @@ fn struct_field_data(name: String, value: schema::StructArrayField) -> Option<Fi
                     .data
                     .into_iter()
                     .map(|scalars| {
-                        field_data_to_json_values(decode_field_data(schema::FieldData {
-                            r#type: array.element_type,
-                            field_name: field_name.clone(),
-                            field_id: field.field_id,
-                            is_dynamic: false,
-                            valid_data: Vec::new(),
-                            field: Some(proto_field_data::Field::Scalars(scalars)),
-                        })?)
+                        field_data_to_json_values(decode_field_data(
+                            schema::FieldData {
+                                r#type: array.element_type,
+                                field_name: field_name.clone(),
+                                field_id: field.field_id,
+                                is_dynamic: false,
+                                valid_data: Vec::new(),
+                                field: Some(proto_field_data::Field::Scalars(scalars)),
+                            },
+                            false,
+                        )?)
                     })
                     .collect::<Option<Vec<_>>>()?
             }
@@ -563,14 +609,17 @@ fn struct_field_data(name: String, value: schema::StructArrayField) -> Option<Fi
                     .data
                     .into_iter()
                     .map(|vectors| {
-                        field_data_to_json_values(decode_field_data(schema::FieldData {
-                            r#type: array.element_type,
-                            field_name: field_name.clone(),
-                            field_id: field.field_id,
-                            is_dynamic: false,
-                            valid_data: Vec::new(),
-                            field: Some(proto_field_data::Field::Vectors(vectors)),
-                        })?)
+                        field_data_to_json_values(decode_field_data(
+                            schema::FieldData {
+                                r#type: array.element_type,
+                                field_name: field_name.clone(),
+                                field_id: field.field_id,
+                                is_dynamic: false,
+                                valid_data: Vec::new(),
+                                field: Some(proto_field_data::Field::Vectors(vectors)),
+                            },
+                            false,
+                        )?)
                     })
                     .collect::<Option<Vec<_>>>()?
             }
@@ -645,6 +694,10 @@ fn field_data_to_json_values(value: FieldData) -> Option<Vec<serde_json::Value>>
             .into_iter()
             .map(|value| Value::Array(value.into_iter().map(|item| json!(item)).collect()))
             .collect(),
+        FieldData::DenseFloatVector { values, .. } => values
+            .rows()
+            .map(|value| Value::Array(value.iter().map(|item| json!(item)).collect()))
+            .collect(),
         FieldData::SparseFloatVector { values, .. } => values
             .into_iter()
             .map(|value| {
@@ -689,6 +742,7 @@ fn array_values_to_json<T: serde::Serialize>(values: Vec<Vec<T>>) -> Vec<serde_j
 pub struct QueryResponse {
     pub(crate) results: QueryResults,
     pub(crate) session_timestamp: u64,
+    pub(crate) missing_ids: Ids,
 }
 
 impl QueryResponse {
@@ -701,6 +755,7 @@ impl QueryResponse {
         Self {
             results: QueryResults::new(),
             session_timestamp: 0,
+            missing_ids: Ids::default(),
         }
     }
 }
@@ -727,17 +782,121 @@ impl QueryResponse {
         self.session_timestamp
     }
 
+    /// Returns the requested primary keys that matched no entity, in request order.
+    ///
+    /// Only `get` reports missing keys; the list is always empty for queries.
+    pub fn missing_ids(&self) -> &Ids {
+        &self.missing_ids
+    }
+
     pub(crate) fn from_proto(value: milvus::QueryResults) -> Result<Self> {
+        Self::from_proto_with_dense_vectors(value, false)
+    }
+
+    pub(crate) fn from_proto_with_dense_vectors(
+        value: milvus::QueryResults,
+        dense_vectors: bool,
+    ) -> Result<Self> {
         Ok(Self {
             results: QueryResults {
                 output_fields: value
                     .fields_data
                     .into_iter()
-                    .map(field_data)
+                    .map(|field| field_data(field, dense_vectors))
                     .collect::<Result<Vec<_>>>()?,
                 output_field_names: value.output_fields,
             },
             session_timestamp: value.session_ts,
+            missing_ids: Ids::default(),
+        })
+    }
+
+    /// Merges the responses of chunked `get` queries into one response.
+    ///
+    /// Rows follow the order of `ids`, with each key returned once, and keys that matched no
+    /// entity are reported through [`missing_ids`](Self::missing_ids).
+    pub(crate) fn merge_get(responses: Vec<Self>, ids: &Ids, primary_field: &str) -> Result<Self> {
+        let mismatch =
+            || Error::MalformedResponse("get chunks returned different output fields".into());
+        let session_timestamp = responses
+            .iter()
+            .map(|response| response.session_timestamp)
+            .max()
+            .unwrap_or_default();
+        let mut merged: Option<QueryResults> = None;
+        for response in responses {
+            let results = response.results;
+            let Some(merged) = merged.as_mut() else {
+                merged = Some(results);
+                continue;
+            };
+            if results.output_fields.is_empty() {
+                continue;
+            }
+            if merged.output_fields.is_empty() {
+                *merged = results;
+                continue;
+            }
+            if merged.output_fields.len() != results.output_fields.len() {
+                return Err(mismatch());
+            }
+            for (field, other) in merged.output_fields.iter_mut().zip(results.output_fields) {
+                if field.name() != other.name() {
+                    return Err(mismatch());
+                }
+                field.append(other)?;
+            }
+        }
+        let results = merged.unwrap_or_else(QueryResults::new);
+
+        let mut rows_by_key = HashMap::new();
+        if !results.output_fields.is_empty() {
+            let primary = results
+                .output_fields
+                .iter()
+                .find(|field| field.name() == primary_field)
+                .ok_or_else(|| {
+                    Error::MalformedResponse(format!(
+                        "get response is missing primary field {primary_field}"
+                    ))
+                })?;
+            for row in 0..primary.len() {
+                rows_by_key
+                    .entry(primary.value_at(row)?.to_string())
+                    .or_insert(row);
+            }
+        }
+        let row_count = results.output_fields.first().map_or(0, FieldData::len);
+        let mut rows = Vec::with_capacity(row_count);
+        let mut missing = Vec::new();
+        let mut requested = HashSet::with_capacity(ids.len());
+        for index in 0..ids.len() {
+            let key = ids.value_at(index)?.to_string();
+            if !requested.insert(key.clone()) {
+                continue;
+            }
+            match rows_by_key.get(&key) {
+                Some(&row) => rows.push(row),
+                None => missing.push(index),
+            }
+        }
+
+        let output_fields = if rows.iter().copied().eq(0..row_count) {
+            results.output_fields
+        } else {
+            results
+                .output_fields
+                .into_iter()
+                .map(|field| select_field_rows(field, &rows))
+                .collect::<Result<Vec<_>>>()?
+        };
+        Ok(Self {
+            results: QueryResults {
+                output_fields,
+                output_field_names: results.output_field_names,
+            },
+            session_timestamp,
+            missing_ids: ids.select(&missing),
         })
     }
 
@@ -771,6 +930,7 @@ impl QueryResponse {
                     output_field_names: output_field_names.clone(),
                 },
                 session_timestamp,
+                missing_ids: Ids::default(),
             },
             Some(Self {
                 results: QueryResults {
@@ -778,6 +938,7 @@ impl QueryResponse {
                     output_field_names,
                 },
                 session_timestamp,
+                missing_ids: Ids::default(),
             }),
         ))
     }
@@ -881,12 +1042,20 @@ impl SearchResponse {
     }
 
     pub(crate) fn from_proto(value: milvus::SearchResults) -> Result<Self> {
-        Self::from_proto_with_row_limit(value, None)
+        Self::from_proto_with_row_limit(value, None, false)
+    }
+
+    pub(crate) fn from_proto_with_dense_vectors(
+        value: milvus::SearchResults,
+        dense_vectors: bool,
+    ) -> Result<Self> {
+        Self::from_proto_with_row_limit(value, None, dense_vectors)
     }
 
     pub(crate) fn from_proto_with_row_limit(
         value: milvus::SearchResults,
         row_limit: Option<usize>,
+        dense_vectors: bool,
     ) -> Result<Self> {
         let extra_info = value
             .status
@@ -960,8 +1129,8 @@ impl SearchResponse {
                 if query_count == 1 {
                     field = truncate_proto_field_data(field, total_rows)?;
                 }
-                let fields =
-                    split_field_data(field_data(field)?, &row_counts).ok_or_else(|| {
+                let fields = split_field_data(field_data(field, dense_vectors)?, &row_counts)
+                    .ok_or_else(|| {
                         Error::MalformedResponse(
                             "search response output fields do not match its top-k values".into(),
                         )
@@ -1078,41 +1247,7 @@ impl SearchResponse {
                 "search iterator page must contain exactly one result".into(),
             ));
         };
-        if left.output_field_names != right.output_field_names
-            || left.primary_field_name != right.primary_field_name
-            || left.score_field_name != right.score_field_name
-            || left.output_fields.len() != right.output_fields.len()
-            || !left.ids.is_compatible_with(&right.ids)
-            || left
-                .output_fields
-                .iter()
-                .zip(&right.output_fields)
-                .any(|(left, right)| !left.is_compatible_with(right))
-        {
-            return Err(Error::MalformedResponse(
-                "search iterator pages contain incompatible result schemas".into(),
-            ));
-        }
-        left.ids.append(std::mem::take(&mut right.ids))?;
-        left.scores.append(&mut right.scores);
-        match (&mut left.element_indices, right.element_indices.take()) {
-            (Some(left), Some(mut right)) => left.append(&mut right),
-            (None, None) => {}
-            _ => {
-                return Err(Error::MalformedResponse(
-                    "search iterator pages disagree about element indices".into(),
-                ));
-            }
-        }
-        for (left, right) in left
-            .output_fields
-            .iter_mut()
-            .zip(std::mem::take(&mut right.output_fields))
-        {
-            left.append(right)?;
-        }
-        left.highlight_results.append(&mut right.highlight_results);
-        Ok(())
+        append_result_rows(left, std::mem::replace(right, SingleResult::new()))
     }
 }
 
@@ -1176,6 +1311,139 @@ fn split_single_result(value: SingleResult, count: usize) -> Result<(SingleResul
     Ok((first, remaining))
 }
 
+/// Appends the rows of `right` to `left`; both must report the same output fields.
+fn append_result_rows(left: &mut SingleResult, mut right: SingleResult) -> Result<()> {
+    if left.output_field_names != right.output_field_names
+        || left.primary_field_name != right.primary_field_name
+        || left.score_field_name != right.score_field_name
+        || left.output_fields.len() != right.output_fields.len()
+        || !left.ids.is_compatible_with(&right.ids)
+        || left
+            .output_fields
+            .iter()
+            .zip(&right.output_fields)
+            .any(|(left, right)| !left.is_compatible_with(right))
+    {
+        return Err(Error::MalformedResponse(
+            "search results contain incompatible result schemas".into(),
+        ));
+    }
+    left.ids.append(std::mem::take(&mut right.ids))?;
+    left.scores.append(&mut right.scores);
+    match (&mut left.element_indices, right.element_indices.take()) {
+        (Some(left), Some(mut right)) => left.append(&mut right),
+        (None, None) => {}
+        _ => {
+            return Err(Error::MalformedResponse(
+                "search results disagree about element indices".into(),
+            ));
+        }
+    }
+    for (left, right) in left
+        .output_fields
+        .iter_mut()
+        .zip(std::mem::take(&mut right.output_fields))
+    {
+        left.append(right)?;
+    }
+    left.highlight_results.append(&mut right.highlight_results);
+    Ok(())
+}
+
+/// Splits `value` into one result per row.
+fn split_result_rows(value: SingleResult) -> Result<Vec<SingleResult>> {
+    let row_count = value.len();
+    let sizes = vec![1; row_count];
+    let malformed =
+        |part: &str| Error::MalformedResponse(format!("failed to split search result {part}"));
+    let ids = split_ids(value.ids, &sizes).ok_or_else(|| malformed("primary keys"))?;
+    let scores = split_values(value.scores, &sizes).ok_or_else(|| malformed("scores"))?;
+    let mut element_indices = value
+        .element_indices
+        .map(|indices| split_values(indices, &sizes).ok_or_else(|| malformed("element indices")))
+        .transpose()?
+        .map(Vec::into_iter);
+    let highlights = if value.highlight_results.is_empty() {
+        vec![HashMap::new(); row_count]
+    } else {
+        split_values(value.highlight_results, &sizes).ok_or_else(|| malformed("highlights"))?
+    };
+    let mut fields = value
+        .output_fields
+        .into_iter()
+        .map(|field| {
+            split_field_data(field, &sizes)
+                .map(Vec::into_iter)
+                .ok_or_else(|| malformed("output fields"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(ids
+        .into_iter()
+        .zip(scores)
+        .zip(highlights)
+        .map(|((ids, scores), highlight_results)| SingleResult {
+            ids,
+            scores,
+            element_indices: element_indices
+                .as_mut()
+                .map(|indices| indices.next().expect("split produces one index per row")),
+            output_fields: fields
+                .iter_mut()
+                .map(|field| field.next().expect("split produces one value per row"))
+                .collect(),
+            output_field_names: value.output_field_names.clone(),
+            primary_field_name: value.primary_field_name.clone(),
+            score_field_name: value.score_field_name.clone(),
+            highlight_results,
+        })
+        .collect())
+}
+
+/// Builds one result from rows of several results.
+///
+/// `rows` lists `(part, row)` positions in output order and `scores` replaces the scores of the
+/// selected rows. Every part must report the same output fields.
+pub(crate) fn select_result_rows(
+    parts: Vec<SingleResult>,
+    rows: &[(usize, usize)],
+    scores: Vec<f32>,
+) -> Result<SingleResult> {
+    let mut empty = None;
+    let mut split = Vec::with_capacity(parts.len());
+    for part in parts {
+        if part.is_empty() {
+            split.push(Vec::new());
+            empty.get_or_insert(part);
+        } else {
+            split.push(split_result_rows(part)?.into_iter().map(Some).collect());
+        }
+    }
+    let mut selected: Option<SingleResult> = None;
+    for &(part, row) in rows {
+        let row = split
+            .get_mut(part)
+            .and_then(|rows| rows.get_mut(row))
+            .and_then(Option::take)
+            .ok_or_else(|| {
+                Error::MalformedResponse(format!("search result row {part}:{row} does not exist"))
+            })?;
+        match &mut selected {
+            Some(selected) => append_result_rows(selected, row)?,
+            None => selected = Some(row),
+        }
+    }
+    let mut selected = match selected {
+        Some(selected) => selected,
+        // Keep the output field layout of a non-empty part when no row is selected.
+        None => match split.iter_mut().flatten().find_map(Option::take) {
+            Some(row) => split_single_result(row, 0)?.0,
+            None => empty.unwrap_or_else(SingleResult::new),
+        },
+    };
+    selected.scores = scores;
+    Ok(selected)
+}
+
 fn split_ids(value: Ids, sizes: &[usize]) -> Option<Vec<Ids>> {
     match value {
         Ids::Int64(values) => {
@@ -1384,6 +1652,155 @@ fn split_named_values<T>(
     )
 }
 
+/// Rebuilds a column from the rows at `rows`, in that order.
+fn select_field_rows(field: FieldData, rows: &[usize]) -> Result<FieldData> {
+    gather_field_data(field, rows)
+        .ok_or_else(|| Error::MalformedResponse("failed to reorder get results".into()))
+}
+
+/// Moves the distinct `rows` of `values` into a new column, in that order.
+fn gather_values<T>(values: Vec<T>, rows: &[usize]) -> Option<Vec<T>> {
+    let mut slots = values.into_iter().map(Some).collect::<Vec<_>>();
+    rows.iter().map(|&row| slots.get_mut(row)?.take()).collect()
+}
+
+fn gather_field_data(data: FieldData, rows: &[usize]) -> Option<FieldData> {
+    Some(match data {
+        FieldData::Bool { name, values } => FieldData::Bool {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Int8 { name, values } => FieldData::Int8 {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Int16 { name, values } => FieldData::Int16 {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Int32 { name, values } => FieldData::Int32 {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Int64 { name, values } => FieldData::Int64 {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Float { name, values } => FieldData::Float {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Double { name, values } => FieldData::Double {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::VarChar { name, values } => FieldData::VarChar {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Json { name, values } => FieldData::Json {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Geometry { name, values } => FieldData::Geometry {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Timestamptz { name, values } => FieldData::Timestamptz {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::ArrayBool { name, values } => FieldData::ArrayBool {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::ArrayInt8 { name, values } => FieldData::ArrayInt8 {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::ArrayInt16 { name, values } => FieldData::ArrayInt16 {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::ArrayInt32 { name, values } => FieldData::ArrayInt32 {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::ArrayInt64 { name, values } => FieldData::ArrayInt64 {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::ArrayFloat { name, values } => FieldData::ArrayFloat {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::ArrayDouble { name, values } => FieldData::ArrayDouble {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::ArrayVarChar { name, values } => FieldData::ArrayVarChar {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Struct { name, values } => FieldData::Struct {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::FloatVector { name, values } => FieldData::FloatVector {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::BinaryVector { name, values } => FieldData::BinaryVector {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Float16Vector { name, values } => FieldData::Float16Vector {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::BFloat16Vector { name, values } => FieldData::BFloat16Vector {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::SparseFloatVector { name, values } => FieldData::SparseFloatVector {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::Int8Vector { name, values } => FieldData::Int8Vector {
+            name,
+            values: gather_values(values, rows)?,
+        },
+        FieldData::DenseFloatVector { name, values } => {
+            let dim = values.dim;
+            let mut data = Vec::with_capacity(rows.len().checked_mul(dim)?);
+            for &row in rows {
+                data.extend_from_slice(values.data.get(row * dim..(row + 1) * dim)?);
+            }
+            FieldData::DenseFloatVector {
+                name,
+                values: DenseVectors { dim, data },
+            }
+        }
+        FieldData::Nullable { data, valid_data } => {
+            let mut inner_rows = Vec::with_capacity(valid_data.len());
+            let mut valid_count = 0;
+            for valid in &valid_data {
+                inner_rows.push(valid.then_some(valid_count));
+                valid_count += usize::from(*valid);
+            }
+            let selected = rows
+                .iter()
+                .filter_map(|&row| inner_rows.get(row).copied().flatten())
+                .collect::<Vec<_>>();
+            let valid_data = rows
+                .iter()
+                .map(|&row| valid_data.get(row).copied())
+                .collect::<Option<Vec<_>>>()?;
+            FieldData::nullable(gather_field_data(*data, &selected)?, valid_data).ok()?
+        }
+    })
+}
+
 fn split_field_data(data: FieldData, sizes: &[usize]) -> Option<Vec<FieldData>> {
     match data {
         FieldData::Bool { name, values } => {
@@ -1537,6 +1954,19 @@ fn split_field_data(data: FieldData, sizes: &[usize]) -> Option<Vec<FieldData>>
                 values,
             })
         }
+        FieldData::DenseFloatVector { name, values } => {
+            let dim = values.dim;
+            let sizes = sizes
+                .iter()
+                .map(|size| size.checked_mul(dim))
+                .collect::<Option<Vec<_>>>()?;
+            split_named_values(name, values.data, &sizes, |name, data| {
+                FieldData::DenseFloatVector {
+                    name,
+                    values: DenseVectors { dim, data },
+                }
+            })
+        }
         FieldData::Nullable { data, valid_data } => {
             let valid_data = split_values(valid_data, sizes)?;
             let inner_sizes = valid_data
@@ -1646,6 +2076,80 @@ pub type GetResponse = QueryResponse;
 /// Response returned by the ClientV2 hybrid_search operation.
 pub type HybridSearchResponse = SearchResponse;
 
+///////////////////////////////////////////////////////////////////////////////
+// FederatedSearchResponse
+///////////////////////////////////////////////////////////////////////////////
+/// Response returned by the ClientV2 federated_search operation.
+///
+/// Results hold one merged ranking per query vector. When every answering target's metric has the
+/// same direction, scores are the targets' own scores; otherwise distance scores are negated so
+/// that a higher score is always a closer match. [`FederatedSearchResponse::sources`] records the
+/// target and original score of every hit.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct FederatedSearchResponse {
+    pub(crate) results: SearchResults,
+    pub(crate) sources: Vec<Vec<FederatedHit>>,
+    pub(crate) failures: Vec<FederatedFailure>,
+}
+
+impl FederatedSearchResponse {
+    /// Returns the merged results.
+    pub fn results(&self) -> &SearchResults {
+        &self.results
+    }
+
+    /// Returns the origin of each hit, parallel to the rows of each result.
+    pub fn sources(&self) -> &[Vec<FederatedHit>] {
+        &self.sources
+    }
+
+    /// Returns the targets that failed under the best-effort policy.
+    pub fn failures(&self) -> &[FederatedFailure] {
+        &self.failures
+    }
+}
+
+/// Origin of one merged federated-search hit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct FederatedHit {
+    pub(crate) target: usize,
+    pub(crate) score: f32,
+}
+
+impl FederatedHit {
+    /// Returns the index of the target that produced the hit; `0` is the search's own collection.
+    pub fn target(&self) -> usize {
+        self.target
+    }
+
+    /// Returns the score reported by the target.
+    pub fn original_score(&self) -> f32 {
+        self.score
+    }
+}
+
+/// Target that failed during a best-effort federated search.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct FederatedFailure {
+    pub(crate) target: usize,
+    pub(crate) error: Error,
+}
+
+impl FederatedFailure {
+    /// Returns the index of the failed target; `0` is the search's own collection.
+    pub fn target(&self) -> usize {
+        self.target
+    }
+
+    /// Returns the error the target reported.
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Test Cases
 ///////////////////////////////////////////////////////////////////////////////
@@ -1687,7 +2191,7 @@ mod tests {
             ),
             (schema::DataType::Int8Vector, DataType::Int8Vector, 2),
         ] {
-            let decoded = field_data(all_null_vector_field(proto_type, dimension)).unwrap();
+            let decoded = field_data(all_null_vector_field(proto_type, dimension), false).unwrap();
             assert_eq!(decoded.data_type(), sdk_type);
             assert_eq!(decoded.valid_data(), Some([false, false].as_slice()));
             assert_eq!(decoded.len(), 2);
@@ -1696,7 +2200,7 @@ mod tests {
 
         let mut malformed = all_null_vector_field(schema::DataType::FloatVector, 2);
         malformed.valid_data = vec![true, false];
-        assert!(field_data(malformed).is_err());
+        assert!(field_data(malformed, false).is_err());
     }
 
     #[test]
@@ -1741,17 +2245,20 @@ mod tests {
     fn nullable_array_and_sparse_fields_decode_without_proto_exposure() {
         use schema::{field_data::Field, scalar_field, vector_field};
 
-        let nullable = field_data(schema::FieldData {
-            r#type: schema::DataType::Int64 as i32,
-            field_name: "optional".into(),
-            valid_data: vec![true, false, true],
-            field: Some(Field::Scalars(schema::ScalarField {
-                data: Some(scalar_field::Data::LongData(schema::LongArray {
-                    data: vec![10, 30],
+        let nullable = field_data(
+            schema::FieldData {
+                r#type: schema::DataType::Int64 as i32,
+                field_name: "optional".into(),
+                valid_data: vec![true, false, true],
+                field: Some(Field::Scalars(schema::ScalarField {
+                    data: Some(scalar_field::Data::LongData(schema::LongArray {
+                        data: vec![10, 30],
+                    })),
                 })),
-            })),
-            ..Default::default()
-        })
+                ..Default::default()
+            },
+            false,
+        )
         .unwrap();
         assert_eq!(nullable.valid_data(), Some([true, false, true].as_slice()));
         assert!(nullable.is_null(1));
@@ -1759,44 +2266,50 @@ mod tests {
             matches!(nullable.inner(), FieldData::Int64 { values, .. } if values == &vec![10, 30])
         );
 
-        let array = field_data(schema::FieldData {
-            r#type: schema::DataType::Array as i32,
-            field_name: "tags".into(),
-            field: Some(Field::Scalars(schema::ScalarField {
-                data: Some(scalar_field::Data::ArrayData(schema::ArrayArray {
-                    element_type: schema::DataType::VarChar as i32,
-                    data: vec![schema::ScalarField {
-                        data: Some(scalar_field::Data::StringData(schema::StringArray {
-                            data: vec!["a".into(), "b".into()],
-                        })),
-                    }],
+        let array = field_data(
+            schema::FieldData {
+                r#type: schema::DataType::Array as i32,
+                field_name: "tags".into(),
+                field: Some(Field::Scalars(schema::ScalarField {
+                    data: Some(scalar_field::Data::ArrayData(schema::ArrayArray {
+                        element_type: schema::DataType::VarChar as i32,
+                        data: vec![schema::ScalarField {
+                            data: Some(scalar_field::Data::StringData(schema::StringArray {
+                                data: vec!["a".into(), "b".into()],
+                            })),
+                        }],
+                    })),
                 })),
-            })),
-            ..Default::default()
-        })
+                ..Default::default()
+            },
+            false,
+        )
         .unwrap();
         assert!(matches!(array, FieldData::ArrayVarChar { values, .. } if values.len() == 1));
 
         let mut sparse_bytes = Vec::new();
         sparse_bytes.extend(2_u32.to_le_bytes());
         sparse_bytes.extend(0.5_f32.to_le_bytes());
-        let sparse = field_data(schema::FieldData {
-            r#type: schema::DataType::SparseFloatVector as i32,
-            field_name: "sparse".into(),
-            field: Some(Field::Vectors(schema::VectorField {
-                dim: 3,
-                data: Some(vector_field::Data::SparseFloatVector(
-                    schema::SparseFloatArray {
-                        contents: vec![sparse_bytes],
-                        dim: 3,
-                    },
-                )),
-            })),
-            ..Default::default()
-        })
+        let sparse = field_data(
+            schema::FieldData {
+                r#type: schema::DataType::SparseFloatVector as i32,
+                field_name: "sparse".into(),
+                field: Some(Field::Vectors(schema::VectorField {
+                    dim: 3,
+                    data: Some(vector_field::Data::SparseFloatVector(
+                        schema::SparseFloatArray {
+                            contents: vec![sparse_bytes],
+                            dim: 3,
+                        },
+                    )),
+                })),
+                ..Default::default()
+            },
+            false,
+        )
         .unwrap();
         assert!(
-            matches!(sparse, FieldData::SparseFloatVector { values, .. } if values[0].get(&2) == Some(&0.5))
+            matches!(sparse, FieldData::SparseFloatVector { values, .. } if values[0].get(2) == Some(0.5))
         );
     }
 
@@ -1820,20 +2333,23 @@ mod tests {
                 },
             ),
         ] {
-            let decoded = field_data(schema::FieldData {
-                r#type: data_type as i32,
-                field_name: "value".into(),
-                field: Some(Field::Scalars(schema::ScalarField {
-                    data: Some(scalar_field::Data::IntData(schema::IntArray {
-                        data: match data_type {
-                            schema::DataType::Int8 => vec![-128, 127],
-                            schema::DataType::Int16 => vec![-32768, 32767],
-                            _ => unreachable!(),
-                        },
+            let decoded = field_data(
+                schema::FieldData {
+                    r#type: data_type as i32,
+                    field_name: "value".into(),
+                    field: Some(Field::Scalars(schema::ScalarField {
+                        data: Some(scalar_field::Data::IntData(schema::IntArray {
+                            data: match data_type {
+                                schema::DataType::Int8 => vec![-128, 127],
+                                schema::DataType::Int16 => vec![-32768, 32767],
+                                _ => unreachable!(),
+                            },
+                        })),
                     })),
-                })),
-                ..Default::default()
-            })
+                    ..Default::default()
+                },
+                false,
+            )
             .unwrap();
             assert_eq!(decoded, expected);
         }
@@ -1906,7 +2422,7 @@ mod tests {
                 ..Default::default()
             },
         ] {
-            assert!(field_data(value).is_err());
+            assert!(field_data(value, false).is_err());
         }
     }
 
@@ -1948,7 +2464,7 @@ mod tests {
         ];
 
         for original in cases {
-            let decoded = field_data(original.clone().into_proto().unwrap()).unwrap();
+            let decoded = field_data(original.clone().into_proto().unwrap(), false).unwrap();
             assert_eq!(decoded, original);
         }
     }
@@ -1957,17 +2473,20 @@ mod tests {
     fn half_precision_wire_bytes_decode_to_u16_values() {
         use schema::{field_data::Field, vector_field};
 
-        let decoded = field_data(schema::FieldData {
-            r#type: schema::DataType::Float16Vector as i32,
-            field_name: "embedding".into(),
-            field: Some(Field::Vectors(schema::VectorField {
-                dim: 2,
-                data: Some(vector_field::Data::Float16Vector(vec![
-                    0x00, 0x3c, 0x00, 0xbc,
-                ])),
-            })),
-            ..Default::default()
-        })
+        let decoded = field_data(
+            schema::FieldData {
+                r#type: schema::DataType::Float16Vector as i32,
+                field_name: "embedding".into(),
+                field: Some(Field::Vectors(schema::VectorField {
+                    dim: 2,
+                    data: Some(vector_field::Data::Float16Vector(vec![
+                        0x00, 0x3c, 0x00, 0xbc,
+                    ])),
+                })),
+                ..Default::default()
+            },
+            false,
+        )
         .unwrap();
 
         assert!(matches!(
@@ -2235,17 +2754,20 @@ mod tests {
 
     #[test]
     fn nullable_json_ignores_invalid_row_placeholders() {
-        let decoded = field_data(schema::FieldData {
-            r#type: schema::DataType::Json as i32,
-            field_name: "metadata".into(),
-            valid_data: vec![true, false],
-            field: Some(schema::field_data::Field::Scalars(schema::ScalarField {
-                data: Some(schema::scalar_field::Data::JsonData(schema::JsonArray {
-                    data: vec![br#"{"present":true}"#.to_vec(), Vec::new()],
+        let decoded = field_data(
+            schema::FieldData {
+                r#type: schema::DataType::Json as i32,
+                field_name: "metadata".into(),
+                valid_data: vec![true, false],
+                field: Some(schema::field_data::Field::Scalars(schema::ScalarField {
+                    data: Some(schema::scalar_field::Data::JsonData(schema::JsonArray {
+                        data: vec![br#"{"present":true}"#.to_vec(), Vec::new()],
+                    })),
                 })),
-            })),
-            ..Default::default()
-        })
+                ..Default::default()
+            },
+            false,
+        )
         .unwrap();
 
         assert_eq!(decoded.valid_data(), Some([true, false].as_slice()));
@@ -2256,6 +2778,33 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "geo")]
+    #[test]
+    fn nullable_wkb_geometry_ignores_null_row_placeholders() {
+        use geo_types::point;
+
+        let field = |data: Vec<Vec<u8>>| schema::FieldData {
+            r#type: schema::DataType::Geometry as i32,
+            field_name: "location".into(),
+            valid_data: vec![true, false],
+            field: Some(schema::field_data::Field::Scalars(schema::ScalarField {
+                data: Some(schema::scalar_field::Data::GeometryData(
+                    schema::GeometryArray { data },
+                )),
+            })),
+            ..Default::default()
+        };
+        let point = crate::v2::geo::to_wkb(&point!(x: 1.0, y: 2.0).into());
+        let decoded = field_data(field(vec![point, Vec::new()]), false).unwrap();
+
+        assert!(decoded.is_null(1));
+        assert!(matches!(
+            decoded.inner(),
+            FieldData::Geometry { values, .. } if values == &vec!["POINT(1 2)".to_owned()]
+        ));
+        assert!(field_data(field(vec![b"bad".to_vec(), Vec::new()]), false).is_err());
+    }
+
     #[test]
     fn search_propagates_out_of_range_narrow_integer_error() {
         let response = SearchResponse::from_proto(milvus::SearchResults {
@@ -2312,6 +2861,57 @@ mod tests {
         assert_eq!(pointers, row_pointers);
     }
 
+    #[test]
+    fn dense_vector_decoding_splits_one_buffer_per_query() {
+        use schema::{field_data::Field, vector_field};
+
+        let search = SearchResponse::from_proto_with_dense_vectors(
+            milvus::SearchResults {
+                results: Some(schema::SearchResultData {
+                    num_queries: 2,
+                    top_k: 2,
+                    topks: vec![2, 1],
+                    scores: vec![0.9, 0.8, 0.7],
+                    ids: Some(schema::IDs {
+                        id_field: Some(schema::i_ds::IdField::IntId(schema::LongArray {
+                            data: vec![1, 2, 3],
+                        })),
+                    }),
+                    fields_data: vec![schema::FieldData {
+                        r#type: schema::DataType::FloatVector as i32,
+                        field_name: "embedding".into(),
+                        field: Some(Field::Vectors(schema::VectorField {
+                            dim: 2,
+                            data: Some(vector_field::Data::FloatVector(schema::FloatArray {
+                                data: vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6],
+                            })),
+                        })),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            true,
+        )
+        .unwrap();
+
+        let results = search.results().get_results();
+        let Some(FieldData::DenseFloatVector { values, .. }) =
+            results[0].get_output_field("embedding")
+        else {
+            panic!("expected dense float vectors")
+        };
+        assert_eq!(values.as_slice(), &[0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(values.row(1), Some(&[0.3, 0.4][..]));
+        let Some(FieldData::DenseFloatVector { values, .. }) =
+            results[1].get_output_field("embedding")
+        else {
+            panic!("expected dense float vectors")
+        };
+        assert_eq!(values.rows().collect::<Vec<_>>(), [&[0.5, 0.6][..]]);
+    }
+
     #[test]
     fn search_splits_flattened_data_into_one_result_per_query_vector() {
         use schema::{field_data::Field, scalar_field};
@@ -2712,4 +3312,89 @@ mod builder_value_tests {
         assert_eq!(value.scanned_total_bytes().to_owned(), scanned_total_bytes);
         assert_eq!(value.cache_hit_ratio().to_owned(), cache_hit_ratio);
     }
+
+    #[test]
+    fn merged_get_chunks_follow_request_order_and_report_missing_ids() {
+        let chunk = |ids: Vec<i64>, titles: &[&str], session_timestamp| {
+            QueryResponse::builder()
+                .results(QueryResults::new().output_fields(vec![
+                    FieldData::Int64 {
+                        name: "id".into(),
+                        values: ids,
+                    },
+                    FieldData::VarChar {
+                        name: "title".into(),
+                        values: titles.iter().map(|title| title.to_string()).collect(),
+                    },
+                ]))
+                .session_timestamp(session_timestamp)
+                .build()
+        };
+        let requested = Ids::Int64(vec![3, 9, 1, 3, 2, 8]);
+        let merged = QueryResponse::merge_get(
+            vec![
+                chunk(vec![1, 3], &["one", "three"], 7),
+                QueryResponse::builder().build(),
+                chunk(vec![2], &["two"], 5),
+            ],
+            &requested,
+            "id",
+        )
+        .unwrap();
+
+        let fields = merged.results().get_output_fields();
+        assert_eq!(
+            fields[0],
+            FieldData::Int64 {
+                name: "id".into(),
+                values: vec![3, 1, 2],
+            }
+        );
+        assert_eq!(
+            fields[1],
+            FieldData::VarChar {
+                name: "title".into(),
+                values: vec!["three".into(), "one".into(), "two".into()],
+            }
+        );
+        assert_eq!(merged.missing_ids(), &Ids::Int64(vec![9, 8]));
+        assert_eq!(merged.session_timestamp(), 7);
+
+        let none =
+            QueryResponse::merge_get(vec![QueryResponse::builder().build()], &requested, "id")
+                .unwrap();
+        assert!(none.results().get_output_fields().is_empty());
+        assert_eq!(none.missing_ids(), &Ids::Int64(vec![3, 9, 1, 2, 8]));
+    }
+
+    #[test]
+    fn selected_rows_gather_dense_and_nullable_columns() {
+        let dense = FieldData::DenseFloatVector {
+            name: "vector".into(),
+            values: DenseVectors::new(2, vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.5]).unwrap(),
+        };
+        assert_eq!(
+            select_field_rows(dense, &[2, 0]).unwrap(),
+            FieldData::DenseFloatVector {
+                name: "vector".into(),
+                values: DenseVectors::new(2, vec![2.0, 2.5, 0.0, 0.5]).unwrap(),
+            }
+        );
+
+        let nullable = |values: Vec<i64>, valid_data: Vec<bool>| {
+            FieldData::nullable(
+                FieldData::Int64 {
+                    name: "rank".into(),
+                    values,
+                },
+                valid_data,
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            select_field_rows(nullable(vec![10, 30], vec![true, false, true]), &[2, 1, 0]).unwrap(),
+            nullable(vec![30, 10], vec![true, false, true])
+        );
+        assert!(select_field_rows(nullable(vec![10], vec![true]), &[1]).is_err());
+    }
 }
@@ -0,0 +1,496 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pluggable retry policies for ClientV2 RPCs.
+//!
+//! Every RPC attempt that fails is described by a [`RetryContext`] and handed to the client's
+//! [`RetryPolicy`], which decides whether and when to try again. [`RetryConfig`] is the default
+//! exponential-backoff policy. [`OperationRetryPolicy`] selects a different policy per RPC, and
+//! [`BudgetedRetryPolicy`] caps the retry rate with a shared [`RetryBudget`]:
+//!
+//! ```
+//! use milvus::v2::prelude::*;
+//! use milvus::v2::retry::{BudgetedRetryPolicy, NoRetry, OperationRetryPolicy, RetryBudget};
+//! use std::time::Duration;
+//!
+//! let search = RetryConfig::new()
+//!     .max_attempts(10)
+//!     .initial_backoff(Duration::from_millis(5));
+//! let policy = OperationRetryPolicy::new(RetryConfig::new())
+//!     .operations(["search", "hybrid_search", "query"], search)
+//!     .operations(["create_collection", "drop_collection"], NoRetry);
+//! let policy = BudgetedRetryPolicy::new(policy, RetryBudget::new(100, 10.0));
+//!
+//! let config = ConnectConfig::new()
+//!     .uri("http://localhost:19530")
+//!     .retry_policy(policy);
+//! ```
+//!
+//! A single call can replace the client's policy through its request builder's `retry_policy`.
+//!
+//! Regardless of the policy, a non-idempotent RPC is only replayed after a rate-limit rejection,
+//! because any other failure may hide a mutation that the server has already applied.
+
+use crate::v2::error::Error;
+use crate::v2::types::RetryConfig;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tonic::Code;
+
+///////////////////////////////////////////////////////////////////////////////
+// RetrySemantics
+///////////////////////////////////////////////////////////////////////////////
+/// Whether replaying an RPC after an ambiguous failure is safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetrySemantics {
+    /// Replaying the RPC cannot change the server-side result.
+    Idempotent,
+    /// Replaying the RPC could duplicate a server-side mutation.
+    NonIdempotent,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RetryContext
+///////////////////////////////////////////////////////////////////////////////
+/// Description of one failed RPC attempt, passed to [`RetryPolicy::decide`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct RetryContext<'a> {
+    pub(crate) method: &'a str,
+    pub(crate) semantics: RetrySemantics,
+    pub(crate) attempt: u32,
+    pub(crate) elapsed: Duration,
+    pub(crate) error: &'a Error,
+    pub(crate) rate_limited: bool,
+    pub(crate) transient: bool,
+}
+
+impl<'a> RetryContext<'a> {
+    /// Returns the snake_case Milvus RPC name, such as `search` or `create_collection`.
+    pub fn method(&self) -> &'a str {
+        self.method
+    }
+
+    /// Returns whether the RPC may be replayed after an ambiguous failure.
+    pub fn semantics(&self) -> RetrySemantics {
+        self.semantics
+    }
+
+    /// Returns the one-based number of the attempt that just failed.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Returns the time spent on this RPC since its first attempt started.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the error reported by the failed attempt.
+    pub fn error(&self) -> &'a Error {
+        self.error
+    }
+
+    /// Returns whether the server rejected the attempt because of rate limiting.
+    pub fn is_rate_limited(&self) -> bool {
+        self.rate_limited
+    }
+
+    /// Returns whether the SDK classifies the failure as transient for this RPC's semantics.
+    ///
+    /// Transient failures are retriable server statuses and retryable gRPC codes of idempotent
+    /// RPCs. Rate-limit rejections are reported separately by [`Self::is_rate_limited`].
+    pub fn is_transient(&self) -> bool {
+        self.transient
+    }
+
+    /// Returns the gRPC status code when the attempt failed at the transport layer.
+    pub fn grpc_code(&self) -> Option<Code> {
+        match self.error {
+            Error::Grpc(status) => Some(status.code()),
+            _ => None,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RetryDecision
+///////////////////////////////////////////////////////////////////////////////
+/// Outcome of a [`RetryPolicy`] decision for a failed attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// Waits for the supplied backoff and starts another attempt.
+    Retry(Duration),
+    /// Returns the attempt's error unchanged because it is not worth retrying.
+    Fail,
+    /// Stops retrying a retriable failure and reports [`Error::RetryExhausted`].
+    Exhausted,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RetryPolicy
+///////////////////////////////////////////////////////////////////////////////
+/// Decides whether a failed ClientV2 RPC attempt is retried.
+///
+/// Policies are shared by every clone of a client and may be called concurrently, so stateful
+/// policies must synchronize internally.
+pub trait RetryPolicy: Debug + Send + Sync {
+    /// Decides what to do after an attempt failed.
+    fn decide(&self, context: &RetryContext<'_>) -> RetryDecision;
+
+    /// Returns the wall-clock limit for all attempts of `method`. Zero disables the limit.
+    fn max_retry_timeout(&self, method: &str) -> Duration {
+        let _ = method;
+        Duration::ZERO
+    }
+}
+
+impl<P: RetryPolicy + ?Sized> RetryPolicy for Arc<P> {
+    fn decide(&self, context: &RetryContext<'_>) -> RetryDecision {
+        (**self).decide(context)
+    }
+
+    fn max_retry_timeout(&self, method: &str) -> Duration {
+        (**self).max_retry_timeout(method)
+    }
+}
+
+impl RetryPolicy for RetryConfig {
+    fn decide(&self, context: &RetryContext<'_>) -> RetryDecision {
+        let eligible = if context.is_rate_limited() {
+            self.retry_on_rate_limit
+        } else {
+            context.is_transient()
+        };
+        if !eligible {
+            RetryDecision::Fail
+        } else if context.attempt() >= self.max_attempts.max(1) {
+            RetryDecision::Exhausted
+        } else {
+            RetryDecision::Retry(self.backoff_after(context.attempt()))
+        }
+    }
+
+    fn max_retry_timeout(&self, _method: &str) -> Duration {
+        self.max_retry_timeout
+    }
+}
+
+impl RetryConfig {
+    /// Returns the delay before the attempt that follows the one-based `attempt`.
+    pub(crate) fn backoff_after(&self, attempt: u32) -> Duration {
+        let multiplier = if self.backoff_multiplier.is_finite() && self.backoff_multiplier > 0.0 {
+            self.backoff_multiplier
+        } else {
+            1.0
+        };
+        let mut backoff = self.initial_backoff.min(self.max_backoff);
+        for _ in 1..attempt {
+            if backoff >= self.max_backoff {
+                break;
+            }
+            backoff = Duration::try_from_secs_f64(backoff.as_secs_f64() * multiplier)
+                .unwrap_or(self.max_backoff)
+                .min(self.max_backoff);
+        }
+        backoff
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// NoRetry
+///////////////////////////////////////////////////////////////////////////////
+/// Policy that never retries and returns the first failure unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoRetry;
+
+impl RetryPolicy for NoRetry {
+    fn decide(&self, _context: &RetryContext<'_>) -> RetryDecision {
+        RetryDecision::Fail
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// OperationRetryPolicy
+///////////////////////////////////////////////////////////////////////////////
+/// Policy that selects a different retry policy for individual RPCs.
+///
+/// Operations are named by their snake_case Milvus RPC, which is usually the ClientV2 method
+/// name. Some methods send a differently named RPC: for example [`crate::v2::ClientV2::get`] is
+/// sent as `query`, `create_user` as `create_credential`, and `list_indexes` as
+/// `get_index_statistics`, and the iterators use `query` and `search`. An override keyed by a
+/// name that is not an RPC never applies. RPCs without an override use the default policy.
+#[derive(Debug, Clone)]
+pub struct OperationRetryPolicy {
+    default: Arc<dyn RetryPolicy>,
+    overrides: HashMap<String, Arc<dyn RetryPolicy>>,
+}
+
+impl OperationRetryPolicy {
+    /// Creates a policy that uses `default` for every RPC without an override.
+    pub fn new(default: impl RetryPolicy + 'static) -> Self {
+        Self {
+            default: Arc::new(default),
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Overrides the policy used by one RPC.
+    pub fn operation(
+        mut self,
+        method: impl Into<String>,
+        policy: impl RetryPolicy + 'static,
+    ) -> Self {
+        self.overrides.insert(method.into(), Arc::new(policy));
+        self
+    }
+
+    /// Overrides the policy used by several RPCs, which share one policy instance.
+    pub fn operations(
+        mut self,
+        methods: impl IntoIterator<Item = impl Into<String>>,
+        policy: impl RetryPolicy + 'static,
+    ) -> Self {
+        let policy: Arc<dyn RetryPolicy> = Arc::new(policy);
+        for method in methods {
+            self.overrides.insert(method.into(), Arc::clone(&policy));
+        }
+        self
+    }
+
+    fn policy_for(&self, method: &str) -> &dyn RetryPolicy {
+        self.overrides.get(method).unwrap_or(&self.default).as_ref()
+    }
+}
+
+impl RetryPolicy for OperationRetryPolicy {
+    fn decide(&self, context: &RetryContext<'_>) -> RetryDecision {
+        self.policy_for(context.method()).decide(context)
+    }
+
+    fn max_retry_timeout(&self, method: &str) -> Duration {
+        self.policy_for(method).max_retry_timeout(method)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RetryBudget
+///////////////////////////////////////////////////////////////////////////////
+/// Token bucket that bounds how many retries may be issued over time.
+///
+/// Each retry withdraws one token and tokens are refilled continuously up to the bucket's
+/// capacity. Clones share the same bucket, so one budget can cap the retries of every client and
+/// policy it is attached to. When the bucket is empty, retriable failures are reported as
+/// [`Error::RetryExhausted`] instead of adding load to a degraded cluster.
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    capacity: f64,
+    refill_per_second: f64,
+    state: Arc<Mutex<RetryBudgetState>>,
+}
+
+#[derive(Debug)]
+struct RetryBudgetState {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RetryBudget {
+    /// Creates a full bucket holding `capacity` retries that refills `refill_per_second` tokens
+    /// per second. Non-finite or negative refill rates disable refilling.
+    pub fn new(capacity: u32, refill_per_second: f64) -> Self {
+        let refill_per_second = if refill_per_second.is_finite() && refill_per_second > 0.0 {
+            refill_per_second
+        } else {
+            0.0
+        };
+        Self {
+            capacity: f64::from(capacity),
+            refill_per_second,
+            state: Arc::new(Mutex::new(RetryBudgetState {
+                tokens: f64::from(capacity),
+                updated: Instant::now(),
+            })),
+        }
+    }
+
+    /// Returns the maximum number of retries the bucket can hold.
+    pub fn capacity(&self) -> u32 {
+        self.capacity as u32
+    }
+
+    /// Returns the number of whole retries currently available.
+    pub fn available(&self) -> u32 {
+        let mut state = self.state.lock();
+        self.refill(&mut state);
+        state.tokens.floor() as u32
+    }
+
+    /// Withdraws one retry token, returning `false` when the budget is exhausted.
+    pub fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock();
+        self.refill(&mut state);
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn refill(&self, state: &mut RetryBudgetState) {
+        let now = Instant::now();
+        let elapsed = now.saturating_duration_since(state.updated).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        state.updated = now;
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// BudgetedRetryPolicy
+///////////////////////////////////////////////////////////////////////////////
+/// Policy wrapper that only allows a retry while its [`RetryBudget`] has tokens left.
+#[derive(Debug, Clone)]
+pub struct BudgetedRetryPolicy<P> {
+    policy: P,
+    budget: RetryBudget,
+}
+
+impl<P: RetryPolicy> BudgetedRetryPolicy<P> {
+    /// Wraps `policy` so that every retry it requests withdraws a token from `budget`.
+    pub fn new(policy: P, budget: RetryBudget) -> Self {
+        Self { policy, budget }
+    }
+
+    /// Returns the budget shared by this policy.
+    pub fn budget(&self) -> &RetryBudget {
+        &self.budget
+    }
+}
+
+impl<P: RetryPolicy> RetryPolicy for BudgetedRetryPolicy<P> {
+    fn decide(&self, context: &RetryContext<'_>) -> RetryDecision {
+        match self.policy.decide(context) {
+            RetryDecision::Retry(_) if !self.budget.try_acquire() => RetryDecision::Exhausted,
+            decision => decision,
+        }
+    }
+
+    fn max_retry_timeout(&self, method: &str) -> Duration {
+        self.policy.max_retry_timeout(method)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context<'a>(method: &'a str, attempt: u32, error: &'a Error) -> RetryContext<'a> {
+        RetryContext {
+            method,
+            semantics: RetrySemantics::Idempotent,
+            attempt,
+            elapsed: Duration::ZERO,
+            error,
+            rate_limited: false,
+            transient: true,
+        }
+    }
+
+    #[test]
+    fn retry_config_backs_off_exponentially_up_to_the_maximum() {
+        let retry = RetryConfig::new()
+            .initial_backoff(Duration::from_millis(10))
+            .max_backoff(Duration::from_millis(100))
+            .backoff_multiplier(3.0);
+
+        assert_eq!(retry.backoff_after(1), Duration::from_millis(10));
+        assert_eq!(retry.backoff_after(2), Duration::from_millis(30));
+        assert_eq!(retry.backoff_after(3), Duration::from_millis(90));
+        assert_eq!(retry.backoff_after(4), Duration::from_millis(100));
+        assert_eq!(retry.backoff_after(40), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn retry_config_reports_exhaustion_only_for_eligible_failures() {
+        let retry = RetryConfig::new().max_attempts(2);
+        let error = Error::Grpc(tonic::Status::unavailable("offline"));
+
+        assert!(matches!(
+            retry.decide(&context("search", 1, &error)),
+            RetryDecision::Retry(_)
+        ));
+        assert_eq!(
+            retry.decide(&context("search", 2, &error)),
+            RetryDecision::Exhausted
+        );
+        let permanent = RetryContext {
+            transient: false,
+            ..context("search", 1, &error)
+        };
+        assert_eq!(retry.decide(&permanent), RetryDecision::Fail);
+    }
+
+    #[test]
+    fn operation_policy_uses_overrides_by_rpc_name() {
+        let policy = OperationRetryPolicy::new(RetryConfig::new())
+            .operations(["create_collection", "drop_collection"], NoRetry);
+        let error = Error::Grpc(tonic::Status::unavailable("offline"));
+
+        assert!(matches!(
+            policy.decide(&context("search", 1, &error)),
+            RetryDecision::Retry(_)
+        ));
+        assert_eq!(
+            policy.decide(&context("create_collection", 1, &error)),
+            RetryDecision::Fail
+        );
+    }
+
+    #[test]
+    fn retry_budget_is_shared_by_clones_and_caps_retries() {
+        let budget = RetryBudget::new(2, 0.0);
+        let policy = BudgetedRetryPolicy::new(RetryConfig::new(), budget.clone());
+        let error = Error::Grpc(tonic::Status::unavailable("offline"));
+
+        assert!(matches!(
+            policy.decide(&context("search", 1, &error)),
+            RetryDecision::Retry(_)
+        ));
+        assert!(budget.try_acquire());
+        assert_eq!(budget.available(), 0);
+        assert_eq!(
+            policy.decide(&context("search", 2, &error)),
+            RetryDecision::Exhausted
+        );
+    }
+
+    #[test]
+    fn retry_budget_refills_over_time() {
+        let budget = RetryBudget::new(1, 1_000.0);
+        assert!(budget.try_acquire());
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(budget.try_acquire());
+        assert_eq!(budget.capacity(), 1);
+    }
+}
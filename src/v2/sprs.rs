@@ -0,0 +1,99 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `sprs` sparse matrix conversions, enabled by the `sprs` feature.
+//!
+//! Each row of a CSR matrix becomes one [`SparseVector`]; column indexes must fit in `u32`.
+//!
+//! ```no_run
+//! # use milvus::v2::prelude::*;
+//! use milvus::v2::sprs::CsMat;
+//!
+//! # fn main() -> milvus::v2::error::Result<()> {
+//! let matrix = CsMat::new((2, 4), vec![0, 1, 3], vec![2, 0, 3], vec![0.5, 0.25, 1.0]);
+//! let column = FieldData::sparse_float_vector("sparse", SparseVector::from_csr(&matrix)?);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::v2::error::{Error, Result};
+use crate::v2::types::{SearchVectors, SparseVector};
+
+pub use sprs::{CsMat, CsVecView};
+
+impl SparseVector {
+    /// Converts every row of a sparse matrix, converting CSC input to CSR first.
+    pub fn from_csr(matrix: &CsMat<f32>) -> Result<Vec<Self>> {
+        if !matrix.is_csr() {
+            return Self::from_csr(&matrix.to_csr());
+        }
+        matrix.outer_iterator().map(Self::try_from).collect()
+    }
+}
+
+impl TryFrom<CsVecView<'_, f32>> for SparseVector {
+    type Error = Error;
+
+    fn try_from(vector: CsVecView<'_, f32>) -> Result<Self> {
+        let indices = vector
+            .indices()
+            .iter()
+            .map(|&index| {
+                u32::try_from(index).map_err(|_| {
+                    Error::conversion(format!("sparse index {index} does not fit in u32"))
+                })
+            })
+            .collect::<Result<_>>()?;
+        SparseVector::from_parts(indices, vector.data().to_vec())
+    }
+}
+
+impl SearchVectors {
+    /// Creates sparse query vectors from the rows of a sparse matrix.
+    pub fn sparse_csr(matrix: &CsMat<f32>) -> Result<Self> {
+        SparseVector::from_csr(matrix).map(Self::SparseFloat)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csr_and_csc_rows_become_sparse_vectors() {
+        let matrix = CsMat::new(
+            (3, 5),
+            vec![0, 2, 2, 3],
+            vec![1, 4, 0],
+            vec![0.5, 0.25, 1.0],
+        );
+        let expected = vec![
+            SparseVector::from([(1, 0.5), (4, 0.25)]),
+            SparseVector::new(),
+            SparseVector::from([(0, 1.0)]),
+        ];
+        assert_eq!(SparseVector::from_csr(&matrix).unwrap(), expected);
+        assert_eq!(SparseVector::from_csr(&matrix.to_csc()).unwrap(), expected);
+        assert_eq!(
+            SearchVectors::sparse_csr(&matrix).unwrap(),
+            SearchVectors::SparseFloat(expected)
+        );
+    }
+}
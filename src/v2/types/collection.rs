@@ -62,6 +62,7 @@ impl LoadState {
 /// Default value assigned when a nullable or defaulted field is omitted.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DefaultValue {
     /// Represents the Bool case.
     Bool(bool),
@@ -146,6 +147,8 @@ impl DefaultValue {
 /// Schema definition for a collection field.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default = "FieldSchema::new"))]
 pub struct FieldSchema {
     pub(crate) name: String,
     pub(crate) description: String,
@@ -157,7 +160,15 @@ pub struct FieldSchema {
     pub(crate) is_clustering_key: bool,
     pub(crate) nullable: bool,
     pub(crate) default_value: Option<DefaultValue>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::v2::types::serialize_sorted")
+    )]
     pub(crate) type_params: HashMap<String, String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::v2::types::serialize_sorted")
+    )]
     pub(crate) index_params: HashMap<String, String>,
     pub(crate) external_field: String,
 }
@@ -751,6 +762,8 @@ impl FieldSchema {
 /// Schema definition for a field nested inside a struct field.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default = "StructFieldSchema::new"))]
 pub struct StructFieldSchema {
     pub(crate) name: String,
     pub(crate) description: String,
@@ -1061,12 +1074,18 @@ impl StructFieldSchema {
 /// Schema definition for a Milvus collection.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default = "CollectionSchema::new"))]
 pub struct CollectionSchema {
     pub(crate) description: String,
     pub(crate) enable_dynamic_field: bool,
     pub(crate) fields: Vec<FieldSchema>,
     pub(crate) struct_fields: Vec<StructFieldSchema>,
     pub(crate) functions: Vec<Function>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::v2::types::serialize_sorted")
+    )]
     pub(crate) properties: HashMap<String, String>,
     pub(crate) external_source: String,
     pub(crate) external_spec: Option<serde_json::Value>,
@@ -1346,6 +1365,8 @@ impl CollectionSchema {
 /// Detailed collection metadata returned by describe operations.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default = "CollectionDesc::new"))]
 pub struct CollectionDesc {
     pub(crate) database_name: String,
     pub(crate) collection_name: String,
@@ -1364,6 +1385,10 @@ pub struct CollectionDesc {
     pub(crate) created_utc_time: u64,
     pub(crate) update_time: u64,
     pub(crate) consistency_level: ConsistencyLevel,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::v2::types::serialize_sorted")
+    )]
     pub(crate) properties: HashMap<String, String>,
 }
 
@@ -18,8 +18,11 @@
 
 use crate::proto::{common, schema};
 use crate::v2::error::{Error, Result};
+use crate::v2::retry::RetryPolicy;
 use crate::v2::types::dql::{BoostRerank, DecayRerank, ModelRerank, RRFRerank, WeightedRerank};
+use crate::v2::types::{SparseVector, Timestamp};
 use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 use std::time::Duration;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -28,6 +31,7 @@ use std::time::Duration;
 /// Consistency guarantee used by query and search operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConsistencyLevel {
     /// Represents the Strong case.
     Strong,
@@ -137,6 +141,22 @@ impl MetricType {
             _ => Self::Default,
         }
     }
+
+    /// Returns whether smaller scores are closer matches, as for `L2`, `HAMMING`, and `JACCARD`.
+    ///
+    /// Similarity metrics such as `IP`, `COSINE`, and `BM25`, and `Default`, return `false`.
+    pub fn is_distance(self) -> bool {
+        matches!(
+            self,
+            Self::L2
+                | Self::Jaccard
+                | Self::MhJaccard
+                | Self::Hamming
+                | Self::MaxSimL2
+                | Self::MaxSimJaccard
+                | Self::MaxSimHamming
+        )
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -145,6 +165,7 @@ impl MetricType {
 /// Milvus field data type.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     #[default]
     /// Represents the Unknown case.
@@ -264,6 +285,7 @@ impl DataType {
 /// Type of server-side schema function.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FunctionType {
     #[default]
     /// Represents the Unknown case.
@@ -282,12 +304,18 @@ pub enum FunctionType {
 /// Server-side function attached to a collection schema.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default = "Function::new"))]
 pub struct Function {
     pub(crate) name: String,
     pub(crate) description: String,
     pub(crate) function_type: FunctionType,
     pub(crate) input_fields: Vec<String>,
     pub(crate) output_fields: Vec<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::v2::types::serialize_sorted")
+    )]
     pub(crate) params: HashMap<String, String>,
 }
 
@@ -568,6 +596,30 @@ impl Ids {
             (Self::Int64(_), Self::Int64(_)) | (Self::VarChar(_), Self::VarChar(_))
         )
     }
+
+    /// Splits the keys into consecutive chunks of at most `size` keys.
+    pub(crate) fn chunks(&self, size: usize) -> Vec<Self> {
+        match self {
+            Self::Int64(values) => values
+                .chunks(size)
+                .map(|chunk| Self::Int64(chunk.to_vec()))
+                .collect(),
+            Self::VarChar(values) => values
+                .chunks(size)
+                .map(|chunk| Self::VarChar(chunk.to_vec()))
+                .collect(),
+        }
+    }
+
+    /// Returns the keys at `indices`, in that order.
+    pub(crate) fn select(&self, indices: &[usize]) -> Self {
+        match self {
+            Self::Int64(values) => Self::Int64(indices.iter().map(|&i| values[i]).collect()),
+            Self::VarChar(values) => {
+                Self::VarChar(indices.iter().map(|&i| values[i].clone()).collect())
+            }
+        }
+    }
 }
 
 impl Default for Ids {
@@ -586,6 +638,108 @@ impl Ids {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// DenseVectors
+///////////////////////////////////////////////////////////////////////////////
+/// Float vectors of one dimension stored row-major in a single contiguous buffer.
+///
+/// Encoding moves the buffer straight into the protobuf `FloatArray`, and rows are read back as
+/// `&[f32]` slices without a per-vector allocation.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct DenseVectors {
+    pub(crate) dim: usize,
+    pub(crate) data: Vec<f32>,
+}
+
+impl DenseVectors {
+    /// Creates vectors from a row-major buffer whose length is a multiple of `dim`.
+    pub fn new(dim: usize, data: Vec<f32>) -> Result<Self> {
+        if dim == 0 || data.len() % dim != 0 {
+            return Err(Error::validation(
+                "vectors".into(),
+                format!(
+                    "{} values do not form whole vectors of dimension {dim}",
+                    data.len()
+                ),
+            ));
+        }
+        Ok(Self { dim, data })
+    }
+
+    /// Copies equally sized rows into one contiguous buffer.
+    pub fn from_rows<R: AsRef<[f32]>>(rows: impl IntoIterator<Item = R>) -> Result<Self> {
+        let mut rows = rows.into_iter().peekable();
+        let dim = rows.peek().map_or(0, |row| row.as_ref().len());
+        let mut data = Vec::with_capacity(dim * rows.size_hint().0);
+        for row in rows {
+            let row = row.as_ref();
+            if row.len() != dim {
+                return Err(Error::validation(
+                    "vectors".into(),
+                    "all vectors must have the same dimension".into(),
+                ));
+            }
+            data.extend_from_slice(row);
+        }
+        Self::new(dim, data)
+    }
+
+    /// Returns the vector dimension.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the number of vectors.
+    pub fn len(&self) -> usize {
+        self.data.len() / self.dim
+    }
+
+    /// Returns whether there are no vectors.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the vector at `index`.
+    pub fn row(&self, index: usize) -> Option<&[f32]> {
+        let start = index.checked_mul(self.dim)?;
+        self.data.get(start..start + self.dim)
+    }
+
+    /// Iterates over the vectors as row slices.
+    pub fn rows(&self) -> std::slice::ChunksExact<'_, f32> {
+        self.data.chunks_exact(self.dim)
+    }
+
+    /// Returns the row-major buffer.
+    pub fn as_slice(&self) -> &[f32] {
+        &self.data
+    }
+
+    /// Returns the row-major buffer, consuming the vectors.
+    pub fn into_inner(self) -> Vec<f32> {
+        self.data
+    }
+
+    /// Copies the vectors into one `Vec` per row.
+    pub fn to_rows(&self) -> Vec<Vec<f32>> {
+        self.rows().map(<[f32]>::to_vec).collect()
+    }
+
+    /// Keeps the rows whose `keep` flag is set; `keep` must have one flag per row.
+    pub(crate) fn filter_rows(&self, keep: &[bool]) -> Option<Self> {
+        (keep.len() == self.len()).then(|| Self {
+            dim: self.dim,
+            data: self
+                .rows()
+                .zip(keep)
+                .filter(|(_, keep)| **keep)
+                .flat_map(|(row, _)| row.iter().copied())
+                .collect(),
+        })
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // FieldData
 ///////////////////////////////////////////////////////////////////////////////
@@ -775,6 +929,13 @@ pub enum FieldData {
         /// Values for this field, in row order.
         values: Vec<Vec<i8>>,
     },
+    /// Float vectors held in one contiguous buffer; encoded as a `FloatVector` field.
+    DenseFloatVector {
+        /// Field name shared with the collection schema.
+        name: String,
+        /// Vectors for this field, in row order.
+        values: DenseVectors,
+    },
     /// Represents the Nullable case.
     Nullable {
         /// Wrapped field data.
@@ -873,6 +1034,23 @@ impl FieldData {
         }
     }
 
+    /// Creates a Timestamptz column rendered as RFC 3339 text in each value's UTC offset.
+    ///
+    /// Accepts [`Timestamp`] values, and `chrono` or `time` date-times when those features are
+    /// enabled.
+    pub fn timestamps(
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<Timestamp>>,
+    ) -> Self {
+        Self::timestamptz(
+            name,
+            values
+                .into_iter()
+                .map(|value| value.into().to_string())
+                .collect(),
+        )
+    }
+
     /// Performs the array bool operation.
     pub fn array_bool(name: impl Into<String>, values: Vec<Vec<bool>>) -> Self {
         Self::ArrayBool {
@@ -993,6 +1171,14 @@ impl FieldData {
         }
     }
 
+    /// Creates a float vector column backed by one contiguous buffer.
+    pub fn dense_float_vector(name: impl Into<String>, values: DenseVectors) -> Self {
+        Self::DenseFloatVector {
+            name: name.into(),
+            values,
+        }
+    }
+
     /// Returns this value configured with with validity.
     pub fn with_validity(self, valid_data: Vec<bool>) -> Result<Self> {
         Self::nullable(self, valid_data)
@@ -1086,6 +1272,12 @@ impl FieldData {
         }
     }
 
+    /// Parses the non-null values of a Timestamptz column, or returns `None` for other columns.
+    pub fn to_timestamps(&self) -> Option<Result<Vec<Timestamp>>> {
+        self.as_timestamptz()
+            .map(|values| values.iter().map(|value| Timestamp::parse(value)).collect())
+    }
+
     /// Returns the as array bool.
     pub fn as_array_bool(&self) -> Option<&[Vec<bool>]> {
         match self.inner() {
@@ -1159,6 +1351,9 @@ impl FieldData {
     }
 
     /// Returns the as float vectors.
+    ///
+    /// Returns `None` for [`FieldData::DenseFloatVector`] columns, such as results of requests
+    /// that enable `dense_vectors`; read either layout with [`Self::float_vector_rows`].
     pub fn as_float_vectors(&self) -> Option<&[Vec<f32>]> {
         match self.inner() {
             Self::FloatVector { values, .. } => Some(values),
@@ -1206,6 +1401,25 @@ impl FieldData {
         }
     }
 
+    /// Returns the contiguous float vectors of a [`FieldData::DenseFloatVector`] column.
+    ///
+    /// [`Self::as_float_vectors`] returns `None` for these columns.
+    pub fn as_dense_float_vectors(&self) -> Option<&DenseVectors> {
+        match self.inner() {
+            Self::DenseFloatVector { values, .. } => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the rows of a float vector column stored in either layout.
+    pub fn float_vector_rows(&self) -> Option<Vec<&[f32]>> {
+        match self.inner() {
+            Self::FloatVector { values, .. } => Some(values.iter().map(Vec::as_slice).collect()),
+            Self::DenseFloatVector { values, .. } => Some(values.rows().collect()),
+            _ => None,
+        }
+    }
+
     /// Returns the name.
     pub fn name(&self) -> &str {
         match self {
@@ -1234,7 +1448,8 @@ impl FieldData {
             | Self::Float16Vector { name, .. }
             | Self::BFloat16Vector { name, .. }
             | Self::SparseFloatVector { name, .. }
-            | Self::Int8Vector { name, .. } => name,
+            | Self::Int8Vector { name, .. }
+            | Self::DenseFloatVector { name, .. } => name,
             Self::Nullable { data, .. } => data.name(),
         }
     }
@@ -1268,6 +1483,7 @@ impl FieldData {
             Self::BFloat16Vector { values, .. } => values.len(),
             Self::SparseFloatVector { values, .. } => values.len(),
             Self::Int8Vector { values, .. } => values.len(),
+            Self::DenseFloatVector { values, .. } => values.len(),
             Self::Nullable { valid_data, .. } => valid_data.len(),
         }
     }
@@ -1580,6 +1796,16 @@ impl FieldData {
                 values.extend(other);
                 true
             }
+            (
+                Self::DenseFloatVector { name, values },
+                Self::DenseFloatVector {
+                    name: other_name,
+                    values: other,
+                },
+            ) if *name == other_name && values.dim == other.dim => {
+                values.data.extend(other.data);
+                true
+            }
             (
                 Self::Nullable { data, valid_data },
                 Self::Nullable {
@@ -1680,6 +1906,18 @@ impl FieldData {
                     .collect(),
             )),
             Self::Int8Vector { values, .. } => value(values, index),
+            Self::DenseFloatVector { values, .. } => values
+                .row(index)
+                .ok_or_else(|| {
+                    Error::validation(
+                        "index".into(),
+                        format!(
+                            "row index {index} is out of bounds for {} rows",
+                            values.len()
+                        ),
+                    )
+                })
+                .and_then(|row| serde_json::to_value(row).map_err(Into::into)),
             Self::Nullable { data, valid_data } => {
                 let valid = *valid_data.get(index).ok_or_else(|| {
                     Error::validation(
@@ -1727,6 +1965,7 @@ impl FieldData {
             Self::BFloat16Vector { .. } => DataType::BFloat16Vector,
             Self::SparseFloatVector { .. } => DataType::SparseFloatVector,
             Self::Int8Vector { .. } => DataType::Int8Vector,
+            Self::DenseFloatVector { .. } => DataType::FloatVector,
             Self::Nullable { data, .. } => data.data_type(),
         }
     }
@@ -1740,6 +1979,7 @@ impl FieldData {
                 values.first().and_then(|value| value.len().checked_mul(8))
             }
             Self::Int8Vector { values, .. } => values.first().map(Vec::len),
+            Self::DenseFloatVector { values, .. } => Some(values.dim),
             Self::Nullable { data, .. } => data.dimension(),
             _ => None,
         }
@@ -2029,13 +2269,9 @@ impl FieldData {
                 )
             }
             Self::SparseFloatVector { name, values } => {
-                let dimension = values
-                    .iter()
-                    .flat_map(|value| value.keys().copied())
-                    .max()
-                    .map_or(0, |value| i64::from(value) + 1);
+                let dimension = sparse_dimension(&values);
                 let contents = values
-                    .into_iter()
+                    .iter()
                     .map(|values| encode_sparse_vector(&name, values))
                     .collect::<Result<_>>()?;
                 (
@@ -2069,6 +2305,16 @@ impl FieldData {
                     }),
                 )
             }
+            Self::DenseFloatVector { name, values } => (
+                name,
+                schema::DataType::FloatVector,
+                field_data::Field::Vectors(schema::VectorField {
+                    dim: values.dim as i64,
+                    data: Some(vector_field::Data::FloatVector(schema::FloatArray {
+                        data: values.data,
+                    })),
+                }),
+            ),
             Self::Nullable { .. } => unreachable!(),
         };
 
@@ -2324,14 +2570,10 @@ impl FieldData {
                 )
             }
             Self::SparseFloatVector { name, values } => {
-                let dimension = values
-                    .iter()
-                    .flat_map(|value| value.keys().copied())
-                    .max()
-                    .map_or(0, |value| i64::from(value) + 1);
+                let dimension = sparse_dimension(values);
                 let contents = values
                     .iter()
-                    .map(|values| encode_sparse_vector_by_ref(name, values))
+                    .map(|values| encode_sparse_vector(name, values))
                     .collect::<Result<_>>()?;
                 (
                     name.clone(),
@@ -2365,6 +2607,16 @@ impl FieldData {
                     }),
                 )
             }
+            Self::DenseFloatVector { name, values } => (
+                name.clone(),
+                schema::DataType::FloatVector,
+                field_data::Field::Vectors(schema::VectorField {
+                    dim: values.dim as i64,
+                    data: Some(vector_field::Data::FloatVector(schema::FloatArray {
+                        data: values.data.clone(),
+                    })),
+                }),
+            ),
             Self::Nullable { .. } => unreachable!(),
         };
 
@@ -2558,6 +2810,569 @@ impl RetryConfig {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// OperationClass
+///////////////////////////////////////////////////////////////////////////////
+/// Class of RPC operations that share one client-side rate and concurrency limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum OperationClass {
+    /// Insert, upsert, and delete operations.
+    Dml,
+    /// Search, hybrid search, and query operations, including `get` which is sent as a query.
+    Dql,
+    /// Collection, partition, index, alias, and field schema and lifecycle operations such as
+    /// create, drop, alter, load, and release.
+    ///
+    /// Database, RBAC, resource group, and snapshot operations are not limited.
+    Ddl,
+}
+
+const DML_RPCS: &[&str] = &["insert", "upsert", "delete"];
+
+const DQL_RPCS: &[&str] = &["search", "hybrid_search", "query"];
+
+const DDL_RPCS: &[&str] = &[
+    "add_collection_field",
+    "add_collection_function",
+    "alter_alias",
+    "alter_collection",
+    "alter_collection_field",
+    "alter_collection_function",
+    "alter_index",
+    "create_alias",
+    "create_collection",
+    "create_index",
+    "create_partition",
+    "drop_alias",
+    "drop_collection",
+    "drop_collection_function",
+    "drop_index",
+    "drop_partition",
+    "load_collection",
+    "load_partitions",
+    "refresh_external_collection",
+    "release_collection",
+    "release_partitions",
+    "rename_collection",
+    "truncate_collection",
+];
+
+impl OperationClass {
+    /// Returns the class of the named RPC method, or `None` when the method is not limited.
+    ///
+    /// The name must be the exact snake_case RPC name that retry policies see; client methods
+    /// such as `get` are limited through the RPC they send.
+    pub fn of_method(method: &str) -> Option<Self> {
+        [
+            (Self::Dml, DML_RPCS),
+            (Self::Dql, DQL_RPCS),
+            (Self::Ddl, DDL_RPCS),
+        ]
+        .into_iter()
+        .find(|(_, rpcs)| rpcs.contains(&method))
+        .map(|(class, _)| class)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// RateLimitConfig
+///////////////////////////////////////////////////////////////////////////////
+/// Client-side limits applied to one [`OperationClass`].
+///
+/// Requests that exceed a limit wait inside the client instead of reaching the server. A zero
+/// value disables the corresponding limit.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RateLimitConfig {
+    /// Maximum number of requests in flight at the same time.
+    pub(crate) max_in_flight: usize,
+    /// Maximum number of requests started per second.
+    pub(crate) requests_per_second: f64,
+    /// Maximum number of encoded request bytes sent per second.
+    pub(crate) bytes_per_second: u64,
+    /// Whether server rate-limit responses shrink the limits (AIMD).
+    pub(crate) adaptive: bool,
+}
+
+impl RateLimitConfig {
+    /// Creates a value initialized with its SDK defaults.
+    pub fn new() -> Self {
+        Self {
+            max_in_flight: 0,
+            requests_per_second: 0.0,
+            bytes_per_second: 0,
+            adaptive: true,
+        }
+    }
+
+    /// Sets the max in flight and returns the updated value.
+    pub fn max_in_flight(mut self, value: usize) -> Self {
+        self.max_in_flight = value;
+        self
+    }
+
+    /// Sets the max in flight and returns this value for further mutation.
+    pub fn set_max_in_flight(&mut self, value: usize) -> &mut Self {
+        self.max_in_flight = value;
+        self
+    }
+
+    /// Returns the configured max in flight.
+    pub fn get_max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Sets the requests per second and returns the updated value.
+    pub fn requests_per_second(mut self, value: f64) -> Self {
+        self.requests_per_second = value;
+        self
+    }
+
+    /// Sets the requests per second and returns this value for further mutation.
+    pub fn set_requests_per_second(&mut self, value: f64) -> &mut Self {
+        self.requests_per_second = value;
+        self
+    }
+
+    /// Returns the configured requests per second.
+    pub fn get_requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// Sets the bytes per second and returns the updated value.
+    pub fn bytes_per_second(mut self, value: u64) -> Self {
+        self.bytes_per_second = value;
+        self
+    }
+
+    /// Sets the bytes per second and returns this value for further mutation.
+    pub fn set_bytes_per_second(&mut self, value: u64) -> &mut Self {
+        self.bytes_per_second = value;
+        self
+    }
+
+    /// Returns the configured bytes per second.
+    pub fn get_bytes_per_second(&self) -> u64 {
+        self.bytes_per_second
+    }
+
+    /// Sets whether rate-limit responses adapt the limits and returns the updated value.
+    ///
+    /// Adaptive limits halve the effective rate and concurrency after each rate-limit response
+    /// and recover additively after successful calls, never exceeding the configured values.
+    pub fn adaptive(mut self, value: bool) -> Self {
+        self.adaptive = value;
+        self
+    }
+
+    /// Sets whether rate-limit responses adapt the limits and returns this value for further
+    /// mutation.
+    pub fn set_adaptive(&mut self, value: bool) -> &mut Self {
+        self.adaptive = value;
+        self
+    }
+
+    /// Returns whether rate-limit responses adapt the limits.
+    pub fn get_adaptive(&self) -> bool {
+        self.adaptive
+    }
+
+    pub(crate) fn is_unlimited(&self) -> bool {
+        self.max_in_flight == 0
+            && !(self.requests_per_second.is_finite() && self.requests_per_second > 0.0)
+            && self.bytes_per_second == 0
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// CircuitBreakerConfig
+///////////////////////////////////////////////////////////////////////////////
+/// Circuit breaker that fails calls fast while the Milvus endpoint is unreachable.
+///
+/// The breaker opens after consecutive `Unavailable` or `DeadlineExceeded` failures. While open,
+/// calls fail with [`Error::CircuitOpen`] without reaching the server. After the open timeout the
+/// next call sends a `check_health` probe and closes the breaker when the server reports healthy.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct CircuitBreakerConfig {
+    /// Consecutive transport failures that open the breaker.
+    pub(crate) failure_threshold: u32,
+    /// Time the breaker stays open before a half-open probe is attempted.
+    pub(crate) open_timeout: Duration,
+    /// Deadline for one half-open `check_health` probe.
+    pub(crate) probe_timeout: Duration,
+}
+
+impl CircuitBreakerConfig {
+    /// Creates a value initialized with its SDK defaults.
+    pub fn new() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_timeout: Duration::from_secs(30),
+            probe_timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets the failure threshold and returns the updated value.
+    pub fn failure_threshold(mut self, value: u32) -> Self {
+        self.failure_threshold = value;
+        self
+    }
+
+    /// Sets the failure threshold and returns this value for further mutation.
+    pub fn set_failure_threshold(&mut self, value: u32) -> &mut Self {
+        self.failure_threshold = value;
+        self
+    }
+
+    /// Returns the configured failure threshold.
+    pub fn get_failure_threshold(&self) -> u32 {
+        self.failure_threshold
+    }
+
+    /// Sets the open timeout and returns the updated value.
+    pub fn open_timeout(mut self, value: Duration) -> Self {
+        self.open_timeout = value;
+        self
+    }
+
+    /// Sets the open timeout and returns this value for further mutation.
+    pub fn set_open_timeout(&mut self, value: Duration) -> &mut Self {
+        self.open_timeout = value;
+        self
+    }
+
+    /// Returns the configured open timeout.
+    pub fn get_open_timeout(&self) -> Duration {
+        self.open_timeout
+    }
+
+    /// Sets the probe timeout and returns the updated value.
+    pub fn probe_timeout(mut self, value: Duration) -> Self {
+        self.probe_timeout = value;
+        self
+    }
+
+    /// Sets the probe timeout and returns this value for further mutation.
+    pub fn set_probe_timeout(&mut self, value: Duration) -> &mut Self {
+        self.probe_timeout = value;
+        self
+    }
+
+    /// Returns the configured probe timeout.
+    pub fn get_probe_timeout(&self) -> Duration {
+        self.probe_timeout
+    }
+}
+
+/// Observable state of a client circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitState {
+    /// Calls reach the server normally.
+    Closed,
+    /// Calls fail fast with [`Error::CircuitOpen`].
+    Open,
+    /// A `check_health` probe is deciding whether the breaker closes again.
+    HalfOpen,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// HedgingConfig
+///////////////////////////////////////////////////////////////////////////////
+/// Request hedging for latency-sensitive idempotent reads.
+///
+/// When a `search`, `hybrid_search`, `query`, or `get` attempt has not answered within the hedge
+/// delay, the client sends a duplicate request and keeps the first response; the slower requests
+/// are cancelled. Hedging never applies to non-idempotent operations. With a latency percentile
+/// configured, the delay tracks that percentile of recently observed read latencies and the
+/// configured delay acts as its lower bound.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct HedgingConfig {
+    /// Fixed hedge delay, and the lower bound for a percentile-based delay.
+    pub(crate) delay: Duration,
+    /// Observed-latency percentile, in `(0, 1]`, used as the hedge delay.
+    pub(crate) percentile: Option<f64>,
+    /// Maximum duplicate requests sent for one attempt.
+    pub(crate) max_hedges: usize,
+}
+
+impl HedgingConfig {
+    /// Creates a value initialized with its SDK defaults.
+    pub fn new() -> Self {
+        Self {
+            delay: Duration::from_millis(50),
+            percentile: Some(0.95),
+            max_hedges: 1,
+        }
+    }
+
+    /// Sets the delay and returns the updated value.
+    pub fn delay(mut self, value: Duration) -> Self {
+        self.delay = value;
+        self
+    }
+
+    /// Sets the delay and returns this value for further mutation.
+    pub fn set_delay(&mut self, value: Duration) -> &mut Self {
+        self.delay = value;
+        self
+    }
+
+    /// Returns the configured delay.
+    pub fn get_delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Sets the latency percentile and returns the updated value.
+    ///
+    /// `None` always waits for the fixed delay.
+    pub fn percentile(mut self, value: Option<f64>) -> Self {
+        self.percentile = value;
+        self
+    }
+
+    /// Sets the latency percentile and returns this value for further mutation.
+    pub fn set_percentile(&mut self, value: Option<f64>) -> &mut Self {
+        self.percentile = value;
+        self
+    }
+
+    /// Returns the configured latency percentile.
+    pub fn get_percentile(&self) -> Option<f64> {
+        self.percentile
+    }
+
+    /// Sets the max hedges and returns the updated value.
+    pub fn max_hedges(mut self, value: usize) -> Self {
+        self.max_hedges = value;
+        self
+    }
+
+    /// Sets the max hedges and returns this value for further mutation.
+    pub fn set_max_hedges(&mut self, value: usize) -> &mut Self {
+        self.max_hedges = value;
+        self
+    }
+
+    /// Returns the configured max hedges.
+    pub fn get_max_hedges(&self) -> usize {
+        self.max_hedges
+    }
+}
+
+/// Counters describing hedged reads sent by one client and its clones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HedgingMetrics {
+    pub(crate) hedged_attempts: u64,
+    pub(crate) hedges_sent: u64,
+    pub(crate) hedge_wins: u64,
+    pub(crate) cancelled_requests: u64,
+}
+
+impl HedgingMetrics {
+    /// Returns the number of attempts that sent at least one hedge.
+    pub fn get_hedged_attempts(&self) -> u64 {
+        self.hedged_attempts
+    }
+
+    /// Returns the number of duplicate requests sent.
+    pub fn get_hedges_sent(&self) -> u64 {
+        self.hedges_sent
+    }
+
+    /// Returns the number of attempts answered first by a hedge rather than the original request.
+    pub fn get_hedge_wins(&self) -> u64 {
+        self.hedge_wins
+    }
+
+    /// Returns the number of losing requests cancelled after another request answered.
+    pub fn get_cancelled_requests(&self) -> u64 {
+        self.cancelled_requests
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ResultCacheConfig
+///////////////////////////////////////////////////////////////////////////////
+/// Client-side caching of repeated `search`, `query`, and `get` results.
+///
+/// Only requests that explicitly read at Bounded or Eventually consistency are cached, since they
+/// already accept slightly stale data. An entry is served until it is older than the TTL or this
+/// process performs DML on its collection through the same endpoint, whichever comes first.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ResultCacheConfig {
+    /// Maximum cached responses; the least recently used entry is evicted first.
+    pub(crate) max_entries: usize,
+    /// Maximum encoded size of the cached responses and their keys.
+    pub(crate) max_bytes: usize,
+    /// Longest time a cached response is served.
+    pub(crate) ttl: Duration,
+}
+
+impl ResultCacheConfig {
+    /// Creates a value initialized with its SDK defaults.
+    pub fn new() -> Self {
+        Self {
+            max_entries: 1_024,
+            max_bytes: 64 * 1024 * 1024,
+            ttl: Duration::from_secs(10),
+        }
+    }
+
+    /// Sets the max entries and returns the updated value.
+    ///
+    /// Zero disables the cache.
+    pub fn max_entries(mut self, value: usize) -> Self {
+        self.max_entries = value;
+        self
+    }
+
+    /// Sets the max entries and returns this value for further mutation.
+    pub fn set_max_entries(&mut self, value: usize) -> &mut Self {
+        self.max_entries = value;
+        self
+    }
+
+    /// Returns the configured max entries.
+    pub fn get_max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Sets the max bytes and returns the updated value.
+    ///
+    /// Least recently used entries are evicted once the cached responses exceed it, and a response
+    /// larger than the limit is not cached. Zero disables the cache.
+    pub fn max_bytes(mut self, value: usize) -> Self {
+        self.max_bytes = value;
+        self
+    }
+
+    /// Sets the max bytes and returns this value for further mutation.
+    pub fn set_max_bytes(&mut self, value: usize) -> &mut Self {
+        self.max_bytes = value;
+        self
+    }
+
+    /// Returns the configured max bytes.
+    pub fn get_max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Sets the TTL and returns the updated value.
+    pub fn ttl(mut self, value: Duration) -> Self {
+        self.ttl = value;
+        self
+    }
+
+    /// Sets the TTL and returns this value for further mutation.
+    pub fn set_ttl(&mut self, value: Duration) -> &mut Self {
+        self.ttl = value;
+        self
+    }
+
+    /// Returns the configured TTL.
+    pub fn get_ttl(&self) -> Duration {
+        self.ttl
+    }
+}
+
+/// Counters describing result-cache lookups by one client and its clones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResultCacheMetrics {
+    pub(crate) hits: u64,
+    pub(crate) misses: u64,
+    pub(crate) entries: usize,
+    pub(crate) bytes: usize,
+}
+
+impl ResultCacheMetrics {
+    /// Returns the number of reads answered from the cache.
+    pub fn get_hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of cacheable reads sent to the server, including reads whose entry had
+    /// expired or been invalidated by DML.
+    pub fn get_misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Returns the number of responses currently cached.
+    pub fn get_entries(&self) -> usize {
+        self.entries
+    }
+
+    /// Returns the encoded size of the cached responses and their keys.
+    pub fn get_bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SearchBatchConfig
+///////////////////////////////////////////////////////////////////////////////
+/// Limits for coalescing concurrent searches in a `SearchBatcher`.
+///
+/// A batch is sent once it holds `max_batch_size` query vectors or `max_wait` has passed since
+/// its first search arrived, whichever comes first.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct SearchBatchConfig {
+    /// Maximum query vectors sent in one batched RPC.
+    pub(crate) max_batch_size: usize,
+    /// Longest time the first search of a batch waits for companions.
+    pub(crate) max_wait: Duration,
+}
+
+impl SearchBatchConfig {
+    /// Creates a value initialized with its SDK defaults.
+    pub fn new() -> Self {
+        Self {
+            max_batch_size: 64,
+            max_wait: Duration::from_millis(2),
+        }
+    }
+
+    /// Sets the max batch size and returns the updated value.
+    pub fn max_batch_size(mut self, value: usize) -> Self {
+        self.max_batch_size = value;
+        self
+    }
+
+    /// Sets the max batch size and returns this value for further mutation.
+    pub fn set_max_batch_size(&mut self, value: usize) -> &mut Self {
+        self.max_batch_size = value;
+        self
+    }
+
+    /// Returns the configured max batch size.
+    pub fn get_max_batch_size(&self) -> usize {
+        self.max_batch_size
+    }
+
+    /// Sets the max wait and returns the updated value.
+    pub fn max_wait(mut self, value: Duration) -> Self {
+        self.max_wait = value;
+        self
+    }
+
+    /// Sets the max wait and returns this value for further mutation.
+    pub fn set_max_wait(&mut self, value: Duration) -> &mut Self {
+        self.max_wait = value;
+        self
+    }
+
+    /// Returns the configured max wait.
+    pub fn get_max_wait(&self) -> Duration {
+        self.max_wait
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // ConnectConfig
 ///////////////////////////////////////////////////////////////////////////////
@@ -2578,6 +3393,11 @@ pub struct ConnectConfig {
     pub(crate) keepalive_while_idle: bool,
     pub(crate) database: String,
     pub(crate) retry: RetryConfig,
+    pub(crate) retry_policy: Option<Arc<dyn RetryPolicy>>,
+    pub(crate) rate_limits: BTreeMap<OperationClass, RateLimitConfig>,
+    pub(crate) circuit_breaker: Option<CircuitBreakerConfig>,
+    pub(crate) hedging: Option<HedgingConfig>,
+    pub(crate) result_cache: Option<ResultCacheConfig>,
 }
 
 impl std::fmt::Debug for ConnectConfig {
@@ -2599,6 +3419,11 @@ impl std::fmt::Debug for ConnectConfig {
             .field("keepalive_while_idle", &self.keepalive_while_idle)
             .field("database", &self.database)
             .field("retry", &self.retry)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limits", &self.rate_limits)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("hedging", &self.hedging)
+            .field("result_cache", &self.result_cache)
             .finish()
     }
 }
@@ -2620,6 +3445,11 @@ impl ConnectConfig {
             keepalive_while_idle: true,
             database: String::new(),
             retry: RetryConfig::new(),
+            retry_policy: None,
+            rate_limits: BTreeMap::new(),
+            circuit_breaker: None,
+            hedging: None,
+            result_cache: None,
         }
     }
 
@@ -2846,6 +3676,94 @@ impl ConnectConfig {
         &self.retry
     }
 
+    /// Sets a custom retry policy and returns the updated value.
+    ///
+    /// A custom policy takes precedence over [`ConnectConfig::retry`].
+    pub fn retry_policy(mut self, policy: impl RetryPolicy + 'static) -> Self {
+        self.retry_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Sets a custom retry policy and returns this value for further mutation.
+    pub fn set_retry_policy(&mut self, policy: impl RetryPolicy + 'static) -> &mut Self {
+        self.retry_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Returns the configured custom retry policy, if any.
+    pub fn get_retry_policy(&self) -> Option<&Arc<dyn RetryPolicy>> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Sets the client-side limits for one operation class and returns the updated value.
+    pub fn rate_limit(mut self, class: OperationClass, limit: RateLimitConfig) -> Self {
+        self.rate_limits.insert(class, limit);
+        self
+    }
+
+    /// Sets the client-side limits for one operation class and returns this value for further
+    /// mutation.
+    pub fn set_rate_limit(&mut self, class: OperationClass, limit: RateLimitConfig) -> &mut Self {
+        self.rate_limits.insert(class, limit);
+        self
+    }
+
+    /// Returns the configured client-side limits for one operation class.
+    pub fn get_rate_limit(&self, class: OperationClass) -> Option<&RateLimitConfig> {
+        self.rate_limits.get(&class)
+    }
+
+    /// Enables a circuit breaker and returns the updated value.
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Enables a circuit breaker and returns this value for further mutation.
+    pub fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) -> &mut Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Returns the configured circuit breaker, if enabled.
+    pub fn get_circuit_breaker(&self) -> Option<&CircuitBreakerConfig> {
+        self.circuit_breaker.as_ref()
+    }
+
+    /// Enables hedging for idempotent reads and returns the updated value.
+    pub fn hedging(mut self, config: HedgingConfig) -> Self {
+        self.hedging = Some(config);
+        self
+    }
+
+    /// Enables hedging for idempotent reads and returns this value for further mutation.
+    pub fn set_hedging(&mut self, config: HedgingConfig) -> &mut Self {
+        self.hedging = Some(config);
+        self
+    }
+
+    /// Returns the configured hedging, if enabled.
+    pub fn get_hedging(&self) -> Option<&HedgingConfig> {
+        self.hedging.as_ref()
+    }
+
+    /// Enables the search and query result cache and returns the updated value.
+    pub fn result_cache(mut self, config: ResultCacheConfig) -> Self {
+        self.result_cache = Some(config);
+        self
+    }
+
+    /// Enables the search and query result cache and returns this value for further mutation.
+    pub fn set_result_cache(&mut self, config: ResultCacheConfig) -> &mut Self {
+        self.result_cache = Some(config);
+        self
+    }
+
+    /// Returns the configured result cache, if enabled.
+    pub fn get_result_cache(&self) -> Option<&ResultCacheConfig> {
+        self.result_cache.as_ref()
+    }
+
     /// Performs the username password operation.
     pub fn username_password(self, username: &str, password: &str) -> Self {
         self.token(format!("{username}:{password}"))
@@ -2870,9 +3788,6 @@ pub type StructValue = serde_json::Map<String, serde_json::Value>;
 /// JSON object representing one row for row-oriented DML input.
 pub type EntityRow = serde_json::Map<String, serde_json::Value>;
 
-/// Sparse vector represented by dimension indexes and values.
-pub type SparseVector = BTreeMap<u32, f32>;
-
 fn validate_array_capacity<T>(
     name: &str,
     values: &[Vec<T>],
@@ -2929,13 +3844,13 @@ fn array_proto_field_by_ref<T: Clone>(
 }
 
 pub(crate) fn validate_sparse_vector(name: &str, values: &SparseVector) -> Result<()> {
-    for (&index, &value) in values {
-        if index == u32::MAX {
-            return Err(Error::validation(
-                name.to_owned(),
-                "sparse vector indices must be less than u32::MAX".into(),
-            ));
-        }
+    if values.indices.last() == Some(&u32::MAX) {
+        return Err(Error::validation(
+            name.to_owned(),
+            "sparse vector indices must be less than u32::MAX".into(),
+        ));
+    }
+    for &value in &values.values {
         if !value.is_finite() {
             return Err(Error::validation(
                 name.to_owned(),
@@ -2952,24 +3867,17 @@ pub(crate) fn validate_sparse_vector(name: &str, values: &SparseVector) -> Resul
     Ok(())
 }
 
-pub(crate) fn encode_sparse_vector(name: &str, values: SparseVector) -> Result<Vec<u8>> {
-    validate_sparse_vector(name, &values)?;
-    let mut bytes = Vec::with_capacity(values.len() * 8);
-    for (index, value) in values {
-        bytes.extend(index.to_le_bytes());
-        bytes.extend(value.to_le_bytes());
-    }
-    Ok(bytes)
+pub(crate) fn encode_sparse_vector(name: &str, values: &SparseVector) -> Result<Vec<u8>> {
+    validate_sparse_vector(name, values)?;
+    Ok(values.to_bytes())
 }
 
-fn encode_sparse_vector_by_ref(name: &str, values: &SparseVector) -> Result<Vec<u8>> {
-    validate_sparse_vector(name, values)?;
-    let mut bytes = Vec::with_capacity(values.len() * 8);
-    for (&index, &value) in values {
-        bytes.extend(index.to_le_bytes());
-        bytes.extend(value.to_le_bytes());
-    }
-    Ok(bytes)
+fn sparse_dimension(values: &[SparseVector]) -> i64 {
+    values
+        .iter()
+        .map(SparseVector::dimension)
+        .max()
+        .unwrap_or(0)
 }
 
 fn vector_dimension<T>(name: &str, values: &[Vec<T>]) -> Result<usize> {
@@ -3037,7 +3945,7 @@ mod data_type_tests {
 
 #[cfg(test)]
 mod field_data_tests {
-    use super::{DataType, Error, FieldData, SparseVector};
+    use super::{DataType, DenseVectors, Error, FieldData, SparseVector};
     use crate::proto::schema::{self, field_data, scalar_field, vector_field};
 
     #[test]
@@ -3347,6 +4255,55 @@ mod field_data_tests {
         assert_eq!(vectors.dim, 4);
         assert_eq!(encoded.valid_data, vec![false, false]);
     }
+
+    #[test]
+    fn dense_vectors_validate_their_shape_and_expose_row_slices() {
+        assert!(DenseVectors::new(0, Vec::new()).is_err());
+        assert!(DenseVectors::new(2, vec![0.1, 0.2, 0.3]).is_err());
+        assert!(DenseVectors::from_rows([vec![0.1, 0.2], vec![0.3]]).is_err());
+
+        let values = DenseVectors::from_rows([[0.1, 0.2], [0.3, 0.4]]).unwrap();
+        assert_eq!(values.dim(), 2);
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.row(1), Some(&[0.3, 0.4][..]));
+        assert_eq!(values.row(2), None);
+        assert_eq!(values.as_slice(), &[0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(values.to_rows(), vec![vec![0.1, 0.2], vec![0.3, 0.4]]);
+    }
+
+    #[test]
+    fn dense_float_vector_columns_encode_their_buffer_as_float_vectors() {
+        let values = DenseVectors::new(2, vec![0.1, 0.2, 0.3, 0.4]).unwrap();
+        let data = FieldData::dense_float_vector("embedding", values.clone());
+        assert_eq!(data.len(), 2);
+        assert_eq!(data.data_type(), DataType::FloatVector);
+        assert_eq!(data.dimension(), Some(2));
+        assert!(data.as_float_vectors().is_none());
+        assert_eq!(data.as_dense_float_vectors(), Some(&values));
+        assert_eq!(
+            data.float_vector_rows(),
+            Some(vec![[0.1, 0.2].as_slice(), [0.3, 0.4].as_slice()])
+        );
+        assert_eq!(
+            FieldData::float_vector("embedding", values.to_rows()).float_vector_rows(),
+            data.float_vector_rows()
+        );
+
+        let by_ref = data.to_proto().unwrap();
+        let encoded = data.into_proto().unwrap();
+        assert_eq!(by_ref, encoded);
+        assert_eq!(encoded.r#type, schema::DataType::FloatVector as i32);
+        let Some(field_data::Field::Vectors(vectors)) = encoded.field else {
+            panic!("expected float vector data")
+        };
+        assert_eq!(vectors.dim, 2);
+        assert_eq!(
+            vectors.data,
+            Some(vector_field::Data::FloatVector(schema::FloatArray {
+                data: vec![0.1, 0.2, 0.3, 0.4],
+            }))
+        );
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -3441,6 +4398,44 @@ mod constructor_value_tests {
         assert!(value.get_retry_on_rate_limit());
     }
 
+    #[test]
+    fn rate_limit_config_defaults_to_unlimited() {
+        let value = RateLimitConfig::new();
+
+        assert_eq!(value.get_max_in_flight(), 0);
+        assert_eq!(value.get_requests_per_second(), 0.0);
+        assert_eq!(value.get_bytes_per_second(), 0);
+        assert!(value.get_adaptive());
+        assert!(value.is_unlimited());
+        assert!(!value.max_in_flight(4).is_unlimited());
+    }
+
+    #[test]
+    fn operation_classes_follow_method_names() {
+        assert_eq!(
+            OperationClass::of_method("upsert"),
+            Some(OperationClass::Dml)
+        );
+        assert_eq!(
+            OperationClass::of_method("hybrid_search"),
+            Some(OperationClass::Dql)
+        );
+        assert_eq!(
+            OperationClass::of_method("create_collection"),
+            Some(OperationClass::Ddl)
+        );
+        assert_eq!(OperationClass::of_method("describe_collection"), None);
+        for method in [
+            "get",
+            "create_credential",
+            "create_role",
+            "drop_database",
+            "add_file_resource",
+        ] {
+            assert_eq!(OperationClass::of_method(method), None, "{method}");
+        }
+    }
+
     #[test]
     fn retry_config_populated_values() {
         let value = RetryConfig::new()
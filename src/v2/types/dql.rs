@@ -16,7 +16,11 @@
 
 //! Query, search, reranking, highlighting, and result types.
 
-use super::common::{EntityRow, FieldData, Function, FunctionType, Ids, SparseVector, StructValue};
+use super::common::{
+    DenseVectors, EntityRow, FieldData, Function, FunctionType, Ids, MetricType, SparseVector,
+    StructValue,
+};
+use super::timestamp::Timestamp;
 use crate::proto::{common, schema};
 use crate::v2::error::{Error, Result};
 use serde_json::Value;
@@ -504,6 +508,8 @@ impl FunctionScore {
 pub enum SearchVectors {
     /// Represents the Float case.
     Float(Vec<Vec<f32>>),
+    /// Float query vectors stored in one contiguous buffer.
+    DenseFloat(DenseVectors),
     /// Represents the Binary case.
     Binary(Vec<Vec<u8>>),
     /// Represents the Float16 case.
@@ -568,6 +574,142 @@ impl EmbeddingList {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// SearchTarget
+///////////////////////////////////////////////////////////////////////////////
+/// Collection searched by a federated search.
+///
+/// A `(database, collection)` tuple converts into a target.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct SearchTarget {
+    pub(crate) database_name: Option<String>,
+    pub(crate) collection_name: String,
+    pub(crate) metric_type: Option<MetricType>,
+}
+
+impl SearchTarget {
+    /// Creates a target for a collection in the client's current database.
+    pub fn new(collection_name: impl Into<String>) -> Self {
+        Self {
+            database_name: None,
+            collection_name: collection_name.into(),
+            metric_type: None,
+        }
+    }
+
+    /// Sets the database name and returns the updated value.
+    pub fn database_name(mut self, value: impl Into<String>) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Returns the configured database name.
+    pub fn get_database_name(&self) -> Option<&str> {
+        self.database_name.as_deref()
+    }
+
+    /// Returns the configured collection name.
+    pub fn get_collection_name(&self) -> &str {
+        &self.collection_name
+    }
+
+    /// Sets the metric type of the target's index and returns the updated value.
+    ///
+    /// Without it, the search's metric type is used, or the index is described.
+    pub fn metric_type(mut self, value: MetricType) -> Self {
+        self.metric_type = Some(value);
+        self
+    }
+
+    /// Returns the configured metric type.
+    pub fn get_metric_type(&self) -> Option<MetricType> {
+        self.metric_type
+    }
+}
+
+impl<D: Into<String>, C: Into<String>> From<(D, C)> for SearchTarget {
+    fn from((database_name, collection_name): (D, C)) -> Self {
+        Self::new(collection_name).database_name(database_name)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// FailurePolicy
+///////////////////////////////////////////////////////////////////////////////
+/// How a federated search treats targets that fail.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FailurePolicy {
+    #[default]
+    /// Returns the first error and cancels the remaining targets.
+    FailFast,
+    /// Merges the targets that answered and reports the others; fails only when every target
+    /// fails.
+    BestEffort,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ScoreRange
+///////////////////////////////////////////////////////////////////////////////
+/// Range of scores accepted by a range search, written independently of the metric type.
+///
+/// Milvus bounds a range search with `radius`, the worst score kept, and `range_filter`, the
+/// best. Which of the two is larger depends on whether the metric is a distance such as L2,
+/// where smaller scores are closer matches, or a similarity such as IP and COSINE. A score range
+/// names plain minimum and maximum scores instead and is converted once the metric is known.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum ScoreRange {
+    /// Keeps matches closer than the bound: scores below it for distance metrics and above it
+    /// for similarity metrics.
+    Within(f64),
+    /// Keeps scores between `min` and `max`. The bound at the closer-match end is inclusive and
+    /// the other exclusive.
+    Between {
+        /// Lowest score kept.
+        min: f64,
+        /// Highest score kept.
+        max: f64,
+    },
+}
+
+impl ScoreRange {
+    /// Returns whether the range can only be converted with a known metric type.
+    pub fn needs_metric_type(self) -> bool {
+        matches!(self, Self::Between { .. })
+    }
+
+    /// Returns the `radius` and `range_filter` that express this range for `metric`.
+    pub fn to_radius_and_range_filter(self, metric: MetricType) -> (f64, Option<f64>) {
+        match self {
+            Self::Within(bound) => (bound, None),
+            Self::Between { min, max } if metric.is_distance() => (max, Some(min)),
+            Self::Between { min, max } => (min, Some(max)),
+        }
+    }
+
+    pub(crate) fn validate(self) -> Result<()> {
+        let (min, max) = match self {
+            Self::Within(bound) => (bound, bound),
+            Self::Between { min, max } => (min, max),
+        };
+        if !min.is_finite() || !max.is_finite() {
+            return Err(Error::validation(
+                "score_range".into(),
+                "bounds must be finite".into(),
+            ));
+        }
+        if matches!(self, Self::Between { .. }) && min >= max {
+            return Err(Error::validation(
+                "score_range".into(),
+                "min must be less than max".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // HighlightType
 ///////////////////////////////////////////////////////////////////////////////
@@ -2046,6 +2188,27 @@ impl<'a> ResultRow<'a> {
         }
     }
 
+    /// Returns a timestamptz field parsed into a [`Timestamp`].
+    pub fn get_timestamp(&self, name: &str) -> Result<Timestamp> {
+        match self.value(name)? {
+            ResultValue::Timestamptz(value) => Timestamp::parse(value),
+            ResultValue::Json(Value::String(value)) => Timestamp::parse(value),
+            value => Err(result_row_type_error(name, "timestamptz", value.kind())),
+        }
+    }
+
+    /// Returns a timestamptz field as a UTC `chrono` date-time.
+    #[cfg(feature = "chrono")]
+    pub fn get_datetime(&self, name: &str) -> Result<chrono::DateTime<chrono::Utc>> {
+        self.get_timestamp(name)?.try_into()
+    }
+
+    /// Returns a timestamptz field as a `time` date-time in the offset the server rendered.
+    #[cfg(feature = "time")]
+    pub fn get_offset_datetime(&self, name: &str) -> Result<time::OffsetDateTime> {
+        self.get_timestamp(name)?.try_into()
+    }
+
     /// Returns a borrowed JSON field or dynamic-field value.
     pub fn get_json(&self, name: &str) -> Result<&'a Value> {
         match self.value(name)? {
@@ -2304,6 +2467,10 @@ fn result_field_value(field: &FieldData, index: usize) -> Result<ResultValue<'_>
         FieldData::FloatVector { values, .. } => {
             Ok(ResultValue::FloatVector(at(values, index)?.as_slice()))
         }
+        FieldData::DenseFloatVector { values, .. } => values
+            .row(index)
+            .map(ResultValue::FloatVector)
+            .ok_or_else(|| row_index_error(index, values.len())),
         FieldData::BinaryVector { values, .. } => {
             Ok(ResultValue::BinaryVector(at(values, index)?.as_slice()))
         }
@@ -2436,6 +2603,105 @@ impl<'a> IntoIterator for &'a SearchResults {
     }
 }
 
+/// Hits of every query flattened into columns for Arrow and Polars exports.
+#[cfg(any(feature = "arrow", feature = "polars"))]
+pub(crate) struct FlatHits {
+    pub(crate) query_index: Vec<u32>,
+    pub(crate) id_name: String,
+    pub(crate) ids: Ids,
+    pub(crate) score_name: String,
+    pub(crate) scores: Vec<f32>,
+    pub(crate) output_fields: Vec<FieldData>,
+}
+
+#[cfg(any(feature = "arrow", feature = "polars"))]
+impl SearchResults {
+    /// Concatenates the hits of every query, recording the query index of each hit.
+    ///
+    /// The primary key keeps its field name, or `id` when the server did not report one, and an
+    /// output field repeating the primary key is dropped. Output fields are matched by name, and
+    /// every query with hits must report the same set of them.
+    pub(crate) fn flatten(&self) -> Result<FlatHits> {
+        let first = self.results.first();
+        let id_name = first
+            .map(|result| result.primary_field_name.as_str())
+            .filter(|name| !name.is_empty())
+            .unwrap_or("id")
+            .to_owned();
+        let score_name = first
+            .map(|result| result.score_field_name.as_str())
+            .filter(|name| !name.is_empty())
+            .unwrap_or("score")
+            .to_owned();
+
+        let mut query_index = Vec::new();
+        let mut ids: Option<Ids> = None;
+        let mut scores = Vec::new();
+        let mut output_fields: Option<Vec<FieldData>> = None;
+        for (index, result) in self.results.iter().enumerate() {
+            if result.is_empty() {
+                continue;
+            }
+            query_index.extend(std::iter::repeat(index as u32).take(result.len()));
+            scores.extend_from_slice(&result.scores);
+            match (&mut ids, &result.ids) {
+                (None, result_ids) => ids = Some(result_ids.clone()),
+                (Some(Ids::Int64(ids)), Ids::Int64(more)) => ids.extend_from_slice(more),
+                (Some(Ids::VarChar(ids)), Ids::VarChar(more)) => ids.extend_from_slice(more),
+                _ => {
+                    return Err(Error::conversion(
+                        "search results mix Int64 and VarChar primary keys",
+                    ))
+                }
+            }
+            let Some(merged) = &mut output_fields else {
+                output_fields = Some(result.output_fields.clone());
+                continue;
+            };
+            let covers = |left: &[FieldData], right: &[FieldData]| {
+                left.iter()
+                    .all(|field| right.iter().any(|other| other.name() == field.name()))
+            };
+            let same_fields = merged.len() == result.output_fields.len()
+                && covers(merged.as_slice(), &result.output_fields)
+                && covers(&result.output_fields, merged.as_slice());
+            if !same_fields {
+                let names = |fields: &[FieldData]| {
+                    fields
+                        .iter()
+                        .map(|field| field.name().to_owned())
+                        .collect::<Vec<_>>()
+                };
+                return Err(Error::conversion(format!(
+                    "search results have different output fields: query {index} reports {:?}, \
+                     earlier queries report {:?}",
+                    names(&result.output_fields),
+                    names(merged.as_slice())
+                )));
+            }
+            for merged in merged.iter_mut() {
+                let field = result
+                    .output_fields
+                    .iter()
+                    .find(|field| field.name() == merged.name())
+                    .expect("field sets were compared");
+                merged.append(field.clone())?;
+            }
+        }
+        let mut output_fields = output_fields.unwrap_or_default();
+        output_fields.retain(|field| field.name() != id_name);
+
+        Ok(FlatHits {
+            query_index,
+            id_name,
+            ids: ids.unwrap_or(Ids::Int64(Vec::new())),
+            score_name,
+            scores,
+            output_fields,
+        })
+    }
+}
+
 fn output_row_count(fields: &[FieldData]) -> Result<usize> {
     let row_count = fields.first().map_or(0, FieldData::len);
     if fields.iter().any(|field| field.len() != row_count) {
@@ -2555,7 +2821,7 @@ mod function_tests {
 mod result_row_tests {
     use super::{QueryResults, ResultValue, SearchResults, SingleResult};
     use crate::v2::error::Error;
-    use crate::v2::types::{FieldData, Ids, SparseVector};
+    use crate::v2::types::{FieldData, Ids, SparseVector, Timestamp};
     use serde_json::json;
 
     #[test]
@@ -2826,6 +3092,10 @@ mod result_row_tests {
             row.get("time").unwrap(),
             ResultValue::Timestamptz("2026-07-31T12:00:00Z")
         ));
+        assert_eq!(
+            row.get_timestamp("time").unwrap(),
+            Timestamp::from_unix_micros(1_785_499_200_000_000)
+        );
         assert!(
             matches!(row.get("bools").unwrap(), ResultValue::ArrayBool(value) if value == [true, false])
         );
@@ -201,11 +201,17 @@ impl IndexType {
 /// Parameters used to create an index for a field.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default = "IndexParam::new"))]
 pub struct IndexParam {
     pub(crate) field_name: String,
     pub(crate) index_name: String,
     pub(crate) index_type: IndexType,
     pub(crate) metric_type: Option<MetricType>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::v2::types::serialize_sorted")
+    )]
     pub(crate) extra_params: HashMap<String, String>,
 }
 
@@ -339,6 +345,19 @@ impl IndexParam {
     }
 }
 
+impl From<IndexDesc> for IndexParam {
+    /// Recreates the parameters of a described index.
+    fn from(value: IndexDesc) -> Self {
+        Self {
+            field_name: value.field_name,
+            index_name: value.index_name,
+            index_type: value.index_type,
+            metric_type: (value.metric_type != MetricType::Default).then_some(value.metric_type),
+            extra_params: value.extra_params,
+        }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // IndexDesc
 ///////////////////////////////////////////////////////////////////////////////
@@ -25,7 +25,11 @@ mod index;
 mod partition;
 mod rbac;
 mod resource_group;
+#[cfg(feature = "serde")]
+mod schema_format;
 mod snapshot;
+mod sparse;
+mod timestamp;
 mod utility;
 
 pub use cdc::*;
@@ -37,5 +41,9 @@ pub use index::*;
 pub use partition::*;
 pub use rbac::*;
 pub use resource_group::*;
+#[cfg(feature = "serde")]
+pub(crate) use schema_format::serialize_sorted;
 pub use snapshot::*;
+pub use sparse::*;
+pub use timestamp::*;
 pub use utility::*;
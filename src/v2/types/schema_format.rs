@@ -0,0 +1,233 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serialized format of schema and index definitions, enabled by the `serde` feature.
+//!
+//! Structs serialize their fields under the Rust field names, and every field is optional on
+//! input, falling back to the value `new()` would give it. Maps are written with sorted keys so
+//! serialized definitions diff cleanly. `DataType`, `FunctionType`, and `ConsistencyLevel` use
+//! their variant names, while `IndexType` and `MetricType` use the Milvus parameter strings such
+//! as `"HNSW"` and `"COSINE"`.
+
+use crate::v2::types::{IndexType, MetricType};
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+
+pub(crate) fn serialize_sorted<S: Serializer>(
+    values: &HashMap<String, String>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    values
+        .iter()
+        .collect::<BTreeMap<_, _>>()
+        .serialize(serializer)
+}
+
+impl Serialize for IndexType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for IndexType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match IndexType::from_str(&value) {
+            IndexType::Invalid if !value.eq_ignore_ascii_case("INVALID") => Err(
+                de::Error::invalid_value(de::Unexpected::Str(&value), &"a Milvus index type"),
+            ),
+            index_type => Ok(index_type),
+        }
+    }
+}
+
+impl Serialize for MetricType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for MetricType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match MetricType::from_str(&value) {
+            MetricType::Default
+                if !["DEFAULT", "INVALID"]
+                    .iter()
+                    .any(|name| value.eq_ignore_ascii_case(name)) =>
+            {
+                Err(de::Error::invalid_value(
+                    de::Unexpected::Str(&value),
+                    &"a Milvus metric type",
+                ))
+            }
+            metric_type => Ok(metric_type),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::v2::request::collection::CreateCollectionRequest;
+    use crate::v2::response::collection::DescribeCollectionResponse;
+    use crate::v2::response::index::DescribeIndexResponse;
+    use crate::v2::types::{
+        CollectionDesc, CollectionSchema, DataType, DefaultValue, FieldSchema, Function,
+        FunctionType, IndexDesc, IndexParam, IndexType, MetricType,
+    };
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn schema() -> CollectionSchema {
+        CollectionSchema::new()
+            .description("articles")
+            .add_field(
+                FieldSchema::new()
+                    .name("id")
+                    .data_type(DataType::Int64)
+                    .primary_key(true),
+            )
+            .add_field(
+                FieldSchema::new()
+                    .name("text")
+                    .data_type(DataType::VarChar)
+                    .max_length(256)
+                    .enable_analyzer(true)
+                    .default_value(DefaultValue::String("".into())),
+            )
+            .add_field(
+                FieldSchema::new()
+                    .name("sparse")
+                    .data_type(DataType::SparseFloatVector),
+            )
+            .add_function(
+                Function::new()
+                    .name("bm25")
+                    .function_type(FunctionType::Bm25)
+                    .input_fields(["text"])
+                    .output_fields(["sparse"]),
+            )
+    }
+
+    #[test]
+    fn schemas_round_trip_with_sorted_maps() {
+        let schema = schema();
+        let value = serde_json::to_value(&schema).unwrap();
+        assert_eq!(value["fields"][0]["data_type"], "Int64");
+        assert_eq!(
+            value["fields"][1]["type_params"],
+            json!({"enable_analyzer": "true", "max_length": "256"})
+        );
+        assert_eq!(value["functions"][0]["function_type"], "Bm25");
+        assert_eq!(
+            serde_json::from_value::<CollectionSchema>(value).unwrap(),
+            schema
+        );
+
+        let field = FieldSchema::new()
+            .name("embedding")
+            .data_type(DataType::FloatVector)
+            .dimension(8)
+            .type_params(HashMap::from([("mmap.enabled".into(), "true".into())]));
+        let text = serde_json::to_string(&field).unwrap();
+        assert!(
+            text.contains(r#""type_params":{"dim":"8","mmap.enabled":"true"}"#),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn omitted_fields_take_their_sdk_defaults() {
+        let field: FieldSchema =
+            serde_json::from_value(json!({"name": "id", "data_type": "Int64"})).unwrap();
+        assert_eq!(
+            field,
+            FieldSchema::new().name("id").data_type(DataType::Int64)
+        );
+        let schema: CollectionSchema = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(schema, CollectionSchema::new());
+    }
+
+    #[test]
+    fn index_and_metric_types_use_milvus_names() {
+        let index = IndexParam::new()
+            .field_name("embedding")
+            .index_type(IndexType::Hnsw)
+            .metric_type(MetricType::Cosine)
+            .extra_params(HashMap::from([("M".into(), "16".into())]));
+        let value = serde_json::to_value(&index).unwrap();
+        assert_eq!(value["index_type"], "HNSW");
+        assert_eq!(value["metric_type"], "COSINE");
+        assert_eq!(serde_json::from_value::<IndexParam>(value).unwrap(), index);
+
+        assert!(serde_json::from_value::<IndexType>(json!("HSNW")).is_err());
+        assert!(serde_json::from_value::<MetricType>(json!("COSIN")).is_err());
+        assert_eq!(
+            serde_json::from_value::<MetricType>(json!("ip")).unwrap(),
+            MetricType::Ip
+        );
+    }
+
+    #[test]
+    fn described_collections_become_create_requests() {
+        let description = CollectionDesc::new()
+            .collection_name("articles")
+            .description("articles")
+            .schema(schema())
+            .properties(HashMap::from([(
+                "collection.ttl.seconds".into(),
+                "60".into(),
+            )]));
+        let response: DescribeCollectionResponse =
+            serde_json::from_value(serde_json::to_value(&description).unwrap()).unwrap();
+        assert_eq!(response.description(), &description);
+
+        let indexes = DescribeIndexResponse::builder()
+            .indexes(vec![IndexDesc::new()
+                .field_name("sparse")
+                .index_type(IndexType::SparseInvertedIndex)
+                .metric_type(MetricType::Bm25)])
+            .build();
+        let request = CreateCollectionRequest::try_from((response, indexes)).unwrap();
+        let text = serde_json::to_string(&request).unwrap();
+        let restored: CreateCollectionRequest = serde_json::from_str(&text).unwrap();
+        assert_eq!(restored, request);
+        assert_eq!(restored.collection_name(), "articles");
+        assert_eq!(restored.schema(), &Some(schema()));
+        assert_eq!(
+            restored.index_params(),
+            [IndexParam::new()
+                .field_name("sparse")
+                .index_type(IndexType::SparseInvertedIndex)
+                .metric_type(MetricType::Bm25)]
+        );
+        assert_eq!(restored.properties()["collection.ttl.seconds"], "60");
+
+        assert!(CreateCollectionRequest::try_from(description.num_shards(i64::MAX)).is_err());
+        let mut invalid = serde_json::to_value(&request).unwrap();
+        invalid["num_shards"] = json!(0);
+        assert!(serde_json::from_value::<CreateCollectionRequest>(invalid).is_err());
+        assert!(
+            serde_json::from_value::<CreateCollectionRequest>(json!({"collection_name": "a"}))
+                .is_err()
+        );
+    }
+}
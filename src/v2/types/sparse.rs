@@ -0,0 +1,365 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sparse float vectors stored as sorted parallel index and value arrays.
+
+use crate::v2::error::{Error, Result};
+use std::collections::BTreeMap;
+use std::iter::{Copied, Zip};
+use std::slice;
+
+///////////////////////////////////////////////////////////////////////////////
+// SparseVector
+///////////////////////////////////////////////////////////////////////////////
+/// Sparse vector represented by strictly increasing dimension indexes and their values.
+///
+/// Collecting `(index, value)` pairs sorts them; when an index repeats, the last value wins.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct SparseVector {
+    pub(crate) indices: Vec<u32>,
+    pub(crate) values: Vec<f32>,
+}
+
+/// Iterator over the `(index, value)` entries of a [`SparseVector`].
+pub type SparseVectorIter<'a> = Zip<Copied<slice::Iter<'a, u32>>, Copied<slice::Iter<'a, f32>>>;
+
+impl SparseVector {
+    /// Creates an empty sparse vector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a sparse vector from parallel arrays.
+    ///
+    /// `indices` must be strictly increasing and have the same length as `values`.
+    pub fn from_parts(indices: Vec<u32>, values: Vec<f32>) -> Result<Self> {
+        if indices.len() != values.len() {
+            return Err(Error::validation(
+                "sparse_vector".into(),
+                format!(
+                    "{} indices do not match {} values",
+                    indices.len(),
+                    values.len()
+                ),
+            ));
+        }
+        if indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(Error::validation(
+                "sparse_vector".into(),
+                "indices must be strictly increasing".into(),
+            ));
+        }
+        Ok(Self { indices, values })
+    }
+
+    /// Creates a sparse vector from a dense slice, keeping entries whose magnitude exceeds
+    /// `threshold`.
+    pub fn from_dense(values: &[f32], threshold: f32) -> Self {
+        values
+            .iter()
+            .zip(0..u32::MAX)
+            .filter(|(value, _)| value.abs() > threshold)
+            .map(|(&value, index)| (index, value))
+            .collect()
+    }
+
+    /// Returns the sorted dimension indexes.
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    /// Returns the values, in index order.
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Moves the parallel index and value arrays out.
+    pub fn into_parts(self) -> (Vec<u32>, Vec<f32>) {
+        (self.indices, self.values)
+    }
+
+    /// Returns the number of stored entries.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns whether no entries are stored.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the value stored at `index`.
+    pub fn get(&self, index: u32) -> Option<f32> {
+        self.indices
+            .binary_search(&index)
+            .ok()
+            .map(|position| self.values[position])
+    }
+
+    /// Stores `value` at `index`, returning the value it replaced.
+    pub fn insert(&mut self, index: u32, value: f32) -> Option<f32> {
+        match self.indices.binary_search(&index) {
+            Ok(position) => Some(std::mem::replace(&mut self.values[position], value)),
+            Err(position) => {
+                self.indices.insert(position, index);
+                self.values.insert(position, value);
+                None
+            }
+        }
+    }
+
+    /// Iterates `(index, value)` entries in index order.
+    pub fn iter(&self) -> SparseVectorIter<'_> {
+        self.indices
+            .iter()
+            .copied()
+            .zip(self.values.iter().copied())
+    }
+
+    /// Returns the dot product with another sparse vector.
+    pub fn dot(&self, other: &SparseVector) -> f32 {
+        let (mut left, mut right) = (0, 0);
+        let mut sum = 0.0;
+        while left < self.len() && right < other.len() {
+            match self.indices[left].cmp(&other.indices[right]) {
+                std::cmp::Ordering::Less => left += 1,
+                std::cmp::Ordering::Greater => right += 1,
+                std::cmp::Ordering::Equal => {
+                    sum += self.values[left] * other.values[right];
+                    left += 1;
+                    right += 1;
+                }
+            }
+        }
+        sum
+    }
+
+    /// Returns the Euclidean norm.
+    pub fn norm(&self) -> f32 {
+        self.values
+            .iter()
+            .map(|value| value * value)
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    /// Returns a copy keeping the `k` entries with the largest magnitudes, matching the
+    /// `abs()` threshold of [`Self::from_dense`].
+    ///
+    /// Ties keep the lower index.
+    pub fn top_k(&self, k: usize) -> SparseVector {
+        if k >= self.len() {
+            return self.clone();
+        }
+        let mut positions = (0..self.len()).collect::<Vec<_>>();
+        positions
+            .sort_by(|&left, &right| self.values[right].abs().total_cmp(&self.values[left].abs()));
+        positions.truncate(k);
+        positions.sort_unstable();
+        SparseVector {
+            indices: positions
+                .iter()
+                .map(|&position| self.indices[position])
+                .collect(),
+            values: positions
+                .iter()
+                .map(|&position| self.values[position])
+                .collect(),
+        }
+    }
+
+    /// Returns the dimension implied by the largest index.
+    pub(crate) fn dimension(&self) -> i64 {
+        self.indices.last().map_or(0, |&index| i64::from(index) + 1)
+    }
+
+    /// Encodes the entries in the Milvus sparse wire format: little-endian `u32` index and `f32`
+    /// value pairs.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.len() * 8);
+        for (index, value) in self {
+            bytes.extend(index.to_le_bytes());
+            bytes.extend(value.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes the Milvus sparse wire format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (indices, values) = wire_entries(bytes)?.unzip();
+        Self::from_parts(indices, values)
+    }
+
+    /// Decodes the Milvus sparse wire format as sent by the server, sorting the entries and
+    /// keeping the last value of a repeated index instead of rejecting them.
+    pub(crate) fn from_bytes_unordered(bytes: &[u8]) -> Result<Self> {
+        Ok(wire_entries(bytes)?.collect())
+    }
+}
+
+fn wire_entries(bytes: &[u8]) -> Result<impl Iterator<Item = (u32, f32)> + '_> {
+    if bytes.len() % 8 != 0 {
+        return Err(Error::conversion(format!(
+            "sparse vector payload of {} bytes is not a multiple of 8",
+            bytes.len()
+        )));
+    }
+    Ok(bytes.chunks_exact(8).map(|item| {
+        (
+            u32::from_le_bytes(item[..4].try_into().unwrap()),
+            f32::from_le_bytes(item[4..].try_into().unwrap()),
+        )
+    }))
+}
+
+impl FromIterator<(u32, f32)> for SparseVector {
+    fn from_iter<I: IntoIterator<Item = (u32, f32)>>(iter: I) -> Self {
+        let mut entries = iter.into_iter().collect::<Vec<_>>();
+        if !entries.windows(2).all(|pair| pair[0].0 < pair[1].0) {
+            entries.sort_by_key(|&(index, _)| index);
+            // `dedup_by` keeps the first of each run; moving the later value into it gives the
+            // last-insert-wins behavior of a map.
+            entries.dedup_by(|later, earlier| {
+                let duplicate = later.0 == earlier.0;
+                if duplicate {
+                    earlier.1 = later.1;
+                }
+                duplicate
+            });
+        }
+        let (indices, values) = entries.into_iter().unzip();
+        Self { indices, values }
+    }
+}
+
+impl<const N: usize> From<[(u32, f32); N]> for SparseVector {
+    fn from(entries: [(u32, f32); N]) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+impl From<BTreeMap<u32, f32>> for SparseVector {
+    fn from(entries: BTreeMap<u32, f32>) -> Self {
+        let (indices, values) = entries.into_iter().unzip();
+        Self { indices, values }
+    }
+}
+
+impl From<SparseVector> for BTreeMap<u32, f32> {
+    fn from(vector: SparseVector) -> Self {
+        vector.indices.into_iter().zip(vector.values).collect()
+    }
+}
+
+impl<'a> IntoIterator for &'a SparseVector {
+    type Item = (u32, f32);
+    type IntoIter = SparseVectorIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collecting_sorts_and_keeps_the_last_duplicate() {
+        let vector: SparseVector = [(9, 0.5), (1, 0.25), (9, 0.75)].into_iter().collect();
+        assert_eq!(vector.indices(), [1, 9]);
+        assert_eq!(vector.values(), [0.25, 0.75]);
+        assert_eq!(vector.get(9), Some(0.75));
+        assert_eq!(vector.get(2), None);
+
+        let mut vector = SparseVector::new();
+        assert_eq!(vector.insert(4, 1.0), None);
+        assert_eq!(vector.insert(2, 2.0), None);
+        assert_eq!(vector.insert(4, 3.0), Some(1.0));
+        assert_eq!(vector, SparseVector::from([(2, 2.0), (4, 3.0)]));
+        assert_eq!(
+            BTreeMap::from(vector.clone()),
+            BTreeMap::from([(2, 2.0), (4, 3.0)])
+        );
+        assert_eq!(SparseVector::from(BTreeMap::from(vector.clone())), vector);
+    }
+
+    #[test]
+    fn parts_must_be_parallel_and_strictly_increasing() {
+        assert!(SparseVector::from_parts(vec![1, 3], vec![0.5, 1.0]).is_ok());
+        assert!(SparseVector::from_parts(vec![1, 3], vec![0.5]).is_err());
+        assert!(SparseVector::from_parts(vec![3, 1], vec![0.5, 1.0]).is_err());
+        assert!(SparseVector::from_parts(vec![1, 1], vec![0.5, 1.0]).is_err());
+    }
+
+    #[test]
+    fn dense_slices_are_thresholded() {
+        let vector = SparseVector::from_dense(&[0.0, 0.5, 0.01, -0.2, 0.3], 0.1);
+        assert_eq!(vector.indices(), [1, 3, 4]);
+        assert_eq!(vector.values(), [0.5, -0.2, 0.3]);
+        assert_eq!(vector.dimension(), 5);
+        assert_eq!(SparseVector::new().dimension(), 0);
+    }
+
+    #[test]
+    fn dot_norm_and_top_k() {
+        let left = SparseVector::from([(1, 1.0), (3, 2.0), (5, 3.0)]);
+        let right = SparseVector::from([(0, 4.0), (3, 0.5), (5, 2.0)]);
+        assert_eq!(left.dot(&right), 7.0);
+        assert_eq!(SparseVector::from([(0, 3.0), (7, 4.0)]).norm(), 5.0);
+
+        let pruned = left.top_k(2);
+        assert_eq!(pruned, SparseVector::from([(3, 2.0), (5, 3.0)]));
+        assert_eq!(
+            SparseVector::from([(1, 1.0), (2, 1.0)]).top_k(1),
+            SparseVector::from([(1, 1.0)])
+        );
+        assert_eq!(left.top_k(10), left);
+        assert_eq!(
+            SparseVector::from([(0, -4.0), (1, 1.0), (2, 3.0)]).top_k(2),
+            SparseVector::from([(0, -4.0), (2, 3.0)])
+        );
+    }
+
+    #[test]
+    fn wire_format_round_trips() {
+        let vector = SparseVector::from([(1, 0.5), (u32::MAX - 1, 1.5)]);
+        let bytes = vector.to_bytes();
+        assert_eq!(&bytes[..8], [1, 0, 0, 0, 0, 0, 0, 0x3f]);
+        assert_eq!(SparseVector::from_bytes(&bytes).unwrap(), vector);
+        assert!(SparseVector::from_bytes(&bytes[..7]).is_err());
+    }
+
+    #[test]
+    fn server_payloads_decode_unordered_entries() {
+        let bytes = [(5_u32, 1.0_f32), (1, 2.0), (5, 3.0)]
+            .iter()
+            .flat_map(|(index, value)| [index.to_le_bytes(), value.to_le_bytes()].concat())
+            .collect::<Vec<_>>();
+
+        assert!(SparseVector::from_bytes(&bytes).is_err());
+        assert_eq!(
+            SparseVector::from_bytes_unordered(&bytes).unwrap(),
+            SparseVector::from([(1, 2.0), (5, 3.0)])
+        );
+        assert!(SparseVector::from_bytes_unordered(&bytes[..7]).is_err());
+    }
+}
@@ -0,0 +1,426 @@
+// Licensed to the LF AI & Data foundation under one
+// or more contributor license agreements. See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership. The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License. You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed Timestamptz values.
+//!
+//! Milvus stores Timestamptz fields as UTC instants with microsecond precision and exchanges them
+//! as RFC 3339 text. [`Timestamp`] keeps the instant together with the UTC offset used to render
+//! it, and converts to and from `chrono` and `time` values when the matching feature is enabled.
+
+use crate::v2::error::{Error, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+///////////////////////////////////////////////////////////////////////////////
+// Timestamp
+///////////////////////////////////////////////////////////////////////////////
+/// Instant stored in a Timestamptz field, with the UTC offset used to render it.
+///
+/// Equality compares both the instant and the offset; compare [`Self::unix_micros`] to test
+/// whether two values denote the same instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Timestamp {
+    pub(crate) unix_micros: i64,
+    pub(crate) offset_seconds: i32,
+}
+
+impl Timestamp {
+    /// Creates a UTC timestamp from microseconds since the Unix epoch.
+    pub fn from_unix_micros(unix_micros: i64) -> Self {
+        Self {
+            unix_micros,
+            offset_seconds: 0,
+        }
+    }
+
+    /// Parses RFC 3339 text such as `2026-07-31T12:00:00+08:00`, or microseconds since the Unix
+    /// epoch as returned for integer-encoded Timestamptz columns.
+    pub fn parse(text: &str) -> Result<Self> {
+        if let Ok(unix_micros) = text.parse::<i64>() {
+            return Ok(Self::from_unix_micros(unix_micros));
+        }
+        parse_rfc3339(text)
+            .ok_or_else(|| Error::conversion(format!("{text:?} is not an RFC 3339 timestamp")))
+    }
+
+    /// Returns the same instant rendered with a UTC offset of `offset_seconds`.
+    pub fn with_offset(self, offset_seconds: i32) -> Result<Self> {
+        if i64::from(offset_seconds).abs() >= SECONDS_PER_DAY {
+            return Err(Error::validation(
+                "offset_seconds".into(),
+                "must be less than one day".into(),
+            ));
+        }
+        Ok(Self {
+            unix_micros: self.unix_micros,
+            offset_seconds,
+        })
+    }
+
+    /// Returns the microseconds since the Unix epoch.
+    pub fn unix_micros(&self) -> i64 {
+        self.unix_micros
+    }
+
+    /// Returns the UTC offset used to render this timestamp, in seconds.
+    pub fn offset_seconds(&self) -> i32 {
+        self.offset_seconds
+    }
+
+    /// Renders this timestamp as an `ISO '...'` literal for inline filter expressions.
+    pub fn to_filter_literal(&self) -> String {
+        format!("ISO '{self}'")
+    }
+}
+
+impl fmt::Display for Timestamp {
+    /// Formats the timestamp as RFC 3339 in its UTC offset.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let local = self
+            .unix_micros
+            .saturating_add(i64::from(self.offset_seconds) * MICROS_PER_SECOND);
+        let seconds = local.div_euclid(MICROS_PER_SECOND);
+        let micros = local.rem_euclid(MICROS_PER_SECOND);
+        let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+        let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+            second_of_day / 3600,
+            second_of_day / 60 % 60,
+            second_of_day % 60
+        )?;
+        if micros != 0 {
+            write!(f, ".{micros:06}")?;
+        }
+        if self.offset_seconds == 0 {
+            return f.write_str("Z");
+        }
+        let sign = if self.offset_seconds < 0 { '-' } else { '+' };
+        let offset = self.offset_seconds.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", offset / 3600, offset / 60 % 60)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        Self::parse(text)
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Self::parse(&text).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Timestamp> for Value {
+    /// Converts the timestamp to RFC 3339 text, the form Milvus expects in filter templates.
+    fn from(value: Timestamp) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    /// Keeps the instant and the UTC offset of the date-time.
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        use chrono::Offset;
+
+        Self {
+            unix_micros: value.timestamp_micros(),
+            offset_seconds: value.offset().fix().local_minus_utc(),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    fn try_from(value: Timestamp) -> Result<Self> {
+        chrono::DateTime::from_timestamp_micros(value.unix_micros)
+            .ok_or_else(|| out_of_range(value, "chrono"))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = Error;
+
+    fn try_from(value: Timestamp) -> Result<Self> {
+        let offset = chrono::FixedOffset::east_opt(value.offset_seconds)
+            .ok_or_else(|| out_of_range(value, "chrono"))?;
+        Ok(chrono::DateTime::<chrono::Utc>::try_from(value)?.with_timezone(&offset))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    /// Keeps the instant and the UTC offset of the date-time.
+    fn from(value: time::OffsetDateTime) -> Self {
+        Self {
+            unix_micros: (value.unix_timestamp_nanos() / 1_000) as i64,
+            offset_seconds: value.offset().whole_seconds(),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<Timestamp> for time::OffsetDateTime {
+    type Error = Error;
+
+    fn try_from(value: Timestamp) -> Result<Self> {
+        let offset = time::UtcOffset::from_whole_seconds(value.offset_seconds)
+            .map_err(|_| out_of_range(value, "time"))?;
+        time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(value.unix_micros) * 1_000)
+            .map(|value| value.to_offset(offset))
+            .map_err(|_| out_of_range(value, "time"))
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn out_of_range(value: Timestamp, library: &str) -> Error {
+    Error::conversion(format!(
+        "timestamp {} is outside the range supported by {library}",
+        value.unix_micros
+    ))
+}
+
+/// Parses `YYYY-MM-DD[T ]HH:MM:SS[.fraction](Z|±HH:MM)`; fractions beyond microseconds are
+/// truncated.
+fn parse_rfc3339(text: &str) -> Option<Timestamp> {
+    let bytes = text.as_bytes();
+    let number = |range: std::ops::Range<usize>| digits(bytes.get(range)?);
+    if bytes.len() < 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    let mut rest = &text[19..];
+    let mut micros = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let length = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if length == 0 {
+            return None;
+        }
+        let kept = &fraction[..length.min(6)];
+        micros = kept.parse::<i64>().ok()? * 10_i64.pow(6 - kept.len() as u32);
+        rest = &fraction[length..];
+    }
+    let offset_seconds = match rest.as_bytes() {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => {
+            let (hours, minutes) = (digits(&[*h1, *h2])?, digits(&[*m1, *m2])?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let offset = (hours * 3600 + minutes * 60) as i32;
+            if *sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return None,
+    };
+
+    let local_seconds =
+        days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second;
+    Some(Timestamp {
+        unix_micros: (local_seconds - i64::from(offset_seconds)) * MICROS_PER_SECOND + micros,
+        offset_seconds,
+    })
+}
+
+fn digits(bytes: &[u8]) -> Option<i64> {
+    if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    std::str::from_utf8(bytes).ok()?.parse().ok()
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date of a day count since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test Cases
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc3339_text_round_trips_with_its_offset() {
+        let utc = Timestamp::parse("2026-07-31T12:00:00Z").unwrap();
+        assert_eq!(utc.unix_micros(), 1_785_499_200 * MICROS_PER_SECOND);
+        assert_eq!(utc.to_string(), "2026-07-31T12:00:00Z");
+
+        let shanghai = Timestamp::parse("2026-07-31T20:00:00.5+08:00").unwrap();
+        assert_eq!(shanghai.unix_micros(), utc.unix_micros() + 500_000);
+        assert_eq!(shanghai.offset_seconds(), 8 * 3600);
+        assert_eq!(shanghai.to_string(), "2026-07-31T20:00:00.500000+08:00");
+
+        let negative = utc.with_offset(-(3 * 3600 + 30 * 60)).unwrap();
+        assert_eq!(negative.to_string(), "2026-07-31T08:30:00-03:30");
+        assert_eq!(
+            negative.to_filter_literal(),
+            "ISO '2026-07-31T08:30:00-03:30'"
+        );
+        assert!(utc.with_offset(86_400).is_err());
+    }
+
+    #[test]
+    fn integer_micros_and_dates_before_the_epoch_are_supported() {
+        assert_eq!(
+            Timestamp::parse("1785499200000000").unwrap(),
+            Timestamp::parse("2026-07-31T12:00:00Z").unwrap()
+        );
+        let before_epoch = Timestamp::parse("1969-12-31T23:59:59.999999Z").unwrap();
+        assert_eq!(before_epoch.unix_micros(), -1);
+        assert_eq!(before_epoch.to_string(), "1969-12-31T23:59:59.999999Z");
+        assert_eq!(
+            Timestamp::parse("2024-02-29 00:00:00Z")
+                .unwrap()
+                .to_string(),
+            "2024-02-29T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn malformed_text_is_rejected() {
+        for text in [
+            "",
+            "2026-07-31",
+            "2026-07-31T12:00:00",
+            "2026-13-01T00:00:00Z",
+            "2025-02-29T00:00:00Z",
+            "2026-07-31T24:00:00Z",
+            "2026-07-31T12:00:00.Z",
+            "2026-07-31T12:00:00+0800",
+        ] {
+            assert!(Timestamp::parse(text).is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn timestamps_serialize_as_rfc3339_strings() {
+        let value = Timestamp::parse("2026-07-31T12:00:00Z").unwrap();
+        assert_eq!(
+            Value::from(value),
+            Value::String("2026-07-31T12:00:00Z".into())
+        );
+        assert_eq!(
+            serde_json::from_value::<Timestamp>(serde_json::to_value(value).unwrap()).unwrap(),
+            value
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_date_times_keep_their_offset() {
+        use chrono::{DateTime, FixedOffset, Utc};
+
+        let local = DateTime::parse_from_rfc3339("2026-07-31T20:00:00+08:00").unwrap();
+        let value = Timestamp::from(local);
+        assert_eq!(value.to_string(), "2026-07-31T20:00:00+08:00");
+        assert_eq!(DateTime::<FixedOffset>::try_from(value).unwrap(), local);
+        assert_eq!(
+            DateTime::<Utc>::try_from(value).unwrap(),
+            local.with_timezone(&Utc)
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_offset_date_times_keep_their_offset() {
+        use time::{OffsetDateTime, UtcOffset};
+
+        let local = OffsetDateTime::from_unix_timestamp(1_785_499_200)
+            .unwrap()
+            .to_offset(UtcOffset::from_hms(-5, 0, 0).unwrap());
+        let value = Timestamp::from(local);
+        assert_eq!(value.to_string(), "2026-07-31T07:00:00-05:00");
+        assert_eq!(OffsetDateTime::try_from(value).unwrap(), local);
+    }
+}
@@ -17,14 +17,46 @@
 //! Float16 and bfloat16 conversion utilities.
 //!
 //! Milvus represents both formats as their 16-bit bit patterns in the SDK API
-//! and as little-endian bytes on the protobuf wire.
+//! and as little-endian bytes on the protobuf wire. Conversions from `f32`
+//! round to nearest, ties to even; the `_truncated` variants keep the Milvus
+//! C++ SDK behaviour for callers that must reproduce its bit patterns.
 
 /// Converts an `f32` to an IEEE 754 binary16 bit pattern.
 ///
-/// This intentionally matches the Milvus C++ SDK conversion: the mantissa is
-/// truncated, values below the normal binary16 range become signed zero, and
-/// overflow becomes infinity.
+/// The mantissa is rounded to nearest, ties to even. Values below the normal
+/// binary16 range become subnormals or signed zero, overflow becomes infinity,
+/// and NaN stays NaN.
 pub fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) & 0x8000;
+    let exponent = (bits >> 23) & 0xff;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        let nan = if mantissa == 0 { 0 } else { 0x0200 };
+        return (sign | 0x7c00 | nan | (mantissa >> 13)) as u16;
+    }
+
+    let exponent = exponent as i32 - 127 + 15;
+    if exponent >= 0x1f {
+        return (sign | 0x7c00) as u16;
+    }
+    if exponent <= 0 {
+        if 14 - exponent > 24 {
+            return sign as u16;
+        }
+        let mantissa = mantissa | 0x80_0000;
+        let shift = (14 - exponent) as u32;
+        return (sign | ((mantissa >> shift) + round_up(mantissa, shift))) as u16;
+    }
+    ((sign | ((exponent as u32) << 10) | (mantissa >> 13)) + round_up(mantissa, 13)) as u16
+}
+
+/// Converts an `f32` to an IEEE 754 binary16 bit pattern by truncating its mantissa.
+///
+/// This matches the Milvus C++ SDK conversion: values below the normal
+/// binary16 range become signed zero, and overflow becomes infinity.
+pub fn f32_to_f16_truncated(value: f32) -> u16 {
     let bits = value.to_bits();
     let sign = ((bits >> 31) as u16) << 15;
     let mut exponent = ((bits >> 23) & 0xff) as i32 - 127;
@@ -79,8 +111,17 @@ pub fn f16_to_f32(value: u16) -> f32 {
     }
 }
 
-/// Converts an `f32` to a bfloat16 bit pattern by truncating its low 16 bits.
+/// Converts an `f32` to a bfloat16 bit pattern, rounding to nearest, ties to even.
 pub fn f32_to_bf16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    if value.is_nan() {
+        return ((bits >> 16) | 0x0040) as u16;
+    }
+    ((bits >> 16) + round_up(bits, 16)) as u16
+}
+
+/// Converts an `f32` to a bfloat16 bit pattern by truncating its low 16 bits.
+pub fn f32_to_bf16_truncated(value: f32) -> u16 {
     (value.to_bits() >> 16) as u16
 }
 
@@ -94,6 +135,11 @@ pub fn array_f32_to_f16(values: &[f32]) -> Vec<u16> {
     values.iter().copied().map(f32_to_f16).collect()
 }
 
+/// Converts every value with [`f32_to_f16_truncated`].
+pub fn array_f32_to_f16_truncated(values: &[f32]) -> Vec<u16> {
+    values.iter().copied().map(f32_to_f16_truncated).collect()
+}
+
 /// Performs the array f16 to f32 operation.
 pub fn array_f16_to_f32(values: &[u16]) -> Vec<f32> {
     values.iter().copied().map(f16_to_f32).collect()
@@ -104,11 +150,22 @@ pub fn array_f32_to_bf16(values: &[f32]) -> Vec<u16> {
     values.iter().copied().map(f32_to_bf16).collect()
 }
 
+/// Converts every value with [`f32_to_bf16_truncated`].
+pub fn array_f32_to_bf16_truncated(values: &[f32]) -> Vec<u16> {
+    values.iter().copied().map(f32_to_bf16_truncated).collect()
+}
+
 /// Performs the array bf16 to f32 operation.
 pub fn array_bf16_to_f32(values: &[u16]) -> Vec<f32> {
     values.iter().copied().map(bf16_to_f32).collect()
 }
 
+/// Returns 1 when dropping the low `shift` bits of `bits` rounds up under ties-to-even.
+fn round_up(bits: u32, shift: u32) -> u32 {
+    let half = 1 << (shift - 1);
+    u32::from(bits & half != 0 && bits & (3 * half - 1) != 0)
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Test Cases
 ///////////////////////////////////////////////////////////////////////////////
@@ -118,17 +175,45 @@ mod tests {
     use super::*;
 
     #[test]
-    fn converts_f16_with_cpp_sdk_semantics() {
+    fn converts_f16_with_round_to_nearest_even() {
         assert_eq!(f32_to_f16(0.0), 0x0000);
-        assert_eq!(f32_to_f16(-0.0), 0x0000);
+        assert_eq!(f32_to_f16(-0.0), 0x8000);
         assert_eq!(f32_to_f16(1.0), 0x3c00);
         assert_eq!(f32_to_f16(-1.0), 0xbc00);
         assert_eq!(f32_to_f16(65504.0), 0x7bff);
-        assert_eq!(f32_to_f16(f32::INFINITY), 0x7c00);
+        assert_eq!(f32_to_f16(65519.0), 0x7bff);
+        assert_eq!(f32_to_f16(65520.0), 0x7c00);
         assert_eq!(f32_to_f16(f32::NEG_INFINITY), 0xfc00);
-        assert_eq!(f32_to_f16(f32::NAN), 0x7e00);
-        assert_eq!(f32_to_f16(2.0_f32.powi(-15)), 0x0000);
-        assert_eq!(f32_to_f16(65536.0), 0x7c00);
+        assert!(f16_to_f32(f32_to_f16(f32::NAN)).is_nan());
+
+        // Halfway cases round to the even mantissa.
+        assert_eq!(f32_to_f16(1.0 + 2.0_f32.powi(-11)), 0x3c00);
+        assert_eq!(f32_to_f16(1.0 + 3.0 * 2.0_f32.powi(-11)), 0x3c02);
+        assert_eq!(
+            f32_to_f16(1.0 + 2.0_f32.powi(-11) + 2.0_f32.powi(-20)),
+            0x3c01
+        );
+
+        // Values below the normal range become subnormals.
+        assert_eq!(f32_to_f16(2.0_f32.powi(-15)), 0x0200);
+        assert_eq!(f32_to_f16(2.0_f32.powi(-24)), 0x0001);
+        assert_eq!(f32_to_f16(2.0_f32.powi(-26)), 0x0000);
+        assert_eq!(f16_to_f32(0x0001), 2.0_f32.powi(-24));
+    }
+
+    #[test]
+    fn converts_f16_with_cpp_sdk_semantics() {
+        assert_eq!(f32_to_f16_truncated(0.0), 0x0000);
+        assert_eq!(f32_to_f16_truncated(-0.0), 0x0000);
+        assert_eq!(f32_to_f16_truncated(1.0), 0x3c00);
+        assert_eq!(f32_to_f16_truncated(-1.0), 0xbc00);
+        assert_eq!(f32_to_f16_truncated(65504.0), 0x7bff);
+        assert_eq!(f32_to_f16_truncated(f32::INFINITY), 0x7c00);
+        assert_eq!(f32_to_f16_truncated(f32::NEG_INFINITY), 0xfc00);
+        assert_eq!(f32_to_f16_truncated(f32::NAN), 0x7e00);
+        assert_eq!(f32_to_f16_truncated(2.0_f32.powi(-15)), 0x0000);
+        assert_eq!(f32_to_f16_truncated(65536.0), 0x7c00);
+        assert_eq!(f32_to_f16_truncated(1.0 + 3.0 * 2.0_f32.powi(-11)), 0x3c01);
 
         assert_eq!(f16_to_f32(0x3c00), 1.0);
         assert_eq!(f16_to_f32(0xbc00), -1.0);
@@ -136,12 +221,25 @@ mod tests {
         assert!(f16_to_f32(0x7e00).is_nan());
     }
 
+    #[test]
+    fn converts_bf16_with_round_to_nearest_even() {
+        assert_eq!(f32_to_bf16(1.0), 0x3f80);
+        assert_eq!(f32_to_bf16(1.0 + 2.0_f32.powi(-8)), 0x3f80);
+        assert_eq!(f32_to_bf16(1.0 + 3.0 * 2.0_f32.powi(-8)), 0x3f82);
+        assert_eq!(
+            f32_to_bf16(1.0 + 2.0_f32.powi(-8) + 2.0_f32.powi(-20)),
+            0x3f81
+        );
+        assert_eq!(f32_to_bf16(f32::MAX), 0x7f80);
+        assert!(bf16_to_f32(f32_to_bf16(f32::NAN)).is_nan());
+    }
+
     #[test]
     fn converts_bf16_by_truncating_low_bits() {
         let value = 1.234567_f32;
-        assert_eq!(f32_to_bf16(value), (value.to_bits() >> 16) as u16);
+        assert_eq!(f32_to_bf16_truncated(value), (value.to_bits() >> 16) as u16);
         assert_eq!(
-            bf16_to_f32(f32_to_bf16(value)).to_bits(),
+            bf16_to_f32(f32_to_bf16_truncated(value)).to_bits(),
             value.to_bits() & 0xffff_0000
         );
     }
@@ -154,5 +252,7 @@ mod tests {
 
         let bf16 = array_f32_to_bf16(&values);
         assert_eq!(array_bf16_to_f32(&bf16), values);
+        assert_eq!(array_f32_to_f16_truncated(&[0.1]), vec![0x2e66]);
+        assert_eq!(array_f32_to_bf16_truncated(&[0.1]), vec![0x3dcc]);
     }
 }
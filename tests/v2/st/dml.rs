@@ -16,7 +16,6 @@
 
 use milvus::v2::prelude::*;
 use serde_json::json;
-use std::collections::BTreeMap;
 
 use super::common;
 
@@ -243,7 +242,7 @@ async fn insert_nullable_and_default_values_by_columns() {
         FieldData::nullable(
             FieldData::SparseFloatVector {
                 name: common::SPARSE_VECTOR_FIELD.into(),
-                values: vec![BTreeMap::from([(1, 0.5)])],
+                values: vec![SparseVector::from([(1, 0.5)])],
             },
             vec![true, false],
         )
@@ -677,7 +676,7 @@ async fn insert_and_upsert_array_struct_sparse() {
             },
             FieldData::SparseFloatVector {
                 name: "sparse".into(),
-                values: vec![BTreeMap::from([(2, 0.5), (10, 0.25)])],
+                values: vec![SparseVector::from([(2, 0.5), (10, 0.25)])],
             },
             FieldData::Struct {
                 name: "items".into(),
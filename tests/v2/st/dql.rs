@@ -17,7 +17,7 @@
 use chrono::DateTime;
 use milvus::v2::prelude::*;
 use serde_json::{json, Value};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::HashMap;
 
 use super::common;
 
@@ -775,7 +775,7 @@ async fn search_decodes_advanced_output_fields() {
             SearchRequest::builder()
                 .collection_name(&collection_name)
                 .vector_field(common::SPARSE_VECTOR_FIELD)
-                .vectors(SearchVectors::SparseFloat(vec![BTreeMap::from([
+                .vectors(SearchVectors::SparseFloat(vec![SparseVector::from([
                     (1, 0.5),
                     (7, 0.25),
                 ])]))
@@ -136,6 +136,59 @@ pub(super) fn assert_search_response(response: &SearchResponse) {
     assert_eq!(result.get_output_row(0).unwrap().to_owned(), rows[0]);
 }
 
+#[tokio::test]
+async fn cancelled_requests_fail_before_reaching_the_server() {
+    let server = MockServer::start().await;
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let error = server
+        .client
+        .query(
+            QueryRequest::builder()
+                .collection_name("books")
+                .filter("id > 0")
+                .cancellation_token(token)
+                .build()
+                .expect("valid request"),
+        )
+        .await
+        .expect_err("cancelled query");
+    assert!(matches!(error, Error::Cancelled(_)), "{error:?}");
+    assert_eq!(server.service.call_count("query"), 0);
+
+    server.shutdown().await;
+}
+
+#[tokio::test]
+async fn request_timeout_bounds_retries() {
+    let server = MockServer::start().await;
+    for _ in 0..10 {
+        server
+            .service
+            .fail_next_transport("search", tonic::Code::Unavailable);
+    }
+
+    let error = server
+        .client
+        .search(
+            SearchRequest::builder()
+                .collection_name("books")
+                .vector_field("vector")
+                .vectors(SearchVectors::Float(vec![vec![0.1, 0.2]]))
+                .limit(5)
+                .request_timeout(std::time::Duration::from_millis(50))
+                .build()
+                .expect("valid request"),
+        )
+        .await
+        .expect_err("timed out search");
+    assert!(matches!(error, Error::Timeout(_)), "{error:?}");
+    assert!(server.service.call_count("search") < 10);
+
+    server.shutdown().await;
+}
+
 #[tokio::test]
 async fn dql_interfaces_reach_rpc_server() {
     let server = MockServer::start().await;
@@ -172,6 +172,39 @@ async fn iterator_interfaces_reach_rpc_server() {
     server.shutdown().await;
 }
 
+#[tokio::test]
+async fn iterator_pages_observe_the_request_cancellation_token() {
+    let server = MockServer::start().await;
+    let token = CancellationToken::new();
+
+    let mut query = server
+        .client
+        .query_iterator(
+            QueryIteratorRequest::builder()
+                .query(
+                    QueryRequest::builder()
+                        .collection_name("books")
+                        .filter("id > 0")
+                        .build()
+                        .expect("valid query"),
+                )
+                .batch_size(1)
+                .cancellation_token(token.clone())
+                .build()
+                .expect("valid query iterator"),
+        )
+        .await
+        .expect("create query iterator");
+    let calls = server.service.call_count("query");
+
+    token.cancel();
+    let error = query.next().await.expect_err("cancelled page");
+    assert!(matches!(error, Error::Cancelled(_)), "{error:?}");
+    assert_eq!(server.service.call_count("query"), calls);
+
+    server.shutdown().await;
+}
+
 #[tokio::test]
 async fn search_iterator_v2_lets_the_server_deduce_the_default_metric() {
     let server = MockServer::start().await;